@@ -1,7 +1,7 @@
 //! Main [`Asset`] type
 
 use std::fmt::{Debug, Formatter};
-use std::io::{Read, Seek, SeekFrom, Write};
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
 use std::mem::size_of;
 
 use byteorder::{ReadBytesExt, WriteBytesExt, BE, LE};
@@ -11,6 +11,7 @@ use unreal_asset_base::passthrough_archive_reader;
 use unreal_asset_base::types::PackageIndexTrait;
 use unreal_asset_base::{
     cast,
+    compression::{CompressionMethod, COMPRESSION_FLAGS_TYPE_MASK},
     containers::{Chain, IndexedMap, NameMap, SharedResource},
     crc,
     custom_version::{CustomVersion, CustomVersionTrait},
@@ -29,6 +30,7 @@ use unreal_asset_properties::world_tile_property::FWorldTileInfo;
 
 use crate::asset_archive_writer::AssetArchiveWriter;
 use crate::asset_data::{AssetData, AssetTrait, ExportReaderTrait};
+use crate::compressed_chunk::{self, CompressedChunk, PackageCompression};
 use crate::fengineversion::FEngineVersion;
 use crate::UE4_ASSET_MAGIC;
 
@@ -91,6 +93,10 @@ pub struct UAssetExportMapEntry {
     pub serialization_before_create_dependencies: Vec<PackageIndex>,
     /// Dependencies that should be created before this export is created
     pub create_before_create_dependencies: Vec<PackageIndex>,
+    /// Start of the tagged properties relative to the serial offset
+    pub script_serialization_start_offset: i64,
+    /// End of the tagged properties relative to the serial offset
+    pub script_serialization_end_offset: i64,
 }
 
 impl UAssetExportMapEntry {
@@ -159,6 +165,13 @@ impl UAssetExportMapEntry {
                 Vec::with_capacity(archive.read_i32::<LE>()? as usize);
         }
 
+        if !archive.has_unversioned_properties()
+            && archive.get_object_version_ue5() >= ObjectVersionUE5::SCRIPT_SERIALIZATION_OFFSET
+        {
+            entry.script_serialization_start_offset = archive.read_i64::<LE>()?;
+            entry.script_serialization_end_offset = archive.read_i64::<LE>()?;
+        }
+
         Ok(entry)
     }
 
@@ -247,6 +260,13 @@ impl UAssetExportMapEntry {
             archive.write_i32::<LE>(self.serialization_before_create_dependencies.len() as i32)?;
             archive.write_i32::<LE>(self.create_before_create_dependencies.len() as i32)?;
         }
+
+        if !archive.has_unversioned_properties()
+            && archive.get_object_version_ue5() >= ObjectVersionUE5::SCRIPT_SERIALIZATION_OFFSET
+        {
+            archive.write_i64::<LE>(self.script_serialization_start_offset)?;
+            archive.write_i64::<LE>(self.script_serialization_end_offset)?;
+        }
         Ok(())
     }
 
@@ -277,6 +297,8 @@ impl UAssetExportMapEntry {
             create_before_serialization_dependencies: self.create_before_serialization_dependencies,
             serialization_before_create_dependencies: self.serialization_before_create_dependencies,
             create_before_create_dependencies: self.create_before_create_dependencies,
+            script_serialization_start_offset: self.script_serialization_start_offset,
+            script_serialization_end_offset: self.script_serialization_end_offset,
         }
     }
 
@@ -312,6 +334,8 @@ impl UAssetExportMapEntry {
                 .serialization_before_create_dependencies
                 .clone(),
             create_before_create_dependencies: b.create_before_create_dependencies.clone(),
+            script_serialization_start_offset: b.script_serialization_start_offset,
+            script_serialization_end_offset: b.script_serialization_end_offset,
         }
     }
 }
@@ -340,6 +364,10 @@ struct AssetHeader {
     header_offset: i32,
     /// Bulk data start offset
     bulk_data_start_offset: i64,
+    /// Compression flags
+    compression_flags: u32,
+    /// Compressed chunks
+    compressed_chunks: Vec<CompressedChunk>,
 }
 
 //#[derive(Debug)]
@@ -367,8 +395,11 @@ pub struct Asset<C: Read + Seek> {
     /// Generations
     #[container_ignore]
     pub generations: Vec<GenerationInfo>,
-    /// Asset guid
+    /// Asset guid, only serialized before `ObjectVersionUE5::PACKAGE_SAVED_HASH`
     pub package_guid: Guid,
+    /// Package saved hash, serialized since `ObjectVersionUE5::PACKAGE_SAVED_HASH`
+    #[container_ignore]
+    pub package_saved_hash: [u8; 20],
     /// Recorded engine version
     #[container_ignore]
     pub engine_version_recorded: FEngineVersion,
@@ -403,6 +434,12 @@ pub struct Asset<C: Read + Seek> {
     export_offset: i32,
     /// Imports offset
     import_offset: i32,
+    /// Cell exports offset
+    cell_export_offset: i32,
+    /// Cell imports offset
+    cell_import_offset: i32,
+    /// Metadata offset
+    metadata_offset: i32,
     /// Depends offset
     depends_offset: i32,
     /// Soft package reference count
@@ -415,6 +452,9 @@ pub struct Asset<C: Read + Seek> {
     thumbnail_table_offset: i32,
     /// Compression flags
     compression_flags: u32,
+    /// Package compression, the package is written with compressed chunks when set
+    #[container_ignore]
+    pub package_compression: Option<PackageCompression>,
     /// Asset registry data offset
     asset_registry_data_offset: i32,
     /// Bulk data start offset
@@ -459,6 +499,20 @@ impl<'a, C: Read + Seek> Asset<C> {
         engine_version: EngineVersion,
         mappings: Option<Usmap>,
     ) -> Result<Self, Error> {
+        let mut asset = Asset::new_unparsed(asset_data, bulk_data, engine_version, mappings);
+        asset.parse_data()?;
+        Ok(asset)
+    }
+
+    /// Create an asset without parsing its data
+    ///
+    /// Used when an asset is built from another package format
+    pub(crate) fn new_unparsed(
+        asset_data: C,
+        bulk_data: Option<C>,
+        engine_version: EngineVersion,
+        mappings: Option<Usmap>,
+    ) -> Self {
         let use_event_driven_loader = bulk_data.is_some();
 
         let chain = Chain::new(asset_data, bulk_data);
@@ -481,6 +535,7 @@ impl<'a, C: Read + Seek> Asset<C> {
             legacy_file_version: 0,
             generations: Vec::new(),
             package_guid: Guid::default(),
+            package_saved_hash: [0u8; 20],
             engine_version_recorded: FEngineVersion::unknown(),
             engine_version_compatible: FEngineVersion::unknown(),
             chunk_ids: Vec::new(),
@@ -495,12 +550,16 @@ impl<'a, C: Read + Seek> Asset<C> {
             gatherable_text_data_offset: 0,
             export_offset: 0,
             import_offset: 0,
+            cell_export_offset: 0,
+            cell_import_offset: 0,
+            metadata_offset: 0,
             depends_offset: 0,
             soft_package_reference_count: 0,
             soft_package_reference_offset: 0,
             searchable_names_offset: 0,
             thumbnail_table_offset: 0,
             compression_flags: 0,
+            package_compression: None,
             asset_registry_data_offset: 0,
             bulk_data_start_offset: 0,
             world_tile_info_offset: 0,
@@ -519,8 +578,7 @@ impl<'a, C: Read + Seek> Asset<C> {
         };
         asset.set_engine_version(engine_version);
         asset.asset_data.mappings = mappings;
-        asset.parse_data()?;
-        Ok(asset)
+        asset
     }
 
     /// Set asset engine version
@@ -594,6 +652,10 @@ impl<'a, C: Read + Seek> Asset<C> {
             )?;
         }
 
+        if self.get_object_version_ue5() >= ObjectVersionUE5::PACKAGE_SAVED_HASH {
+            self.raw_reader.read_exact(&mut self.package_saved_hash)?;
+        }
+
         // read header offset
         self.header_offset = self.read_i32::<LE>()?;
 
@@ -626,6 +688,20 @@ impl<'a, C: Read + Seek> Asset<C> {
         self.export_offset = self.read_i32::<LE>()?;
         self.asset_data.summary.import_count = self.read_i32::<LE>()?;
         self.import_offset = self.read_i32::<LE>()?;
+        if self.get_object_version_ue5() >= ObjectVersionUE5::VERSE_CELLS {
+            let cell_export_count = self.read_i32::<LE>()?;
+            self.cell_export_offset = self.read_i32::<LE>()?;
+            let cell_import_count = self.read_i32::<LE>()?;
+            self.cell_import_offset = self.read_i32::<LE>()?;
+            if cell_export_count != 0 || cell_import_count != 0 {
+                return Err(Error::unimplemented(format!(
+                    "Verse cells are not supported, cell export count: {cell_export_count}, cell import count: {cell_import_count}"
+                )));
+            }
+        }
+        if self.get_object_version_ue5() >= ObjectVersionUE5::METADATA_SERIALIZATION_OFFSET {
+            self.metadata_offset = self.read_i32::<LE>()?;
+        }
         self.depends_offset = self.read_i32::<LE>()?;
         if self.asset_data.object_version >= ObjectVersion::VER_UE4_ADD_STRING_ASSET_REFERENCES_MAP
         {
@@ -638,7 +714,9 @@ impl<'a, C: Read + Seek> Asset<C> {
         self.thumbnail_table_offset = self.read_i32::<LE>()?;
 
         // read guid
-        self.package_guid = self.raw_reader.read_guid()?;
+        if self.get_object_version_ue5() < ObjectVersionUE5::PACKAGE_SAVED_HASH {
+            self.package_guid = self.raw_reader.read_guid()?;
+        }
 
         // raed generations
         let generations_count = self.read_i32::<LE>()?;
@@ -668,11 +746,18 @@ impl<'a, C: Read + Seek> Asset<C> {
 
         // read compression data
         self.compression_flags = self.read_u32::<LE>()?;
-        let compression_block_count = self.read_u32::<LE>()?;
+        let compression_block_count = self.read_i32::<LE>()?;
         if compression_block_count > 0 {
-            return Err(Error::invalid_file(
-                "Compression block count is not zero".to_string(),
-            ));
+            // every chunk is 16 bytes, don't preallocate more chunks than the package can hold
+            if compression_block_count as u64 * 16 > self.data_length()? {
+                return Err(Error::invalid_file(format!(
+                    "Invalid compressed chunk count {compression_block_count}"
+                )));
+            }
+            let compressed_chunks = (0..compression_block_count)
+                .map(|_| CompressedChunk::read(self))
+                .collect::<Result<Vec<_>, _>>()?;
+            self.decompress_chunks(&compressed_chunks)?;
         }
 
         self.package_source = self.read_u32::<LE>()?;
@@ -997,6 +1082,84 @@ impl<'a, C: Read + Seek> Asset<C> {
         Ok(())
     }
 
+    /// Decompress package compressed chunks, replacing the package data with the decompressed data
+    fn decompress_chunks(&mut self, compressed_chunks: &[CompressedChunk]) -> Result<(), Error> {
+        let method =
+            CompressionMethod::from_legacy_flags(self.compression_flags).ok_or_else(|| {
+                Error::invalid_file(format!(
+                    "Unknown package compression flags {:#x}",
+                    self.compression_flags
+                ))
+            })?;
+        let position = self.position();
+        let data_length = self.data_length()?;
+
+        // (uncompressed range, compressed range) of every chunk, checked against the package size
+        let ranges = compressed_chunks
+            .iter()
+            .map(|chunk| {
+                let range = |offset: i32, size: i32| {
+                    let offset = usize::try_from(offset).ok()?;
+                    let end = offset.checked_add(usize::try_from(size).ok()?)?;
+                    Some(offset..end)
+                };
+                let uncompressed = range(chunk.uncompressed_offset, chunk.uncompressed_size);
+                let compressed = range(chunk.compressed_offset, chunk.compressed_size)
+                    .filter(|e| e.end as u64 <= data_length);
+
+                uncompressed.zip(compressed).ok_or_else(|| {
+                    Error::invalid_file(format!("Invalid compressed chunk {chunk:?}"))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let summary_size = ranges
+            .iter()
+            .map(|(uncompressed, _)| uncompressed.start)
+            .min()
+            .unwrap_or_default();
+        if summary_size as u64 > data_length {
+            return Err(Error::invalid_file(format!(
+                "Compressed chunks start at {summary_size} but the package is {data_length} bytes"
+            )));
+        }
+        let size = ranges
+            .iter()
+            .map(|(uncompressed, _)| uncompressed.end)
+            .max()
+            .unwrap_or_default();
+
+        let mut data = vec![0u8; size];
+        self.rewind()?;
+        self.raw_reader.read_exact(&mut data[..summary_size])?;
+
+        let mut block_size = PackageCompression::DEFAULT_BLOCK_SIZE;
+        for (uncompressed, compressed_range) in ranges {
+            let mut compressed = vec![0u8; compressed_range.len()];
+            self.seek(SeekFrom::Start(compressed_range.start as u64))?;
+            self.raw_reader.read_exact(&mut compressed)?;
+
+            block_size = compressed_chunk::decompress_chunk(
+                &compressed,
+                method.clone(),
+                &mut data[uncompressed],
+            )?;
+        }
+
+        self.raw_reader.replace_data(data)?;
+        self.seek(SeekFrom::Start(position))?;
+        // the compression method is stored in package_compression from now on
+        self.compression_flags &= !COMPRESSION_FLAGS_TYPE_MASK;
+
+        self.package_compression = Some(PackageCompression {
+            method,
+            level: Default::default(),
+            chunk_size: compressed_chunks[0].uncompressed_size as u32,
+            block_size,
+        });
+        Ok(())
+    }
+
     /// Write asset header
     fn write_header<Writer: ArchiveWriter<impl PackageIndexTrait>>(
         &self,
@@ -1039,6 +1202,10 @@ impl<'a, C: Read + Seek> Asset<C> {
             };
         }
 
+        if self.get_object_version_ue5() >= ObjectVersionUE5::PACKAGE_SAVED_HASH {
+            cursor.write_all(&self.package_saved_hash)?;
+        }
+
         cursor.write_i32::<LE>(asset_header.header_offset)?;
         cursor.write_fstring(Some(&self.folder_name))?;
         cursor.write_u32::<LE>(self.asset_data.summary.package_flags.bits())?;
@@ -1059,6 +1226,18 @@ impl<'a, C: Read + Seek> Asset<C> {
         cursor.write_i32::<LE>(asset_header.export_offset)?;
         cursor.write_i32::<LE>(self.imports.len() as i32)?;
         cursor.write_i32::<LE>(asset_header.import_offset)?;
+
+        if self.get_object_version_ue5() >= ObjectVersionUE5::VERSE_CELLS {
+            cursor.write_i32::<LE>(0)?;
+            cursor.write_i32::<LE>(self.cell_export_offset)?;
+            cursor.write_i32::<LE>(0)?;
+            cursor.write_i32::<LE>(self.cell_import_offset)?;
+        }
+
+        if self.get_object_version_ue5() >= ObjectVersionUE5::METADATA_SERIALIZATION_OFFSET {
+            cursor.write_i32::<LE>(self.metadata_offset)?;
+        }
+
         cursor.write_i32::<LE>(asset_header.depends_offset)?;
 
         if self.asset_data.object_version >= ObjectVersion::VER_UE4_ADD_STRING_ASSET_REFERENCES_MAP
@@ -1072,7 +1251,9 @@ impl<'a, C: Read + Seek> Asset<C> {
         }
 
         cursor.write_i32::<LE>(self.thumbnail_table_offset)?;
-        cursor.write_guid(&self.package_guid)?;
+        if self.get_object_version_ue5() < ObjectVersionUE5::PACKAGE_SAVED_HASH {
+            cursor.write_guid(&self.package_guid)?;
+        }
         cursor.write_i32::<LE>(self.generations.len() as i32)?;
 
         for _ in 0..self.generations.len() {
@@ -1093,8 +1274,11 @@ impl<'a, C: Read + Seek> Asset<C> {
             self.engine_version_recorded.write(cursor)?;
         }
 
-        cursor.write_u32::<LE>(self.compression_flags)?;
-        cursor.write_i32::<LE>(0)?; // numCompressedChunks
+        cursor.write_u32::<LE>(asset_header.compression_flags)?;
+        cursor.write_i32::<LE>(asset_header.compressed_chunks.len() as i32)?;
+        for chunk in &asset_header.compressed_chunks {
+            chunk.write(cursor)?;
+        }
         cursor.write_u32::<LE>(self.package_source)?;
         cursor.write_i32::<LE>(0)?; // numAdditionalPackagesToCook
 
@@ -1171,11 +1355,135 @@ impl<'a, C: Read + Seek> Asset<C> {
     }
 
     /// Write asset data
+    ///
+    /// The package is written with compressed chunks if `package_compression` is set
     pub fn write_data<W: Read + Seek + Write>(
         &self,
         cursor: &mut W,
         uexp_cursor: Option<&mut W>,
     ) -> Result<(), Error> {
+        self.register_export_names()?;
+
+        match self.package_compression {
+            Some(ref compression) => {
+                if uexp_cursor.is_some() {
+                    return Err(Error::invalid_file(
+                        "Packages with compressed chunks can't have a uexp".to_string(),
+                    ));
+                }
+                self.write_compressed(cursor, compression)
+            }
+            None => {
+                self.write_uncompressed(cursor, uexp_cursor, self.compression_flags, Vec::new())?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Add names that exports only create while being serialized to the name map
+    ///
+    /// Exports can add names while writing, e.g. complete property type names or struct field names.
+    /// The name map is written before the exports, so exports are serialized once up front
+    /// to make sure every name they reference is in it.
+    fn register_export_names(&self) -> Result<(), Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        let mut raw_serializer = RawWriter::new(
+            &mut cursor,
+            self.asset_data.object_version,
+            self.asset_data.object_version_ue5,
+            self.asset_data.use_event_driven_loader,
+            self.name_map.clone(),
+        );
+        let mut serializer = AssetArchiveWriter::new(
+            &mut raw_serializer,
+            &self.asset_data,
+            &self.imports,
+            self.name_map.clone(),
+        );
+
+        for export in &self.asset_data.exports {
+            export.write(&mut serializer)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write asset data with compressed chunks
+    fn write_compressed<W: Seek + Write>(
+        &self,
+        cursor: &mut W,
+        compression: &PackageCompression,
+    ) -> Result<(), Error> {
+        let method_flags = compression.method.to_legacy_flags().ok_or_else(|| {
+            Error::unimplemented(format!(
+                "{} can't be used for package compression",
+                compression.method
+            ))
+        })?;
+        let compression_flags =
+            (self.compression_flags & !COMPRESSION_FLAGS_TYPE_MASK) | method_flags;
+        let chunk_size = compression.chunk_size as usize;
+        if chunk_size == 0 || compression.block_size == 0 {
+            return Err(Error::invalid_file(
+                "Package compression chunk and block sizes must not be zero".to_string(),
+            ));
+        }
+
+        // the summary size depends on the chunk count, serialize until the chunk count doesn't change
+        let mut chunk_count = 0;
+        let (data, summary_size) = loop {
+            let mut data = Cursor::new(Vec::new());
+            let summary_size = self.write_uncompressed(
+                &mut data,
+                None,
+                compression_flags,
+                vec![CompressedChunk::default(); chunk_count],
+            )? as usize;
+
+            let data = data.into_inner();
+            let needed_chunk_count = (data.len() - summary_size).div_ceil(chunk_size);
+            if needed_chunk_count == chunk_count {
+                break (data, summary_size);
+            }
+            chunk_count = needed_chunk_count;
+        };
+
+        let mut compressed_chunks = Vec::with_capacity(chunk_count);
+        let mut compressed_data = Vec::with_capacity(chunk_count);
+        let mut compressed_offset = summary_size;
+        for (i, chunk) in data[summary_size..].chunks(chunk_size).enumerate() {
+            let compressed = compressed_chunk::compress_chunk(chunk, compression)?;
+            compressed_chunks.push(CompressedChunk {
+                uncompressed_offset: (summary_size + i * chunk_size) as i32,
+                uncompressed_size: chunk.len() as i32,
+                compressed_offset: compressed_offset as i32,
+                compressed_size: compressed.len() as i32,
+            });
+            compressed_offset += compressed.len();
+            compressed_data.push(compressed);
+        }
+
+        let mut summary = Cursor::new(Vec::new());
+        self.write_uncompressed(&mut summary, None, compression_flags, compressed_chunks)?;
+        let summary = summary.into_inner();
+
+        cursor.write_all(&summary[..summary_size])?;
+        for compressed in compressed_data {
+            cursor.write_all(&compressed)?;
+        }
+        Ok(())
+    }
+
+    /// Write uncompressed asset data
+    ///
+    /// Returns the size of the package file summary
+    fn write_uncompressed<W: Read + Seek + Write>(
+        &self,
+        cursor: &mut W,
+        uexp_cursor: Option<&mut W>,
+        compression_flags: u32,
+        compressed_chunks: Vec<CompressedChunk>,
+    ) -> Result<u64, Error> {
         if self.asset_data.use_event_driven_loader != uexp_cursor.is_some() {
             return Err(Error::no_data(format!(
                 "use_separate_bulk_data_files is {} but uexp_cursor is {}",
@@ -1199,6 +1507,8 @@ impl<'a, C: Read + Seek> Asset<C> {
             preload_dependency_offset: self.preload_dependency_offset,
             header_offset: self.header_offset,
             bulk_data_start_offset: self.bulk_data_start_offset,
+            compression_flags,
+            compressed_chunks,
         };
 
         let mut raw_serializer = RawWriter::new(
@@ -1216,6 +1526,7 @@ impl<'a, C: Read + Seek> Asset<C> {
         );
 
         self.write_header(&mut serializer, &header)?;
+        let summary_size = serializer.position();
 
         let name_offset = match !self.name_map.get_ref().is_empty() {
             true => serializer.position() as i32,
@@ -1448,12 +1759,14 @@ impl<'a, C: Read + Seek> Asset<C> {
             preload_dependency_offset,
             header_offset,
             bulk_data_start_offset,
+            compression_flags,
+            compressed_chunks: header.compressed_chunks,
         };
         self.write_header(&mut serializer, &header)?;
 
         serializer.rewind()?;
 
-        Ok(())
+        Ok(summary_size)
     }
 }
 
@@ -1596,6 +1909,7 @@ impl<C: Read + Seek> Debug for Asset<C> {
             // preload dependencies
             .field("generations", &self.generations)
             .field("package_guid", &self.package_guid)
+            .field("package_saved_hash", &self.package_saved_hash)
             .field("engine_version", &self.asset_data.get_engine_version())
             .field("engine_version_recorded", &self.engine_version_recorded)
             .field("engine_version_compatible", &self.engine_version_compatible)
@@ -1618,6 +1932,9 @@ impl<C: Read + Seek> Debug for Asset<C> {
             )
             .field("export_offset", &self.export_offset)
             .field("import_offset", &self.import_offset)
+            .field("cell_export_offset", &self.cell_export_offset)
+            .field("cell_import_offset", &self.cell_import_offset)
+            .field("metadata_offset", &self.metadata_offset)
             .field("depends_offset", &self.depends_offset)
             .field(
                 "soft_package_reference_count",
@@ -1630,6 +1947,7 @@ impl<C: Read + Seek> Debug for Asset<C> {
             .field("searchable_names_offset", &self.searchable_names_offset)
             .field("thumbnail_table_offset", &self.thumbnail_table_offset)
             .field("compression_flags", &self.compression_flags)
+            .field("package_compression", &self.package_compression)
             .field(
                 "asset_registry_data_offset",
                 &self.asset_registry_data_offset,
@@ -17,7 +17,8 @@ use unreal_asset_base::{
     FNameContainer,
 };
 use unreal_asset_exports::{
-    base_export::BaseExport, class_export::ClassExport, data_table_export::DataTableExport,
+    base_export::BaseExport, class_export::ClassExport, curve_table_export::CurveTableExport,
+    data_table_export::DataTableExport,
     enum_export::EnumExport, function_export::FunctionExport, level_export::LevelExport,
     normal_export::NormalExport, properties::fproperty::FProperty, property_export::PropertyExport,
     raw_export::RawExport, string_table_export::StringTableExport,
@@ -352,6 +353,8 @@ pub trait ExportReaderTrait<Index: PackageIndexTrait>:
                 _ => {
                     if export_class_type.ends_with("DataTable") {
                         DataTableExport::from_base(&base_export, self)?.into()
+                    } else if export_class_type.ends_with("CurveTable") {
+                        CurveTableExport::from_base(&base_export, self)?.into()
                     } else if export_class_type.ends_with("StringTable") {
                         StringTableExport::from_base(&base_export, self)?.into()
                     } else if export_class_type.ends_with("BlueprintGeneratedClass") {
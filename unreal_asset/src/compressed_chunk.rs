@@ -0,0 +1,232 @@
+//! Package-level compressed chunks
+//!
+//! Older UE4 packages can store everything after the package file summary as compressed chunks
+
+use std::io::{Cursor, Read, Write};
+use std::mem::size_of;
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use crate::compression::{self, CompressionLevel, CompressionMethod};
+use crate::error::Error;
+
+/// Tag at the start of every compressed chunk
+const PACKAGE_FILE_TAG: i64 = 0x9e2a83c1;
+/// Compression block size used when the chunk header doesn't store one
+const LOADING_COMPRESSION_CHUNK_SIZE: i64 = 0x20000;
+
+/// Package compressed chunk, stored in the package file summary
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CompressedChunk {
+    /// Offset in the decompressed package
+    pub uncompressed_offset: i32,
+    /// Decompressed size
+    pub uncompressed_size: i32,
+    /// Offset in the package file
+    pub compressed_offset: i32,
+    /// Size in the package file
+    pub compressed_size: i32,
+}
+
+impl CompressedChunk {
+    /// Read a `CompressedChunk` from a reader
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self, Error> {
+        Ok(CompressedChunk {
+            uncompressed_offset: reader.read_i32::<LE>()?,
+            uncompressed_size: reader.read_i32::<LE>()?,
+            compressed_offset: reader.read_i32::<LE>()?,
+            compressed_size: reader.read_i32::<LE>()?,
+        })
+    }
+
+    /// Write a `CompressedChunk` to a writer
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        writer.write_i32::<LE>(self.uncompressed_offset)?;
+        writer.write_i32::<LE>(self.uncompressed_size)?;
+        writer.write_i32::<LE>(self.compressed_offset)?;
+        writer.write_i32::<LE>(self.compressed_size)?;
+        Ok(())
+    }
+}
+
+/// Package compression settings
+///
+/// Set when reading a package with compressed chunks, packages are written with compressed chunks when set
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PackageCompression {
+    /// Compression method, must be representable with legacy compression flags
+    pub method: CompressionMethod,
+    /// Compression level, only used for writing
+    pub level: CompressionLevel,
+    /// Decompressed size of a chunk
+    pub chunk_size: u32,
+    /// Decompressed size of a compression block inside of a chunk
+    pub block_size: u32,
+}
+
+impl PackageCompression {
+    /// Default chunk size
+    pub const DEFAULT_CHUNK_SIZE: u32 = 0x100000;
+    /// Default compression block size
+    pub const DEFAULT_BLOCK_SIZE: u32 = LOADING_COMPRESSION_CHUNK_SIZE as u32;
+
+    /// Create new `PackageCompression` settings with default chunk and block sizes
+    pub fn new(method: CompressionMethod) -> Self {
+        PackageCompression {
+            method,
+            level: CompressionLevel::Default,
+            chunk_size: Self::DEFAULT_CHUNK_SIZE,
+            block_size: Self::DEFAULT_BLOCK_SIZE,
+        }
+    }
+}
+
+/// Compressed block size info
+struct CompressedBlockInfo {
+    /// Compressed size
+    compressed_size: i64,
+    /// Decompressed size
+    uncompressed_size: i64,
+}
+
+impl CompressedBlockInfo {
+    /// Read a `CompressedBlockInfo` from a reader
+    fn read<R: Read>(reader: &mut R) -> Result<Self, Error> {
+        Ok(CompressedBlockInfo {
+            compressed_size: reader.read_i64::<LE>()?,
+            uncompressed_size: reader.read_i64::<LE>()?,
+        })
+    }
+
+    /// Write a `CompressedBlockInfo` to a writer
+    fn write<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        writer.write_i64::<LE>(self.compressed_size)?;
+        writer.write_i64::<LE>(self.uncompressed_size)?;
+        Ok(())
+    }
+}
+
+/// Decompress a compressed chunk
+///
+/// # Arguments
+///
+/// * `compressed` - compressed chunk data
+/// * `method` - compression method
+/// * `decompressed` - output buffer, must be the decompressed size of the chunk
+///
+/// Returns the compression block size the chunk was written with
+pub fn decompress_chunk(
+    compressed: &[u8],
+    method: CompressionMethod,
+    decompressed: &mut [u8],
+) -> Result<u32, Error> {
+    let mut reader = Cursor::new(compressed);
+
+    let tag = CompressedBlockInfo::read(&mut reader)?;
+    if tag.compressed_size != PACKAGE_FILE_TAG {
+        return Err(Error::invalid_file(format!(
+            "Invalid compressed chunk tag {:#x}",
+            tag.compressed_size
+        )));
+    }
+    let block_size = match tag.uncompressed_size {
+        size if size == PACKAGE_FILE_TAG => LOADING_COMPRESSION_CHUNK_SIZE,
+        size if size > 0 => size,
+        size => {
+            return Err(Error::invalid_file(format!(
+                "Invalid compressed chunk block size {size}"
+            )))
+        }
+    };
+
+    let summary = CompressedBlockInfo::read(&mut reader)?;
+    if summary.uncompressed_size != decompressed.len() as i64 {
+        return Err(Error::invalid_file(format!(
+            "Compressed chunk decompresses to {} bytes, expected {}",
+            summary.uncompressed_size,
+            decompressed.len()
+        )));
+    }
+
+    let block_size = u32::try_from(block_size).map_err(|_| {
+        Error::invalid_file(format!("Invalid compressed chunk block size {block_size}"))
+    })?;
+    let block_count = decompressed.len().div_ceil(block_size as usize);
+    let remaining = compressed.len() - reader.position() as usize;
+    if block_count > remaining / size_of::<CompressedBlockInfo>() {
+        return Err(Error::invalid_file(format!(
+            "Compressed chunk has {block_count} blocks but only {remaining} bytes left"
+        )));
+    }
+    let blocks = (0..block_count)
+        .map(|_| CompressedBlockInfo::read(&mut reader))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut offset = 0usize;
+    for block in blocks {
+        let start = reader.position() as usize;
+        let compressed = usize::try_from(block.compressed_size)
+            .ok()
+            .and_then(|size| start.checked_add(size))
+            .and_then(|end| compressed.get(start..end))
+            .ok_or_else(|| {
+                Error::invalid_file(format!(
+                    "Invalid compressed chunk block compressed size {}",
+                    block.compressed_size
+                ))
+            })?;
+        reader.set_position((start + compressed.len()) as u64);
+
+        let output = usize::try_from(block.uncompressed_size)
+            .ok()
+            .and_then(|size| offset.checked_add(size))
+            .and_then(|end| decompressed.get_mut(offset..end))
+            .ok_or_else(|| {
+                Error::invalid_file(format!(
+                    "Invalid compressed chunk block decompressed size {}",
+                    block.uncompressed_size
+                ))
+            })?;
+        offset += output.len();
+        compression::decompress(method.clone(), compressed, output)?;
+    }
+
+    Ok(block_size)
+}
+
+/// Compress data into a compressed chunk
+pub fn compress_chunk(data: &[u8], compression: &PackageCompression) -> Result<Vec<u8>, Error> {
+    let blocks = data
+        .chunks(compression.block_size as usize)
+        .map(|e| compression::compress(compression.method.clone(), e, compression.level))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut writer = Vec::new();
+    CompressedBlockInfo {
+        compressed_size: PACKAGE_FILE_TAG,
+        uncompressed_size: compression.block_size as i64,
+    }
+    .write(&mut writer)?;
+    CompressedBlockInfo {
+        compressed_size: blocks.iter().map(|e| e.len() as i64).sum(),
+        uncompressed_size: data.len() as i64,
+    }
+    .write(&mut writer)?;
+
+    for (block, uncompressed) in blocks
+        .iter()
+        .zip(data.chunks(compression.block_size as usize))
+    {
+        CompressedBlockInfo {
+            compressed_size: block.len() as i64,
+            uncompressed_size: uncompressed.len() as i64,
+        }
+        .write(&mut writer)?;
+    }
+
+    for block in blocks {
+        writer.write_all(&block)?;
+    }
+
+    Ok(writer)
+}
@@ -0,0 +1,103 @@
+//! IoStore chunk id
+
+use std::io::{Read, Write};
+
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use unreal_asset_base::crc;
+
+use crate::error::Error;
+
+/// IoStore chunk type
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, TryFromPrimitive, IntoPrimitive,
+)]
+#[repr(u8)]
+pub enum EIoChunkType {
+    /// Invalid
+    Invalid,
+    /// Export bundle data, contains the package itself
+    ExportBundleData,
+    /// Bulk data
+    BulkData,
+    /// Optional bulk data
+    OptionalBulkData,
+    /// Memory mapped bulk data
+    MemoryMappedBulkData,
+    /// Script objects
+    ScriptObjects,
+    /// Container header
+    ContainerHeader,
+    /// External file
+    ExternalFile,
+    /// Shader code library
+    ShaderCodeLibrary,
+    /// Shader code
+    ShaderCode,
+    /// Package store entry
+    PackageStoreEntry,
+    /// Derived data
+    DerivedData,
+    /// Editor derived data
+    EditorDerivedData,
+    /// Package resource
+    PackageResource,
+}
+
+/// Identifier of a chunk stored in an IoStore container
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct IoChunkId {
+    /// Raw chunk id
+    pub id: [u8; 12],
+}
+
+impl IoChunkId {
+    /// Create a new `IoChunkId`
+    pub fn new(chunk_id: u64, chunk_index: u16, chunk_type: EIoChunkType) -> Self {
+        let mut id = [0u8; 12];
+        id[..8].copy_from_slice(&chunk_id.to_le_bytes());
+        id[8..10].copy_from_slice(&chunk_index.to_be_bytes());
+        id[11] = chunk_type.into();
+        IoChunkId { id }
+    }
+
+    /// Create a new `IoChunkId` for a package name, e.g. `/Game/Maps/Foo`
+    pub fn from_package_name(package_name: &str, chunk_type: EIoChunkType) -> Self {
+        IoChunkId::new(package_id_from_name(package_name), 0, chunk_type)
+    }
+
+    /// Get chunk id value, for package chunks this is the package id
+    pub fn get_chunk_id(&self) -> u64 {
+        let mut value = [0u8; 8];
+        value.copy_from_slice(&self.id[..8]);
+        u64::from_le_bytes(value)
+    }
+
+    /// Get chunk index
+    pub fn get_chunk_index(&self) -> u16 {
+        u16::from_be_bytes([self.id[8], self.id[9]])
+    }
+
+    /// Get chunk type
+    pub fn get_chunk_type(&self) -> Result<EIoChunkType, Error> {
+        Ok(EIoChunkType::try_from(self.id[11])?)
+    }
+
+    /// Read an `IoChunkId` from a reader
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self, Error> {
+        let mut id = [0u8; 12];
+        reader.read_exact(&mut id)?;
+        Ok(IoChunkId { id })
+    }
+
+    /// Write an `IoChunkId` to a writer
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        writer.write_all(&self.id)?;
+        Ok(())
+    }
+}
+
+/// Get a package id from a package name, e.g. `/Game/Maps/Foo`
+pub fn package_id_from_name(package_name: &str) -> u64 {
+    crc::cityhash64_to_lower(package_name)
+}
@@ -0,0 +1,293 @@
+//! IoStore directory index
+
+use std::io::{Cursor, Read, Seek, Write};
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use unreal_helpers::{UnrealReadExt, UnrealWriteExt};
+
+use crate::error::Error;
+
+/// Invalid directory index entry
+pub const INVALID_INDEX: u32 = u32::MAX;
+
+/// Directory index directory entry
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct IoDirectoryIndexEntry {
+    /// Directory name index in the string table
+    pub name: u32,
+    /// First child directory index
+    pub first_child_entry: u32,
+    /// Next sibling directory index
+    pub next_sibling_entry: u32,
+    /// First file index
+    pub first_file_entry: u32,
+}
+
+impl Default for IoDirectoryIndexEntry {
+    fn default() -> Self {
+        IoDirectoryIndexEntry {
+            name: INVALID_INDEX,
+            first_child_entry: INVALID_INDEX,
+            next_sibling_entry: INVALID_INDEX,
+            first_file_entry: INVALID_INDEX,
+        }
+    }
+}
+
+impl IoDirectoryIndexEntry {
+    /// Read an `IoDirectoryIndexEntry` from a reader
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self, Error> {
+        Ok(IoDirectoryIndexEntry {
+            name: reader.read_u32::<LE>()?,
+            first_child_entry: reader.read_u32::<LE>()?,
+            next_sibling_entry: reader.read_u32::<LE>()?,
+            first_file_entry: reader.read_u32::<LE>()?,
+        })
+    }
+
+    /// Write an `IoDirectoryIndexEntry` to a writer
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        writer.write_u32::<LE>(self.name)?;
+        writer.write_u32::<LE>(self.first_child_entry)?;
+        writer.write_u32::<LE>(self.next_sibling_entry)?;
+        writer.write_u32::<LE>(self.first_file_entry)?;
+        Ok(())
+    }
+}
+
+/// Directory index file entry
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct IoFileIndexEntry {
+    /// File name index in the string table
+    pub name: u32,
+    /// Next file index
+    pub next_file_entry: u32,
+    /// User data, this is the toc entry index
+    pub user_data: u32,
+}
+
+impl IoFileIndexEntry {
+    /// Read an `IoFileIndexEntry` from a reader
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self, Error> {
+        Ok(IoFileIndexEntry {
+            name: reader.read_u32::<LE>()?,
+            next_file_entry: reader.read_u32::<LE>()?,
+            user_data: reader.read_u32::<LE>()?,
+        })
+    }
+
+    /// Write an `IoFileIndexEntry` to a writer
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        writer.write_u32::<LE>(self.name)?;
+        writer.write_u32::<LE>(self.next_file_entry)?;
+        writer.write_u32::<LE>(self.user_data)?;
+        Ok(())
+    }
+}
+
+/// IoStore directory index
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct IoDirectoryIndex {
+    /// Mount point
+    pub mount_point: Option<String>,
+    /// Directory entries, first entry is the root directory
+    pub directory_entries: Vec<IoDirectoryIndexEntry>,
+    /// File entries
+    pub file_entries: Vec<IoFileIndexEntry>,
+    /// String table
+    pub string_table: Vec<String>,
+}
+
+impl IoDirectoryIndex {
+    /// Create a new `IoDirectoryIndex` from a list of paths relative to the mount point and their toc entry indices
+    pub fn from_files<'a>(
+        mount_point: Option<String>,
+        files: impl IntoIterator<Item = (&'a str, u32)>,
+    ) -> Self {
+        let mut index = IoDirectoryIndex {
+            mount_point,
+            directory_entries: vec![IoDirectoryIndexEntry::default()],
+            ..Default::default()
+        };
+
+        for (path, user_data) in files {
+            let mut components = path
+                .split('/')
+                .filter(|e| !e.is_empty())
+                .collect::<Vec<_>>();
+            let Some(file_name) = components.pop() else {
+                continue;
+            };
+
+            let mut directory = 0;
+            for component in components {
+                directory = index.get_or_add_child_directory(directory, component);
+            }
+
+            let name = index.get_or_add_string(file_name);
+            let file_index = index.file_entries.len() as u32;
+            index.file_entries.push(IoFileIndexEntry {
+                name,
+                next_file_entry: index.directory_entries[directory].first_file_entry,
+                user_data,
+            });
+            index.directory_entries[directory].first_file_entry = file_index;
+        }
+
+        index
+    }
+
+    /// Get a string table index for a string, adding it if it doesn't exist
+    fn get_or_add_string(&mut self, value: &str) -> u32 {
+        match self.string_table.iter().position(|e| e == value) {
+            Some(index) => index as u32,
+            None => {
+                self.string_table.push(value.to_string());
+                self.string_table.len() as u32 - 1
+            }
+        }
+    }
+
+    /// Get a child directory index by name, adding it if it doesn't exist
+    fn get_or_add_child_directory(&mut self, parent: usize, name: &str) -> usize {
+        let mut child = self.directory_entries[parent].first_child_entry;
+        while child != INVALID_INDEX {
+            let entry = &self.directory_entries[child as usize];
+            if self.string_table[entry.name as usize] == name {
+                return child as usize;
+            }
+            child = entry.next_sibling_entry;
+        }
+
+        let name = self.get_or_add_string(name);
+        let index = self.directory_entries.len();
+        self.directory_entries.push(IoDirectoryIndexEntry {
+            name,
+            next_sibling_entry: self.directory_entries[parent].first_child_entry,
+            ..Default::default()
+        });
+        self.directory_entries[parent].first_child_entry = index as u32;
+        index
+    }
+
+    /// Get all files in this directory index as paths relative to the mount point and their toc entry indices
+    pub fn get_files(&self) -> Result<Vec<(String, u32)>, Error> {
+        let mut files = Vec::new();
+        if !self.directory_entries.is_empty() {
+            self.collect_files(0, String::new(), &mut files)?;
+        }
+        Ok(files)
+    }
+
+    /// Recursively collect files from a directory
+    fn collect_files(
+        &self,
+        directory: u32,
+        path: String,
+        files: &mut Vec<(String, u32)>,
+    ) -> Result<(), Error> {
+        let entry = self.get_directory_entry(directory)?;
+
+        let mut file = entry.first_file_entry;
+        while file != INVALID_INDEX {
+            let file_entry = self.file_entries.get(file as usize).ok_or_else(|| {
+                Error::invalid_file(format!("Invalid directory index file entry {file}"))
+            })?;
+            files.push((
+                path.clone() + self.get_string(file_entry.name)?,
+                file_entry.user_data,
+            ));
+            file = file_entry.next_file_entry;
+        }
+
+        let mut child = entry.first_child_entry;
+        while child != INVALID_INDEX {
+            let child_entry = self.get_directory_entry(child)?;
+            let child_path = path.clone() + self.get_string(child_entry.name)? + "/";
+            self.collect_files(child, child_path, files)?;
+            child = child_entry.next_sibling_entry;
+        }
+
+        Ok(())
+    }
+
+    /// Get a directory entry
+    fn get_directory_entry(&self, index: u32) -> Result<&IoDirectoryIndexEntry, Error> {
+        self.directory_entries.get(index as usize).ok_or_else(|| {
+            Error::invalid_file(format!("Invalid directory index directory entry {index}"))
+        })
+    }
+
+    /// Get a string from the string table
+    fn get_string(&self, index: u32) -> Result<&str, Error> {
+        self.string_table
+            .get(index as usize)
+            .map(|e| e.as_str())
+            .ok_or_else(|| Error::invalid_file(format!("Invalid directory index string {index}")))
+    }
+
+    /// Read an `IoDirectoryIndex` from a reader
+    pub fn read<R: Read + Seek>(reader: &mut R) -> Result<Self, Error> {
+        let mount_point = reader.read_fstring()?;
+
+        let directory_entries_count = reader.read_i32::<LE>()?;
+        let mut directory_entries = Vec::with_capacity(directory_entries_count as usize);
+        for _ in 0..directory_entries_count {
+            directory_entries.push(IoDirectoryIndexEntry::read(reader)?);
+        }
+
+        let file_entries_count = reader.read_i32::<LE>()?;
+        let mut file_entries = Vec::with_capacity(file_entries_count as usize);
+        for _ in 0..file_entries_count {
+            file_entries.push(IoFileIndexEntry::read(reader)?);
+        }
+
+        let string_table_count = reader.read_i32::<LE>()?;
+        let mut string_table = Vec::with_capacity(string_table_count as usize);
+        for _ in 0..string_table_count {
+            string_table.push(reader.read_fstring()?.unwrap_or_default());
+        }
+
+        Ok(IoDirectoryIndex {
+            mount_point,
+            directory_entries,
+            file_entries,
+            string_table,
+        })
+    }
+
+    /// Read an `IoDirectoryIndex` from a buffer
+    pub fn from_buffer(buffer: &[u8]) -> Result<Self, Error> {
+        IoDirectoryIndex::read(&mut Cursor::new(buffer))
+    }
+
+    /// Write an `IoDirectoryIndex` to a writer
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        writer.write_fstring(self.mount_point.as_deref())?;
+
+        writer.write_i32::<LE>(self.directory_entries.len() as i32)?;
+        for entry in &self.directory_entries {
+            entry.write(writer)?;
+        }
+
+        writer.write_i32::<LE>(self.file_entries.len() as i32)?;
+        for entry in &self.file_entries {
+            entry.write(writer)?;
+        }
+
+        writer.write_i32::<LE>(self.string_table.len() as i32)?;
+        for string in &self.string_table {
+            writer.write_fstring(Some(string))?;
+        }
+
+        Ok(())
+    }
+
+    /// Write an `IoDirectoryIndex` to a buffer
+    pub fn to_buffer(&self) -> Result<Vec<u8>, Error> {
+        let mut buffer = Vec::new();
+        self.write(&mut buffer)?;
+        Ok(buffer)
+    }
+}
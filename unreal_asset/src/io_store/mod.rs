@@ -0,0 +1,329 @@
+//! IoStore (.utoc/.ucas) container reading
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+use crate::compression::{self, CompressionMethod};
+use crate::encryption::{AesKey, EncryptionKeys, AES_BLOCK_SIZE};
+use crate::error::{Error, IoStoreError};
+
+pub mod chunk_id;
+pub mod directory_index;
+pub mod toc;
+
+pub use chunk_id::{EIoChunkType, IoChunkId};
+pub use directory_index::IoDirectoryIndex;
+pub use toc::{EIoContainerFlags, EIoStoreTocVersion, IoStoreToc};
+
+/// Normalize a container path for lookups
+///
+/// Strips leading `../` and `/` so that both mount point relative and absolute paths can be used
+pub(crate) fn normalize_path(path: &str) -> &str {
+    let mut path = path;
+    loop {
+        if let Some(stripped) = path.strip_prefix("../") {
+            path = stripped;
+        } else if let Some(stripped) = path.strip_prefix('/') {
+            path = stripped;
+        } else {
+            return path;
+        }
+    }
+}
+
+/// IoStore container reader
+#[derive(Debug)]
+pub struct IoStoreReader<R: Read + Seek> {
+    /// Table of contents
+    pub toc: IoStoreToc,
+    /// Directory index
+    pub directory_index: Option<IoDirectoryIndex>,
+    /// .ucas partitions
+    partitions: Vec<R>,
+    /// Keys used for decrypting encrypted containers
+    encryption_keys: EncryptionKeys,
+    /// Chunk id to toc entry index lookup
+    chunk_lookup: HashMap<IoChunkId, usize>,
+    /// Normalized path to toc entry index lookup
+    file_lookup: HashMap<String, usize>,
+}
+
+impl IoStoreReader<File> {
+    /// Open an IoStore container from a .utoc path, .ucas partitions are expected to be next to it
+    ///
+    /// # Arguments
+    ///
+    /// * `toc_path` - .utoc file path
+    /// * `encryption_keys` - keys for encrypted containers
+    pub fn open<P: AsRef<Path>>(
+        toc_path: P,
+        encryption_keys: Option<EncryptionKeys>,
+    ) -> Result<Self, Error> {
+        let toc_path = toc_path.as_ref();
+        let toc = IoStoreToc::read(&mut std::io::Cursor::new(std::fs::read(toc_path)?))?;
+
+        let mut partitions = Vec::with_capacity(toc.header.partition_count as usize);
+        for i in 0..toc.header.partition_count.max(1) {
+            let partition_path = match i {
+                0 => toc_path.with_extension("ucas"),
+                _ => {
+                    let stem = toc_path
+                        .file_stem()
+                        .map(|e| e.to_string_lossy().to_string())
+                        .unwrap_or_default();
+                    toc_path.with_file_name(format!("{stem}_s{i}.ucas"))
+                }
+            };
+            partitions.push(File::open(partition_path)?);
+        }
+
+        IoStoreReader::from_toc(toc, partitions, encryption_keys)
+    }
+}
+
+impl<R: Read + Seek> IoStoreReader<R> {
+    /// Create a new `IoStoreReader` from a .utoc reader and .ucas partition readers
+    ///
+    /// # Arguments
+    ///
+    /// * `toc` - .utoc reader
+    /// * `partitions` - .ucas partition readers
+    /// * `encryption_keys` - keys for encrypted containers, the key is looked up by the toc's encryption key guid
+    pub fn new<T: Read + Seek>(
+        toc: &mut T,
+        partitions: Vec<R>,
+        encryption_keys: Option<EncryptionKeys>,
+    ) -> Result<Self, Error> {
+        IoStoreReader::from_toc(IoStoreToc::read(toc)?, partitions, encryption_keys)
+    }
+
+    /// Create a new `IoStoreReader` from an already parsed toc and .ucas partition readers
+    pub fn from_toc(
+        toc: IoStoreToc,
+        partitions: Vec<R>,
+        encryption_keys: Option<EncryptionKeys>,
+    ) -> Result<Self, Error> {
+        let chunk_lookup = toc
+            .chunk_ids
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (*e, i))
+            .collect();
+
+        let mut reader = IoStoreReader {
+            toc,
+            directory_index: None,
+            partitions,
+            encryption_keys: encryption_keys.unwrap_or_default(),
+            chunk_lookup,
+            file_lookup: HashMap::new(),
+        };
+        reader.load_directory_index()?;
+
+        Ok(reader)
+    }
+
+    /// Check if this container is encrypted
+    pub fn is_encrypted(&self) -> bool {
+        self.toc
+            .header
+            .container_flags
+            .contains(EIoContainerFlags::ENCRYPTED)
+    }
+
+    /// Get the key this container is encrypted with
+    fn get_encryption_key(&self) -> Result<&AesKey, Error> {
+        self.encryption_keys
+            .get_key(&self.toc.header.encryption_key_guid)
+            .ok_or_else(|| IoStoreError::NoEncryptionKey.into())
+    }
+
+    /// Parse the directory index and build the file lookup
+    fn load_directory_index(&mut self) -> Result<(), Error> {
+        if self.toc.directory_index_buffer.is_empty() {
+            return Ok(());
+        }
+
+        let directory_index = match self.is_encrypted() {
+            true => {
+                let mut buffer = self.toc.directory_index_buffer.clone();
+                self.get_encryption_key()?.decrypt(&mut buffer)?;
+                IoDirectoryIndex::from_buffer(&buffer)?
+            }
+            false => IoDirectoryIndex::from_buffer(&self.toc.directory_index_buffer)?,
+        };
+        let mount_point = directory_index.mount_point.clone().unwrap_or_default();
+
+        self.file_lookup.clear();
+        for (path, toc_entry_index) in directory_index.get_files()? {
+            let path = mount_point.clone() + &path;
+            self.file_lookup
+                .insert(normalize_path(&path).to_string(), toc_entry_index as usize);
+        }
+
+        self.directory_index = Some(directory_index);
+        Ok(())
+    }
+
+    /// Get container mount point
+    pub fn get_mount_point(&self) -> Option<&str> {
+        self.directory_index
+            .as_ref()
+            .and_then(|e| e.mount_point.as_deref())
+    }
+
+    /// Get all file paths in this container
+    pub fn get_file_names(&self) -> impl Iterator<Item = &str> {
+        self.file_lookup.keys().map(|e| e.as_str())
+    }
+
+    /// Check if this container has a file
+    pub fn has_file(&self, path: &str) -> bool {
+        self.file_lookup.contains_key(normalize_path(path))
+    }
+
+    /// Check if this container has a chunk
+    pub fn has_chunk(&self, chunk_id: &IoChunkId) -> bool {
+        self.chunk_lookup.contains_key(chunk_id)
+    }
+
+    /// Get chunk id of a file
+    pub fn get_chunk_id(&self, path: &str) -> Option<IoChunkId> {
+        self.file_lookup
+            .get(normalize_path(path))
+            .map(|e| self.toc.chunk_ids[*e])
+    }
+
+    /// Read a file by path
+    pub fn read_file(&mut self, path: &str) -> Result<Vec<u8>, Error> {
+        let toc_entry_index = *self
+            .file_lookup
+            .get(normalize_path(path))
+            .ok_or_else(|| IoStoreError::NoFile(path.to_string().into_boxed_str()))?;
+        self.read_toc_entry(toc_entry_index)
+    }
+
+    /// Read a chunk by its id
+    pub fn read_chunk(&mut self, chunk_id: &IoChunkId) -> Result<Vec<u8>, Error> {
+        let toc_entry_index = *self
+            .chunk_lookup
+            .get(chunk_id)
+            .ok_or_else(|| IoStoreError::NoFile(format!("{chunk_id:?}").into_boxed_str()))?;
+        self.read_toc_entry(toc_entry_index)
+    }
+
+    /// Read a package's export bundle data by package name, e.g. `/Game/Maps/Foo`
+    pub fn read_package(&mut self, package_name: &str) -> Result<Vec<u8>, Error> {
+        self.read_chunk(&IoChunkId::from_package_name(
+            package_name,
+            EIoChunkType::ExportBundleData,
+        ))
+    }
+
+    /// Read a toc entry
+    fn read_toc_entry(&mut self, toc_entry_index: usize) -> Result<Vec<u8>, Error> {
+        let offset_length = *self
+            .toc
+            .chunk_offset_lengths
+            .get(toc_entry_index)
+            .ok_or_else(|| Error::invalid_file(format!("Invalid toc entry {toc_entry_index}")))?;
+
+        let mut data = Vec::with_capacity(offset_length.length as usize);
+        if offset_length.length == 0 {
+            return Ok(data);
+        }
+
+        let block_size = self.toc.header.compression_block_size as u64;
+        if block_size == 0 {
+            return Err(Error::invalid_file(
+                "IoStore container has a compression block size of 0".to_string(),
+            ));
+        }
+
+        let first_block = offset_length.offset / block_size;
+        let last_block = (offset_length.offset + offset_length.length - 1) / block_size;
+
+        let mut offset_in_block = (offset_length.offset % block_size) as usize;
+        let mut remaining = offset_length.length as usize;
+
+        for block_index in first_block..=last_block {
+            let block = self.read_block(block_index as usize)?;
+            let to_copy = remaining.min(block.len().saturating_sub(offset_in_block));
+            data.extend_from_slice(&block[offset_in_block..offset_in_block + to_copy]);
+
+            remaining -= to_copy;
+            offset_in_block = 0;
+        }
+
+        Ok(data)
+    }
+
+    /// Read and decompress a compression block
+    fn read_block(&mut self, block_index: usize) -> Result<Vec<u8>, Error> {
+        let block = *self
+            .toc
+            .compression_blocks
+            .get(block_index)
+            .ok_or_else(|| {
+                Error::invalid_file(format!("Invalid compression block {block_index}"))
+            })?;
+
+        let key = match self.is_encrypted() {
+            true => Some(
+                self.encryption_keys
+                    .get_key(&self.toc.header.encryption_key_guid)
+                    .ok_or(IoStoreError::NoEncryptionKey)?,
+            ),
+            false => None,
+        };
+
+        let partition_size = self.toc.header.partition_size;
+        let (partition_index, partition_offset) = match partition_size {
+            0 | u64::MAX => (0, block.offset),
+            _ => (
+                (block.offset / partition_size) as usize,
+                block.offset % partition_size,
+            ),
+        };
+
+        let partition = self.partitions.get_mut(partition_index).ok_or_else(|| {
+            Error::invalid_file(format!("Missing .ucas partition {partition_index}"))
+        })?;
+
+        // encrypted blocks are padded to the aes block size
+        let mut compressed = match key {
+            Some(_) => vec![0u8; (block.compressed_size as usize).next_multiple_of(AES_BLOCK_SIZE)],
+            None => vec![0u8; block.compressed_size as usize],
+        };
+        partition.seek(SeekFrom::Start(partition_offset))?;
+        partition.read_exact(&mut compressed)?;
+
+        if let Some(key) = key {
+            key.decrypt(&mut compressed)?;
+            compressed.truncate(block.compressed_size as usize);
+        }
+
+        let method = match block.compression_method_index {
+            0 => CompressionMethod::None,
+            index => self
+                .toc
+                .compression_methods
+                .get(index as usize - 1)
+                .cloned()
+                .ok_or_else(|| {
+                    Error::invalid_file(format!("Invalid compression method index {index}"))
+                })?,
+        };
+
+        if method == CompressionMethod::None {
+            compressed.truncate(block.uncompressed_size as usize);
+            return Ok(compressed);
+        }
+
+        let mut decompressed = vec![0u8; block.uncompressed_size as usize];
+        compression::decompress(method, &compressed, &mut decompressed)?;
+        Ok(decompressed)
+    }
+}
@@ -0,0 +1,496 @@
+//! IoStore table of contents (.utoc)
+
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+
+use bitflags::bitflags;
+use byteorder::{ReadBytesExt, WriteBytesExt, BE, LE};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use unreal_helpers::{Guid, UnrealReadExt};
+
+use crate::compression::CompressionMethod;
+use crate::error::{Error, IoStoreError};
+
+use super::chunk_id::IoChunkId;
+
+/// .utoc magic
+pub const TOC_MAGIC: [u8; 16] = *b"-==--==--==--==-";
+
+/// IoStore toc version
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, TryFromPrimitive, IntoPrimitive,
+)]
+#[repr(u8)]
+pub enum EIoStoreTocVersion {
+    /// Invalid
+    Invalid,
+    /// Initial
+    Initial,
+    /// Directory index
+    DirectoryIndex,
+    /// Partition size
+    PartitionSize,
+    /// Perfect hash
+    PerfectHash,
+    /// Perfect hash with overflow
+    PerfectHashWithOverflow,
+    /// On demand metadata
+    OnDemandMetaData,
+    /// Removed on demand metadata
+    RemovedOnDemandMetaData,
+    /// Replace IoChunkHash with IoHash
+    ReplaceIoChunkHashWithIoHash,
+
+    /// Latest plus one
+    LatestPlusOne,
+}
+
+bitflags! {
+    /// IoStore container flags
+    #[derive(Debug, Copy, Clone, Default, Hash, PartialEq, Eq)]
+    pub struct EIoContainerFlags : u8 {
+        /// None
+        const NONE = 0;
+        /// Compressed
+        const COMPRESSED = 1;
+        /// Encrypted
+        const ENCRYPTED = 2;
+        /// Signed
+        const SIGNED = 4;
+        /// Indexed, has a directory index
+        const INDEXED = 8;
+        /// On demand
+        const ON_DEMAND = 16;
+    }
+}
+
+/// .utoc header
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IoStoreTocHeader {
+    /// Toc version
+    pub version: EIoStoreTocVersion,
+    /// Toc entry count
+    pub toc_entry_count: u32,
+    /// Compressed block entry count
+    pub toc_compressed_block_entry_count: u32,
+    /// Compressed block entry size
+    pub toc_compressed_block_entry_size: u32,
+    /// Compression method name count
+    pub compression_method_name_count: u32,
+    /// Compression method name length
+    pub compression_method_name_length: u32,
+    /// Compression block size
+    pub compression_block_size: u32,
+    /// Directory index size
+    pub directory_index_size: u32,
+    /// Partition count
+    pub partition_count: u32,
+    /// Container id
+    pub container_id: u64,
+    /// Encryption key guid
+    pub encryption_key_guid: Guid,
+    /// Container flags
+    pub container_flags: EIoContainerFlags,
+    /// Perfect hash seeds count
+    pub toc_chunk_perfect_hash_seeds_count: u32,
+    /// Partition size
+    pub partition_size: u64,
+    /// Chunks without perfect hash count
+    pub toc_chunks_without_perfect_hash_count: u32,
+}
+
+impl IoStoreTocHeader {
+    /// Serialized header size
+    pub const SIZE: u32 = 144;
+    /// Serialized compressed block entry size
+    pub const COMPRESSED_BLOCK_ENTRY_SIZE: u32 = 12;
+    /// Serialized compression method name length
+    pub const COMPRESSION_METHOD_NAME_LENGTH: u32 = 32;
+
+    /// Read an `IoStoreTocHeader` from a reader
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self, Error> {
+        let mut magic = [0u8; 16];
+        reader.read_exact(&mut magic)?;
+        if magic != TOC_MAGIC {
+            return Err(IoStoreError::InvalidTocMagic(magic).into());
+        }
+
+        let version = EIoStoreTocVersion::try_from(reader.read_u8()?)?;
+        let _reserved0 = reader.read_u8()?;
+        let _reserved1 = reader.read_u16::<LE>()?;
+
+        let toc_header_size = reader.read_u32::<LE>()?;
+        if toc_header_size != Self::SIZE {
+            return Err(IoStoreError::invalid_toc_header_size(Self::SIZE, toc_header_size).into());
+        }
+
+        let toc_entry_count = reader.read_u32::<LE>()?;
+        let toc_compressed_block_entry_count = reader.read_u32::<LE>()?;
+        let toc_compressed_block_entry_size = reader.read_u32::<LE>()?;
+        let compression_method_name_count = reader.read_u32::<LE>()?;
+        let compression_method_name_length = reader.read_u32::<LE>()?;
+        let compression_block_size = reader.read_u32::<LE>()?;
+        let directory_index_size = reader.read_u32::<LE>()?;
+        let mut partition_count = reader.read_u32::<LE>()?;
+        let container_id = reader.read_u64::<LE>()?;
+
+        let mut encryption_key_guid = [0u8; 16];
+        reader.read_exact(&mut encryption_key_guid)?;
+
+        let container_flags = EIoContainerFlags::from_bits_retain(reader.read_u8()?);
+        let _reserved3 = reader.read_u8()?;
+        let _reserved4 = reader.read_u16::<LE>()?;
+        let toc_chunk_perfect_hash_seeds_count = reader.read_u32::<LE>()?;
+        let mut partition_size = reader.read_u64::<LE>()?;
+        let toc_chunks_without_perfect_hash_count = reader.read_u32::<LE>()?;
+        let _reserved7 = reader.read_u32::<LE>()?;
+        for _ in 0..5 {
+            let _reserved8 = reader.read_u64::<LE>()?;
+        }
+
+        if version < EIoStoreTocVersion::PartitionSize {
+            partition_count = 1;
+            partition_size = u64::MAX;
+        }
+
+        Ok(IoStoreTocHeader {
+            version,
+            toc_entry_count,
+            toc_compressed_block_entry_count,
+            toc_compressed_block_entry_size,
+            compression_method_name_count,
+            compression_method_name_length,
+            compression_block_size,
+            directory_index_size,
+            partition_count,
+            container_id,
+            encryption_key_guid: Guid(encryption_key_guid),
+            container_flags,
+            toc_chunk_perfect_hash_seeds_count,
+            partition_size,
+            toc_chunks_without_perfect_hash_count,
+        })
+    }
+
+    /// Write an `IoStoreTocHeader` to a writer
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        writer.write_all(&TOC_MAGIC)?;
+        writer.write_u8(self.version.into())?;
+        writer.write_u8(0)?;
+        writer.write_u16::<LE>(0)?;
+        writer.write_u32::<LE>(Self::SIZE)?;
+        writer.write_u32::<LE>(self.toc_entry_count)?;
+        writer.write_u32::<LE>(self.toc_compressed_block_entry_count)?;
+        writer.write_u32::<LE>(self.toc_compressed_block_entry_size)?;
+        writer.write_u32::<LE>(self.compression_method_name_count)?;
+        writer.write_u32::<LE>(self.compression_method_name_length)?;
+        writer.write_u32::<LE>(self.compression_block_size)?;
+        writer.write_u32::<LE>(self.directory_index_size)?;
+        writer.write_u32::<LE>(self.partition_count)?;
+        writer.write_u64::<LE>(self.container_id)?;
+        writer.write_all(&self.encryption_key_guid.0)?;
+        writer.write_u8(self.container_flags.bits())?;
+        writer.write_u8(0)?;
+        writer.write_u16::<LE>(0)?;
+        writer.write_u32::<LE>(self.toc_chunk_perfect_hash_seeds_count)?;
+        writer.write_u64::<LE>(self.partition_size)?;
+        writer.write_u32::<LE>(self.toc_chunks_without_perfect_hash_count)?;
+        writer.write_u32::<LE>(0)?;
+        writer.write_all(&[0u8; 5 * 8])?;
+        Ok(())
+    }
+}
+
+/// Chunk offset and length in the uncompressed container address space
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct IoOffsetAndLength {
+    /// Offset
+    pub offset: u64,
+    /// Length
+    pub length: u64,
+}
+
+impl IoOffsetAndLength {
+    /// Read an `IoOffsetAndLength` from a reader
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self, Error> {
+        Ok(IoOffsetAndLength {
+            offset: reader.read_uint::<BE>(5)?,
+            length: reader.read_uint::<BE>(5)?,
+        })
+    }
+
+    /// Write an `IoOffsetAndLength` to a writer
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        writer.write_uint::<BE>(self.offset, 5)?;
+        writer.write_uint::<BE>(self.length, 5)?;
+        Ok(())
+    }
+}
+
+/// Compressed block entry
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct IoStoreTocCompressedBlockEntry {
+    /// Offset in the .ucas partitions
+    pub offset: u64,
+    /// Compressed size
+    pub compressed_size: u32,
+    /// Uncompressed size
+    pub uncompressed_size: u32,
+    /// Compression method index, 0 means no compression
+    pub compression_method_index: u8,
+}
+
+impl IoStoreTocCompressedBlockEntry {
+    /// Read an `IoStoreTocCompressedBlockEntry` from a reader
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self, Error> {
+        Ok(IoStoreTocCompressedBlockEntry {
+            offset: reader.read_uint::<LE>(5)?,
+            compressed_size: reader.read_u24::<LE>()?,
+            uncompressed_size: reader.read_u24::<LE>()?,
+            compression_method_index: reader.read_u8()?,
+        })
+    }
+
+    /// Write an `IoStoreTocCompressedBlockEntry` to a writer
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        writer.write_uint::<LE>(self.offset, 5)?;
+        writer.write_u24::<LE>(self.compressed_size)?;
+        writer.write_u24::<LE>(self.uncompressed_size)?;
+        writer.write_u8(self.compression_method_index)?;
+        Ok(())
+    }
+}
+
+/// Toc signatures, present in signed containers
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct IoStoreTocSignatures {
+    /// Toc signature
+    pub toc_signature: Vec<u8>,
+    /// Block signature
+    pub block_signature: Vec<u8>,
+    /// Compressed block SHA1 hashes
+    pub chunk_block_signatures: Vec<[u8; 20]>,
+}
+
+/// Toc entry meta
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct IoStoreTocEntryMeta {
+    /// Chunk hash, only the first 20 bytes are used since `ReplaceIoChunkHashWithIoHash`
+    pub chunk_hash: [u8; 32],
+    /// Meta flags
+    pub flags: u8,
+}
+
+/// IoStore table of contents
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IoStoreToc {
+    /// Header
+    pub header: IoStoreTocHeader,
+    /// Chunk ids
+    pub chunk_ids: Vec<IoChunkId>,
+    /// Chunk offsets and lengths
+    pub chunk_offset_lengths: Vec<IoOffsetAndLength>,
+    /// Chunk perfect hash seeds
+    pub chunk_perfect_hash_seeds: Vec<i32>,
+    /// Indices of chunks without a perfect hash
+    pub chunk_indices_without_perfect_hash: Vec<i32>,
+    /// Compression blocks
+    pub compression_blocks: Vec<IoStoreTocCompressedBlockEntry>,
+    /// Compression methods, compression method index 1 is the first entry
+    pub compression_methods: Vec<CompressionMethod>,
+    /// Signatures
+    pub signatures: Option<IoStoreTocSignatures>,
+    /// Serialized directory index, may be encrypted
+    pub directory_index_buffer: Vec<u8>,
+    /// Chunk metas
+    pub chunk_metas: Vec<IoStoreTocEntryMeta>,
+}
+
+impl IoStoreToc {
+    /// Read an `IoStoreToc` from a reader
+    pub fn read<R: Read + Seek>(reader: &mut R) -> Result<Self, Error> {
+        let header = IoStoreTocHeader::read(reader)?;
+
+        let entry_count = header.toc_entry_count as usize;
+
+        let mut chunk_ids = Vec::with_capacity(entry_count);
+        for _ in 0..entry_count {
+            chunk_ids.push(IoChunkId::read(reader)?);
+        }
+
+        let mut chunk_offset_lengths = Vec::with_capacity(entry_count);
+        for _ in 0..entry_count {
+            chunk_offset_lengths.push(IoOffsetAndLength::read(reader)?);
+        }
+
+        let mut chunk_perfect_hash_seeds = Vec::new();
+        if header.version >= EIoStoreTocVersion::PerfectHash {
+            for _ in 0..header.toc_chunk_perfect_hash_seeds_count {
+                chunk_perfect_hash_seeds.push(reader.read_i32::<LE>()?);
+            }
+        }
+
+        let mut chunk_indices_without_perfect_hash = Vec::new();
+        if header.version >= EIoStoreTocVersion::PerfectHashWithOverflow {
+            for _ in 0..header.toc_chunks_without_perfect_hash_count {
+                chunk_indices_without_perfect_hash.push(reader.read_i32::<LE>()?);
+            }
+        }
+
+        let mut compression_blocks =
+            Vec::with_capacity(header.toc_compressed_block_entry_count as usize);
+        for _ in 0..header.toc_compressed_block_entry_count {
+            let start = reader.stream_position()?;
+            compression_blocks.push(IoStoreTocCompressedBlockEntry::read(reader)?);
+            reader.seek(SeekFrom::Start(
+                start + header.toc_compressed_block_entry_size as u64,
+            ))?;
+        }
+
+        let mut compression_methods =
+            Vec::with_capacity(header.compression_method_name_count as usize);
+        for _ in 0..header.compression_method_name_count {
+            let name = reader.read_vec(header.compression_method_name_length as usize)?;
+            let length = name.iter().position(|e| *e == 0).unwrap_or(name.len());
+            let name = String::from_utf8(name[..length].to_vec())?;
+            compression_methods.push(CompressionMethod::new(&name));
+        }
+
+        let signatures = match header.container_flags.contains(EIoContainerFlags::SIGNED) {
+            true => {
+                let hash_size = reader.read_i32::<LE>()?;
+                let toc_signature = reader.read_vec(hash_size as usize)?;
+                let block_signature = reader.read_vec(hash_size as usize)?;
+
+                let mut chunk_block_signatures = Vec::with_capacity(compression_blocks.len());
+                for _ in 0..compression_blocks.len() {
+                    let mut hash = [0u8; 20];
+                    reader.read_exact(&mut hash)?;
+                    chunk_block_signatures.push(hash);
+                }
+
+                Some(IoStoreTocSignatures {
+                    toc_signature,
+                    block_signature,
+                    chunk_block_signatures,
+                })
+            }
+            false => None,
+        };
+
+        let directory_index_buffer = match header.version >= EIoStoreTocVersion::DirectoryIndex
+            && header.container_flags.contains(EIoContainerFlags::INDEXED)
+            && header.directory_index_size > 0
+        {
+            true => reader.read_vec(header.directory_index_size as usize)?,
+            false => Vec::new(),
+        };
+
+        let hash_size = match header.version >= EIoStoreTocVersion::ReplaceIoChunkHashWithIoHash {
+            true => 20,
+            false => 32,
+        };
+
+        let mut chunk_metas = Vec::with_capacity(entry_count);
+        for _ in 0..entry_count {
+            let mut chunk_hash = [0u8; 32];
+            reader.read_exact(&mut chunk_hash[..hash_size])?;
+            let flags = reader.read_u8()?;
+            chunk_metas.push(IoStoreTocEntryMeta { chunk_hash, flags });
+        }
+
+        Ok(IoStoreToc {
+            header,
+            chunk_ids,
+            chunk_offset_lengths,
+            chunk_perfect_hash_seeds,
+            chunk_indices_without_perfect_hash,
+            compression_blocks,
+            compression_methods,
+            signatures,
+            directory_index_buffer,
+            chunk_metas,
+        })
+    }
+
+    /// Read an `IoStoreToc` from a buffer
+    pub fn from_buffer(buffer: &[u8]) -> Result<Self, Error> {
+        IoStoreToc::read(&mut Cursor::new(buffer))
+    }
+
+    /// Write an `IoStoreToc` to a writer
+    ///
+    /// Header counts are derived from the toc contents
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        let mut header = self.header.clone();
+        header.toc_entry_count = self.chunk_ids.len() as u32;
+        header.toc_compressed_block_entry_count = self.compression_blocks.len() as u32;
+        header.toc_compressed_block_entry_size = IoStoreTocHeader::COMPRESSED_BLOCK_ENTRY_SIZE;
+        header.compression_method_name_count = self.compression_methods.len() as u32;
+        header.compression_method_name_length = IoStoreTocHeader::COMPRESSION_METHOD_NAME_LENGTH;
+        header.directory_index_size = self.directory_index_buffer.len() as u32;
+        header.toc_chunk_perfect_hash_seeds_count = self.chunk_perfect_hash_seeds.len() as u32;
+        header.toc_chunks_without_perfect_hash_count =
+            self.chunk_indices_without_perfect_hash.len() as u32;
+        header.write(writer)?;
+
+        for chunk_id in &self.chunk_ids {
+            chunk_id.write(writer)?;
+        }
+
+        for offset_length in &self.chunk_offset_lengths {
+            offset_length.write(writer)?;
+        }
+
+        if header.version >= EIoStoreTocVersion::PerfectHash {
+            for seed in &self.chunk_perfect_hash_seeds {
+                writer.write_i32::<LE>(*seed)?;
+            }
+        }
+
+        if header.version >= EIoStoreTocVersion::PerfectHashWithOverflow {
+            for index in &self.chunk_indices_without_perfect_hash {
+                writer.write_i32::<LE>(*index)?;
+            }
+        }
+
+        for block in &self.compression_blocks {
+            block.write(writer)?;
+        }
+
+        for method in &self.compression_methods {
+            let mut name = [0u8; IoStoreTocHeader::COMPRESSION_METHOD_NAME_LENGTH as usize];
+            let method = method.to_string();
+            let length = method.len().min(name.len() - 1);
+            name[..length].copy_from_slice(&method.as_bytes()[..length]);
+            writer.write_all(&name)?;
+        }
+
+        if header.container_flags.contains(EIoContainerFlags::SIGNED) {
+            let signatures = self
+                .signatures
+                .as_ref()
+                .ok_or_else(|| Error::no_data("Signed container has no signatures".to_string()))?;
+            writer.write_i32::<LE>(signatures.toc_signature.len() as i32)?;
+            writer.write_all(&signatures.toc_signature)?;
+            writer.write_all(&signatures.block_signature)?;
+            for hash in &signatures.chunk_block_signatures {
+                writer.write_all(hash)?;
+            }
+        }
+
+        writer.write_all(&self.directory_index_buffer)?;
+
+        let hash_size = match header.version >= EIoStoreTocVersion::ReplaceIoChunkHashWithIoHash {
+            true => 20,
+            false => 32,
+        };
+
+        for meta in &self.chunk_metas {
+            writer.write_all(&meta.chunk_hash[..hash_size])?;
+            writer.write_u8(meta.flags)?;
+        }
+
+        Ok(())
+    }
+}
@@ -46,6 +46,7 @@ pub use base::compression;
 pub use base::containers;
 pub use base::crc;
 pub use base::custom_version;
+pub use base::encryption;
 pub use base::engine_version;
 pub use base::enums;
 pub use base::error;
@@ -85,9 +86,16 @@ pub mod ac7;
 pub mod asset;
 pub mod asset_archive_writer;
 pub mod asset_data;
+pub mod compressed_chunk;
 pub mod fengineversion;
+pub mod io_store;
 pub mod package_file_summary;
+pub mod pak;
+pub mod provider;
+pub mod usmap_generator;
+pub mod zen;
 
 pub use asset::Asset;
+pub use zen::ZenAsset;
 
 const UE4_ASSET_MAGIC: u32 = u32::from_be_bytes([0xc1, 0x83, 0x2a, 0x9e]);
@@ -0,0 +1,344 @@
+//! Pak file entries
+
+use std::io::{Read, Write};
+
+use bitflags::bitflags;
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use crate::compression::{COMPRESS_CUSTOM, COMPRESS_GZIP, COMPRESS_ZLIB};
+use crate::encryption::AES_BLOCK_SIZE;
+use crate::error::Error;
+
+use super::info::{EPakVersion, PakInfo};
+
+bitflags! {
+    /// Pak entry flags
+    #[derive(Debug, Copy, Clone, Default, Hash, PartialEq, Eq)]
+    pub struct EPakEntryFlags : u8 {
+        /// None
+        const NONE = 0;
+        /// Entry data is encrypted
+        const ENCRYPTED = 1;
+        /// Entry is a delete record
+        const DELETED = 2;
+    }
+}
+
+/// Pak compression block
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PakCompressedBlock {
+    /// Compressed data start offset
+    ///
+    /// Relative to the entry offset since [`EPakVersion::RelativeChunkOffsets`]
+    pub compressed_start: u64,
+    /// Compressed data end offset
+    pub compressed_end: u64,
+}
+
+/// Pak entry
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PakEntry {
+    /// Offset of the serialized entry header, data starts right after it
+    pub offset: u64,
+    /// Stored data size
+    pub size: u64,
+    /// Uncompressed data size
+    pub uncompressed_size: u64,
+    /// 1-based compression method index into [`PakInfo::compression_methods`], 0 is uncompressed
+    pub compression_method_index: u32,
+    /// Timestamp, only used by [`EPakVersion::Initial`]
+    pub timestamp: u64,
+    /// Data SHA1 hash
+    pub hash: [u8; 20],
+    /// Compression blocks
+    pub compression_blocks: Vec<PakCompressedBlock>,
+    /// Entry flags
+    pub flags: EPakEntryFlags,
+    /// Compression block size
+    pub compression_block_size: u32,
+}
+
+impl PakEntry {
+    /// Is entry data encrypted
+    pub fn is_encrypted(&self) -> bool {
+        self.flags.contains(EPakEntryFlags::ENCRYPTED)
+    }
+
+    /// Is this entry a delete record
+    pub fn is_deleted(&self) -> bool {
+        self.flags.contains(EPakEntryFlags::DELETED)
+    }
+
+    /// Get serialized entry header size
+    pub fn get_serialized_size(&self, info: &PakInfo) -> u64 {
+        let mut size = 8 + 8 + 8 + 20;
+        size += match Self::has_u8_compression_method(info) {
+            true => 1,
+            false => 4,
+        };
+        if info.version >= EPakVersion::CompressionEncryption {
+            size += 1 + 4;
+            if self.compression_method_index != 0 {
+                size += 4 + 16 * self.compression_blocks.len() as u64;
+            }
+        }
+        if info.version < EPakVersion::NoTimestamps {
+            size += 8;
+        }
+        size
+    }
+
+    /// Get offset of the compression block data base
+    ///
+    /// Compression block offsets are relative to this
+    pub fn get_compression_block_base(&self, info: &PakInfo) -> u64 {
+        match info.version >= EPakVersion::RelativeChunkOffsets {
+            true => self.offset,
+            false => 0,
+        }
+    }
+
+    /// UE 4.22 paks store the compression method index as a byte
+    fn has_u8_compression_method(info: &PakInfo) -> bool {
+        info.version == EPakVersion::FNameBasedCompressionMethod
+            && info.compression_method_slots < PakInfo::COMPRESSION_METHOD_SLOTS
+    }
+
+    /// Read a `PakEntry` from a reader
+    pub fn read<R: Read>(reader: &mut R, info: &PakInfo) -> Result<Self, Error> {
+        let mut entry = PakEntry {
+            offset: reader.read_u64::<LE>()?,
+            size: reader.read_u64::<LE>()?,
+            uncompressed_size: reader.read_u64::<LE>()?,
+            ..Default::default()
+        };
+
+        entry.compression_method_index =
+            match info.version >= EPakVersion::FNameBasedCompressionMethod {
+                true => match Self::has_u8_compression_method(info) {
+                    true => reader.read_u8()? as u32,
+                    false => reader.read_u32::<LE>()?,
+                },
+                false => match reader.read_u32::<LE>()? {
+                    0 => 0,
+                    COMPRESS_ZLIB => 1,
+                    COMPRESS_GZIP => 2,
+                    COMPRESS_CUSTOM => 3,
+                    flags => {
+                        return Err(Error::invalid_file(format!(
+                            "Unknown pak compression flags {flags}"
+                        )))
+                    }
+                },
+            };
+
+        if info.version < EPakVersion::NoTimestamps {
+            entry.timestamp = reader.read_u64::<LE>()?;
+        }
+
+        reader.read_exact(&mut entry.hash)?;
+
+        if info.version >= EPakVersion::CompressionEncryption {
+            if entry.compression_method_index != 0 {
+                let block_count = reader.read_u32::<LE>()?;
+                entry.compression_blocks = Vec::with_capacity(block_count as usize);
+                for _ in 0..block_count {
+                    entry.compression_blocks.push(PakCompressedBlock {
+                        compressed_start: reader.read_u64::<LE>()?,
+                        compressed_end: reader.read_u64::<LE>()?,
+                    });
+                }
+            }
+
+            entry.flags = EPakEntryFlags::from_bits_retain(reader.read_u8()?);
+            entry.compression_block_size = reader.read_u32::<LE>()?;
+        }
+
+        Ok(entry)
+    }
+
+    /// Write a `PakEntry` to a writer
+    pub fn write<W: Write>(&self, writer: &mut W, info: &PakInfo) -> Result<(), Error> {
+        writer.write_u64::<LE>(self.offset)?;
+        writer.write_u64::<LE>(self.size)?;
+        writer.write_u64::<LE>(self.uncompressed_size)?;
+
+        match info.version >= EPakVersion::FNameBasedCompressionMethod {
+            true => match Self::has_u8_compression_method(info) {
+                true => writer.write_u8(self.compression_method_index as u8)?,
+                false => writer.write_u32::<LE>(self.compression_method_index)?,
+            },
+            false => writer.write_u32::<LE>(match self.compression_method_index {
+                0 => 0,
+                1 => COMPRESS_ZLIB,
+                2 => COMPRESS_GZIP,
+                3 => COMPRESS_CUSTOM,
+                index => {
+                    return Err(Error::invalid_file(format!(
+                        "Invalid legacy pak compression method index {index}"
+                    )))
+                }
+            })?,
+        }
+
+        if info.version < EPakVersion::NoTimestamps {
+            writer.write_u64::<LE>(self.timestamp)?;
+        }
+
+        writer.write_all(&self.hash)?;
+
+        if info.version >= EPakVersion::CompressionEncryption {
+            if self.compression_method_index != 0 {
+                writer.write_u32::<LE>(self.compression_blocks.len() as u32)?;
+                for block in &self.compression_blocks {
+                    writer.write_u64::<LE>(block.compressed_start)?;
+                    writer.write_u64::<LE>(block.compressed_end)?;
+                }
+            }
+
+            writer.write_u8(self.flags.bits())?;
+            writer.write_u32::<LE>(self.compression_block_size)?;
+        }
+
+        Ok(())
+    }
+
+    /// Read an encoded `PakEntry` from a reader
+    ///
+    /// Encoded entries are used by the index since [`EPakVersion::PathHashIndex`]
+    pub fn read_encoded<R: Read>(reader: &mut R, info: &PakInfo) -> Result<Self, Error> {
+        let value = reader.read_u32::<LE>()?;
+
+        let compression_block_size = match value & 0x3f {
+            0x3f => reader.read_u32::<LE>()?,
+            size => size << 11,
+        };
+
+        let mut entry = PakEntry {
+            compression_method_index: (value >> 23) & 0x3f,
+            ..Default::default()
+        };
+
+        entry.offset = match value & (1 << 31) != 0 {
+            true => reader.read_u32::<LE>()? as u64,
+            false => reader.read_u64::<LE>()?,
+        };
+        entry.uncompressed_size = match value & (1 << 30) != 0 {
+            true => reader.read_u32::<LE>()? as u64,
+            false => reader.read_u64::<LE>()?,
+        };
+        entry.size = match entry.compression_method_index {
+            0 => entry.uncompressed_size,
+            _ => match value & (1 << 29) != 0 {
+                true => reader.read_u32::<LE>()? as u64,
+                false => reader.read_u64::<LE>()?,
+            },
+        };
+
+        if value & (1 << 22) != 0 {
+            entry.flags |= EPakEntryFlags::ENCRYPTED;
+        }
+
+        let block_count = (value >> 6) & 0xffff;
+        entry.compression_block_size = match block_count {
+            0 => 0,
+            1 => entry.uncompressed_size as u32,
+            _ => compression_block_size,
+        };
+
+        entry.compression_blocks = vec![PakCompressedBlock::default(); block_count as usize];
+
+        // block offsets are stored in the same form as in the serialized entry header
+        let mut block_offset = match info.version >= EPakVersion::RelativeChunkOffsets {
+            true => 0,
+            false => entry.offset,
+        } + entry.get_serialized_size(info);
+
+        // block sizes are only stored when they can't be derived from the entry
+
+        if block_count == 1 && !entry.is_encrypted() {
+            entry.compression_blocks[0] = PakCompressedBlock {
+                compressed_start: block_offset,
+                compressed_end: block_offset + entry.size,
+            };
+        } else {
+            let alignment = match entry.is_encrypted() {
+                true => AES_BLOCK_SIZE as u64,
+                false => 1,
+            };
+            for block in entry.compression_blocks.iter_mut() {
+                let block_size = reader.read_u32::<LE>()? as u64;
+                *block = PakCompressedBlock {
+                    compressed_start: block_offset,
+                    compressed_end: block_offset + block_size,
+                };
+                block_offset += block_size.div_ceil(alignment) * alignment;
+            }
+        }
+
+        Ok(entry)
+    }
+
+    /// Check if this entry can be stored as an encoded entry
+    pub fn can_encode(&self) -> bool {
+        self.compression_method_index <= 0x3f
+            && self.compression_blocks.len() <= 0xffff
+            && !self.is_deleted()
+    }
+
+    /// Write an encoded `PakEntry` to a writer
+    pub fn write_encoded<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        if !self.can_encode() {
+            return Err(Error::invalid_file(
+                "Pak entry can't be encoded".to_string(),
+            ));
+        }
+
+        let is_offset_32_bit_safe = self.offset <= u32::MAX as u64;
+        let is_uncompressed_size_32_bit_safe = self.uncompressed_size <= u32::MAX as u64;
+        let is_size_32_bit_safe = self.size <= u32::MAX as u64;
+
+        let mut compression_block_size = (self.compression_block_size >> 11) & 0x3f;
+        if compression_block_size << 11 != self.compression_block_size {
+            compression_block_size = 0x3f;
+        }
+
+        let value = (u32::from(is_offset_32_bit_safe) << 31)
+            | (u32::from(is_uncompressed_size_32_bit_safe) << 30)
+            | (u32::from(is_size_32_bit_safe) << 29)
+            | (self.compression_method_index << 23)
+            | (u32::from(self.is_encrypted()) << 22)
+            | ((self.compression_blocks.len() as u32) << 6)
+            | compression_block_size;
+        writer.write_u32::<LE>(value)?;
+
+        if compression_block_size == 0x3f {
+            writer.write_u32::<LE>(self.compression_block_size)?;
+        }
+
+        match is_offset_32_bit_safe {
+            true => writer.write_u32::<LE>(self.offset as u32)?,
+            false => writer.write_u64::<LE>(self.offset)?,
+        }
+        match is_uncompressed_size_32_bit_safe {
+            true => writer.write_u32::<LE>(self.uncompressed_size as u32)?,
+            false => writer.write_u64::<LE>(self.uncompressed_size)?,
+        }
+        if self.compression_method_index != 0 {
+            match is_size_32_bit_safe {
+                true => writer.write_u32::<LE>(self.size as u32)?,
+                false => writer.write_u64::<LE>(self.size)?,
+            }
+        }
+
+        if self.compression_blocks.len() > 1
+            || (self.compression_blocks.len() == 1 && self.is_encrypted())
+        {
+            for block in &self.compression_blocks {
+                writer.write_u32::<LE>((block.compressed_end - block.compressed_start) as u32)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,382 @@
+//! Pak file index
+
+use std::collections::BTreeMap;
+use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use unreal_helpers::{UnrealReadExt, UnrealWriteExt};
+
+use crate::encryption::AesKey;
+use crate::error::{Error, PakError};
+
+use super::entry::PakEntry;
+use super::info::{EPakVersion, PakInfo};
+use super::sha1;
+
+/// Full directory index, directory paths mapped to file names and entry locations
+type DirectoryIndex = BTreeMap<String, Vec<(String, i32)>>;
+
+/// Location of a secondary index stored outside of the primary index
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PakSecondaryIndex {
+    /// Offset in the pak file
+    pub offset: u64,
+    /// Size
+    pub size: u64,
+    /// SHA1 hash
+    pub hash: [u8; 20],
+}
+
+impl PakSecondaryIndex {
+    /// Read an optional `PakSecondaryIndex` from a reader
+    fn read<R: Read>(reader: &mut R) -> Result<Option<Self>, Error> {
+        if reader.read_u32::<LE>()? == 0 {
+            return Ok(None);
+        }
+
+        let mut index = PakSecondaryIndex {
+            offset: reader.read_u64::<LE>()?,
+            size: reader.read_u64::<LE>()?,
+            ..Default::default()
+        };
+        reader.read_exact(&mut index.hash)?;
+        Ok(Some(index))
+    }
+
+    /// Create a new `PakSecondaryIndex` for data that will be written at an offset
+    fn new(offset: u64, data: &[u8]) -> Self {
+        PakSecondaryIndex {
+            offset,
+            size: data.len() as u64,
+            hash: sha1(data),
+        }
+    }
+
+    /// Write a `PakSecondaryIndex` to a writer
+    fn write<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        writer.write_u32::<LE>(1)?;
+        writer.write_u64::<LE>(self.offset)?;
+        writer.write_u64::<LE>(self.size)?;
+        writer.write_all(&self.hash)?;
+        Ok(())
+    }
+}
+
+/// Pak file index
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PakIndex {
+    /// Mount point
+    pub mount_point: Option<String>,
+    /// Path hash seed, only used since [`EPakVersion::PathHashIndex`]
+    pub path_hash_seed: u64,
+    /// Entries with their paths relative to the mount point
+    pub entries: Vec<(String, PakEntry)>,
+}
+
+impl PakIndex {
+    /// Read a `PakIndex` from a pak file
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - pak file reader, the index is located with the footer
+    /// * `info` - pak footer
+    /// * `key` - key the index is encrypted with, only required if [`PakInfo::encrypted_index`] is set
+    pub fn read<R: Read + Seek>(
+        reader: &mut R,
+        info: &PakInfo,
+        key: Option<&AesKey>,
+    ) -> Result<Self, Error> {
+        if info.frozen_index {
+            return Err(Error::unimplemented(
+                "Frozen pak indices are not supported".to_string(),
+            ));
+        }
+
+        let index = Self::read_index_data(reader, info.index_offset, info.index_size, info, key)?;
+
+        match info.version >= EPakVersion::PathHashIndex {
+            true => Self::read_path_hash_index(reader, &mut Cursor::new(index), info, key),
+            false => Self::read_legacy_index(&mut Cursor::new(index), info),
+        }
+    }
+
+    /// Read index data from a pak file, decrypting it if the index is encrypted
+    fn read_index_data<R: Read + Seek>(
+        reader: &mut R,
+        offset: u64,
+        size: u64,
+        info: &PakInfo,
+        key: Option<&AesKey>,
+    ) -> Result<Vec<u8>, Error> {
+        reader.seek(SeekFrom::Start(offset))?;
+        let mut data = reader.read_vec(size as usize)?;
+
+        if info.encrypted_index {
+            key.ok_or(PakError::NoEncryptionKey)?.decrypt(&mut data)?;
+        }
+
+        Ok(data)
+    }
+
+    /// Read an index before [`EPakVersion::PathHashIndex`]
+    fn read_legacy_index(index: &mut Cursor<Vec<u8>>, info: &PakInfo) -> Result<Self, Error> {
+        let mount_point = index.read_fstring()?;
+        let entry_count = index.read_u32::<LE>()?;
+
+        let mut entries = Vec::with_capacity(entry_count as usize);
+        for _ in 0..entry_count {
+            let path = index.read_fstring()?.unwrap_or_default();
+            entries.push((path, PakEntry::read(index, info)?));
+        }
+
+        Ok(PakIndex {
+            mount_point,
+            path_hash_seed: 0,
+            entries,
+        })
+    }
+
+    /// Read an index since [`EPakVersion::PathHashIndex`]
+    ///
+    /// File paths are only stored in the full directory index, the path hash index is skipped
+    fn read_path_hash_index<R: Read + Seek>(
+        reader: &mut R,
+        index: &mut Cursor<Vec<u8>>,
+        info: &PakInfo,
+        key: Option<&AesKey>,
+    ) -> Result<Self, Error> {
+        let mount_point = index.read_fstring()?;
+        let entry_count = index.read_u32::<LE>()?;
+        let path_hash_seed = index.read_u64::<LE>()?;
+
+        let _path_hash_index = PakSecondaryIndex::read(index)?;
+        let full_directory_index =
+            PakSecondaryIndex::read(index)?.ok_or(PakError::NoDirectoryIndex)?;
+
+        let encoded_entries_size = index.read_u32::<LE>()?;
+        let encoded_entries = index.read_vec(encoded_entries_size as usize)?;
+
+        let file_count = index.read_u32::<LE>()?;
+        let mut files = Vec::with_capacity(file_count as usize);
+        for _ in 0..file_count {
+            files.push(PakEntry::read(index, info)?);
+        }
+
+        let mut directory_index = Cursor::new(Self::read_index_data(
+            reader,
+            full_directory_index.offset,
+            full_directory_index.size,
+            info,
+            key,
+        )?);
+
+        let mut encoded_entries = Cursor::new(encoded_entries);
+        let mut entries = Vec::with_capacity(entry_count as usize);
+
+        let directory_count = directory_index.read_u32::<LE>()?;
+        for _ in 0..directory_count {
+            let directory = directory_index.read_fstring()?.unwrap_or_default();
+            let directory = directory.trim_start_matches('/');
+
+            let directory_file_count = directory_index.read_u32::<LE>()?;
+            for _ in 0..directory_file_count {
+                let file_name = directory_index.read_fstring()?.unwrap_or_default();
+                let entry_location = directory_index.read_i32::<LE>()?;
+
+                // negative locations point into the list of entries that couldn't be encoded
+                let entry = match entry_location < 0 {
+                    true => files
+                        .get((-entry_location - 1) as usize)
+                        .cloned()
+                        .ok_or_else(|| {
+                            Error::invalid_file(format!(
+                                "Invalid pak entry location {entry_location}"
+                            ))
+                        })?,
+                    false => {
+                        encoded_entries.seek(SeekFrom::Start(entry_location as u64))?;
+                        PakEntry::read_encoded(&mut encoded_entries, info)?
+                    }
+                };
+
+                entries.push((directory.to_string() + &file_name, entry));
+            }
+        }
+
+        Ok(PakIndex {
+            mount_point,
+            path_hash_seed,
+            entries,
+        })
+    }
+
+    /// Hash a path relative to the mount point for the path hash index
+    pub fn hash_path(path: &str, seed: u64, version: EPakVersion) -> u64 {
+        let (offset, prime) = match version >= EPakVersion::Fnv64BugFix {
+            true => (0xcbf29ce484222325u64, 0x00000100000001b3u64),
+            // older versions had the offset basis and the prime swapped
+            false => (0x00000100000001b3u64, 0xcbf29ce484222325u64),
+        };
+
+        let mut hash = offset.wrapping_add(seed);
+        for byte in path
+            .to_lowercase()
+            .encode_utf16()
+            .flat_map(|e| e.to_le_bytes())
+        {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(prime);
+        }
+        hash
+    }
+
+    /// Write a `PakIndex` at the current position of a pak file
+    ///
+    /// The index location and hash are stored in the footer
+    pub fn write<W: Write + Seek>(&self, writer: &mut W, info: &mut PakInfo) -> Result<(), Error> {
+        let index_offset = writer.stream_position()?;
+
+        let (index, secondary_indices) = match info.version >= EPakVersion::PathHashIndex {
+            true => self.write_path_hash_index(index_offset, info)?,
+            false => (self.write_legacy_index(info)?, Vec::new()),
+        };
+
+        info.index_offset = index_offset;
+        info.index_size = index.len() as u64;
+        info.index_hash = sha1(&index);
+
+        writer.write_all(&index)?;
+        writer.write_all(&secondary_indices)?;
+        Ok(())
+    }
+
+    /// Write an index before [`EPakVersion::PathHashIndex`]
+    fn write_legacy_index(&self, info: &PakInfo) -> Result<Vec<u8>, Error> {
+        let mut index = Vec::new();
+        index.write_fstring(self.mount_point.as_deref())?;
+        index.write_u32::<LE>(self.entries.len() as u32)?;
+        for (path, entry) in &self.entries {
+            index.write_fstring(Some(path))?;
+            entry.write(&mut index, info)?;
+        }
+        Ok(index)
+    }
+
+    /// Write a `DirectoryIndex`
+    fn write_directory_index<W: Write>(
+        writer: &mut W,
+        directory_index: &DirectoryIndex,
+    ) -> Result<(), Error> {
+        writer.write_u32::<LE>(directory_index.len() as u32)?;
+        for (directory, files) in directory_index {
+            writer.write_fstring(Some(directory))?;
+            writer.write_u32::<LE>(files.len() as u32)?;
+            for (file_name, location) in files {
+                writer.write_fstring(Some(file_name))?;
+                writer.write_i32::<LE>(*location)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write an index since [`EPakVersion::PathHashIndex`]
+    ///
+    /// Returns the primary index and the secondary indices that are written right after it
+    fn write_path_hash_index(
+        &self,
+        index_offset: u64,
+        info: &PakInfo,
+    ) -> Result<(Vec<u8>, Vec<u8>), Error> {
+        let mut encoded_entries = Vec::new();
+        let mut files = Vec::new();
+        let mut path_hash_index = Vec::with_capacity(self.entries.len());
+        let mut directory_index = DirectoryIndex::new();
+        directory_index.insert("/".to_string(), Vec::new());
+
+        for (path, entry) in &self.entries {
+            // entries that can't be encoded are referenced with negative locations
+            let location = match entry.can_encode() {
+                true => {
+                    let location = encoded_entries.len() as i32;
+                    entry.write_encoded(&mut encoded_entries)?;
+                    location
+                }
+                false => {
+                    files.push(entry);
+                    -(files.len() as i32)
+                }
+            };
+
+            path_hash_index.push((
+                Self::hash_path(path, self.path_hash_seed, info.version),
+                location,
+            ));
+
+            let (directory, file_name) = match path.rfind('/') {
+                Some(i) => (&path[..=i], &path[i + 1..]),
+                None => ("/", path.as_str()),
+            };
+
+            let mut parent = directory;
+            while let Some(i) = parent[..parent.len() - 1].rfind('/') {
+                parent = &parent[..=i];
+                directory_index.entry(parent.to_string()).or_default();
+            }
+
+            directory_index
+                .entry(directory.to_string())
+                .or_default()
+                .push((file_name.to_string(), location));
+        }
+
+        let mut full_directory_index = Vec::new();
+        Self::write_directory_index(&mut full_directory_index, &directory_index)?;
+
+        // the path hash index is followed by the pruned directory index, nothing is pruned
+        let mut path_hash_index_data = Vec::new();
+        path_hash_index_data.write_u32::<LE>(path_hash_index.len() as u32)?;
+        for (hash, location) in path_hash_index {
+            path_hash_index_data.write_u64::<LE>(hash)?;
+            path_hash_index_data.write_i32::<LE>(location)?;
+        }
+        path_hash_index_data.extend_from_slice(&full_directory_index);
+
+        let write_primary_index = |path_hash_index: &PakSecondaryIndex,
+                                   full_directory_index: &PakSecondaryIndex|
+         -> Result<Vec<u8>, Error> {
+            let mut index = Vec::new();
+            index.write_fstring(self.mount_point.as_deref())?;
+            index.write_u32::<LE>(self.entries.len() as u32)?;
+            index.write_u64::<LE>(self.path_hash_seed)?;
+            path_hash_index.write(&mut index)?;
+            full_directory_index.write(&mut index)?;
+            index.write_u32::<LE>(encoded_entries.len() as u32)?;
+            index.write_all(&encoded_entries)?;
+            index.write_u32::<LE>(files.len() as u32)?;
+            for entry in &files {
+                entry.write(&mut index, info)?;
+            }
+            Ok(index)
+        };
+
+        // secondary index locations have a fixed size, so the primary index size is known up front
+        let primary_index_size = write_primary_index(
+            &PakSecondaryIndex::default(),
+            &PakSecondaryIndex::default(),
+        )?
+        .len() as u64;
+
+        let path_hash_index =
+            PakSecondaryIndex::new(index_offset + primary_index_size, &path_hash_index_data);
+        let full_directory_index_location = PakSecondaryIndex::new(
+            path_hash_index.offset + path_hash_index.size,
+            &full_directory_index,
+        );
+        let index = write_primary_index(&path_hash_index, &full_directory_index_location)?;
+
+        let mut secondary_indices = path_hash_index_data;
+        secondary_indices.extend_from_slice(&full_directory_index);
+        Ok((index, secondary_indices))
+    }
+}
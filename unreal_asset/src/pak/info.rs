@@ -0,0 +1,250 @@
+//! Pak file footer
+
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use unreal_helpers::{Guid, UnrealReadExt};
+
+use crate::compression::CompressionMethod;
+use crate::error::{Error, PakError};
+
+/// Pak footer magic
+pub const PAK_MAGIC: u32 = 0x5A6F12E1;
+
+/// Pak file version
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, TryFromPrimitive, IntoPrimitive,
+)]
+#[repr(u32)]
+pub enum EPakVersion {
+    /// Initial
+    Initial = 1,
+    /// Entry timestamps were removed
+    NoTimestamps,
+    /// Compression blocks and encryption
+    CompressionEncryption,
+    /// Index encryption
+    IndexEncryption,
+    /// Compression block offsets are relative to the entry
+    RelativeChunkOffsets,
+    /// Delete records
+    DeleteRecords,
+    /// Encryption key guid
+    EncryptionKeyGuid,
+    /// Compression methods are stored by name in the footer
+    FNameBasedCompressionMethod,
+    /// Frozen index
+    FrozenIndex,
+    /// Path hash index and full directory index
+    PathHashIndex,
+    /// Fixed path hashes
+    Fnv64BugFix,
+}
+
+/// Pak file footer
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PakInfo {
+    /// Pak version
+    pub version: EPakVersion,
+    /// Encryption key guid
+    pub encryption_key_guid: Guid,
+    /// Is the index encrypted
+    pub encrypted_index: bool,
+    /// Index offset
+    pub index_offset: u64,
+    /// Index size
+    pub index_size: u64,
+    /// Index SHA1 hash
+    pub index_hash: [u8; 20],
+    /// Is the index frozen, only used by [`EPakVersion::FrozenIndex`]
+    pub frozen_index: bool,
+    /// Compression methods, entry compression method indices are 1-based indices into this list
+    pub compression_methods: Vec<CompressionMethod>,
+    /// Number of compression method name slots in the footer, UE 4.22 paks only have 4
+    pub compression_method_slots: u32,
+}
+
+impl PakInfo {
+    /// Serialized compression method name length
+    pub const COMPRESSION_METHOD_NAME_LENGTH: usize = 32;
+    /// Compression method name slots since UE 4.23
+    pub const COMPRESSION_METHOD_SLOTS: u32 = 5;
+
+    /// Create a new `PakInfo` for a version
+    pub fn new(version: EPakVersion) -> Self {
+        PakInfo {
+            version,
+            encryption_key_guid: Guid::default(),
+            encrypted_index: false,
+            index_offset: 0,
+            index_size: 0,
+            index_hash: [0u8; 20],
+            frozen_index: false,
+            compression_methods: match version >= EPakVersion::FNameBasedCompressionMethod {
+                true => Vec::new(),
+                false => Self::legacy_compression_methods(),
+            },
+            compression_method_slots: match version >= EPakVersion::FNameBasedCompressionMethod {
+                true => Self::COMPRESSION_METHOD_SLOTS,
+                false => 0,
+            },
+        }
+    }
+
+    /// Compression methods of paks before [`EPakVersion::FNameBasedCompressionMethod`]
+    ///
+    /// Legacy compression flags are mapped to indices into this list
+    fn legacy_compression_methods() -> Vec<CompressionMethod> {
+        vec![
+            CompressionMethod::Zlib,
+            CompressionMethod::Gzip,
+            CompressionMethod::new("Oodle"),
+        ]
+    }
+
+    /// Get serialized footer size
+    pub fn serialized_size(version: EPakVersion, compression_method_slots: u32) -> u64 {
+        let mut size = 4 + 4 + 8 + 8 + 20;
+        if version >= EPakVersion::EncryptionKeyGuid {
+            size += 16;
+        }
+        if version >= EPakVersion::IndexEncryption {
+            size += 1;
+        }
+        if version == EPakVersion::FrozenIndex {
+            size += 1;
+        }
+        if version >= EPakVersion::FNameBasedCompressionMethod {
+            size += compression_method_slots as u64 * Self::COMPRESSION_METHOD_NAME_LENGTH as u64;
+        }
+        size
+    }
+
+    /// Get a compression method by its entry compression method index
+    pub fn get_compression_method(&self, index: u32) -> Option<CompressionMethod> {
+        match index {
+            0 => Some(CompressionMethod::None),
+            _ => self.compression_methods.get(index as usize - 1).cloned(),
+        }
+    }
+
+    /// Read `PakInfo` from the end of a pak file
+    pub fn read<R: Read + Seek>(reader: &mut R) -> Result<Self, Error> {
+        let file_size = reader.seek(SeekFrom::End(0))?;
+
+        for version in (u32::from(EPakVersion::Initial)..=u32::from(EPakVersion::Fnv64BugFix)).rev()
+        {
+            let version = EPakVersion::try_from(version)?;
+            let slot_counts: &[u32] = match version {
+                EPakVersion::FNameBasedCompressionMethod => &[Self::COMPRESSION_METHOD_SLOTS, 4],
+                version if version > EPakVersion::FNameBasedCompressionMethod => {
+                    &[Self::COMPRESSION_METHOD_SLOTS]
+                }
+                _ => &[0],
+            };
+
+            for &compression_method_slots in slot_counts {
+                let size = Self::serialized_size(version, compression_method_slots);
+                if size > file_size {
+                    continue;
+                }
+
+                reader.seek(SeekFrom::Start(file_size - size))?;
+                if let Some(info) = Self::read_version(reader, version, compression_method_slots)? {
+                    return Ok(info);
+                }
+            }
+        }
+
+        Err(PakError::InvalidFooter.into())
+    }
+
+    /// Try to read `PakInfo` with a specific footer layout
+    fn read_version<R: Read + Seek>(
+        reader: &mut R,
+        version: EPakVersion,
+        compression_method_slots: u32,
+    ) -> Result<Option<Self>, Error> {
+        let mut info = PakInfo::new(version);
+        info.compression_method_slots = compression_method_slots;
+
+        if version >= EPakVersion::EncryptionKeyGuid {
+            info.encryption_key_guid = reader.read_guid()?;
+        }
+        if version >= EPakVersion::IndexEncryption {
+            info.encrypted_index = reader.read_bool()?;
+        }
+
+        let magic = reader.read_u32::<LE>()?;
+        let serialized_version = reader.read_u32::<LE>()?;
+        if magic != PAK_MAGIC || serialized_version != u32::from(version) {
+            return Ok(None);
+        }
+
+        info.index_offset = reader.read_u64::<LE>()?;
+        info.index_size = reader.read_u64::<LE>()?;
+        reader.read_exact(&mut info.index_hash)?;
+
+        if version == EPakVersion::FrozenIndex {
+            info.frozen_index = reader.read_bool()?;
+        }
+
+        if version >= EPakVersion::FNameBasedCompressionMethod {
+            for _ in 0..compression_method_slots {
+                let name = reader.read_vec(Self::COMPRESSION_METHOD_NAME_LENGTH)?;
+                let length = name.iter().position(|e| *e == 0).unwrap_or(name.len());
+                if length == 0 {
+                    continue;
+                }
+                let name = String::from_utf8(name[..length].to_vec())?;
+                info.compression_methods.push(CompressionMethod::new(&name));
+            }
+        }
+
+        Ok(Some(info))
+    }
+
+    /// Write `PakInfo` to a writer
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        if self.version >= EPakVersion::EncryptionKeyGuid {
+            writer.write_all(&self.encryption_key_guid.0)?;
+        }
+        if self.version >= EPakVersion::IndexEncryption {
+            writer.write_u8(u8::from(self.encrypted_index))?;
+        }
+
+        writer.write_u32::<LE>(PAK_MAGIC)?;
+        writer.write_u32::<LE>(self.version.into())?;
+        writer.write_u64::<LE>(self.index_offset)?;
+        writer.write_u64::<LE>(self.index_size)?;
+        writer.write_all(&self.index_hash)?;
+
+        if self.version == EPakVersion::FrozenIndex {
+            writer.write_u8(u8::from(self.frozen_index))?;
+        }
+
+        if self.version >= EPakVersion::FNameBasedCompressionMethod {
+            if self.compression_methods.len() > self.compression_method_slots as usize {
+                return Err(Error::invalid_file(format!(
+                    "Pak can only store {} compression methods, got {}",
+                    self.compression_method_slots,
+                    self.compression_methods.len()
+                )));
+            }
+
+            for i in 0..self.compression_method_slots as usize {
+                let mut name = [0u8; Self::COMPRESSION_METHOD_NAME_LENGTH];
+                if let Some(method) = self.compression_methods.get(i) {
+                    let method = method.to_string();
+                    let length = method.len().min(name.len() - 1);
+                    name[..length].copy_from_slice(&method.as_bytes()[..length]);
+                }
+                writer.write_all(&name)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+//! Pak (.pak) file reading and writing
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::path::Path;
+
+use crate::compression::{self, CompressionMethod};
+use crate::encryption::{AesKey, EncryptionKeys, AES_BLOCK_SIZE};
+use crate::error::{Error, PakError};
+use crate::io_store::normalize_path;
+
+pub mod entry;
+pub mod index;
+pub mod info;
+pub mod writer;
+
+pub use entry::{EPakEntryFlags, PakCompressedBlock, PakEntry};
+pub use index::PakIndex;
+pub use info::{EPakVersion, PakInfo};
+pub use writer::PakWriter;
+
+/// Compute the SHA1 hash of data
+pub(crate) fn sha1(data: &[u8]) -> [u8; 20] {
+    sha1_smol::Sha1::from(data).digest().bytes()
+}
+
+/// Pak file reader
+#[derive(Debug)]
+pub struct PakReader<R: Read + Seek> {
+    /// Pak footer
+    pub info: PakInfo,
+    /// Pak index
+    pub index: PakIndex,
+    /// Pak file reader
+    reader: R,
+    /// Keys used for decrypting the index and encrypted entries
+    encryption_keys: EncryptionKeys,
+    /// Normalized path to entry index lookup
+    file_lookup: HashMap<String, usize>,
+}
+
+impl PakReader<File> {
+    /// Open a pak file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - pak file path
+    /// * `encryption_keys` - keys for encrypted paks
+    pub fn open<P: AsRef<Path>>(
+        path: P,
+        encryption_keys: Option<EncryptionKeys>,
+    ) -> Result<Self, Error> {
+        PakReader::new(File::open(path)?, encryption_keys)
+    }
+}
+
+impl<R: Read + Seek> PakReader<R> {
+    /// Create a new `PakReader` from a pak file reader
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - pak file reader
+    /// * `encryption_keys` - keys for encrypted paks, the key is looked up by [`PakInfo::encryption_key_guid`]
+    pub fn new(mut reader: R, encryption_keys: Option<EncryptionKeys>) -> Result<Self, Error> {
+        let encryption_keys = encryption_keys.unwrap_or_default();
+        let info = PakInfo::read(&mut reader)?;
+        let index = PakIndex::read(
+            &mut reader,
+            &info,
+            encryption_keys.get_key(&info.encryption_key_guid),
+        )?;
+
+        let mount_point = index.mount_point.clone().unwrap_or_default();
+        let file_lookup = index
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, entry))| !entry.is_deleted())
+            .map(|(i, (path, _))| {
+                let path = mount_point.clone() + path;
+                (normalize_path(&path).to_string(), i)
+            })
+            .collect();
+
+        Ok(PakReader {
+            info,
+            index,
+            reader,
+            encryption_keys,
+            file_lookup,
+        })
+    }
+
+    /// Get the key this pak is encrypted with
+    fn get_encryption_key(&self) -> Option<&AesKey> {
+        self.encryption_keys.get_key(&self.info.encryption_key_guid)
+    }
+
+    /// Read `size` bytes at `offset`, decrypting them if `encrypted` is set
+    fn read_data(&mut self, offset: u64, size: usize, encrypted: bool) -> Result<Vec<u8>, Error> {
+        self.reader.seek(SeekFrom::Start(offset))?;
+
+        if !encrypted {
+            let mut data = vec![0u8; size];
+            self.reader.read_exact(&mut data)?;
+            return Ok(data);
+        }
+
+        // encrypted data is padded to the aes block size
+        let mut data = vec![0u8; size.next_multiple_of(AES_BLOCK_SIZE)];
+        self.reader.read_exact(&mut data)?;
+        self.get_encryption_key()
+            .ok_or(PakError::NoEncryptionKey)?
+            .decrypt(&mut data)?;
+        data.truncate(size);
+        Ok(data)
+    }
+
+    /// Get pak mount point
+    pub fn get_mount_point(&self) -> Option<&str> {
+        self.index.mount_point.as_deref()
+    }
+
+    /// Get all file paths in this pak
+    pub fn get_file_names(&self) -> impl Iterator<Item = &str> {
+        self.file_lookup.keys().map(|e| e.as_str())
+    }
+
+    /// Check if this pak has a file
+    pub fn has_file(&self, path: &str) -> bool {
+        self.file_lookup.contains_key(normalize_path(path))
+    }
+
+    /// Get the entry of a file
+    pub fn get_entry(&self, path: &str) -> Option<&PakEntry> {
+        self.file_lookup
+            .get(normalize_path(path))
+            .map(|e| &self.index.entries[*e].1)
+    }
+
+    /// Read a file by path
+    pub fn read_file(&mut self, path: &str) -> Result<Vec<u8>, Error> {
+        let entry_index = *self
+            .file_lookup
+            .get(normalize_path(path))
+            .ok_or_else(|| PakError::NoFile(path.to_string().into_boxed_str()))?;
+
+        let entry = self.index.entries[entry_index].1.clone();
+        self.read_entry(&entry)
+    }
+
+    /// Open a file by path
+    ///
+    /// The returned stream can be passed to [`crate::Asset::new`]
+    pub fn open_file(&mut self, path: &str) -> Result<Cursor<Vec<u8>>, Error> {
+        Ok(Cursor::new(self.read_file(path)?))
+    }
+
+    /// Read the data of an entry
+    pub fn read_entry(&mut self, entry: &PakEntry) -> Result<Vec<u8>, Error> {
+        let method = self
+            .info
+            .get_compression_method(entry.compression_method_index)
+            .ok_or_else(|| {
+                Error::invalid_file(format!(
+                    "Invalid compression method index {}",
+                    entry.compression_method_index
+                ))
+            })?;
+
+        if method == CompressionMethod::None {
+            return self.read_data(
+                entry.offset + entry.get_serialized_size(&self.info),
+                entry.uncompressed_size as usize,
+                entry.is_encrypted(),
+            );
+        }
+
+        let block_base = entry.get_compression_block_base(&self.info);
+        let mut data = Vec::with_capacity(entry.uncompressed_size as usize);
+        for block in &entry.compression_blocks {
+            let compressed = self.read_data(
+                block_base + block.compressed_start,
+                (block.compressed_end - block.compressed_start) as usize,
+                entry.is_encrypted(),
+            )?;
+
+            let remaining = entry.uncompressed_size as usize - data.len();
+            let block_size = match entry.compression_block_size {
+                0 => remaining,
+                size => remaining.min(size as usize),
+            };
+
+            let start = data.len();
+            data.resize(start + block_size, 0);
+            compression::decompress(method.clone(), &compressed, &mut data[start..])?;
+        }
+
+        if data.len() != entry.uncompressed_size as usize {
+            return Err(Error::invalid_file(format!(
+                "Pak entry decompressed to {} bytes, expected {}",
+                data.len(),
+                entry.uncompressed_size
+            )));
+        }
+
+        Ok(data)
+    }
+}
@@ -0,0 +1,176 @@
+//! Pak file writer
+
+use std::borrow::Cow;
+use std::io::{Seek, Write};
+
+use crate::compression::{self, CompressionLevel, CompressionMethod};
+use crate::error::Error;
+
+use super::entry::{PakCompressedBlock, PakEntry};
+use super::index::PakIndex;
+use super::info::{EPakVersion, PakInfo};
+use super::sha1;
+
+/// Pak file writer
+#[derive(Debug)]
+pub struct PakWriter<W: Write + Seek> {
+    /// Pak footer
+    pub info: PakInfo,
+    /// Pak index
+    pub index: PakIndex,
+    /// Compression block size used for compressed entries
+    pub compression_block_size: u32,
+    /// Compression level used for compressed entries
+    pub compression_level: CompressionLevel,
+    /// Pak file writer
+    writer: W,
+}
+
+impl<W: Write + Seek> PakWriter<W> {
+    /// Default compression block size
+    pub const DEFAULT_COMPRESSION_BLOCK_SIZE: u32 = 0x10000;
+    /// Default mount point
+    pub const DEFAULT_MOUNT_POINT: &'static str = "../../../";
+
+    /// Create a new `PakWriter`
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - pak file writer
+    /// * `version` - pak version
+    /// * `mount_point` - mount point, paths of written files are relative to it
+    pub fn new(writer: W, version: EPakVersion, mount_point: String) -> Self {
+        PakWriter {
+            info: PakInfo::new(version),
+            index: PakIndex {
+                mount_point: Some(mount_point),
+                ..Default::default()
+            },
+            compression_block_size: Self::DEFAULT_COMPRESSION_BLOCK_SIZE,
+            compression_level: CompressionLevel::Default,
+            writer,
+        }
+    }
+
+    /// Get the entry compression method index of a compression method, adding it to the footer if needed
+    fn get_compression_method_index(&mut self, method: &CompressionMethod) -> Result<u32, Error> {
+        if *method == CompressionMethod::None {
+            return Ok(0);
+        }
+
+        if self.info.version < EPakVersion::CompressionEncryption {
+            return Err(Error::invalid_file(format!(
+                "Pak version {:?} doesn't support compression",
+                self.info.version
+            )));
+        }
+
+        if let Some(index) = self
+            .info
+            .compression_methods
+            .iter()
+            .position(|e| e == method)
+        {
+            return Ok(index as u32 + 1);
+        }
+
+        if self.info.version < EPakVersion::FNameBasedCompressionMethod
+            || self.info.compression_methods.len() >= self.info.compression_method_slots as usize
+        {
+            return Err(Error::invalid_file(format!(
+                "Can't add compression method {method} to a pak of version {:?}",
+                self.info.version
+            )));
+        }
+
+        self.info.compression_methods.push(method.clone());
+        Ok(self.info.compression_methods.len() as u32)
+    }
+
+    /// Write a file to the pak
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - file path relative to the mount point, e.g. `Game/Content/Maps/Foo.umap`
+    /// * `data` - file data
+    /// * `compression_method` - compression method, [`CompressionMethod::None`] stores the file as is
+    ///
+    /// Oodle is only supported for reading, writing an Oodle compressed file returns an error
+    pub fn write_file(
+        &mut self,
+        path: &str,
+        data: &[u8],
+        compression_method: CompressionMethod,
+    ) -> Result<(), Error> {
+        // checked before anything is written, so the pak stays usable
+        if compression_method == CompressionMethod::Oodle {
+            return Err(Error::unimplemented(format!(
+                "Can't write {path}, Oodle compression is not supported, only decompression is"
+            )));
+        }
+
+        let compression_method = match data.is_empty() {
+            true => CompressionMethod::None,
+            false => compression_method,
+        };
+
+        let mut entry = PakEntry {
+            offset: self.writer.stream_position()?,
+            uncompressed_size: data.len() as u64,
+            compression_method_index: self.get_compression_method_index(&compression_method)?,
+            ..Default::default()
+        };
+
+        let stored = match entry.compression_method_index {
+            0 => Cow::Borrowed(data),
+            _ => {
+                let blocks = data
+                    .chunks(self.compression_block_size as usize)
+                    .map(|e| {
+                        compression::compress(compression_method.clone(), e, self.compression_level)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                entry.compression_block_size = self.compression_block_size.min(data.len() as u32);
+                entry.compression_blocks = vec![PakCompressedBlock::default(); blocks.len()];
+
+                let mut block_offset = entry.offset - entry.get_compression_block_base(&self.info)
+                    + entry.get_serialized_size(&self.info);
+                for (block, compressed) in entry.compression_blocks.iter_mut().zip(&blocks) {
+                    *block = PakCompressedBlock {
+                        compressed_start: block_offset,
+                        compressed_end: block_offset + compressed.len() as u64,
+                    };
+                    block_offset += compressed.len() as u64;
+                }
+
+                Cow::Owned(blocks.concat())
+            }
+        };
+
+        entry.size = stored.len() as u64;
+        entry.hash = sha1(&stored);
+
+        // the entry header in front of the data doesn't store its own offset
+        let header = PakEntry {
+            offset: 0,
+            ..entry.clone()
+        };
+        header.write(&mut self.writer, &self.info)?;
+        self.writer.write_all(&stored)?;
+
+        let path = path.replace('\\', "/");
+        self.index
+            .entries
+            .push((path.trim_start_matches('/').to_string(), entry));
+        Ok(())
+    }
+
+    /// Write the index and the footer, returns the pak file writer
+    pub fn finish(mut self) -> Result<W, Error> {
+        self.index.write(&mut self.writer, &mut self.info)?;
+        self.info.write(&mut self.writer)?;
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
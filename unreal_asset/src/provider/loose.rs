@@ -0,0 +1,109 @@
+//! Loose file provider
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, FileProviderError};
+use crate::io_store::normalize_path;
+
+use super::FileProvider;
+
+/// Provides loose files from a directory on disk
+#[derive(Debug, Clone)]
+pub struct LooseFileProvider {
+    /// Directory files are read from
+    pub root: PathBuf,
+    /// Mount point, paths inside of `root` are relative to it
+    pub mount_point: String,
+    /// Extensions of files that are not provided, e.g. `pak` for containers that are mounted separately
+    pub ignored_extensions: Vec<String>,
+}
+
+impl LooseFileProvider {
+    /// Create a new `LooseFileProvider`
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - directory files are read from
+    /// * `mount_point` - mount point of the directory, e.g. `TestGame/Content/` or an empty string for a game install root
+    pub fn new<P: AsRef<Path>>(root: P, mount_point: &str) -> Self {
+        let mount_point = normalize_path(&mount_point.replace('\\', "/")).to_string();
+        let mount_point = match mount_point.is_empty() || mount_point.ends_with('/') {
+            true => mount_point,
+            false => mount_point + "/",
+        };
+
+        LooseFileProvider {
+            root: root.as_ref().to_path_buf(),
+            mount_point,
+            ignored_extensions: Vec::new(),
+        }
+    }
+
+    /// Check if a file is ignored because of its extension
+    fn is_ignored(&self, path: &str) -> bool {
+        Path::new(path)
+            .extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|e| {
+                self.ignored_extensions
+                    .iter()
+                    .any(|ignored| ignored.eq_ignore_ascii_case(e))
+            })
+    }
+
+    /// Get the path on disk of a file
+    pub fn get_disk_path(&self, path: &str) -> Option<PathBuf> {
+        if self.is_ignored(path) {
+            return None;
+        }
+
+        let path = normalize_path(path);
+        let relative = path.strip_prefix(self.mount_point.as_str())?;
+        // don't allow escaping the root directory
+        if relative.split('/').any(|e| e == "..") {
+            return None;
+        }
+        Some(self.root.join(relative))
+    }
+
+    /// Collect all file paths in a directory
+    fn collect_files(&self, dir: &Path, prefix: &str, files: &mut Vec<String>) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let path = prefix.to_string() + &name;
+            match entry.file_type() {
+                Ok(file_type) if file_type.is_dir() => {
+                    self.collect_files(&entry.path(), &(path + "/"), files)
+                }
+                Ok(_) if !self.is_ignored(&path) => files.push(path),
+                Ok(_) => {}
+                Err(_) => {}
+            }
+        }
+    }
+}
+
+impl FileProvider for LooseFileProvider {
+    fn has_file(&self, path: &str) -> bool {
+        self.get_disk_path(path).is_some_and(|e| e.is_file())
+    }
+
+    fn read_file(&mut self, path: &str) -> Result<Vec<u8>, Error> {
+        let disk_path = self
+            .get_disk_path(path)
+            .filter(|e| e.is_file())
+            .ok_or_else(|| FileProviderError::NoFile(path.to_string().into_boxed_str()))?;
+        Ok(fs::read(disk_path)?)
+    }
+
+    fn get_file_names(&self) -> Vec<String> {
+        let mut files = Vec::new();
+        self.collect_files(&self.root, &self.mount_point, &mut files);
+        files
+    }
+}
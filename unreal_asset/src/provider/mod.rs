@@ -0,0 +1,429 @@
+//! Virtual file system over loose files, paks and IoStore containers
+
+use std::fmt::Debug;
+use std::fs;
+use std::io::{Cursor, Read, Seek};
+use std::path::Path;
+
+use unreal_helpers::game_to_absolute;
+
+use crate::asset::Asset;
+use crate::containers::{Chain, NameMap, SharedResource};
+use crate::encryption::EncryptionKeys;
+use crate::engine_version::{self, EngineVersion};
+use crate::error::{Error, FileProviderError};
+use crate::io_store::{normalize_path, EIoChunkType, IoChunkId, IoStoreReader};
+use crate::pak::PakReader;
+use crate::reader::RawReader;
+use crate::registry::AssetRegistryState;
+use crate::unversioned::Usmap;
+use crate::zen::{ScriptObjects, ZenAsset};
+
+pub mod loose;
+
+pub use loose::LooseFileProvider;
+
+/// Extensions of pak and IoStore container files
+const CONTAINER_EXTENSIONS: [&str; 3] = ["pak", "utoc", "ucas"];
+
+/// A source of game files
+///
+/// Paths are relative to the game install root, e.g. `TestGame/Content/Maps/Foo.umap`
+pub trait FileProvider: Debug {
+    /// Check if this provider has a file
+    fn has_file(&self, path: &str) -> bool;
+    /// Read a file by path
+    fn read_file(&mut self, path: &str) -> Result<Vec<u8>, Error>;
+    /// Get all file paths in this provider
+    fn get_file_names(&self) -> Vec<String>;
+
+    /// Check if this provider has a zen package by its package name, e.g. `/Game/Maps/Foo`
+    ///
+    /// Zen packages are only stored in IoStore containers
+    fn has_zen_package(&self, _package_name: &str) -> bool {
+        false
+    }
+
+    /// Read a zen package's export bundle data by its package name
+    fn read_zen_package(&mut self, package_name: &str) -> Result<Vec<u8>, Error> {
+        Err(FileProviderError::NoFile(package_name.to_string().into_boxed_str()).into())
+    }
+}
+
+impl<R: Read + Seek + Debug> FileProvider for PakReader<R> {
+    fn has_file(&self, path: &str) -> bool {
+        PakReader::has_file(self, path)
+    }
+
+    fn read_file(&mut self, path: &str) -> Result<Vec<u8>, Error> {
+        PakReader::read_file(self, path)
+    }
+
+    fn get_file_names(&self) -> Vec<String> {
+        PakReader::get_file_names(self)
+            .map(|e| e.to_string())
+            .collect()
+    }
+}
+
+impl<R: Read + Seek + Debug> FileProvider for IoStoreReader<R> {
+    fn has_file(&self, path: &str) -> bool {
+        IoStoreReader::has_file(self, path)
+    }
+
+    fn read_file(&mut self, path: &str) -> Result<Vec<u8>, Error> {
+        IoStoreReader::read_file(self, path)
+    }
+
+    fn get_file_names(&self) -> Vec<String> {
+        IoStoreReader::get_file_names(self)
+            .map(|e| e.to_string())
+            .collect()
+    }
+
+    fn has_zen_package(&self, package_name: &str) -> bool {
+        self.has_chunk(&IoChunkId::from_package_name(
+            package_name,
+            EIoChunkType::ExportBundleData,
+        ))
+    }
+
+    fn read_zen_package(&mut self, package_name: &str) -> Result<Vec<u8>, Error> {
+        IoStoreReader::read_package(self, package_name)
+    }
+}
+
+/// An asset read from a game install
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum ProviderAsset {
+    /// Legacy package, read from a .uasset/.umap file and its .uexp file
+    Legacy(Asset<Cursor<Vec<u8>>>),
+    /// Zen package, read from an IoStore container
+    Zen(ZenAsset<Cursor<Vec<u8>>>),
+}
+
+/// Streams of a package
+#[derive(Debug)]
+pub struct PackageStreams {
+    /// .uasset/.umap stream
+    pub asset: Cursor<Vec<u8>>,
+    /// .uexp stream
+    pub exports: Option<Cursor<Vec<u8>>>,
+    /// .ubulk stream
+    pub bulk: Option<Cursor<Vec<u8>>>,
+}
+
+/// A mounted file provider
+#[derive(Debug)]
+struct MountedProvider {
+    /// Priority, providers with a higher priority override files of providers with a lower priority
+    priority: i32,
+    /// Provider
+    provider: Box<dyn FileProvider>,
+}
+
+/// Game file provider
+///
+/// Combines multiple file providers into a single view of a game install,
+/// files from providers with a higher priority override files from providers with a lower priority.
+#[derive(Debug)]
+pub struct GameFileProvider {
+    /// Game name, used for resolving `/Game/` paths
+    pub game_name: String,
+    /// Global script objects used for reading zen packages, loaded from the global IoStore container
+    pub script_objects: Option<SharedResource<ScriptObjects>>,
+    /// Mounted providers, sorted by descending priority
+    providers: Vec<MountedProvider>,
+}
+
+impl GameFileProvider {
+    /// Priority of loose files in a game install, paks always override loose files
+    pub const LOOSE_FILE_PRIORITY: i32 = -1;
+
+    /// Create a new empty `GameFileProvider`
+    pub fn new(game_name: &str) -> Self {
+        GameFileProvider {
+            game_name: game_name.to_string(),
+            script_objects: None,
+            providers: Vec::new(),
+        }
+    }
+
+    /// Open a game install
+    ///
+    /// Mounts loose files from `<game_name>/Content` and all paks and IoStore containers in `<game_name>/Content/Paks`,
+    /// the container files themselves are not provided as loose files
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - game install root, the directory containing the `<game_name>` directory
+    /// * `game_name` - game name
+    /// * `encryption_keys` - keys for encrypted paks and IoStore containers
+    pub fn open_install<P: AsRef<Path>>(
+        root: P,
+        game_name: &str,
+        encryption_keys: Option<EncryptionKeys>,
+    ) -> Result<Self, Error> {
+        let content = root.as_ref().join(game_name).join("Content");
+        let mut provider = GameFileProvider::new(game_name);
+
+        let mut loose = LooseFileProvider::new(&content, &format!("{game_name}/Content"));
+        loose.ignored_extensions = CONTAINER_EXTENSIONS.iter().map(|e| e.to_string()).collect();
+        provider.add_provider(loose, Self::LOOSE_FILE_PRIORITY);
+
+        let mut containers = Vec::new();
+        collect_containers(&content.join("Paks"), &mut containers)?;
+        // mount in a stable order, so that equal priorities are resolved the same way every time
+        containers.sort();
+
+        for path in containers {
+            let priority = Self::get_container_priority(&path);
+            match path.extension().and_then(|e| e.to_str()) {
+                Some("pak") => provider
+                    .add_provider(PakReader::open(&path, encryption_keys.clone())?, priority),
+                Some("utoc") => {
+                    let mut reader = IoStoreReader::open(&path, encryption_keys.clone())?;
+                    // the global container stores the script objects instead of packages
+                    let script_objects_id = IoChunkId::new(0, 0, EIoChunkType::ScriptObjects);
+                    if reader.has_chunk(&script_objects_id) {
+                        let script_objects =
+                            ScriptObjects::from_buffer(&reader.read_chunk(&script_objects_id)?)?;
+                        provider.script_objects = Some(SharedResource::new(script_objects));
+                    }
+                    provider.add_provider(reader, priority)
+                }
+                _ => {}
+            }
+        }
+
+        Ok(provider)
+    }
+
+    /// Get the priority of a pak or IoStore container from its path
+    ///
+    /// Patch containers ending with `_P` override base containers,
+    /// patches with a higher version number like `pakchunk0_2_P.pak` override patches with a lower one.
+    pub fn get_container_priority<P: AsRef<Path>>(path: P) -> i32 {
+        let Some(stem) = path.as_ref().file_stem().and_then(|e| e.to_str()) else {
+            return 0;
+        };
+        let Some(stem) = stem.strip_suffix("_P") else {
+            return 0;
+        };
+
+        let version = stem
+            .rsplit_once('_')
+            .and_then(|(_, version)| version.parse::<i32>().ok())
+            .map(|e| e + 1)
+            .unwrap_or(1);
+        version.saturating_mul(100)
+    }
+
+    /// Mount a file provider
+    ///
+    /// If providers have the same priority, the provider mounted last takes precedence
+    pub fn add_provider<T: FileProvider + 'static>(&mut self, provider: T, priority: i32) {
+        let index = self
+            .providers
+            .iter()
+            .position(|e| e.priority <= priority)
+            .unwrap_or(self.providers.len());
+        self.providers.insert(
+            index,
+            MountedProvider {
+                priority,
+                provider: Box::new(provider),
+            },
+        );
+    }
+
+    /// Get the number of mounted providers
+    pub fn provider_count(&self) -> usize {
+        self.providers.len()
+    }
+
+    /// Find the provider a file is read from
+    fn find_provider(&mut self, path: &str) -> Option<&mut MountedProvider> {
+        self.providers
+            .iter_mut()
+            .find(|e| e.provider.has_file(path))
+    }
+
+    /// Resolve a game path like `/Game/Maps/Foo` to the path of its .uasset or .umap file
+    pub fn resolve_package(&self, game_path: &str) -> Option<String> {
+        let path = game_to_absolute(&self.game_name, game_path)?;
+        let has_extension = Path::new(game_path).extension().is_some();
+
+        if self.has_file(&path) {
+            return Some(path);
+        }
+        if has_extension {
+            return None;
+        }
+
+        let path = Path::new(&path).with_extension("umap");
+        let path = path.to_str()?;
+        self.has_file(path).then(|| path.to_string())
+    }
+
+    /// Open the .uasset, .uexp and .ubulk streams of a package by its game path, e.g. `/Game/Maps/Foo`
+    pub fn open_package(&mut self, game_path: &str) -> Result<PackageStreams, Error> {
+        let path = self.resolve_package(game_path).ok_or_else(|| {
+            match game_to_absolute(&self.game_name, game_path) {
+                Some(path) => FileProviderError::NoFile(path.into_boxed_str()),
+                None => FileProviderError::InvalidGamePath(game_path.to_string().into_boxed_str()),
+            }
+        })?;
+
+        let asset = Cursor::new(self.read_file(&path)?);
+        let mut open_optional = |extension: &str| -> Result<Option<Cursor<Vec<u8>>>, Error> {
+            let path = Path::new(&path).with_extension(extension);
+            match path.to_str().filter(|e| self.has_file(e)) {
+                Some(path) => Ok(Some(Cursor::new(self.read_file(path)?))),
+                None => Ok(None),
+            }
+        };
+
+        Ok(PackageStreams {
+            asset,
+            exports: open_optional("uexp")?,
+            bulk: open_optional("ubulk")?,
+        })
+    }
+
+    /// Read an asset by its game path, e.g. `/Game/Maps/Foo`
+    ///
+    /// Packages are read as zen packages if the provider they are read from is an IoStore container,
+    /// imports of zen packages are not resolved across packages
+    pub fn read_asset(
+        &mut self,
+        game_path: &str,
+        engine_version: EngineVersion,
+        mappings: Option<Usmap>,
+    ) -> Result<ProviderAsset, Error> {
+        let package_name = Path::new(game_path).with_extension("");
+        let package_name = package_name.to_str().unwrap_or(game_path);
+        let path = self.resolve_package(game_path);
+
+        let is_zen_package = self
+            .providers
+            .iter()
+            .find(|e| {
+                e.provider.has_zen_package(package_name)
+                    || path.as_ref().is_some_and(|path| e.provider.has_file(path))
+            })
+            .is_some_and(|e| e.provider.has_zen_package(package_name));
+        if is_zen_package {
+            return self
+                .read_zen_asset(package_name, engine_version, mappings)
+                .map(ProviderAsset::Zen);
+        }
+
+        let streams = self.open_package(game_path)?;
+        Asset::new(streams.asset, streams.exports, engine_version, mappings)
+            .map(ProviderAsset::Legacy)
+    }
+
+    /// Read a zen package from an IoStore container by its package name, e.g. `/Game/Maps/Foo`
+    ///
+    /// Script imports are resolved with [`GameFileProvider::script_objects`], or the mappings if there are none
+    pub fn read_zen_asset(
+        &mut self,
+        package_name: &str,
+        engine_version: EngineVersion,
+        mappings: Option<Usmap>,
+    ) -> Result<ZenAsset<Cursor<Vec<u8>>>, Error> {
+        let data = self.read_zen_package(package_name)?;
+        ZenAsset::new(
+            Cursor::new(data),
+            engine_version,
+            mappings,
+            self.script_objects.clone(),
+        )
+    }
+
+    /// Read the game's asset registry from `<game_name>/AssetRegistry.bin`
+    pub fn read_asset_registry(
+        &mut self,
+        engine_version: EngineVersion,
+    ) -> Result<AssetRegistryState, Error> {
+        let data = self.read_file(&format!("{}/AssetRegistry.bin", self.game_name))?;
+
+        let (object_version, object_version_ue5) =
+            engine_version::get_object_versions(engine_version);
+        let mut reader = RawReader::new(
+            Chain::new(Cursor::new(data), None),
+            object_version,
+            object_version_ue5,
+            false,
+            NameMap::new(),
+        );
+        AssetRegistryState::new(&mut reader)
+    }
+}
+
+impl FileProvider for GameFileProvider {
+    fn has_file(&self, path: &str) -> bool {
+        self.providers.iter().any(|e| e.provider.has_file(path))
+    }
+
+    fn read_file(&mut self, path: &str) -> Result<Vec<u8>, Error> {
+        match self.find_provider(path) {
+            Some(mounted) => mounted.provider.read_file(path),
+            None => Err(FileProviderError::NoFile(path.to_string().into_boxed_str()).into()),
+        }
+    }
+
+    fn get_file_names(&self) -> Vec<String> {
+        let mut files = self
+            .providers
+            .iter()
+            .flat_map(|e| e.provider.get_file_names())
+            .map(|e| normalize_path(&e).to_string())
+            .collect::<Vec<_>>();
+        files.sort();
+        files.dedup();
+        files
+    }
+
+    fn has_zen_package(&self, package_name: &str) -> bool {
+        self.providers
+            .iter()
+            .any(|e| e.provider.has_zen_package(package_name))
+    }
+
+    fn read_zen_package(&mut self, package_name: &str) -> Result<Vec<u8>, Error> {
+        match self
+            .providers
+            .iter_mut()
+            .find(|e| e.provider.has_zen_package(package_name))
+        {
+            Some(mounted) => mounted.provider.read_zen_package(package_name),
+            None => {
+                Err(FileProviderError::NoFile(package_name.to_string().into_boxed_str()).into())
+            }
+        }
+    }
+}
+
+/// Recursively collect pak and IoStore container paths in a directory
+fn collect_containers(dir: &Path, containers: &mut Vec<std::path::PathBuf>) -> Result<(), Error> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_containers(&path, containers)?;
+        } else if matches!(
+            path.extension().and_then(|e| e.to_str()),
+            Some("pak") | Some("utoc")
+        ) {
+            containers.push(path);
+        }
+    }
+
+    Ok(())
+}
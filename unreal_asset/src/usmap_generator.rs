@@ -0,0 +1,352 @@
+//! Usmap schema generation from class, struct and enum exports
+
+use std::io::{Read, Seek};
+
+use crate::asset::Asset;
+use crate::containers::IndexedMap;
+use crate::error::Error;
+use crate::exports::{
+    enum_export::EnumExport,
+    properties::{
+        fproperty::{FProperty, FPropertyTrait},
+        uproperty::{UProperty, UPropertyTrait},
+    },
+    property_export::PropertyExport,
+    struct_export::StructExport,
+    Export, ExportBaseTrait,
+};
+use crate::types::{PackageIndex, PackageIndexTrait};
+use crate::unversioned::{
+    properties::{
+        array_property::UsmapArrayPropertyData, enum_property::UsmapEnumPropertyData,
+        map_property::UsmapMapPropertyData, optional_property::UsmapOptionalPropertyData,
+        set_property::UsmapSetPropertyData, shallow_property::UsmapShallowPropertyData,
+        struct_property::UsmapStructPropertyData, EPropertyType, UsmapProperty, UsmapPropertyData,
+    },
+    Usmap, UsmapSchema,
+};
+
+/// Generates usmap schemas and enums from parsed assets
+///
+/// Blueprint classes, user defined structs and user defined enums are usually missing from game mappings,
+/// schemas generated from the assets defining them can fill the gaps.
+#[derive(Debug, Clone, Default)]
+pub struct UsmapGenerator {
+    /// Generated enums
+    pub enum_map: IndexedMap<String, Vec<String>>,
+    /// Generated schemas
+    pub schemas: IndexedMap<String, UsmapSchema>,
+}
+
+impl UsmapGenerator {
+    /// Create a new `UsmapGenerator`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generate schemas and enums from the class, struct and enum exports of an asset
+    ///
+    /// # Arguments
+    ///
+    /// * `asset` - asset to generate schemas from
+    /// * `module_path` - module path of the generated schemas, e.g. `/Game/Blueprints/BP_Foo`
+    pub fn add_asset<C: Read + Seek>(
+        &mut self,
+        asset: &Asset<C>,
+        module_path: Option<&str>,
+    ) -> Result<(), Error> {
+        for export in &asset.asset_data.exports {
+            match export {
+                Export::ClassExport(class) => {
+                    self.add_struct(asset, &class.struct_export, module_path)?
+                }
+                Export::StructExport(struct_export) => {
+                    self.add_struct(asset, struct_export, module_path)?
+                }
+                Export::UserDefinedStructExport(user_defined_struct) => {
+                    self.add_struct(asset, &user_defined_struct.struct_export, module_path)?
+                }
+                Export::EnumExport(enum_export) => self.add_enum(enum_export),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Add generated schemas and enums that are missing from a usmap, existing entries are kept
+    ///
+    /// Returns the amount of added schemas and enums
+    pub fn merge_into(&self, usmap: &mut Usmap) -> usize {
+        let mut added = 0;
+
+        for (_, name, values) in self.enum_map.iter() {
+            if !usmap.enum_map.contains_key(name) {
+                usmap.enum_map.insert(name.clone(), values.clone());
+                added += 1;
+            }
+        }
+
+        for (_, name, schema) in self.schemas.iter() {
+            if !usmap.schemas.contains_key(name) {
+                usmap.schemas.insert(name.clone(), schema.clone());
+                added += 1;
+            }
+        }
+
+        added
+    }
+
+    /// Generate an enum from an enum export
+    fn add_enum(&mut self, enum_export: &EnumExport<PackageIndex>) {
+        let name = enum_export
+            .get_base_export()
+            .object_name
+            .get_owned_content();
+        let values = enum_export
+            .value
+            .names
+            .iter()
+            .map(|(name, _)| name.get_owned_content())
+            .collect();
+
+        self.enum_map.insert(name, values);
+    }
+
+    /// Generate a schema from a struct export
+    fn add_struct<C: Read + Seek>(
+        &mut self,
+        asset: &Asset<C>,
+        struct_export: &StructExport<PackageIndex>,
+        module_path: Option<&str>,
+    ) -> Result<(), Error> {
+        let name = struct_export
+            .normal_export
+            .base_export
+            .object_name
+            .get_owned_content();
+        let super_type = match struct_export.super_struct.index == 0 {
+            true => String::new(),
+            false => get_object_name(asset, struct_export.super_struct)?,
+        };
+
+        let mut schema = UsmapSchema {
+            name: name.clone(),
+            super_type,
+            prop_count: 0,
+            module_path: module_path.map(|e| e.to_string()),
+            properties: IndexedMap::new(),
+        };
+
+        // since 4.25 properties are stored in the struct, before that they are separate exports
+        match struct_export.loaded_properties.is_empty() {
+            false => {
+                for property in &struct_export.loaded_properties {
+                    let generic_property = property.get_generic_property();
+                    add_property(
+                        &mut schema,
+                        generic_property.name.get_owned_content(),
+                        i32::from(generic_property.array_dim),
+                        convert_fproperty(asset, property)?,
+                    );
+                }
+            }
+            true => {
+                for child in &struct_export.children {
+                    let Some(Export::PropertyExport(property)) = asset.get_export(*child) else {
+                        continue;
+                    };
+                    add_property(
+                        &mut schema,
+                        property.get_base_export().object_name.get_owned_content(),
+                        i32::from(property.property.get_generic_property().array_dim),
+                        convert_uproperty(asset, property)?,
+                    );
+                }
+            }
+        }
+
+        self.schemas.insert(name, schema);
+        Ok(())
+    }
+}
+
+/// Add a property to a schema, properties with an array size take up multiple schema indices
+fn add_property(
+    schema: &mut UsmapSchema,
+    name: String,
+    array_dim: i32,
+    property_data: UsmapPropertyData,
+) {
+    let array_size = array_dim.clamp(1, u8::MAX as i32) as u8;
+    let schema_index = schema.prop_count;
+
+    for array_index in 0..array_size as u16 {
+        let property = UsmapProperty {
+            name: name.clone(),
+            schema_index: schema_index + array_index,
+            array_size,
+            array_index,
+            property_data: property_data.clone(),
+        };
+        schema
+            .properties
+            .insert((name.clone(), property.schema_index as u32), property);
+    }
+
+    schema.prop_count += array_size as u16;
+}
+
+/// Get the object name of an import or an export
+fn get_object_name<C: Read + Seek>(asset: &Asset<C>, index: PackageIndex) -> Result<String, Error> {
+    let name = match index.is_import() {
+        true => asset.get_import(index).map(|e| e.object_name),
+        false => asset
+            .get_export(index)
+            .map(|e| e.get_base_export().object_name.clone()),
+    };
+
+    name.map(|e| e.get_owned_content()).ok_or_else(|| {
+        Error::invalid_package_index(format!("Failed to resolve package index {}", index.index))
+    })
+}
+
+/// Create shallow usmap property data
+fn shallow(property_type: EPropertyType) -> UsmapPropertyData {
+    UsmapShallowPropertyData { property_type }.into()
+}
+
+/// Get the usmap property type of a property type name
+fn get_property_type(name: &str) -> EPropertyType {
+    match name {
+        "ClassProperty" => EPropertyType::ObjectProperty,
+        "SoftClassProperty" => EPropertyType::SoftObjectProperty,
+        "MulticastInlineDelegateProperty" | "MulticastSparseDelegateProperty" => {
+            EPropertyType::MulticastDelegateProperty
+        }
+        _ => EPropertyType::from_name(name).unwrap_or(EPropertyType::Unknown),
+    }
+}
+
+/// Create enum usmap property data, byte properties with an enum are stored as enum properties
+fn enum_property(name: String, inner_property: UsmapPropertyData) -> UsmapPropertyData {
+    UsmapEnumPropertyData {
+        inner_property: Box::new(inner_property),
+        name,
+    }
+    .into()
+}
+
+/// Convert an `FProperty` to usmap property data
+fn convert_fproperty<C: Read + Seek>(
+    asset: &Asset<C>,
+    property: &FProperty,
+) -> Result<UsmapPropertyData, Error> {
+    Ok(match property {
+        FProperty::FEnumProperty(property) => enum_property(
+            get_object_name(asset, property.enum_value)?,
+            convert_fproperty(asset, &property.underlying_prop)?,
+        ),
+        FProperty::FByteProperty(property) => match property.enum_value.index == 0 {
+            true => shallow(EPropertyType::ByteProperty),
+            false => enum_property(
+                get_object_name(asset, property.enum_value)?,
+                shallow(EPropertyType::ByteProperty),
+            ),
+        },
+        FProperty::FArrayProperty(property) => UsmapArrayPropertyData {
+            inner_type: Box::new(convert_fproperty(asset, &property.inner)?),
+        }
+        .into(),
+        FProperty::FSetProperty(property) => UsmapSetPropertyData {
+            inner_type: Box::new(convert_fproperty(asset, &property.element_prop)?),
+        }
+        .into(),
+        FProperty::FOptionalProperty(property) => UsmapOptionalPropertyData {
+            inner_type: Box::new(convert_fproperty(asset, &property.value_property)?),
+        }
+        .into(),
+        FProperty::FMapProperty(property) => UsmapMapPropertyData {
+            inner_type: Box::new(convert_fproperty(asset, &property.key_prop)?),
+            value_type: Box::new(convert_fproperty(asset, &property.value_prop)?),
+        }
+        .into(),
+        FProperty::FStructProperty(property) => UsmapStructPropertyData {
+            struct_type: get_object_name(asset, property.struct_value)?,
+        }
+        .into(),
+        FProperty::FObjectProperty(_) | FProperty::FClassProperty(_) => {
+            shallow(EPropertyType::ObjectProperty)
+        }
+        FProperty::FSoftObjectProperty(_) | FProperty::FSoftClassProperty(_) => {
+            shallow(EPropertyType::SoftObjectProperty)
+        }
+        FProperty::FDelegateProperty(_) => shallow(EPropertyType::DelegateProperty),
+        FProperty::FMulticastDelegateProperty(_)
+        | FProperty::FMulticastInlineDelegateProperty(_) => {
+            shallow(EPropertyType::MulticastDelegateProperty)
+        }
+        FProperty::FInterfaceProperty(_) => shallow(EPropertyType::InterfaceProperty),
+        FProperty::FBoolProperty(_) => shallow(EPropertyType::BoolProperty),
+        FProperty::FNumericProperty(_) => shallow(EPropertyType::Unknown),
+        FProperty::FGenericProperty(property) => shallow(
+            property
+                .serialized_type
+                .as_ref()
+                .map(|e| e.get_content(get_property_type))
+                .unwrap_or(EPropertyType::Unknown),
+        ),
+    })
+}
+
+/// Convert a `UProperty` export to usmap property data
+fn convert_uproperty<C: Read + Seek>(
+    asset: &Asset<C>,
+    property: &PropertyExport<PackageIndex>,
+) -> Result<UsmapPropertyData, Error> {
+    // inner properties of containers are separate property exports
+    let convert_inner = |index: PackageIndex| match asset.get_export(index) {
+        Some(Export::PropertyExport(inner)) => convert_uproperty(asset, inner),
+        _ => Err(Error::invalid_package_index(format!(
+            "Inner property {} is not a property export",
+            index.index
+        ))),
+    };
+
+    Ok(match &property.property {
+        UProperty::UEnumProperty(property) => enum_property(
+            get_object_name(asset, property.value)?,
+            convert_inner(property.underlying_prop)?,
+        ),
+        UProperty::UByteProperty(property) => match property.enum_value.index == 0 {
+            true => shallow(EPropertyType::ByteProperty),
+            false => enum_property(
+                get_object_name(asset, property.enum_value)?,
+                shallow(EPropertyType::ByteProperty),
+            ),
+        },
+        UProperty::UArrayProperty(property) => UsmapArrayPropertyData {
+            inner_type: Box::new(convert_inner(property.inner)?),
+        }
+        .into(),
+        UProperty::USetProperty(property) => UsmapSetPropertyData {
+            inner_type: Box::new(convert_inner(property.element_prop)?),
+        }
+        .into(),
+        UProperty::UMapProperty(property) => UsmapMapPropertyData {
+            inner_type: Box::new(convert_inner(property.key_prop)?),
+            value_type: Box::new(convert_inner(property.value_prop)?),
+        }
+        .into(),
+        UProperty::UStructProperty(property) => UsmapStructPropertyData {
+            struct_type: get_object_name(asset, property.struct_value)?,
+        }
+        .into(),
+        UProperty::UUInt8Property(_) => shallow(EPropertyType::ByteProperty),
+        // the export class is the property type
+        _ => shallow(get_property_type(&get_object_name(
+            asset,
+            property.get_base_export().class_index,
+        )?)),
+    })
+}
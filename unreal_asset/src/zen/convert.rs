@@ -0,0 +1,742 @@
+//! Conversion between legacy assets and zen packages
+
+use std::collections::HashMap;
+use std::io::{Cursor, Read, Seek, Write};
+
+use unreal_asset_base::{
+    containers::{Chain, NameMap, SharedResource},
+    enums::{EZenPackageVersion, HASH_VERSION_CITYHASH64},
+    error::Error,
+    flags::EObjectFlags,
+    reader::{ArchiveTrait, RawReader, RawWriter},
+    types::{FName, PackageIndex, PackageIndexTrait, PackageObjectIndex},
+    Import,
+};
+use unreal_asset_exports::{
+    BaseExport, Export, ExportBaseTrait, ExportNormalTrait, ExportTrait, RawExport,
+};
+
+use crate::asset::Asset;
+use crate::asset_archive_writer::AssetArchiveWriter;
+use crate::asset_data::AssetData;
+
+use super::{
+    get_zen_version, EExportCommandType, ExportBundleEntry, ExportBundleHeader, ExternalArc,
+    ScriptObjects, ZenArchiveWriter, ZenAsset, ZenExportMapEntry, ZenPackageSummary,
+    ZenPackageVersioningInfo,
+};
+
+/// Import path element, object name with class package and class name
+type ImportPathElement = (String, String, String);
+
+/// Class of packages
+const PACKAGE_CLASS: (&str, &str) = ("/Script/CoreUObject", "Package");
+
+/// Join object path elements, e.g. `/Game/Package.Object:Subobject`
+fn join_object_path<'a>(elements: impl IntoIterator<Item = &'a str>) -> String {
+    let mut path = String::new();
+    for (i, element) in elements.into_iter().enumerate() {
+        match i {
+            0 => {}
+            1 => path.push('.'),
+            _ => path.push(':'),
+        }
+        path.push_str(element);
+    }
+    path
+}
+
+/// Split an object path into a package name and an object name
+fn split_object_path(object_path: &str) -> (String, String) {
+    let package = object_path
+        .split_once('.')
+        .map(|(package, _)| package)
+        .unwrap_or(object_path);
+    let name = object_path.rsplit(['.', ':']).next().unwrap_or(object_path);
+    (package.to_string(), name.to_string())
+}
+
+/// Order export bundle entries so that every command comes after its dependencies
+///
+/// Entries that can't be ordered because of circular dependencies are appended in export order.
+fn sort_export_bundle_entries<Index: PackageIndexTrait>(
+    exports: &[Export<Index>],
+) -> Vec<ExportBundleEntry> {
+    let export_count = exports.len();
+    let node_index = |export: usize, command_type: EExportCommandType| {
+        command_type as usize * export_count + export
+    };
+    let exports_of = |dependencies: &[PackageIndex]| -> Vec<usize> {
+        dependencies
+            .iter()
+            .filter(|e| e.is_export() && (e.index as usize) <= export_count)
+            .map(|e| e.index as usize - 1)
+            .collect()
+    };
+
+    let mut dependencies = vec![Vec::new(); export_count * 2];
+    for (i, export) in exports.iter().enumerate() {
+        let base_export = export.get_base_export();
+
+        let create = &mut dependencies[node_index(i, EExportCommandType::Create)];
+        for dependency in exports_of(&base_export.create_before_create_dependencies) {
+            create.push(node_index(dependency, EExportCommandType::Create));
+        }
+        for dependency in exports_of(&base_export.serialization_before_create_dependencies) {
+            create.push(node_index(dependency, EExportCommandType::Serialize));
+        }
+
+        let serialize = &mut dependencies[node_index(i, EExportCommandType::Serialize)];
+        serialize.push(node_index(i, EExportCommandType::Create));
+        for dependency in exports_of(&base_export.create_before_serialization_dependencies) {
+            serialize.push(node_index(dependency, EExportCommandType::Create));
+        }
+        for dependency in exports_of(&base_export.serialization_before_serialization_dependencies) {
+            serialize.push(node_index(dependency, EExportCommandType::Serialize));
+        }
+    }
+
+    let mut done = vec![false; export_count * 2];
+    let mut order = Vec::with_capacity(export_count * 2);
+    let mut progress = true;
+    while progress {
+        progress = false;
+        for node in 0..export_count * 2 {
+            if done[node] || dependencies[node].iter().any(|e| *e != node && !done[*e]) {
+                continue;
+            }
+            done[node] = true;
+            order.push(node);
+            progress = true;
+        }
+    }
+    order.extend((0..export_count * 2).filter(|e| !done[*e]));
+
+    order
+        .into_iter()
+        .map(|e| ExportBundleEntry {
+            local_export_index: (e % export_count) as u32,
+            command_type: match e < export_count {
+                true => EExportCommandType::Create,
+                false => EExportCommandType::Serialize,
+            },
+        })
+        .collect()
+}
+
+impl<C: Read + Seek> Asset<C> {
+    /// Get import path elements starting with the package
+    fn get_import_path(&self, index: PackageIndex) -> Vec<String> {
+        let mut elements = Vec::new();
+        let mut current = self.get_import(index);
+        while let Some(import) = current {
+            // guard against circular outers
+            if elements.len() > self.imports.len() {
+                break;
+            }
+            elements.push(import.object_name.get_owned_content());
+            current = self.get_import(import.outer_index);
+        }
+        elements.reverse();
+        elements
+    }
+
+    /// Get export path elements relative to the package
+    fn get_export_path(&self, index: PackageIndex) -> Vec<String> {
+        let mut elements = Vec::new();
+        let mut current = self.asset_data.get_export(index);
+        while let Some(export) = current {
+            if elements.len() > self.asset_data.exports.len() {
+                break;
+            }
+            let base_export = export.get_base_export();
+            elements.push(base_export.object_name.get_owned_content());
+            current = self.asset_data.get_export(base_export.outer_index);
+        }
+        elements.reverse();
+        elements
+    }
+
+    /// Convert this asset to a zen package
+    ///
+    /// Imports are translated to script imports and package imports, for zen versions before
+    /// [`EZenPackageVersion::ImportedPackageNames`] the imported package names are still filled in
+    /// so they can be stored in the container package store.
+    ///
+    /// # Arguments
+    ///
+    /// * `package_name` - package name, e.g. `/Game/Maps/Level`
+    /// * `script_objects` - global script objects, used to parse the exports of the converted package
+    pub fn to_zen(
+        &self,
+        package_name: &str,
+        script_objects: Option<SharedResource<ScriptObjects>>,
+    ) -> Result<ZenAsset<Cursor<Vec<u8>>>, Error> {
+        let zen_version = get_zen_version(self.asset_data.object_version_ue5);
+
+        // name indices are kept so export data can be copied as is
+        let mut name_map = NameMap::new();
+        for name in self.get_name_map().get_ref().get_name_map_index_list() {
+            name_map.get_mut().add_name_reference(name.clone(), true);
+        }
+
+        let mut imported_package_names = Vec::<String>::new();
+        let mut imported_public_export_hashes = Vec::<u64>::new();
+        let mut import_map = Vec::with_capacity(self.imports.len());
+        for i in 0..self.imports.len() {
+            let path = self.get_import_path(PackageIndex::from_import(i as i32)?);
+            let Some(package) = path.first() else {
+                import_map.push(PackageObjectIndex::null());
+                continue;
+            };
+
+            let import = if package.starts_with("/Script/") {
+                PackageObjectIndex::from_script_path(&join_object_path(
+                    path.iter().map(|e| e.as_str()),
+                ))
+            } else if path.len() == 1 {
+                // packages can't be imported by themselves
+                PackageObjectIndex::null()
+            } else {
+                let package_index = match imported_package_names.iter().position(|e| e == package) {
+                    Some(e) => e,
+                    None => {
+                        imported_package_names.push(package.clone());
+                        imported_package_names.len() - 1
+                    }
+                };
+
+                let hash = ZenExportMapEntry::get_public_export_hash(&path[1..].join("/"));
+                let hash_index = match imported_public_export_hashes
+                    .iter()
+                    .position(|e| *e == hash)
+                {
+                    Some(e) => e,
+                    None => {
+                        imported_public_export_hashes.push(hash);
+                        imported_public_export_hashes.len() - 1
+                    }
+                };
+
+                PackageObjectIndex::from_package_import(package_index as u32, hash_index as u32)
+            };
+            import_map.push(import);
+        }
+
+        let convert_index = |index: PackageIndex| -> PackageObjectIndex {
+            if index.is_import() {
+                import_map
+                    .get((-index.index - 1) as usize)
+                    .copied()
+                    .unwrap_or_default()
+            } else if index.is_export() {
+                PackageObjectIndex::from_export(index.index as u32 - 1)
+            } else {
+                PackageObjectIndex::null()
+            }
+        };
+
+        let mut exports = Vec::with_capacity(self.asset_data.exports.len());
+        for (i, export) in self.asset_data.exports.iter().enumerate() {
+            let base_export = export.get_base_export();
+
+            let mut data = Cursor::new(Vec::new());
+            let mut raw_serializer = RawWriter::new(
+                &mut data,
+                self.asset_data.object_version,
+                self.asset_data.object_version_ue5,
+                true,
+                self.get_name_map(),
+            );
+            let mut serializer = AssetArchiveWriter::new(
+                &mut raw_serializer,
+                &self.asset_data,
+                &self.imports,
+                self.get_name_map(),
+            );
+            export.write(&mut serializer)?;
+            if let Some(normal_export) = export.get_normal_export() {
+                serializer.write_all(&normal_export.extras)?;
+            }
+
+            let public_export_hash =
+                match base_export.object_flags.contains(EObjectFlags::RF_PUBLIC) {
+                    true => ZenExportMapEntry::get_public_export_hash(
+                        &self
+                            .get_export_path(PackageIndex::from_export(i as i32)?)
+                            .join("/"),
+                    ),
+                    false => 0,
+                };
+
+            let data = data.into_inner();
+            exports.push(Export::RawExport(RawExport {
+                base_export: BaseExport {
+                    class_index: convert_index(base_export.class_index),
+                    super_index: convert_index(base_export.super_index),
+                    template_index: convert_index(base_export.template_index),
+                    outer_index: convert_index(base_export.outer_index),
+                    object_name: base_export.object_name.clone(),
+                    object_flags: base_export.object_flags,
+                    serial_size: data.len() as i64,
+                    not_for_client: base_export.not_for_client,
+                    not_for_server: base_export.not_for_server,
+                    public_export_hash,
+                    serialization_before_serialization_dependencies: base_export
+                        .serialization_before_serialization_dependencies
+                        .clone(),
+                    create_before_serialization_dependencies: base_export
+                        .create_before_serialization_dependencies
+                        .clone(),
+                    serialization_before_create_dependencies: base_export
+                        .serialization_before_create_dependencies
+                        .clone(),
+                    create_before_create_dependencies: base_export
+                        .create_before_create_dependencies
+                        .clone(),
+                    ..Default::default()
+                },
+                data,
+            }));
+        }
+
+        let export_bundle_entries = sort_export_bundle_entries(&self.asset_data.exports);
+        let export_bundle_headers = match exports.is_empty() {
+            true => Vec::new(),
+            false => vec![ExportBundleHeader {
+                serial_offset: 0,
+                first_entry_index: 0,
+                entry_count: export_bundle_entries.len() as u32,
+            }],
+        };
+
+        // all exports are in a single export bundle, so imports only need arcs to that bundle
+        let mut external_arcs = vec![Vec::<ExternalArc>::new(); imported_package_names.len()];
+        for export in &self.asset_data.exports {
+            let base_export = export.get_base_export();
+            for (dependencies, from_command_type) in [
+                (
+                    &base_export.create_before_create_dependencies,
+                    EExportCommandType::Create,
+                ),
+                (
+                    &base_export.create_before_serialization_dependencies,
+                    EExportCommandType::Create,
+                ),
+                (
+                    &base_export.serialization_before_create_dependencies,
+                    EExportCommandType::Serialize,
+                ),
+                (
+                    &base_export.serialization_before_serialization_dependencies,
+                    EExportCommandType::Serialize,
+                ),
+            ] {
+                for dependency in dependencies.iter().filter(|e| e.is_import()) {
+                    let from_import_index = -dependency.index - 1;
+                    let Some((package_index, _)) = convert_index(*dependency).get_package_import()
+                    else {
+                        continue;
+                    };
+
+                    let arc = ExternalArc {
+                        from_import_index,
+                        from_command_type,
+                        to_export_bundle_index: 0,
+                    };
+                    let arcs = &mut external_arcs[package_index as usize];
+                    if !arcs.contains(&arc) {
+                        arcs.push(arc);
+                    }
+                }
+            }
+        }
+
+        let package_name = name_map.get_mut().add_fname(package_name);
+        let imported_package_names = imported_package_names
+            .into_iter()
+            .map(|e| FName::new_dummy(e, 0))
+            .collect::<Vec<_>>();
+
+        let versioning_info = match self.asset_data.summary.unversioned {
+            true => None,
+            false => Some(ZenPackageVersioningInfo {
+                zen_version,
+                object_version: self.asset_data.object_version,
+                object_version_ue5: self.asset_data.object_version_ue5,
+                licensee_version: self.asset_data.summary.file_licensee_version,
+                custom_versions: self.asset_data.summary.custom_versions.clone(),
+            }),
+        };
+
+        let mut asset_data = AssetData {
+            use_event_driven_loader: true,
+            object_version: self.asset_data.object_version,
+            object_version_ue5: self.asset_data.object_version_ue5,
+            exports,
+            ..Default::default()
+        };
+        asset_data.summary.package_flags = self.asset_data.summary.package_flags;
+        asset_data.summary.unversioned = self.asset_data.summary.unversioned;
+
+        let zen_asset = ZenAsset {
+            raw_reader: RawReader::new(
+                Chain::new(Cursor::new(Vec::new()), None),
+                self.asset_data.object_version,
+                self.asset_data.object_version_ue5,
+                true,
+                name_map.clone(),
+            ),
+            asset_data,
+            zen_version,
+            summary: ZenPackageSummary {
+                cooked_header_size: self
+                    .asset_data
+                    .exports
+                    .first()
+                    .map(|e| e.get_base_export().serial_offset as u32)
+                    .unwrap_or_default(),
+                ..Default::default()
+            },
+            versioning_info,
+            package_name,
+            name_batch_hash_version: HASH_VERSION_CITYHASH64,
+            bulk_data_map: Vec::new(),
+            imported_public_export_hashes,
+            import_map,
+            export_map: Vec::new(),
+            export_bundle_entries,
+            export_bundle_headers,
+            internal_arcs: Vec::new(),
+            external_arcs,
+            dependency_bundle_headers: Vec::new(),
+            dependency_bundle_entries: Vec::new(),
+            imported_package_names: imported_package_names.clone(),
+            script_objects: script_objects.clone(),
+            name_map,
+        };
+
+        let mut cursor = Cursor::new(Vec::new());
+        zen_asset.write_data(&mut cursor)?;
+
+        let mut zen_asset = ZenAsset::new(
+            cursor,
+            self.get_engine_version(),
+            self.asset_data.mappings.clone(),
+            script_objects,
+        )?;
+        if zen_version < EZenPackageVersion::ImportedPackageNames {
+            zen_asset.imported_package_names = imported_package_names;
+        }
+
+        Ok(zen_asset)
+    }
+}
+
+impl<C: Read + Seek> ZenAsset<C> {
+    /// Get the class of an object as a class package and a class name
+    fn get_class_path(&self, class_index: PackageObjectIndex) -> (String, String) {
+        if let Some(export) = class_index
+            .get_export_index()
+            .and_then(|e| self.export_map.get(e as usize))
+        {
+            return (
+                self.package_name.get_owned_content(),
+                export.object_name.get_owned_content(),
+            );
+        }
+
+        self.script_objects
+            .as_ref()
+            .and_then(|e| e.get_ref().get_object_path(class_index))
+            .map(|e| split_object_path(&e))
+            .unwrap_or_else(|| ("/Script/CoreUObject".to_string(), "Object".to_string()))
+    }
+
+    /// Get export path elements starting with the package
+    fn get_export_import_path(&self, export_index: u32) -> Vec<ImportPathElement> {
+        let mut elements = Vec::new();
+        let mut current = self.export_map.get(export_index as usize);
+        while let Some(export) = current {
+            if elements.len() > self.export_map.len() {
+                break;
+            }
+            let (class_package, class_name) = self.get_class_path(export.class_index);
+            elements.push((
+                export.object_name.get_owned_content(),
+                class_package,
+                class_name,
+            ));
+            current = export
+                .outer_index
+                .get_export_index()
+                .and_then(|e| self.export_map.get(e as usize));
+        }
+        elements.push((
+            self.package_name.get_owned_content(),
+            PACKAGE_CLASS.0.to_string(),
+            PACKAGE_CLASS.1.to_string(),
+        ));
+        elements.reverse();
+        elements
+    }
+
+    /// Get script import path elements starting with the package
+    fn get_script_import_path(
+        script_objects: &ScriptObjects,
+        index: PackageObjectIndex,
+    ) -> Option<Vec<ImportPathElement>> {
+        let entry = script_objects.get_entry(index)?;
+        let name = script_objects
+            .name_map
+            .get_ref()
+            .get_owned_name(entry.object_name.get_index() as i32);
+
+        if entry.outer_index.is_null() {
+            return Some(vec![(
+                name,
+                PACKAGE_CLASS.0.to_string(),
+                PACKAGE_CLASS.1.to_string(),
+            )]);
+        }
+
+        // script objects don't store their class, class default objects are the only ones that can be resolved
+        let (class_package, class_name) = match entry.cdo_class_index.is_null() {
+            true => ("/Script/CoreUObject".to_string(), "Class".to_string()),
+            false => split_object_path(&script_objects.get_object_path(entry.cdo_class_index)?),
+        };
+
+        let mut elements = Self::get_script_import_path(script_objects, entry.outer_index)?;
+        elements.push((name, class_package, class_name));
+        Some(elements)
+    }
+
+    /// Get package import path elements starting with the package
+    fn get_package_import_path<D: Read + Seek>(
+        &self,
+        index: PackageObjectIndex,
+        imported_packages: &[&ZenAsset<D>],
+    ) -> Option<Vec<ImportPathElement>> {
+        let (package_index, hash_index) = index.get_package_import()?;
+        let hash = *self
+            .imported_public_export_hashes
+            .get(hash_index as usize)?;
+        let package_name = self
+            .imported_package_names
+            .get(package_index as usize)
+            .map(|e| e.get_owned_content());
+
+        imported_packages
+            .iter()
+            .filter(|e| match package_name {
+                Some(ref name) => e.package_name.get_owned_content() == *name,
+                None => true,
+            })
+            .find_map(|package| {
+                package
+                    .export_map
+                    .iter()
+                    .position(|e| e.public_export_hash == hash)
+                    .map(|e| package.get_export_import_path(e as u32))
+            })
+    }
+
+    /// Convert this zen package to a legacy asset
+    ///
+    /// Script imports are resolved with the global script objects, package imports are resolved
+    /// with the export maps of the imported packages.
+    ///
+    /// # Arguments
+    ///
+    /// * `imported_packages` - packages imported by this package
+    pub fn to_asset<D: Read + Seek>(
+        &self,
+        imported_packages: &[&ZenAsset<D>],
+    ) -> Result<Asset<Cursor<Vec<u8>>>, Error> {
+        let mut asset = Asset::new_unparsed(
+            Cursor::new(Vec::new()),
+            Some(Cursor::new(Vec::new())),
+            self.get_engine_version(),
+            self.asset_data.mappings.clone(),
+        );
+        asset.legacy_file_version = -8;
+        asset.folder_name = String::from("None");
+        asset.asset_data.object_version = self.asset_data.object_version;
+        asset.asset_data.object_version_ue5 = self.asset_data.object_version_ue5;
+        asset.asset_data.summary.unversioned = self.versioning_info.is_none();
+        asset.asset_data.summary.file_licensee_version =
+            self.asset_data.summary.file_licensee_version;
+        asset.asset_data.summary.custom_versions = self.asset_data.summary.custom_versions.clone();
+        asset.asset_data.summary.package_flags = self.asset_data.summary.package_flags;
+
+        // name indices are kept so export data can be copied as is
+        for name in self.name_map.get_ref().get_name_map_index_list() {
+            asset.add_name_reference(name.clone(), true);
+        }
+
+        let mut import_paths = Vec::with_capacity(self.import_map.len());
+        for import in &self.import_map {
+            let path = if import.is_null() {
+                None
+            } else if import.is_script_import() {
+                let script_objects = self.script_objects.as_ref().ok_or_else(|| {
+                    Error::no_data(format!("No script objects to resolve import {}", import))
+                })?;
+                Self::get_script_import_path(&script_objects.get_ref(), *import)
+            } else {
+                self.get_package_import_path(*import, imported_packages)
+            };
+
+            match (import.is_null(), path) {
+                (true, _) => import_paths.push(None),
+                (false, Some(path)) => import_paths.push(Some(path)),
+                (false, None) => {
+                    return Err(Error::invalid_package_index(format!(
+                        "Unresolved import {}",
+                        import
+                    )))
+                }
+            }
+        }
+
+        // the legacy import at index i has to stay at index i, outers are added after them
+        let mut import_indices = HashMap::new();
+        for (i, path) in import_paths.iter().enumerate() {
+            if let Some(path) = path {
+                import_indices
+                    .entry(join_object_path(path.iter().map(|e| e.0.as_str())))
+                    .or_insert(PackageIndex::from_import(i as i32)?);
+            }
+        }
+
+        let none = asset.add_fname("None");
+        let mut imports = vec![
+            Import::new(
+                asset.add_fname(PACKAGE_CLASS.0),
+                asset.add_fname(PACKAGE_CLASS.1),
+                PackageIndex::new(0),
+                none,
+                false,
+            );
+            import_paths.len()
+        ];
+        for (i, path) in import_paths.iter().enumerate() {
+            let Some(path) = path else {
+                continue;
+            };
+
+            let mut outer_index = PackageIndex::new(0);
+            for depth in 0..path.len() {
+                let (object_name, class_package, class_name) = &path[depth];
+                let import = Import::new(
+                    asset.add_fname(class_package),
+                    asset.add_fname(class_name),
+                    outer_index,
+                    asset.add_fname(object_name),
+                    false,
+                );
+
+                if depth == path.len() - 1 {
+                    imports[i] = import;
+                    break;
+                }
+
+                let object_path = join_object_path(path[..=depth].iter().map(|e| e.0.as_str()));
+                outer_index = match import_indices.get(&object_path) {
+                    Some(e) => *e,
+                    None => {
+                        imports.push(import);
+                        let index = PackageIndex::from_import(imports.len() as i32 - 1)?;
+                        import_indices.insert(object_path, index);
+                        index
+                    }
+                };
+            }
+        }
+        asset.imports = imports;
+
+        let mut import_lookup = HashMap::new();
+        for (i, import) in self.import_map.iter().enumerate() {
+            if !import.is_null() {
+                import_lookup.entry(*import).or_insert(i);
+            }
+        }
+        let convert_index = |index: PackageObjectIndex| -> Result<PackageIndex, Error> {
+            if let Some(export_index) = index.get_export_index() {
+                return PackageIndex::from_export(export_index as i32);
+            }
+            match import_lookup.get(&index) {
+                Some(e) => PackageIndex::from_import(*e as i32),
+                None => Ok(PackageIndex::new(0)),
+            }
+        };
+
+        let mut exports = Vec::with_capacity(self.asset_data.exports.len());
+        for export in &self.asset_data.exports {
+            let base_export = export.get_base_export();
+
+            let mut data = Cursor::new(Vec::new());
+            let mut raw_serializer = RawWriter::new(
+                &mut data,
+                self.asset_data.object_version,
+                self.asset_data.object_version_ue5,
+                true,
+                self.name_map.clone(),
+            );
+            let mut serializer = ZenArchiveWriter::new(
+                &mut raw_serializer,
+                &self.asset_data,
+                &self.import_map,
+                self.script_objects.clone(),
+                self.name_map.clone(),
+            );
+            export.write(&mut serializer)?;
+            if let Some(normal_export) = export.get_normal_export() {
+                serializer.write_all(&normal_export.extras)?;
+            }
+
+            let data = data.into_inner();
+            exports.push(Export::RawExport(RawExport {
+                base_export: BaseExport {
+                    class_index: convert_index(base_export.class_index)?,
+                    super_index: convert_index(base_export.super_index)?,
+                    template_index: convert_index(base_export.template_index)?,
+                    outer_index: convert_index(base_export.outer_index)?,
+                    object_name: base_export.object_name.clone(),
+                    object_flags: base_export.object_flags,
+                    serial_size: data.len() as i64,
+                    not_for_client: base_export.not_for_client,
+                    not_for_server: base_export.not_for_server,
+                    generate_public_hash: base_export.public_export_hash != 0,
+                    public_export_hash: base_export.public_export_hash,
+                    serialization_before_serialization_dependencies: base_export
+                        .serialization_before_serialization_dependencies
+                        .clone(),
+                    create_before_serialization_dependencies: base_export
+                        .create_before_serialization_dependencies
+                        .clone(),
+                    serialization_before_create_dependencies: base_export
+                        .serialization_before_create_dependencies
+                        .clone(),
+                    create_before_create_dependencies: base_export
+                        .create_before_create_dependencies
+                        .clone(),
+                    ..Default::default()
+                },
+                data,
+            }));
+        }
+        asset.asset_data.exports = exports;
+
+        let mut uasset = Cursor::new(Vec::new());
+        let mut uexp = Cursor::new(Vec::new());
+        asset.write_data(&mut uasset, Some(&mut uexp))?;
+
+        Asset::new(
+            uasset,
+            Some(uexp),
+            self.get_engine_version(),
+            self.asset_data.mappings.clone(),
+        )
+    }
+}
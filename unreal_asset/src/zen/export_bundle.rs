@@ -0,0 +1,196 @@
+//! Zen export bundles and dependency graph
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use unreal_asset_base::{
+    reader::{ArchiveReader, ArchiveWriter},
+    types::PackageObjectIndex,
+    Error,
+};
+
+/// Export command type
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, TryFromPrimitive, IntoPrimitive,
+)]
+#[repr(u32)]
+pub enum EExportCommandType {
+    /// Create the export
+    Create,
+    /// Serialize the export
+    Serialize,
+}
+
+/// Export bundle entry
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ExportBundleEntry {
+    /// Export index
+    pub local_export_index: u32,
+    /// Command type
+    pub command_type: EExportCommandType,
+}
+
+impl ExportBundleEntry {
+    /// Serialized entry size
+    pub const SIZE: u64 = 8;
+
+    /// Read `ExportBundleEntry` from an archive
+    pub fn read<R: ArchiveReader<PackageObjectIndex>>(archive: &mut R) -> Result<Self, Error> {
+        Ok(ExportBundleEntry {
+            local_export_index: archive.read_u32::<LE>()?,
+            command_type: EExportCommandType::try_from(archive.read_u32::<LE>()?)?,
+        })
+    }
+
+    /// Write `ExportBundleEntry` to an archive
+    pub fn write<W: ArchiveWriter<PackageObjectIndex>>(
+        &self,
+        archive: &mut W,
+    ) -> Result<(), Error> {
+        archive.write_u32::<LE>(self.local_export_index)?;
+        archive.write_u32::<LE>(self.command_type.into())?;
+        Ok(())
+    }
+}
+
+/// Export bundle header
+///
+/// Only used before [`EZenPackageVersion::ImportedPackageNames`](unreal_asset_base::enums::EZenPackageVersion::ImportedPackageNames)
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ExportBundleHeader {
+    /// Serialized offset
+    pub serial_offset: u64,
+    /// First export bundle entry index
+    pub first_entry_index: u32,
+    /// Export bundle entry count
+    pub entry_count: u32,
+}
+
+impl ExportBundleHeader {
+    /// Read `ExportBundleHeader` from an archive
+    pub fn read<R: ArchiveReader<PackageObjectIndex>>(archive: &mut R) -> Result<Self, Error> {
+        Ok(ExportBundleHeader {
+            serial_offset: archive.read_u64::<LE>()?,
+            first_entry_index: archive.read_u32::<LE>()?,
+            entry_count: archive.read_u32::<LE>()?,
+        })
+    }
+
+    /// Write `ExportBundleHeader` to an archive
+    pub fn write<W: ArchiveWriter<PackageObjectIndex>>(
+        &self,
+        archive: &mut W,
+    ) -> Result<(), Error> {
+        archive.write_u64::<LE>(self.serial_offset)?;
+        archive.write_u32::<LE>(self.first_entry_index)?;
+        archive.write_u32::<LE>(self.entry_count)?;
+        Ok(())
+    }
+}
+
+/// Dependency arc between two export bundles of the same package
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct InternalArc {
+    /// Export bundle index the arc starts at
+    pub from_export_bundle_index: i32,
+    /// Export bundle index the arc ends at
+    pub to_export_bundle_index: i32,
+}
+
+impl InternalArc {
+    /// Read `InternalArc` from an archive
+    pub fn read<R: ArchiveReader<PackageObjectIndex>>(archive: &mut R) -> Result<Self, Error> {
+        Ok(InternalArc {
+            from_export_bundle_index: archive.read_i32::<LE>()?,
+            to_export_bundle_index: archive.read_i32::<LE>()?,
+        })
+    }
+
+    /// Write `InternalArc` to an archive
+    pub fn write<W: ArchiveWriter<PackageObjectIndex>>(
+        &self,
+        archive: &mut W,
+    ) -> Result<(), Error> {
+        archive.write_i32::<LE>(self.from_export_bundle_index)?;
+        archive.write_i32::<LE>(self.to_export_bundle_index)?;
+        Ok(())
+    }
+}
+
+/// Dependency arc between an import and an export bundle
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ExternalArc {
+    /// Import index the arc starts at
+    pub from_import_index: i32,
+    /// Command of the import that needs to be finished
+    pub from_command_type: EExportCommandType,
+    /// Export bundle index the arc ends at
+    pub to_export_bundle_index: i32,
+}
+
+impl ExternalArc {
+    /// Read `ExternalArc` from an archive
+    pub fn read<R: ArchiveReader<PackageObjectIndex>>(archive: &mut R) -> Result<Self, Error> {
+        Ok(ExternalArc {
+            from_import_index: archive.read_i32::<LE>()?,
+            from_command_type: EExportCommandType::try_from(archive.read_u8()? as u32)?,
+            to_export_bundle_index: archive.read_i32::<LE>()?,
+        })
+    }
+
+    /// Write `ExternalArc` to an archive
+    pub fn write<W: ArchiveWriter<PackageObjectIndex>>(
+        &self,
+        archive: &mut W,
+    ) -> Result<(), Error> {
+        archive.write_i32::<LE>(self.from_import_index)?;
+        archive.write_u8(u32::from(self.from_command_type) as u8)?;
+        archive.write_i32::<LE>(self.to_export_bundle_index)?;
+        Ok(())
+    }
+}
+
+/// Dependency bundle header
+///
+/// Only used since [`EZenPackageVersion::ImportedPackageNames`](unreal_asset_base::enums::EZenPackageVersion::ImportedPackageNames)
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DependencyBundleHeader {
+    /// First dependency bundle entry index
+    pub first_entry_index: i32,
+    /// Dependency bundle entry counts, indexed by the command type of the export and the command type of the dependency
+    pub entry_count: [[u32; 2]; 2],
+}
+
+impl DependencyBundleHeader {
+    /// Read `DependencyBundleHeader` from an archive
+    pub fn read<R: ArchiveReader<PackageObjectIndex>>(archive: &mut R) -> Result<Self, Error> {
+        let first_entry_index = archive.read_i32::<LE>()?;
+
+        let mut entry_count = [[0u32; 2]; 2];
+        for counts in entry_count.iter_mut() {
+            for count in counts.iter_mut() {
+                *count = archive.read_u32::<LE>()?;
+            }
+        }
+
+        Ok(DependencyBundleHeader {
+            first_entry_index,
+            entry_count,
+        })
+    }
+
+    /// Write `DependencyBundleHeader` to an archive
+    pub fn write<W: ArchiveWriter<PackageObjectIndex>>(
+        &self,
+        archive: &mut W,
+    ) -> Result<(), Error> {
+        archive.write_i32::<LE>(self.first_entry_index)?;
+        for counts in &self.entry_count {
+            for count in counts {
+                archive.write_u32::<LE>(*count)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,202 @@
+//! Zen export map
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use unreal_asset_base::{
+    crc,
+    flags::EObjectFlags,
+    reader::{ArchiveReader, ArchiveWriter},
+    types::{FName, PackageObjectIndex},
+    Error, FNameContainer,
+};
+use unreal_asset_exports::{base_export::EExportFilterFlags, BaseExport};
+
+/// Zen export map entry
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZenExportMapEntry {
+    /// Serialized offset in the legacy cooked package
+    pub cooked_serial_offset: u64,
+    /// Serialized size
+    pub cooked_serial_size: u64,
+    /// Object name
+    pub object_name: FName,
+    /// Outer index
+    #[container_ignore]
+    pub outer_index: PackageObjectIndex,
+    /// Class index
+    #[container_ignore]
+    pub class_index: PackageObjectIndex,
+    /// Super index
+    #[container_ignore]
+    pub super_index: PackageObjectIndex,
+    /// Template index
+    #[container_ignore]
+    pub template_index: PackageObjectIndex,
+    /// Public export hash
+    pub public_export_hash: u64,
+    /// Object flags
+    #[container_ignore]
+    pub object_flags: EObjectFlags,
+    /// Filter flags
+    #[container_ignore]
+    pub filter_flags: EExportFilterFlags,
+}
+
+impl ZenExportMapEntry {
+    /// Serialized entry size
+    pub const SIZE: u64 = 72;
+
+    /// Get public export hash of an export
+    ///
+    /// # Arguments
+    ///
+    /// * `package_relative_export_path` - export path relative to its package with `/` separators, e.g. `object/subobject`
+    pub fn get_public_export_hash(package_relative_export_path: &str) -> u64 {
+        crc::cityhash64_to_lower(package_relative_export_path)
+    }
+
+    /// Read `ZenExportMapEntry` from an archive
+    pub fn read<R: ArchiveReader<PackageObjectIndex>>(archive: &mut R) -> Result<Self, Error> {
+        let entry = ZenExportMapEntry {
+            cooked_serial_offset: archive.read_u64::<LE>()?,
+            cooked_serial_size: archive.read_u64::<LE>()?,
+            object_name: archive.read_fname()?,
+            outer_index: PackageObjectIndex::new(archive.read_u64::<LE>()?),
+            class_index: PackageObjectIndex::new(archive.read_u64::<LE>()?),
+            super_index: PackageObjectIndex::new(archive.read_u64::<LE>()?),
+            template_index: PackageObjectIndex::new(archive.read_u64::<LE>()?),
+            public_export_hash: archive.read_u64::<LE>()?,
+            object_flags: EObjectFlags::from_bits(archive.read_u32::<LE>()?)
+                .ok_or_else(|| Error::invalid_file("Invalid object flags".to_string()))?,
+            filter_flags: EExportFilterFlags::try_from(archive.read_u8()?)?,
+        };
+
+        // padding
+        archive.read_exact(&mut [0u8; 3])?;
+
+        Ok(entry)
+    }
+
+    /// Write `ZenExportMapEntry` to an archive
+    pub fn write<W: ArchiveWriter<PackageObjectIndex>>(
+        &self,
+        archive: &mut W,
+    ) -> Result<(), Error> {
+        archive.write_u64::<LE>(self.cooked_serial_offset)?;
+        archive.write_u64::<LE>(self.cooked_serial_size)?;
+        archive.write_fname(&self.object_name)?;
+        archive.write_u64::<LE>(self.outer_index.type_and_id)?;
+        archive.write_u64::<LE>(self.class_index.type_and_id)?;
+        archive.write_u64::<LE>(self.super_index.type_and_id)?;
+        archive.write_u64::<LE>(self.template_index.type_and_id)?;
+        archive.write_u64::<LE>(self.public_export_hash)?;
+        archive.write_u32::<LE>(self.object_flags.bits())?;
+        archive.write_u8(self.filter_flags.into())?;
+
+        // padding
+        archive.write_all(&[0u8; 3])?;
+
+        Ok(())
+    }
+
+    /// Create `ZenExportMapEntry` from a [`BaseExport`]
+    ///
+    /// # Arguments
+    ///
+    /// * `base_export` - base export
+    /// * `cooked_serial_offset` - offset of the export data in the legacy cooked package
+    pub fn from_base_export(
+        base_export: &BaseExport<PackageObjectIndex>,
+        cooked_serial_offset: u64,
+    ) -> Self {
+        let filter_flags = match (base_export.not_for_client, base_export.not_for_server) {
+            (true, _) => EExportFilterFlags::NotForClient,
+            (false, true) => EExportFilterFlags::NotForServer,
+            (false, false) => EExportFilterFlags::None,
+        };
+
+        ZenExportMapEntry {
+            cooked_serial_offset,
+            cooked_serial_size: base_export.serial_size as u64,
+            object_name: base_export.object_name.clone(),
+            outer_index: base_export.outer_index,
+            class_index: base_export.class_index,
+            super_index: base_export.super_index,
+            template_index: base_export.template_index,
+            public_export_hash: base_export.public_export_hash,
+            object_flags: base_export.object_flags,
+            filter_flags,
+        }
+    }
+
+    /// Convert `ZenExportMapEntry` to [`BaseExport`]
+    ///
+    /// # Arguments
+    ///
+    /// * `serial_offset` - offset of the export data in the zen package
+    pub fn to_base_export(&self, serial_offset: i64) -> BaseExport<PackageObjectIndex> {
+        BaseExport {
+            class_index: self.class_index,
+            super_index: self.super_index,
+            template_index: self.template_index,
+            outer_index: self.outer_index,
+            object_name: self.object_name.clone(),
+            object_flags: self.object_flags,
+            serial_size: self.cooked_serial_size as i64,
+            serial_offset,
+            not_for_client: self.filter_flags == EExportFilterFlags::NotForClient,
+            not_for_server: self.filter_flags == EExportFilterFlags::NotForServer,
+            public_export_hash: self.public_export_hash,
+            ..Default::default()
+        }
+    }
+}
+
+/// Bulk data map entry
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BulkDataMapEntry {
+    /// Serialized offset
+    pub serial_offset: i64,
+    /// Duplicate serialized offset
+    pub duplicate_serial_offset: i64,
+    /// Serialized size
+    pub serial_size: i64,
+    /// Bulk data flags
+    pub flags: u32,
+}
+
+impl BulkDataMapEntry {
+    /// Serialized entry size
+    pub const SIZE: u64 = 32;
+
+    /// Read `BulkDataMapEntry` from an archive
+    pub fn read<R: ArchiveReader<PackageObjectIndex>>(archive: &mut R) -> Result<Self, Error> {
+        let entry = BulkDataMapEntry {
+            serial_offset: archive.read_i64::<LE>()?,
+            duplicate_serial_offset: archive.read_i64::<LE>()?,
+            serial_size: archive.read_i64::<LE>()?,
+            flags: archive.read_u32::<LE>()?,
+        };
+
+        // padding
+        archive.read_u32::<LE>()?;
+
+        Ok(entry)
+    }
+
+    /// Write `BulkDataMapEntry` to an archive
+    pub fn write<W: ArchiveWriter<PackageObjectIndex>>(
+        &self,
+        archive: &mut W,
+    ) -> Result<(), Error> {
+        archive.write_i64::<LE>(self.serial_offset)?;
+        archive.write_i64::<LE>(self.duplicate_serial_offset)?;
+        archive.write_i64::<LE>(self.serial_size)?;
+        archive.write_u32::<LE>(self.flags)?;
+
+        // padding
+        archive.write_u32::<LE>(0)?;
+
+        Ok(())
+    }
+}
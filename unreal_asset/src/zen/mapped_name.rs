@@ -0,0 +1,79 @@
+//! Zen mapped name
+
+use std::io::{Read, Write};
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use unreal_asset_base::{
+    containers::{NameMap, SharedResource},
+    error::{Error, FNameError},
+    types::{fname::EMappedNameType, FName},
+};
+
+/// A name that is mapped to a name batch
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct MappedName {
+    /// Name batch index, the top 2 bits store the [`EMappedNameType`]
+    pub index: u32,
+    /// Name instance number
+    pub number: u32,
+}
+
+impl MappedName {
+    /// Create a new `MappedName`
+    pub fn new(index: u32, number: u32, ty: EMappedNameType) -> Self {
+        MappedName {
+            index: (index & FName::INDEX_MASK) | ((u16::from(ty) as u32) << FName::TYPE_SHIFT),
+            number,
+        }
+    }
+
+    /// Get name batch index
+    pub fn get_index(&self) -> u32 {
+        self.index & FName::INDEX_MASK
+    }
+
+    /// Get name type
+    pub fn get_type(&self) -> Result<EMappedNameType, Error> {
+        Ok(EMappedNameType::try_from(
+            ((self.index & FName::TYPE_MASK) >> FName::TYPE_SHIFT) as u16,
+        )?)
+    }
+
+    /// Convert this `MappedName` to an `FName` backed by a name map
+    pub fn to_fname(&self, name_map: &SharedResource<NameMap>) -> Result<FName, Error> {
+        Ok(FName::new_with_type(
+            self.get_index() as i32,
+            self.number as i32,
+            self.get_type()?,
+            name_map.clone(),
+        ))
+    }
+
+    /// Create a `MappedName` from an `FName` backed by a name map
+    pub fn from_fname(fname: &FName) -> Result<Self, Error> {
+        match fname {
+            FName::Backed {
+                index, number, ty, ..
+            } => Ok(MappedName::new(*index as u32, *number as u32, *ty)),
+            FName::Dummy { value, number } => {
+                Err(FNameError::dummy_serialize(value, *number).into())
+            }
+        }
+    }
+
+    /// Read a `MappedName` from a reader
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self, Error> {
+        Ok(MappedName {
+            index: reader.read_u32::<LE>()?,
+            number: reader.read_u32::<LE>()?,
+        })
+    }
+
+    /// Write a `MappedName` to a writer
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), Error> {
+        writer.write_u32::<LE>(self.index)?;
+        writer.write_u32::<LE>(self.number)?;
+        Ok(())
+    }
+}
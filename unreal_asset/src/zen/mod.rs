@@ -0,0 +1,903 @@
+//! Zen packages, the cooked package format stored in IoStore containers
+
+use std::fmt::{Debug, Formatter};
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use unreal_asset_base::{
+    cast,
+    containers::{Chain, IndexedMap, NameMap, SharedResource},
+    custom_version::{CustomVersion, CustomVersionTrait},
+    engine_version::EngineVersion,
+    enums::{EZenPackageVersion, HASH_VERSION_CITYHASH64},
+    error::{Error, ZenError},
+    flags::EPackageFlags,
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    passthrough_archive_reader,
+    reader::{ArchiveReader, ArchiveTrait, ArchiveType, ArchiveWriter, RawReader, RawWriter},
+    types::{fname::FNameContainer, FName, PackageIndex, PackageIndexTrait, PackageObjectIndex},
+    unversioned::Usmap,
+    FNameContainer,
+};
+use unreal_asset_exports::{Export, ExportBaseTrait, ExportNormalTrait, ExportTrait};
+
+use crate::asset_data::{AssetData, AssetTrait, ExportReaderTrait};
+
+mod convert;
+pub mod export_bundle;
+pub mod export_map;
+pub mod mapped_name;
+pub mod script_objects;
+pub mod summary;
+pub mod zen_archive_writer;
+
+pub use export_bundle::{
+    DependencyBundleHeader, EExportCommandType, ExportBundleEntry, ExportBundleHeader, ExternalArc,
+    InternalArc,
+};
+pub use export_map::{BulkDataMapEntry, ZenExportMapEntry};
+pub use mapped_name::MappedName;
+pub use script_objects::{ScriptObjectEntry, ScriptObjects};
+pub use summary::{ZenPackageSummary, ZenPackageVersioningInfo};
+pub use zen_archive_writer::ZenArchiveWriter;
+
+/// Get the zen package version used by an engine version
+pub fn get_zen_version(object_version_ue5: ObjectVersionUE5) -> EZenPackageVersion {
+    match object_version_ue5 >= ObjectVersionUE5::DATA_RESOURCES {
+        true => EZenPackageVersion::DataResourceTable,
+        false => EZenPackageVersion::Initial,
+    }
+}
+
+/// Unreal Engine zen package
+#[derive(FNameContainer)]
+pub struct ZenAsset<C: Read + Seek> {
+    /// Raw reader
+    #[container_ignore]
+    pub raw_reader: RawReader<PackageObjectIndex, C>,
+    /// Asset data
+    pub asset_data: AssetData<PackageObjectIndex>,
+
+    /// Zen package version
+    #[container_ignore]
+    pub zen_version: EZenPackageVersion,
+    /// Package summary
+    #[container_ignore]
+    pub summary: ZenPackageSummary,
+    /// Versioning info
+    #[container_ignore]
+    pub versioning_info: Option<ZenPackageVersioningInfo>,
+    /// Package name
+    pub package_name: FName,
+    /// Name batch hash version
+    pub name_batch_hash_version: u64,
+    /// Bulk data map
+    #[container_ignore]
+    pub bulk_data_map: Vec<BulkDataMapEntry>,
+    /// Public export hashes of imported objects
+    pub imported_public_export_hashes: Vec<u64>,
+    /// Import map
+    #[container_ignore]
+    pub import_map: Vec<PackageObjectIndex>,
+    /// Export map
+    pub export_map: Vec<ZenExportMapEntry>,
+    /// Export bundle entries
+    #[container_ignore]
+    pub export_bundle_entries: Vec<ExportBundleEntry>,
+    /// Export bundle headers
+    #[container_ignore]
+    pub export_bundle_headers: Vec<ExportBundleHeader>,
+    /// Arcs between export bundles
+    #[container_ignore]
+    pub internal_arcs: Vec<InternalArc>,
+    /// Arcs between imports and export bundles, grouped by imported package
+    #[container_ignore]
+    pub external_arcs: Vec<Vec<ExternalArc>>,
+    /// Dependency bundle headers
+    #[container_ignore]
+    pub dependency_bundle_headers: Vec<DependencyBundleHeader>,
+    /// Dependency bundle entries
+    #[container_ignore]
+    pub dependency_bundle_entries: Vec<PackageIndex>,
+    /// Imported package names
+    #[container_ignore]
+    pub imported_package_names: Vec<FName>,
+
+    /// Global script objects
+    #[container_ignore]
+    pub script_objects: Option<SharedResource<ScriptObjects>>,
+    /// Name map
+    #[container_ignore]
+    name_map: SharedResource<NameMap>,
+}
+
+impl<'a, C: Read + Seek> ZenAsset<C> {
+    /// Create a zen asset from a binary file
+    ///
+    /// # Arguments
+    ///
+    /// * `asset_data` - zen package data, this is the export bundle data chunk of the package
+    /// * `engine_version` - engine version, required for packages without versioning info
+    /// * `mappings` - .usmap mappings, required for reading exports of packages with unversioned properties
+    /// * `script_objects` - global script objects, if `None` these are reconstructed from the mappings
+    pub fn new(
+        asset_data: C,
+        engine_version: EngineVersion,
+        mappings: Option<Usmap>,
+        script_objects: Option<SharedResource<ScriptObjects>>,
+    ) -> Result<Self, Error> {
+        let name_map = NameMap::new();
+        let raw_reader = RawReader::new(
+            Chain::new(asset_data, None),
+            ObjectVersion::UNKNOWN,
+            ObjectVersionUE5::UNKNOWN,
+            true,
+            name_map.clone(),
+        );
+
+        let script_objects = script_objects.or_else(|| {
+            mappings
+                .as_ref()
+                .map(|e| SharedResource::new(ScriptObjects::from_usmap(e)))
+        });
+
+        let mut asset = ZenAsset {
+            raw_reader,
+            asset_data: AssetData {
+                use_event_driven_loader: true,
+                ..Default::default()
+            },
+            zen_version: EZenPackageVersion::Initial,
+            summary: ZenPackageSummary::default(),
+            versioning_info: None,
+            package_name: FName::default(),
+            name_batch_hash_version: 0,
+            bulk_data_map: Vec::new(),
+            imported_public_export_hashes: Vec::new(),
+            import_map: Vec::new(),
+            export_map: Vec::new(),
+            export_bundle_entries: Vec::new(),
+            export_bundle_headers: Vec::new(),
+            internal_arcs: Vec::new(),
+            external_arcs: Vec::new(),
+            dependency_bundle_headers: Vec::new(),
+            dependency_bundle_entries: Vec::new(),
+            imported_package_names: Vec::new(),
+            script_objects,
+            name_map,
+        };
+        asset.set_engine_version(engine_version);
+        asset.asset_data.mappings = mappings;
+        asset.parse_data()?;
+        Ok(asset)
+    }
+
+    /// Set asset engine version
+    fn set_engine_version(&mut self, engine_version: EngineVersion) {
+        self.asset_data.set_engine_version(engine_version);
+        self.raw_reader.object_version = self.asset_data.object_version;
+        self.raw_reader.object_version_ue5 = self.asset_data.object_version_ue5;
+        self.zen_version = get_zen_version(self.asset_data.object_version_ue5);
+    }
+
+    /// Parse package summary and versioning info
+    fn parse_summary(&mut self) -> Result<(), Error> {
+        self.rewind()?;
+
+        let has_versioning_info = self.read_u32::<LE>()? != 0;
+        if has_versioning_info {
+            // zen version is stored after the summary, whose size depends on the zen version.
+            // Newer summaries have a dependency bundle offset at this position which is never a valid older zen version
+            self.seek(SeekFrom::Start(ZenPackageSummary::SIZE_INITIAL))?;
+            let zen_version = self.read_u32::<LE>()?;
+            self.zen_version =
+                match zen_version < u32::from(EZenPackageVersion::ImportedPackageNames) {
+                    true => EZenPackageVersion::try_from(zen_version)?,
+                    false => EZenPackageVersion::ImportedPackageNames,
+                };
+        }
+        self.rewind()?;
+
+        let zen_version = self.zen_version;
+        self.summary = ZenPackageSummary::read(self, zen_version)?;
+
+        if self.summary.has_versioning_info {
+            let versioning_info = ZenPackageVersioningInfo::read(self)?;
+
+            self.zen_version = versioning_info.zen_version;
+            self.asset_data.object_version = versioning_info.object_version;
+            self.asset_data.object_version_ue5 = versioning_info.object_version_ue5;
+            self.asset_data.summary.file_licensee_version = versioning_info.licensee_version;
+            self.asset_data.summary.custom_versions = versioning_info.custom_versions.clone();
+            self.asset_data.summary.unversioned = false;
+
+            self.raw_reader.object_version = self.asset_data.object_version;
+            self.raw_reader.object_version_ue5 = self.asset_data.object_version_ue5;
+
+            self.versioning_info = Some(versioning_info);
+        }
+
+        if self.asset_data.object_version == ObjectVersion::UNKNOWN {
+            return Err(ZenError::NoObjectVersion.into());
+        }
+
+        self.asset_data.summary.package_flags =
+            EPackageFlags::from_bits(self.summary.package_flags)
+                .ok_or_else(|| Error::invalid_file("Invalid package flags".to_string()))?;
+
+        Ok(())
+    }
+
+    /// Parse asset data
+    fn parse_data(&mut self) -> Result<(), Error> {
+        self.parse_summary()?;
+
+        let (name_batch, hash_version) = self.read_name_batch(false)?;
+        for name in name_batch {
+            self.add_name_reference(name, true);
+        }
+        self.name_batch_hash_version = hash_version;
+        self.package_name = self.summary.name.to_fname(&self.name_map)?;
+
+        if self.zen_version >= EZenPackageVersion::DataResourceTable {
+            let bulk_data_map_size = self.read_u64::<LE>()?;
+            for _ in 0..bulk_data_map_size / BulkDataMapEntry::SIZE {
+                let entry = BulkDataMapEntry::read(self)?;
+                self.bulk_data_map.push(entry);
+            }
+        }
+
+        self.seek(SeekFrom::Start(
+            self.summary.imported_public_export_hashes_offset as u64,
+        ))?;
+        let imported_public_export_hashes_count = (self.summary.import_map_offset
+            - self.summary.imported_public_export_hashes_offset)
+            / std::mem::size_of::<u64>() as i32;
+        for _ in 0..imported_public_export_hashes_count {
+            let hash = self.read_u64::<LE>()?;
+            self.imported_public_export_hashes.push(hash);
+        }
+
+        self.seek(SeekFrom::Start(self.summary.import_map_offset as u64))?;
+        let import_count = (self.summary.export_map_offset - self.summary.import_map_offset)
+            / std::mem::size_of::<u64>() as i32;
+        for _ in 0..import_count {
+            let import = PackageObjectIndex::new(self.read_u64::<LE>()?);
+            self.import_map.push(import);
+        }
+        self.asset_data.summary.import_count = import_count;
+
+        self.seek(SeekFrom::Start(self.summary.export_map_offset as u64))?;
+        let export_count = (self.summary.export_bundle_entries_offset
+            - self.summary.export_map_offset)
+            / ZenExportMapEntry::SIZE as i32;
+        for _ in 0..export_count {
+            let entry = ZenExportMapEntry::read(self)?;
+            self.export_map.push(entry);
+        }
+        self.asset_data.summary.export_count = export_count;
+
+        self.seek(SeekFrom::Start(
+            self.summary.export_bundle_entries_offset as u64,
+        ))?;
+        for _ in 0..export_count * 2 {
+            let entry = ExportBundleEntry::read(self)?;
+            self.export_bundle_entries.push(entry);
+        }
+
+        match self.zen_version >= EZenPackageVersion::ImportedPackageNames {
+            true => self.parse_dependency_bundles()?,
+            false => self.parse_graph_data()?,
+        }
+
+        self.parse_exports()
+    }
+
+    /// Parse export bundle headers and arcs, used before [`EZenPackageVersion::ImportedPackageNames`]
+    fn parse_graph_data(&mut self) -> Result<(), Error> {
+        self.seek(SeekFrom::Start(self.summary.graph_data_offset as u64))?;
+
+        // export bundle count is not stored, so headers are read until they cover all entries
+        let mut remaining_entries = self.export_bundle_entries.len() as u32;
+        while remaining_entries > 0 {
+            let header = ExportBundleHeader::read(self)?;
+            if header.entry_count == 0 || header.entry_count > remaining_entries {
+                return Err(Error::invalid_file(format!(
+                    "Invalid export bundle entry count {}",
+                    header.entry_count
+                )));
+            }
+            remaining_entries -= header.entry_count;
+            self.export_bundle_headers.push(header);
+        }
+
+        let internal_arc_count = self.read_i32::<LE>()?;
+        for _ in 0..internal_arc_count {
+            let arc = InternalArc::read(self)?;
+            self.internal_arcs.push(arc);
+        }
+
+        // imported package count is not stored either, external arcs span the rest of the header
+        while self.position() < self.summary.header_size as u64 {
+            let external_arc_count = self.read_i32::<LE>()?;
+            let mut external_arcs = Vec::with_capacity(external_arc_count as usize);
+            for _ in 0..external_arc_count {
+                external_arcs.push(ExternalArc::read(self)?);
+            }
+            self.external_arcs.push(external_arcs);
+        }
+
+        Ok(())
+    }
+
+    /// Parse dependency bundles and imported package names, used since [`EZenPackageVersion::ImportedPackageNames`]
+    fn parse_dependency_bundles(&mut self) -> Result<(), Error> {
+        self.seek(SeekFrom::Start(
+            self.summary.dependency_bundle_headers_offset as u64,
+        ))?;
+        for _ in 0..self.export_map.len() {
+            let header = DependencyBundleHeader::read(self)?;
+            self.dependency_bundle_headers.push(header);
+        }
+
+        self.seek(SeekFrom::Start(
+            self.summary.dependency_bundle_entries_offset as u64,
+        ))?;
+        let dependency_bundle_entry_count = (self.summary.imported_package_names_offset
+            - self.summary.dependency_bundle_entries_offset)
+            / std::mem::size_of::<i32>() as i32;
+        for _ in 0..dependency_bundle_entry_count {
+            let entry = PackageIndex::new(self.read_i32::<LE>()?);
+            self.dependency_bundle_entries.push(entry);
+        }
+
+        self.seek(SeekFrom::Start(
+            self.summary.imported_package_names_offset as u64,
+        ))?;
+        let (imported_package_names, _) = self.read_name_batch(false)?;
+        for name in imported_package_names {
+            let number = self.read_i32::<LE>()?;
+            self.imported_package_names
+                .push(FName::new_dummy(name, number));
+        }
+
+        Ok(())
+    }
+
+    /// Parse exports
+    fn parse_exports(&mut self) -> Result<(), Error> {
+        // export data is stored right after the header in the order of export bundle serialize commands
+        let mut serial_offsets = vec![0u64; self.export_map.len()];
+        let mut serial_offset = self.summary.header_size as u64;
+        for entry in &self.export_bundle_entries {
+            if entry.command_type != EExportCommandType::Serialize {
+                continue;
+            }
+
+            let export = self
+                .export_map
+                .get(entry.local_export_index as usize)
+                .ok_or_else(|| {
+                    Error::invalid_file(format!(
+                        "Invalid export bundle export index {}",
+                        entry.local_export_index
+                    ))
+                })?;
+            serial_offsets[entry.local_export_index as usize] = serial_offset;
+            serial_offset += export.cooked_serial_size;
+        }
+
+        self.asset_data.exports.reserve(self.export_map.len());
+        for (i, serial_offset) in serial_offsets.into_iter().enumerate() {
+            let mut base_export = self.export_map[i].to_base_export(serial_offset as i64);
+
+            if let Some(header) = self.dependency_bundle_headers.get(i).copied() {
+                let mut entries = self
+                    .dependency_bundle_entries
+                    .iter()
+                    .skip(header.first_entry_index.max(0) as usize);
+                let mut read_deps = |count: u32| -> Vec<PackageIndex> {
+                    entries.by_ref().take(count as usize).copied().collect()
+                };
+
+                let create = EExportCommandType::Create as usize;
+                let serialize = EExportCommandType::Serialize as usize;
+                base_export.create_before_create_dependencies =
+                    read_deps(header.entry_count[create][create]);
+                base_export.serialization_before_create_dependencies =
+                    read_deps(header.entry_count[create][serialize]);
+                base_export.create_before_serialization_dependencies =
+                    read_deps(header.entry_count[serialize][create]);
+                base_export.serialization_before_serialization_dependencies =
+                    read_deps(header.entry_count[serialize][serialize]);
+            }
+
+            let next_starting = serial_offset + self.export_map[i].cooked_serial_size;
+            let export = self.read_export(base_export, next_starting)?;
+            self.asset_data.exports.push(export);
+        }
+
+        Ok(())
+    }
+
+    /// Rebuild the FName map
+    /// This can be used if it's too complicated to keep track of all FNames that were added into the asset
+    /// This is useful when copying export from one asset into another
+    /// This will automatically figure out every new FName and add them to the name map
+    pub fn rebuild_name_map(&mut self) {
+        let mut current_name_map = self.name_map.clone();
+        self.traverse_fnames(&mut |mut name| {
+            let content = name.get_owned_content();
+            let FName::Backed {
+                index, name_map, ..
+            } = &mut name
+            else {
+                return;
+            };
+
+            if *name_map.get_ref() != *current_name_map.get_ref() {
+                let new_index = current_name_map
+                    .get_mut()
+                    .add_name_reference(content, false);
+
+                *index = new_index;
+                *name_map = current_name_map.clone();
+            }
+        });
+    }
+
+    /// Build dependency bundles from export dependencies
+    fn build_dependency_bundles(&self) -> (Vec<DependencyBundleHeader>, Vec<PackageIndex>) {
+        let mut headers = Vec::with_capacity(self.asset_data.exports.len());
+        let mut entries = Vec::new();
+
+        for export in &self.asset_data.exports {
+            let base_export = export.get_base_export();
+
+            let create = EExportCommandType::Create as usize;
+            let serialize = EExportCommandType::Serialize as usize;
+            let mut header = DependencyBundleHeader {
+                first_entry_index: -1,
+                ..Default::default()
+            };
+            header.entry_count[create][create] =
+                base_export.create_before_create_dependencies.len() as u32;
+            header.entry_count[create][serialize] =
+                base_export.serialization_before_create_dependencies.len() as u32;
+            header.entry_count[serialize][create] =
+                base_export.create_before_serialization_dependencies.len() as u32;
+            header.entry_count[serialize][serialize] = base_export
+                .serialization_before_serialization_dependencies
+                .len() as u32;
+
+            if header.entry_count.iter().flatten().any(|e| *e > 0) {
+                header.first_entry_index = entries.len() as i32;
+            }
+
+            entries.extend_from_slice(&base_export.create_before_create_dependencies);
+            entries.extend_from_slice(&base_export.serialization_before_create_dependencies);
+            entries.extend_from_slice(&base_export.create_before_serialization_dependencies);
+            entries.extend_from_slice(&base_export.serialization_before_serialization_dependencies);
+
+            headers.push(header);
+        }
+
+        (headers, entries)
+    }
+
+    /// Write package header, returns the written summary
+    fn write_header<Writer: ArchiveWriter<PackageObjectIndex>>(
+        &self,
+        cursor: &mut Writer,
+        export_map: &[ZenExportMapEntry],
+        export_bundle_headers: &[ExportBundleHeader],
+    ) -> Result<ZenPackageSummary, Error> {
+        let mut summary = ZenPackageSummary {
+            has_versioning_info: self.versioning_info.is_some(),
+            name: MappedName::from_fname(&self.package_name)?,
+            package_flags: self.asset_data.summary.package_flags.bits(),
+            cooked_header_size: self.summary.cooked_header_size,
+            ..Default::default()
+        };
+
+        cursor.seek(SeekFrom::Start(ZenPackageSummary::size(self.zen_version)))?;
+
+        if let Some(ref versioning_info) = self.versioning_info {
+            versioning_info.write(cursor)?;
+        }
+
+        let hash_version = match self.name_batch_hash_version {
+            0 => HASH_VERSION_CITYHASH64,
+            e => e,
+        };
+        cursor.write_name_batch(
+            self.name_map.get_ref().get_name_map_index_list(),
+            hash_version,
+        )?;
+
+        if self.zen_version >= EZenPackageVersion::DataResourceTable {
+            cursor.write_u64::<LE>(self.bulk_data_map.len() as u64 * BulkDataMapEntry::SIZE)?;
+            for entry in &self.bulk_data_map {
+                entry.write(cursor)?;
+            }
+        }
+
+        summary.imported_public_export_hashes_offset = cursor.position() as i32;
+        for hash in &self.imported_public_export_hashes {
+            cursor.write_u64::<LE>(*hash)?;
+        }
+
+        summary.import_map_offset = cursor.position() as i32;
+        for import in &self.import_map {
+            cursor.write_u64::<LE>(import.type_and_id)?;
+        }
+
+        summary.export_map_offset = cursor.position() as i32;
+        for entry in export_map {
+            entry.write(cursor)?;
+        }
+
+        summary.export_bundle_entries_offset = cursor.position() as i32;
+        for entry in &self.export_bundle_entries {
+            entry.write(cursor)?;
+        }
+
+        match self.zen_version >= EZenPackageVersion::ImportedPackageNames {
+            true => {
+                let (dependency_bundle_headers, dependency_bundle_entries) =
+                    self.build_dependency_bundles();
+
+                summary.dependency_bundle_headers_offset = cursor.position() as i32;
+                for header in &dependency_bundle_headers {
+                    header.write(cursor)?;
+                }
+
+                summary.dependency_bundle_entries_offset = cursor.position() as i32;
+                for entry in &dependency_bundle_entries {
+                    cursor.write_i32::<LE>(entry.index)?;
+                }
+
+                summary.imported_package_names_offset = cursor.position() as i32;
+                let imported_package_names = self
+                    .imported_package_names
+                    .iter()
+                    .map(|e| e.get_owned_content())
+                    .collect::<Vec<_>>();
+                cursor.write_name_batch(&imported_package_names, HASH_VERSION_CITYHASH64)?;
+                for name in &self.imported_package_names {
+                    cursor.write_i32::<LE>(name.get_number())?;
+                }
+            }
+            false => {
+                summary.graph_data_offset = cursor.position() as i32;
+                for header in export_bundle_headers {
+                    header.write(cursor)?;
+                }
+
+                cursor.write_i32::<LE>(self.internal_arcs.len() as i32)?;
+                for arc in &self.internal_arcs {
+                    arc.write(cursor)?;
+                }
+
+                for external_arcs in &self.external_arcs {
+                    cursor.write_i32::<LE>(external_arcs.len() as i32)?;
+                    for arc in external_arcs {
+                        arc.write(cursor)?;
+                    }
+                }
+            }
+        }
+
+        summary.header_size = cursor.position() as u32;
+
+        cursor.rewind()?;
+        summary.write(cursor, self.zen_version)?;
+        cursor.seek(SeekFrom::Start(summary.header_size as u64))?;
+
+        Ok(summary)
+    }
+
+    /// Write zen package data
+    ///
+    /// Export data is written right after the header in the order of export bundle serialize commands.
+    /// Export map entries are rebuilt from the base exports.
+    pub fn write_data<W: Read + Seek + Write>(&self, cursor: &mut W) -> Result<(), Error> {
+        if self.export_bundle_entries.len() != self.asset_data.exports.len() * 2 {
+            return Err(Error::no_data(format!(
+                "export_bundle_entries has {} entries but {} are needed for {} exports",
+                self.export_bundle_entries.len(),
+                self.asset_data.exports.len() * 2,
+                self.asset_data.exports.len()
+            )));
+        }
+
+        let mut raw_serializer = RawWriter::new(
+            cursor,
+            self.asset_data.object_version,
+            self.asset_data.object_version_ue5,
+            self.asset_data.use_event_driven_loader,
+            self.name_map.clone(),
+        );
+        let mut serializer = ZenArchiveWriter::new(
+            &mut raw_serializer,
+            &self.asset_data,
+            &self.import_map,
+            self.script_objects.clone(),
+            self.name_map.clone(),
+        );
+
+        let mut export_map = self
+            .asset_data
+            .exports
+            .iter()
+            .map(|e| ZenExportMapEntry::from_base_export(e.get_base_export(), 0))
+            .collect::<Vec<_>>();
+        let summary =
+            self.write_header(&mut serializer, &export_map, &self.export_bundle_headers)?;
+
+        // serialized offsets relative to the end of the header, in export bundle entry order
+        let mut entry_offsets = Vec::with_capacity(self.export_bundle_entries.len());
+        for entry in &self.export_bundle_entries {
+            entry_offsets.push(serializer.position() - summary.header_size as u64);
+            if entry.command_type != EExportCommandType::Serialize {
+                continue;
+            }
+
+            let index = entry.local_export_index as usize;
+            let export = self.asset_data.exports.get(index).ok_or_else(|| {
+                Error::invalid_file(format!("Invalid export bundle export index {}", index))
+            })?;
+
+            let start = serializer.position();
+            export.write(&mut serializer)?;
+            if let Some(normal_export) = export.get_normal_export() {
+                serializer.write_all(&normal_export.extras)?;
+            }
+            export_map[index].cooked_serial_size = serializer.position() - start;
+        }
+
+        let mut cooked_serial_offset = self.summary.cooked_header_size as u64;
+        for entry in export_map.iter_mut() {
+            entry.cooked_serial_offset = cooked_serial_offset;
+            cooked_serial_offset += entry.cooked_serial_size;
+        }
+
+        let export_bundle_headers = self
+            .export_bundle_headers
+            .iter()
+            .map(|e| ExportBundleHeader {
+                serial_offset: entry_offsets
+                    .get(e.first_entry_index as usize)
+                    .copied()
+                    .unwrap_or_default(),
+                ..*e
+            })
+            .collect::<Vec<_>>();
+
+        serializer.rewind()?;
+        self.write_header(&mut serializer, &export_map, &export_bundle_headers)?;
+        serializer.rewind()?;
+
+        Ok(())
+    }
+
+    /// Get name map
+    /// This method should be used if you want to mutate the namemap
+    ///
+    /// # Panics
+    ///
+    /// If the name map is borrowed mutably and you try to write the asset, the lib will panic
+    pub fn get_name_map(&self) -> SharedResource<NameMap> {
+        self.name_map.clone()
+    }
+
+    /// Search an FName reference
+    pub fn search_name_reference(&self, name: &str) -> Option<i32> {
+        self.name_map.get_ref().search_name_reference(name)
+    }
+
+    /// Add an FName reference
+    pub fn add_name_reference(&mut self, name: String, force_add_duplicates: bool) -> i32 {
+        self.name_map
+            .get_mut()
+            .add_name_reference(name, force_add_duplicates)
+    }
+
+    /// Get a name reference by an FName map index
+    pub fn get_name_reference<T>(&self, index: i32, func: impl FnOnce(&str) -> T) -> T {
+        func(self.name_map.get_ref().get_name_reference(index))
+    }
+
+    /// Add an `FName`
+    pub fn add_fname(&mut self, slice: &str) -> FName {
+        self.name_map.get_mut().add_fname(slice)
+    }
+
+    /// Get an import by [`PackageIndex`]
+    pub fn get_import(&self, index: PackageIndex) -> Option<PackageObjectIndex> {
+        if !index.is_import() {
+            return None;
+        }
+
+        self.import_map.get((-index.index - 1) as usize).copied()
+    }
+
+    /// Get an export
+    pub fn get_export(&'a self, index: PackageIndex) -> Option<&'a Export<PackageObjectIndex>> {
+        self.asset_data.get_export(index)
+    }
+
+    /// Get a mutable export reference
+    pub fn get_export_mut(
+        &'a mut self,
+        index: PackageIndex,
+    ) -> Option<&'a mut Export<PackageObjectIndex>> {
+        self.asset_data.get_export_mut(index)
+    }
+}
+
+impl<C: Read + Seek> AssetTrait<PackageObjectIndex> for ZenAsset<C> {
+    fn get_asset_data(&self) -> &AssetData<PackageObjectIndex> {
+        &self.asset_data
+    }
+
+    fn get_asset_data_mut(&mut self) -> &mut AssetData<PackageObjectIndex> {
+        &mut self.asset_data
+    }
+
+    fn get_name_map(&self) -> SharedResource<NameMap> {
+        self.name_map.clone()
+    }
+
+    fn search_name_reference(&self, name: &str) -> Option<i32> {
+        self.name_map.get_ref().search_name_reference(name)
+    }
+
+    fn add_name_reference(&mut self, name: String, force_add_duplicates: bool) -> i32 {
+        self.name_map
+            .get_mut()
+            .add_name_reference(name, force_add_duplicates)
+    }
+
+    fn get_name_reference<T>(&self, index: i32, func: impl FnOnce(&str) -> T) -> T {
+        func(self.name_map.get_ref().get_name_reference(index))
+    }
+
+    fn add_fname(&mut self, slice: &str) -> FName {
+        self.name_map.get_mut().add_fname(slice)
+    }
+}
+
+impl<C: Read + Seek> ArchiveTrait<PackageObjectIndex> for ZenAsset<C> {
+    fn get_archive_type(&self) -> ArchiveType {
+        ArchiveType::Zen
+    }
+
+    fn get_custom_version<T>(&self) -> CustomVersion
+    where
+        T: CustomVersionTrait + Into<i32>,
+    {
+        self.asset_data.get_custom_version::<T>()
+    }
+
+    fn has_unversioned_properties(&self) -> bool {
+        self.asset_data.has_unversioned_properties()
+    }
+
+    fn use_event_driven_loader(&self) -> bool {
+        self.asset_data.use_event_driven_loader
+    }
+
+    fn position(&mut self) -> u64 {
+        self.raw_reader.position()
+    }
+
+    fn get_name_map(&self) -> SharedResource<NameMap> {
+        self.name_map.clone()
+    }
+
+    fn get_array_struct_type_override(&self) -> &IndexedMap<String, String> {
+        &self.asset_data.array_struct_type_override
+    }
+
+    fn get_map_key_override(&self) -> &IndexedMap<String, String> {
+        &self.asset_data.map_key_override
+    }
+
+    fn get_map_value_override(&self) -> &IndexedMap<String, String> {
+        &self.asset_data.map_value_override
+    }
+
+    fn get_engine_version(&self) -> EngineVersion {
+        self.asset_data.get_engine_version()
+    }
+
+    fn get_object_version(&self) -> ObjectVersion {
+        self.asset_data.object_version
+    }
+
+    fn get_object_version_ue5(&self) -> ObjectVersionUE5 {
+        self.asset_data.object_version_ue5
+    }
+
+    fn get_mappings(&self) -> Option<&Usmap> {
+        self.asset_data.mappings.as_ref()
+    }
+
+    fn get_parent_class_export_name(&self) -> Option<FName> {
+        let script_objects = self.script_objects.as_ref()?.get_ref();
+        self.asset_data
+            .exports
+            .iter()
+            .find_map(|e| cast!(Export, ClassExport, e))
+            .and_then(|e| self.get_import(e.struct_export.super_struct))
+            .and_then(|e| script_objects.get_entry(e))
+            .and_then(|e| script_objects.get_object_name(e.outer_index))
+    }
+
+    fn get_object_name(&self, index: PackageObjectIndex) -> Option<FName> {
+        if let Some(export_index) = index.get_export_index() {
+            return self
+                .export_map
+                .get(export_index as usize)
+                .map(|e| e.object_name.clone());
+        }
+
+        match index.is_script_import() {
+            true => self
+                .script_objects
+                .as_ref()
+                .and_then(|e| e.get_ref().get_object_name(index)),
+            // resolving package imports requires the imported package
+            false => None,
+        }
+    }
+
+    fn get_object_name_packageindex(&self, index: PackageIndex) -> Option<FName> {
+        self.get_import(index).and_then(|e| self.get_object_name(e))
+    }
+}
+
+impl<C: Read + Seek> ArchiveReader<PackageObjectIndex> for ZenAsset<C> {
+    passthrough_archive_reader!(raw_reader);
+}
+
+impl<C: Read + Seek> Read for ZenAsset<C> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.raw_reader.read(buf)
+    }
+}
+
+impl<C: Read + Seek> Seek for ZenAsset<C> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        self.raw_reader.seek(pos)
+    }
+}
+
+// custom debug implementation to not print the whole data buffer
+impl<C: Read + Seek> Debug for ZenAsset<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        f.debug_struct("ZenAsset")
+            .field("asset_data", &self.asset_data)
+            .field("zen_version", &self.zen_version)
+            .field("summary", &self.summary)
+            .field("versioning_info", &self.versioning_info)
+            .field("package_name", &self.package_name)
+            .field("bulk_data_map", &self.bulk_data_map)
+            .field(
+                "imported_public_export_hashes",
+                &self.imported_public_export_hashes,
+            )
+            .field("import_map", &self.import_map)
+            .field("export_map", &self.export_map)
+            .field("export_bundle_entries", &self.export_bundle_entries)
+            .field("export_bundle_headers", &self.export_bundle_headers)
+            .field("internal_arcs", &self.internal_arcs)
+            .field("external_arcs", &self.external_arcs)
+            .field("dependency_bundle_headers", &self.dependency_bundle_headers)
+            .field("dependency_bundle_entries", &self.dependency_bundle_entries)
+            .field("imported_package_names", &self.imported_package_names)
+            .finish()
+    }
+}
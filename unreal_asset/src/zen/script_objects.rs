@@ -0,0 +1,207 @@
+//! Global script objects
+
+use std::collections::HashMap;
+use std::io::Cursor;
+
+use byteorder::{ReadBytesExt, LE};
+
+use unreal_asset_base::{
+    containers::{Chain, NameMap, SharedResource},
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    reader::{ArchiveReader, RawReader},
+    types::{fname::EMappedNameType, FName, PackageObjectIndex},
+    unversioned::Usmap,
+    Error,
+};
+
+use super::mapped_name::MappedName;
+
+/// Script object entry
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ScriptObjectEntry {
+    /// Object name
+    pub object_name: MappedName,
+    /// Global index of this object
+    pub global_index: PackageObjectIndex,
+    /// Outer index
+    pub outer_index: PackageObjectIndex,
+    /// Class default object class index
+    pub cdo_class_index: PackageObjectIndex,
+}
+
+impl ScriptObjectEntry {
+    /// Read `ScriptObjectEntry` from an archive
+    pub fn read<R: ArchiveReader<PackageObjectIndex>>(archive: &mut R) -> Result<Self, Error> {
+        Ok(ScriptObjectEntry {
+            object_name: MappedName::read(archive)?,
+            global_index: PackageObjectIndex::new(archive.read_u64::<LE>()?),
+            outer_index: PackageObjectIndex::new(archive.read_u64::<LE>()?),
+            cdo_class_index: PackageObjectIndex::new(archive.read_u64::<LE>()?),
+        })
+    }
+}
+
+/// Global script objects
+///
+/// Used to resolve script imports of zen packages
+#[derive(Debug, Clone)]
+pub struct ScriptObjects {
+    /// Global name map
+    pub name_map: SharedResource<NameMap>,
+    /// Script object entries
+    pub entries: Vec<ScriptObjectEntry>,
+    /// Global index to entry index lookup
+    lookup: HashMap<PackageObjectIndex, usize>,
+}
+
+impl ScriptObjects {
+    /// Create a new empty `ScriptObjects` instance
+    pub fn new() -> Self {
+        ScriptObjects {
+            name_map: NameMap::new(),
+            entries: Vec::new(),
+            lookup: HashMap::new(),
+        }
+    }
+
+    /// Read `ScriptObjects` from an archive
+    pub fn read<R: ArchiveReader<PackageObjectIndex>>(archive: &mut R) -> Result<Self, Error> {
+        let mut script_objects = ScriptObjects::new();
+
+        let (names, _) = archive.read_name_batch(false)?;
+        for name in names {
+            script_objects
+                .name_map
+                .get_mut()
+                .add_name_reference(name, true);
+        }
+
+        let entry_count = archive.read_i32::<LE>()?;
+        for _ in 0..entry_count {
+            script_objects.add_entry(ScriptObjectEntry::read(archive)?);
+        }
+
+        Ok(script_objects)
+    }
+
+    /// Read `ScriptObjects` from the contents of a script objects IoStore chunk
+    pub fn from_buffer(buffer: &[u8]) -> Result<Self, Error> {
+        let mut reader = RawReader::<PackageObjectIndex, _>::new(
+            Chain::new(Cursor::new(buffer), None),
+            ObjectVersion::UNKNOWN,
+            ObjectVersionUE5::UNKNOWN,
+            false,
+            NameMap::new(),
+        );
+        ScriptObjects::read(&mut reader)
+    }
+
+    /// Create `ScriptObjects` from .usmap mappings
+    ///
+    /// Mappings don't contain the global script objects, so they are reconstructed from schema module paths.
+    /// Schemas without a module path are skipped.
+    pub fn from_usmap(mappings: &Usmap) -> Self {
+        let mut script_objects = ScriptObjects::new();
+
+        for (_, name, schema) in mappings.schemas.iter() {
+            let Some(module_path) = schema.module_path.as_ref() else {
+                continue;
+            };
+
+            let package_path = match module_path.starts_with('/') {
+                true => module_path.clone(),
+                false => format!("/Script/{module_path}"),
+            };
+            let package_index = PackageObjectIndex::from_script_path(&package_path);
+            if !script_objects.lookup.contains_key(&package_index) {
+                let object_name = script_objects.add_name(&package_path);
+                script_objects.add_entry(ScriptObjectEntry {
+                    object_name,
+                    global_index: package_index,
+                    outer_index: PackageObjectIndex::null(),
+                    cdo_class_index: PackageObjectIndex::null(),
+                });
+            }
+
+            let class_index =
+                PackageObjectIndex::from_script_path(&format!("{package_path}.{name}"));
+            let object_name = script_objects.add_name(name);
+            script_objects.add_entry(ScriptObjectEntry {
+                object_name,
+                global_index: class_index,
+                outer_index: package_index,
+                cdo_class_index: PackageObjectIndex::null(),
+            });
+
+            let cdo_name = format!("Default__{name}");
+            let object_name = script_objects.add_name(&cdo_name);
+            script_objects.add_entry(ScriptObjectEntry {
+                object_name,
+                global_index: PackageObjectIndex::from_script_path(&format!(
+                    "{package_path}.{cdo_name}"
+                )),
+                outer_index: package_index,
+                cdo_class_index: class_index,
+            });
+        }
+
+        script_objects
+    }
+
+    /// Add a name to the global name map
+    fn add_name(&mut self, name: &str) -> MappedName {
+        let index = self
+            .name_map
+            .get_mut()
+            .add_name_reference(name.to_string(), false);
+        MappedName::new(index as u32, 0, EMappedNameType::Global)
+    }
+
+    /// Add a script object entry
+    pub fn add_entry(&mut self, entry: ScriptObjectEntry) {
+        self.lookup.insert(entry.global_index, self.entries.len());
+        self.entries.push(entry);
+    }
+
+    /// Get a script object entry by its global index
+    pub fn get_entry(&self, index: PackageObjectIndex) -> Option<&ScriptObjectEntry> {
+        self.lookup.get(&index).map(|e| &self.entries[*e])
+    }
+
+    /// Get script object name by its global index
+    pub fn get_object_name(&self, index: PackageObjectIndex) -> Option<FName> {
+        self.get_entry(index)
+            .and_then(|e| e.object_name.to_fname(&self.name_map).ok())
+    }
+
+    /// Get full script object path by its global index, e.g. `/Script/Engine.Actor`
+    pub fn get_object_path(&self, index: PackageObjectIndex) -> Option<String> {
+        let entry = self.get_entry(index)?;
+        let name = self
+            .name_map
+            .get_ref()
+            .get_owned_name(entry.object_name.get_index() as i32);
+
+        if entry.outer_index.is_null() {
+            return Some(name);
+        }
+
+        let outer = self.get_entry(entry.outer_index)?;
+        let separator = match outer.outer_index.is_null() {
+            true => '.',
+            false => ':',
+        };
+        Some(format!(
+            "{}{}{}",
+            self.get_object_path(entry.outer_index)?,
+            separator,
+            name
+        ))
+    }
+}
+
+impl Default for ScriptObjects {
+    fn default() -> Self {
+        ScriptObjects::new()
+    }
+}
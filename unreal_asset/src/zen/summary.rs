@@ -0,0 +1,173 @@
+//! Zen package summary
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+
+use unreal_asset_base::{
+    custom_version::CustomVersion,
+    enums::{ECustomVersionSerializationFormat, EZenPackageVersion},
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    reader::{ArchiveReader, ArchiveWriter},
+    types::PackageObjectIndex,
+    Error,
+};
+
+use super::mapped_name::MappedName;
+
+/// Zen package summary
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ZenPackageSummary {
+    /// Is versioning info present after the summary
+    pub has_versioning_info: bool,
+    /// Total header size, export data starts right after the header
+    pub header_size: u32,
+    /// Package name
+    pub name: MappedName,
+    /// Package flags
+    pub package_flags: u32,
+    /// Header size of the legacy cooked package
+    pub cooked_header_size: u32,
+    /// Imported public export hashes offset
+    pub imported_public_export_hashes_offset: i32,
+    /// Import map offset
+    pub import_map_offset: i32,
+    /// Export map offset
+    pub export_map_offset: i32,
+    /// Export bundle entries offset
+    pub export_bundle_entries_offset: i32,
+    /// Graph data offset, only used before [`EZenPackageVersion::ImportedPackageNames`]
+    pub graph_data_offset: i32,
+    /// Dependency bundle headers offset, only used since [`EZenPackageVersion::ImportedPackageNames`]
+    pub dependency_bundle_headers_offset: i32,
+    /// Dependency bundle entries offset, only used since [`EZenPackageVersion::ImportedPackageNames`]
+    pub dependency_bundle_entries_offset: i32,
+    /// Imported package names offset, only used since [`EZenPackageVersion::ImportedPackageNames`]
+    pub imported_package_names_offset: i32,
+}
+
+impl ZenPackageSummary {
+    /// Summary size before [`EZenPackageVersion::ImportedPackageNames`]
+    pub const SIZE_INITIAL: u64 = 44;
+    /// Summary size since [`EZenPackageVersion::ImportedPackageNames`]
+    pub const SIZE_IMPORTED_PACKAGE_NAMES: u64 = 52;
+
+    /// Get summary size for a zen version
+    pub fn size(zen_version: EZenPackageVersion) -> u64 {
+        match zen_version >= EZenPackageVersion::ImportedPackageNames {
+            true => Self::SIZE_IMPORTED_PACKAGE_NAMES,
+            false => Self::SIZE_INITIAL,
+        }
+    }
+
+    /// Read `ZenPackageSummary` from an archive
+    pub fn read<R: ArchiveReader<PackageObjectIndex>>(
+        archive: &mut R,
+        zen_version: EZenPackageVersion,
+    ) -> Result<Self, Error> {
+        let mut summary = ZenPackageSummary {
+            has_versioning_info: archive.read_u32::<LE>()? != 0,
+            header_size: archive.read_u32::<LE>()?,
+            name: MappedName::read(archive)?,
+            package_flags: archive.read_u32::<LE>()?,
+            cooked_header_size: archive.read_u32::<LE>()?,
+            imported_public_export_hashes_offset: archive.read_i32::<LE>()?,
+            import_map_offset: archive.read_i32::<LE>()?,
+            export_map_offset: archive.read_i32::<LE>()?,
+            export_bundle_entries_offset: archive.read_i32::<LE>()?,
+            ..Default::default()
+        };
+
+        if zen_version >= EZenPackageVersion::ImportedPackageNames {
+            summary.dependency_bundle_headers_offset = archive.read_i32::<LE>()?;
+            summary.dependency_bundle_entries_offset = archive.read_i32::<LE>()?;
+            summary.imported_package_names_offset = archive.read_i32::<LE>()?;
+        } else {
+            summary.graph_data_offset = archive.read_i32::<LE>()?;
+        }
+
+        Ok(summary)
+    }
+
+    /// Write `ZenPackageSummary` to an archive
+    pub fn write<W: ArchiveWriter<PackageObjectIndex>>(
+        &self,
+        archive: &mut W,
+        zen_version: EZenPackageVersion,
+    ) -> Result<(), Error> {
+        archive.write_u32::<LE>(match self.has_versioning_info {
+            true => 1,
+            false => 0,
+        })?;
+        archive.write_u32::<LE>(self.header_size)?;
+        self.name.write(archive)?;
+        archive.write_u32::<LE>(self.package_flags)?;
+        archive.write_u32::<LE>(self.cooked_header_size)?;
+        archive.write_i32::<LE>(self.imported_public_export_hashes_offset)?;
+        archive.write_i32::<LE>(self.import_map_offset)?;
+        archive.write_i32::<LE>(self.export_map_offset)?;
+        archive.write_i32::<LE>(self.export_bundle_entries_offset)?;
+
+        if zen_version >= EZenPackageVersion::ImportedPackageNames {
+            archive.write_i32::<LE>(self.dependency_bundle_headers_offset)?;
+            archive.write_i32::<LE>(self.dependency_bundle_entries_offset)?;
+            archive.write_i32::<LE>(self.imported_package_names_offset)?;
+        } else {
+            archive.write_i32::<LE>(self.graph_data_offset)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Zen package versioning info
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZenPackageVersioningInfo {
+    /// Zen package version
+    pub zen_version: EZenPackageVersion,
+    /// Object version
+    pub object_version: ObjectVersion,
+    /// UE5 object version
+    pub object_version_ue5: ObjectVersionUE5,
+    /// Licensee version
+    pub licensee_version: i32,
+    /// Custom versions
+    pub custom_versions: Vec<CustomVersion>,
+}
+
+impl ZenPackageVersioningInfo {
+    /// Read `ZenPackageVersioningInfo` from an archive
+    pub fn read<R: ArchiveReader<PackageObjectIndex>>(archive: &mut R) -> Result<Self, Error> {
+        let zen_version = EZenPackageVersion::try_from(archive.read_u32::<LE>()?)?;
+        let object_version = ObjectVersion::try_from(archive.read_i32::<LE>()?)?;
+        let object_version_ue5 = ObjectVersionUE5::try_from(archive.read_i32::<LE>()?)?;
+        let licensee_version = archive.read_i32::<LE>()?;
+        let custom_versions = archive
+            .read_custom_version_container(ECustomVersionSerializationFormat::Optimized, None)?;
+
+        Ok(ZenPackageVersioningInfo {
+            zen_version,
+            object_version,
+            object_version_ue5,
+            licensee_version,
+            custom_versions,
+        })
+    }
+
+    /// Write `ZenPackageVersioningInfo` to an archive
+    pub fn write<W: ArchiveWriter<PackageObjectIndex>>(
+        &self,
+        archive: &mut W,
+    ) -> Result<(), Error> {
+        archive.write_u32::<LE>(self.zen_version.into())?;
+        archive.write_i32::<LE>(self.object_version.into())?;
+        archive.write_i32::<LE>(self.object_version_ue5.into())?;
+        archive.write_i32::<LE>(self.licensee_version)?;
+
+        archive.write_i32::<LE>(self.custom_versions.len() as i32)?;
+        for custom_version in &self.custom_versions {
+            archive.write_guid(&custom_version.guid)?;
+            archive.write_i32::<LE>(custom_version.version)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+//! Archive that can be used to write a zen package
+
+use std::io::{Seek, Write};
+
+use unreal_asset_base::{
+    cast,
+    containers::{IndexedMap, NameMap, SharedResource},
+    custom_version::{CustomVersion, CustomVersionTrait},
+    engine_version::EngineVersion,
+    flags::EPackageFlags,
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    passthrough_archive_writer,
+    reader::{ArchiveTrait, ArchiveType, ArchiveWriter},
+    types::{FName, PackageIndex, PackageIndexTrait, PackageObjectIndex},
+    unversioned::Usmap,
+    Error,
+};
+use unreal_asset_exports::{Export, ExportBaseTrait};
+
+use crate::asset_data::AssetData;
+
+use super::script_objects::ScriptObjects;
+
+/// Archive that can be used to write zen package data
+pub struct ZenArchiveWriter<'parent_writer, 'asset, ParentWriter: ArchiveWriter<PackageObjectIndex>>
+{
+    /// Parent writer for this writer
+    writer: &'parent_writer mut ParentWriter,
+    /// Asset data
+    asset_data: &'asset AssetData<PackageObjectIndex>,
+    /// Package import map
+    import_map: &'asset [PackageObjectIndex],
+    /// Global script objects
+    script_objects: Option<SharedResource<ScriptObjects>>,
+    /// Package name map
+    name_map: SharedResource<NameMap>,
+}
+
+impl<'parent_writer, 'asset, ParentWriter: ArchiveWriter<PackageObjectIndex>>
+    ZenArchiveWriter<'parent_writer, 'asset, ParentWriter>
+{
+    /// Create a new `ZenArchiveWriter` instance
+    pub fn new(
+        parent_writer: &'parent_writer mut ParentWriter,
+        asset_data: &'asset AssetData<PackageObjectIndex>,
+        import_map: &'asset [PackageObjectIndex],
+        script_objects: Option<SharedResource<ScriptObjects>>,
+        name_map: SharedResource<NameMap>,
+    ) -> Self {
+        ZenArchiveWriter {
+            writer: parent_writer,
+            asset_data,
+            import_map,
+            script_objects,
+            name_map,
+        }
+    }
+
+    /// Get an import from this `ZenArchiveWriter`
+    pub fn get_import(&self, index: PackageIndex) -> Option<PackageObjectIndex> {
+        if !index.is_import() {
+            return None;
+        }
+
+        self.import_map.get((-index.index - 1) as usize).copied()
+    }
+}
+
+impl<ParentWriter: ArchiveWriter<PackageObjectIndex>> ArchiveTrait<PackageObjectIndex>
+    for ZenArchiveWriter<'_, '_, ParentWriter>
+{
+    #[inline(always)]
+    fn get_archive_type(&self) -> ArchiveType {
+        ArchiveType::Zen
+    }
+
+    fn get_custom_version<T>(&self) -> CustomVersion
+    where
+        T: CustomVersionTrait + Into<i32>,
+    {
+        self.asset_data.get_custom_version::<T>()
+    }
+
+    fn has_unversioned_properties(&self) -> bool {
+        self.asset_data
+            .summary
+            .package_flags
+            .contains(EPackageFlags::PKG_UNVERSIONED_PROPERTIES)
+    }
+
+    fn use_event_driven_loader(&self) -> bool {
+        self.asset_data.use_event_driven_loader
+    }
+
+    fn position(&mut self) -> u64 {
+        self.writer.position()
+    }
+
+    fn set_position(&mut self, pos: u64) -> std::io::Result<()> {
+        self.writer.set_position(pos)
+    }
+
+    fn get_name_map(&self) -> SharedResource<NameMap> {
+        self.name_map.clone()
+    }
+
+    fn get_array_struct_type_override(&self) -> &IndexedMap<String, String> {
+        &self.asset_data.array_struct_type_override
+    }
+
+    fn get_map_key_override(&self) -> &IndexedMap<String, String> {
+        &self.asset_data.map_key_override
+    }
+
+    fn get_map_value_override(&self) -> &IndexedMap<String, String> {
+        &self.asset_data.map_value_override
+    }
+
+    fn get_engine_version(&self) -> EngineVersion {
+        self.asset_data.get_engine_version()
+    }
+
+    fn get_object_version(&self) -> ObjectVersion {
+        self.asset_data.object_version
+    }
+
+    fn get_object_version_ue5(&self) -> ObjectVersionUE5 {
+        self.asset_data.object_version_ue5
+    }
+
+    fn get_mappings(&self) -> Option<&Usmap> {
+        self.asset_data.mappings.as_ref()
+    }
+
+    fn get_parent_class_export_name(&self) -> Option<FName> {
+        let script_objects = self.script_objects.as_ref()?.get_ref();
+        self.asset_data
+            .exports
+            .iter()
+            .find_map(|e| cast!(Export, ClassExport, e))
+            .and_then(|e| self.get_import(e.struct_export.super_struct))
+            .and_then(|e| script_objects.get_entry(e))
+            .and_then(|e| script_objects.get_object_name(e.outer_index))
+    }
+
+    fn get_object_name(&self, index: PackageObjectIndex) -> Option<FName> {
+        if let Some(export_index) = index.get_export_index() {
+            return self
+                .asset_data
+                .exports
+                .get(export_index as usize)
+                .map(|e| e.get_base_export().object_name.clone());
+        }
+
+        match index.is_script_import() {
+            true => self
+                .script_objects
+                .as_ref()
+                .and_then(|e| e.get_ref().get_object_name(index)),
+            false => None,
+        }
+    }
+
+    fn get_object_name_packageindex(&self, index: PackageIndex) -> Option<FName> {
+        self.get_import(index).and_then(|e| self.get_object_name(e))
+    }
+}
+
+impl<ParentWriter: ArchiveWriter<PackageObjectIndex>> ArchiveWriter<PackageObjectIndex>
+    for ZenArchiveWriter<'_, '_, ParentWriter>
+{
+    passthrough_archive_writer!(writer);
+}
+
+impl<ParentWriter: ArchiveWriter<PackageObjectIndex>> Write
+    for ZenArchiveWriter<'_, '_, ParentWriter>
+{
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.writer.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+impl<ParentWriter: ArchiveWriter<PackageObjectIndex>> Seek
+    for ZenArchiveWriter<'_, '_, ParentWriter>
+{
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        self.writer.seek(pos)
+    }
+}
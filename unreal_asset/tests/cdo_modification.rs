@@ -5,7 +5,7 @@ use unreal_asset::{
     engine_version::EngineVersion,
     exports::{Export, ExportBaseTrait, NormalExport},
     flags::EObjectFlags,
-    properties::{object_property::ObjectProperty, PropertyDataTrait},
+    properties::{object_property::ObjectProperty, PropertyDataTrait, PropertyTagExtensions},
     types::PackageIndex,
     unversioned::Ancestry,
     Asset, Error,
@@ -57,7 +57,9 @@ fn cdo_modification() -> Result<(), Error> {
     *pickup_actor = ObjectProperty {
         name: new_name,
         property_guid: None,
+        property_tag_extensions: PropertyTagExtensions::default(),
         duplication_index: 0,
+        property_type_name: None,
         value: PackageIndex::new(0),
         ancestry: Ancestry::default(),
     }
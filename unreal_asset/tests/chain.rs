@@ -34,3 +34,19 @@ fn seek() {
     assert_eq!(read_at(SeekFrom::End(-4)).unwrap(), 4);
     assert!(read_at(SeekFrom::End(-12)).is_err());
 }
+
+#[test]
+fn replace_first() {
+    use std::io::Cursor;
+    let mut chain = Chain::new(
+        Cursor::new(vec![0, 1, 2, 3]),
+        Some(Cursor::new(vec![4, 5, 6, 7])),
+    );
+    chain.seek(SeekFrom::Start(2)).unwrap();
+    chain.replace_first(vec![10, 11, 12, 13, 14, 15]).unwrap();
+
+    let mut v = Vec::new();
+    chain.read_to_end(&mut v).unwrap();
+    assert_eq!(v, [10, 11, 12, 13, 14, 15, 4, 5, 6, 7]);
+    assert_eq!(chain.seek(SeekFrom::End(-4)).unwrap(), 6);
+}
@@ -0,0 +1,225 @@
+use std::io::Cursor;
+
+use unreal_asset::compressed_chunk::{self, PackageCompression};
+use unreal_asset::compression::{CompressionLevel, CompressionMethod};
+use unreal_asset::{engine_version::EngineVersion, Asset, Error};
+
+#[allow(clippy::duplicate_mod)]
+#[path = "shared.rs"]
+mod shared;
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/general/Astroneer_prebulk/Staging_T2.umap"
+));
+
+#[test]
+fn chunk_round_trip() -> Result<(), Error> {
+    let data = b"compressed chunk data".repeat(100);
+
+    let compression = PackageCompression {
+        block_size: 0x100,
+        ..PackageCompression::new(CompressionMethod::Zlib)
+    };
+    let compressed = compressed_chunk::compress_chunk(&data, &compression)?;
+
+    let mut decompressed = vec![0u8; data.len()];
+    let block_size = compressed_chunk::decompress_chunk(
+        &compressed,
+        CompressionMethod::Zlib,
+        &mut decompressed,
+    )?;
+    assert_eq!(block_size, 0x100);
+    assert_eq!(decompressed, data);
+
+    // wrong decompressed size
+    let mut short = vec![0u8; data.len() - 1];
+    assert!(
+        compressed_chunk::decompress_chunk(&compressed, CompressionMethod::Zlib, &mut short)
+            .is_err()
+    );
+
+    // invalid tag
+    let mut invalid = compressed;
+    invalid[0] = 0;
+    assert!(compressed_chunk::decompress_chunk(
+        &invalid,
+        CompressionMethod::Zlib,
+        &mut decompressed
+    )
+    .is_err());
+
+    Ok(())
+}
+
+#[test]
+fn malformed_chunk() -> Result<(), Error> {
+    let data = b"malformed chunk".repeat(20);
+    let compression = PackageCompression {
+        block_size: 0x40,
+        ..PackageCompression::new(CompressionMethod::Zlib)
+    };
+    let compressed = compressed_chunk::compress_chunk(&data, &compression)?;
+    let mut decompressed = vec![0u8; data.len()];
+
+    let block_info = |block: usize| 32 + block * 16;
+    let with_i64 = |offset: usize, value: i64| {
+        let mut malformed = compressed.clone();
+        malformed[offset..offset + 8].copy_from_slice(&value.to_le_bytes());
+        malformed
+    };
+
+    for malformed in [
+        // block size that doesn't fit in a u32
+        with_i64(8, i64::MAX),
+        // negative block sizes
+        with_i64(block_info(0), -1),
+        with_i64(block_info(0) + 8, -1),
+        // block sizes past the end of the chunk and the decompressed data
+        with_i64(block_info(0), i64::MAX),
+        with_i64(block_info(0) + 8, i64::MAX),
+        // more blocks than the chunk can hold
+        with_i64(8, 1),
+    ] {
+        let result = compressed_chunk::decompress_chunk(
+            &malformed,
+            CompressionMethod::Zlib,
+            &mut decompressed,
+        );
+        assert!(matches!(result, Err(Error::InvalidFile(_))), "{result:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn malformed_compressed_package() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    asset.package_compression = Some(PackageCompression::new(CompressionMethod::Zlib));
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    let compressed = cursor.into_inner();
+
+    // the first chunk starts right after the summary, both of its offsets are the summary size
+    let summary_size = compressed
+        .windows(8)
+        .position(|e| e == 0x9e2a83c1i64.to_le_bytes())
+        .expect("Failed to find compressed chunk");
+    let summary_size_bytes = (summary_size as i32).to_le_bytes();
+    let chunk = (0..summary_size - 16)
+        .find(|&i| {
+            compressed[i..i + 4] == summary_size_bytes
+                && compressed[i + 8..i + 12] == summary_size_bytes
+        })
+        .expect("Failed to find compressed chunk header");
+
+    let with_i32 = |offset: usize, value: i32| {
+        let mut malformed = compressed.clone();
+        malformed[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        malformed
+    };
+
+    for malformed in [
+        // negative offsets and sizes
+        with_i32(chunk, -1),
+        with_i32(chunk + 4, -1),
+        with_i32(chunk + 8, -1),
+        with_i32(chunk + 12, -1),
+        // compressed data past the end of the package
+        with_i32(chunk + 12, i32::MAX),
+        with_i32(chunk + 8, i32::MAX),
+        // summary past the end of the package
+        with_i32(chunk, i32::MAX - 1),
+        // more chunks than the package can hold
+        with_i32(chunk - 4, i32::MAX),
+    ] {
+        let result = Asset::new(
+            Cursor::new(malformed.as_slice()),
+            None,
+            EngineVersion::VER_UE4_23,
+            None,
+        );
+        assert!(matches!(result, Err(Error::InvalidFile(_))), "{result:?}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn legacy_block_size() -> Result<(), Error> {
+    let data = b"legacy block size".repeat(10);
+
+    let compressed =
+        compressed_chunk::compress_chunk(&data, &PackageCompression::new(CompressionMethod::Gzip))?;
+    // old packages store the tag in place of the block size
+    let mut legacy = compressed.clone();
+    legacy.copy_within(0..8, 8);
+
+    let mut decompressed = vec![0u8; data.len()];
+    let block_size =
+        compressed_chunk::decompress_chunk(&legacy, CompressionMethod::Gzip, &mut decompressed)?;
+    assert_eq!(block_size, PackageCompression::DEFAULT_BLOCK_SIZE);
+    assert_eq!(decompressed, data);
+
+    Ok(())
+}
+
+#[test]
+fn compressed_package() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    assert!(asset.package_compression.is_none());
+
+    for method in [CompressionMethod::Zlib, CompressionMethod::Gzip] {
+        let compression = PackageCompression {
+            method: method.clone(),
+            level: CompressionLevel::Best,
+            chunk_size: 0x4000,
+            block_size: 0x1000,
+        };
+        asset.package_compression = Some(compression.clone());
+
+        let mut cursor = Cursor::new(Vec::new());
+        asset.write_data(&mut cursor, None)?;
+        let compressed = cursor.into_inner();
+        assert!(compressed.len() < TEST_ASSET.len());
+
+        let mut compressed_asset = Asset::new(
+            Cursor::new(compressed.as_slice()),
+            None,
+            EngineVersion::VER_UE4_23,
+            None,
+        )?;
+        assert_eq!(
+            compressed_asset.package_compression,
+            Some(PackageCompression {
+                level: CompressionLevel::Default,
+                ..compression
+            })
+        );
+        assert!(shared::verify_all_exports_parsed(&compressed_asset));
+
+        // writing without compression gives back the original package
+        compressed_asset.package_compression = None;
+        let mut cursor = Cursor::new(Vec::new());
+        compressed_asset.write_data(&mut cursor, None)?;
+        assert_eq!(cursor.into_inner(), TEST_ASSET);
+    }
+
+    // methods without legacy compression flags can't be used
+    asset.package_compression = Some(PackageCompression::new(CompressionMethod::Zstd));
+    assert!(asset
+        .write_data(&mut Cursor::new(Vec::new()), None)
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use unreal_asset::compression::{self, CompressionLevel, CompressionMethod};
+use unreal_asset::error::Error;
+
+const METHODS: [CompressionMethod; 6] = [
+    CompressionMethod::None,
+    CompressionMethod::Zlib,
+    CompressionMethod::Gzip,
+    CompressionMethod::Lz4,
+    CompressionMethod::Zstd,
+    CompressionMethod::Brotli,
+];
+
+const LEVELS: [CompressionLevel; 5] = [
+    CompressionLevel::Fastest,
+    CompressionLevel::Default,
+    CompressionLevel::Best,
+    CompressionLevel::Level(3),
+    CompressionLevel::Level(100),
+];
+
+fn test_data() -> Vec<u8> {
+    b"unreal compression test data "
+        .iter()
+        .cycle()
+        .take(0x1000)
+        .copied()
+        .chain(0..=255u8)
+        .collect()
+}
+
+#[test]
+fn method_names() {
+    for method in METHODS.into_iter().chain([CompressionMethod::Oodle]) {
+        assert_eq!(CompressionMethod::new(&method.to_string()), method);
+    }
+    assert_eq!(
+        CompressionMethod::new("Custom"),
+        CompressionMethod::Unknown("Custom".into())
+    );
+}
+
+#[test]
+fn compress_round_trip() -> Result<(), Error> {
+    let data = test_data();
+
+    for method in METHODS {
+        for level in LEVELS {
+            let compressed = compression::compress(method.clone(), &data, level)?;
+            if method != CompressionMethod::None {
+                assert!(
+                    compressed.len() < data.len(),
+                    "{method} at {level:?} didn't compress"
+                );
+            }
+
+            let mut decompressed = vec![0u8; data.len()];
+            compression::decompress(method.clone(), &compressed, &mut decompressed)?;
+            assert_eq!(decompressed, data, "{method} at {level:?}");
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn compression_levels() -> Result<(), Error> {
+    let data = test_data();
+
+    for method in [
+        CompressionMethod::Zlib,
+        CompressionMethod::Zstd,
+        CompressionMethod::Brotli,
+    ] {
+        let fastest = compression::compress(method.clone(), &data, CompressionLevel::Fastest)?;
+        let best = compression::compress(method.clone(), &data, CompressionLevel::Best)?;
+        assert!(best.len() <= fastest.len(), "{method}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn invalid_data() {
+    let mut decompressed = vec![0u8; 64];
+    for method in [
+        CompressionMethod::Zlib,
+        CompressionMethod::Gzip,
+        CompressionMethod::Lz4,
+        CompressionMethod::Zstd,
+        CompressionMethod::Brotli,
+    ] {
+        assert!(
+            compression::decompress(method.clone(), &[0xff; 16], &mut decompressed).is_err(),
+            "{method}"
+        );
+    }
+
+    assert!(matches!(
+        compression::compress(
+            CompressionMethod::Unknown("Custom".into()),
+            b"data",
+            CompressionLevel::Default
+        ),
+        Err(Error::UnknownCompressionMethod(_))
+    ));
+}
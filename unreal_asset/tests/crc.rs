@@ -0,0 +1,40 @@
+use unreal_asset::crc;
+
+/// Name map entries and hashes from `general/pseudoregalia/BP_PlayerGoatMain.uasset`
+const NAME_MAP_HASHES: [(&str, u32); 6] = [
+    ("A", 0xe0446dc0),
+    ("actionState", 0x72d17d26),
+    ("ActionValue", 0x5fb10aa0),
+    ("Activate?", 0x37310258),
+    ("ActorTickFunction", 0x1b3f4b5d),
+    ("AddMovementInput", 0x7d32219d),
+];
+
+/// Names with their wide and ansi lowercase cityhash64 hashes
+const LOWERCASE_HASHES: [(&str, u64, u64); 4] = [
+    ("Actor", 0x066b7c61fb77f545, 0x5dc91adc2d0fecd5),
+    ("ActorTickFunction", 0xc9569d493199a688, 0xe4667bdd5daca913),
+    ("AddMovementInput", 0x178b1d0a68b132c0, 0x86ff76aa2ccd01e9),
+    ("/Game/Maps/Level_01", 0x432c73c58ad6b12e, 0xb1d52c34fb44b8b9),
+];
+
+#[test]
+fn name_map_hashes() {
+    for (name, hash) in NAME_MAP_HASHES {
+        assert_eq!(crc::generate_hash(name), hash, "{name}");
+    }
+}
+
+#[test]
+fn lowercase_name_hashes() {
+    for (name, wide_hash, ansi_hash) in LOWERCASE_HASHES {
+        assert_eq!(crc::cityhash64_to_lower(name), wide_hash, "{name}");
+        assert_eq!(crc::cityhash64_to_lower_name(name, true), wide_hash, "{name}");
+        assert_eq!(crc::cityhash64_to_lower_name(name, false), ansi_hash, "{name}");
+
+        // hashes are case insensitive
+        let lowercase = name.to_lowercase();
+        assert_eq!(crc::cityhash64_to_lower(&lowercase), wide_hash, "{name}");
+        assert_eq!(crc::cityhash64_to_lower(&name.to_uppercase()), wide_hash, "{name}");
+    }
+}
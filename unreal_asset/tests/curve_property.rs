@@ -0,0 +1,537 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    custom_version::{CustomVersionTrait, FFortniteMainBranchObjectVersion},
+    engine_version::EngineVersion,
+    exports::{
+        curve_table_export::{CurveTable, CurveTableExport, CurveTableMode, CurveTableRow},
+        Export, ExportNormalTrait,
+    },
+    properties::{
+        curve_key_property::{IntegralKeyProperty, SimpleCurveKeyProperty, StringCurveKeyProperty},
+        curve_property::{
+            IntegralCurveProperty, RichCurveProperty, SimpleCurveProperty, StringCurveProperty,
+        },
+        rich_curve_key_property::{
+            RichCurveExtrapolation, RichCurveInterpMode, RichCurveKeyProperty,
+            RichCurveTangentWeightMode,
+        },
+        Property, PropertyDataTrait,
+    },
+    Asset, Error,
+};
+
+mod shared;
+
+const TEST_ASSET: (&[u8], &[u8]) = (
+    include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/assets/general/pseudoregalia/BP_PlayerGoatMain.uasset"
+    )),
+    include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/assets/general/pseudoregalia/BP_PlayerGoatMain.uexp"
+    )),
+);
+
+/// Curve float asset with a single rich curve
+const CURVE_ASSET: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/general/Astroneer_prebulk/ResourceProgressCurve.uasset"
+));
+
+fn assert_near(value: f32, expected: f32) {
+    assert!(
+        (value - expected).abs() < 1e-4,
+        "Expected {expected}, got {value}"
+    );
+}
+
+fn rich_key(time: f32, value: f32, interp_mode: RichCurveInterpMode) -> RichCurveKeyProperty {
+    RichCurveKeyProperty {
+        interp_mode,
+        time: time.into(),
+        value: value.into(),
+        ..Default::default()
+    }
+}
+
+fn rich_curve(keys: Vec<RichCurveKeyProperty>) -> RichCurveProperty {
+    RichCurveProperty {
+        keys,
+        ..Default::default()
+    }
+}
+
+fn simple_key(time: f32, value: f32) -> SimpleCurveKeyProperty {
+    SimpleCurveKeyProperty {
+        time: time.into(),
+        value: value.into(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn rich_curve_eval() {
+    let linear = rich_curve(vec![
+        rich_key(0.0, 0.0, RichCurveInterpMode::Linear),
+        rich_key(2.0, 4.0, RichCurveInterpMode::Linear),
+    ]);
+    assert_near(linear.eval(1.0), 2.0);
+    assert_near(linear.eval(-1.0), 0.0);
+    assert_near(linear.eval(3.0), 4.0);
+
+    let mut constant = linear.clone();
+    constant.keys[0].interp_mode = RichCurveInterpMode::Constant;
+    assert_near(constant.eval(1.9), 0.0);
+    assert_near(constant.eval(2.0), 4.0);
+
+    let cubic = rich_curve(vec![
+        rich_key(0.0, 0.0, RichCurveInterpMode::Cubic),
+        rich_key(1.0, 1.0, RichCurveInterpMode::Cubic),
+    ]);
+    assert_near(cubic.eval(0.5), 0.5);
+    assert_near(cubic.eval(0.25), 0.15625);
+
+    // extrapolation
+    let extrapolated = |pre_infinity_extrap, post_infinity_extrap| RichCurveProperty {
+        pre_infinity_extrap,
+        post_infinity_extrap,
+        ..linear.clone()
+    };
+    let linear_extrap = extrapolated(
+        RichCurveExtrapolation::Linear,
+        RichCurveExtrapolation::Linear,
+    );
+    assert_near(linear_extrap.eval(-1.0), -2.0);
+    assert_near(linear_extrap.eval(4.0), 8.0);
+
+    let cycle = extrapolated(RichCurveExtrapolation::Cycle, RichCurveExtrapolation::Cycle);
+    assert_near(cycle.eval(2.5), 1.0);
+    assert_near(cycle.eval(-1.5), 1.0);
+
+    let cycle_with_offset = extrapolated(
+        RichCurveExtrapolation::CycleWithOffset,
+        RichCurveExtrapolation::CycleWithOffset,
+    );
+    assert_near(cycle_with_offset.eval(2.5), 5.0);
+    assert_near(cycle_with_offset.eval(-1.5), -3.0);
+
+    let oscillate = extrapolated(
+        RichCurveExtrapolation::Oscillate,
+        RichCurveExtrapolation::Oscillate,
+    );
+    assert_near(oscillate.eval(2.5), 3.0);
+    assert_near(oscillate.eval(4.5), 1.0);
+
+    // default values
+    let empty = rich_curve(Vec::new());
+    assert_near(empty.eval(1.0), 0.0);
+    assert_near(empty.eval_with_default(1.0, 7.0), 7.0);
+    let with_default = RichCurveProperty {
+        default_value: 3.0.into(),
+        ..empty
+    };
+    assert_near(with_default.eval_with_default(1.0, 7.0), 3.0);
+}
+
+#[test]
+fn rich_curve_weighted_tangents() {
+    let tangent_key = |time: f32, value: f32, tangent_weight_mode| RichCurveKeyProperty {
+        tangent_weight_mode,
+        arrive_tangent: 1.0.into(),
+        leave_tangent: 1.0.into(),
+        // weights matching the unweighted tangent lengths
+        arrive_tangent_weight: (2.0f32.sqrt() / 3.0).into(),
+        leave_tangent_weight: (2.0f32.sqrt() / 3.0).into(),
+        ..rich_key(time, value, RichCurveInterpMode::Cubic)
+    };
+
+    let unweighted = rich_curve(vec![
+        tangent_key(0.0, 0.0, RichCurveTangentWeightMode::WeightedNone),
+        tangent_key(1.0, 2.0, RichCurveTangentWeightMode::WeightedNone),
+    ]);
+    let weighted = rich_curve(vec![
+        tangent_key(0.0, 0.0, RichCurveTangentWeightMode::WeightedBoth),
+        tangent_key(1.0, 2.0, RichCurveTangentWeightMode::WeightedBoth),
+    ]);
+    for time in [0.1, 0.3, 0.5, 0.9] {
+        assert_near(weighted.eval(time), unweighted.eval(time));
+    }
+
+    // heavier tangents stay within the curve range and keep the curve monotonic
+    let mut heavy = weighted.clone();
+    heavy.keys[0].leave_tangent_weight = 0.9.into();
+    heavy.keys[1].arrive_tangent_weight = 0.9.into();
+    let values = [0.1, 0.3, 0.5, 0.7, 0.9].map(|e| heavy.eval(e));
+    assert!(values.windows(2).all(|e| e[0] < e[1]), "{values:?}");
+    assert!(values.iter().all(|e| (0.0..=2.0).contains(e)), "{values:?}");
+}
+
+#[test]
+fn simple_integral_string_curve_eval() {
+    let mut simple = SimpleCurveProperty {
+        keys: vec![simple_key(0.0, 1.0), simple_key(1.0, 3.0)],
+        ..Default::default()
+    };
+    assert_near(simple.eval(0.5), 2.0);
+    simple.interp_mode = RichCurveInterpMode::Cubic;
+    assert_near(simple.eval(0.5), 2.0);
+    simple.interp_mode = RichCurveInterpMode::Constant;
+    assert_near(simple.eval(0.5), 1.0);
+    simple.post_infinity_extrap = RichCurveExtrapolation::Linear;
+    assert_near(simple.eval(2.0), 5.0);
+
+    let integral_key = |time: f32, value| IntegralKeyProperty {
+        time: time.into(),
+        value,
+        ..Default::default()
+    };
+    let mut integral = IntegralCurveProperty {
+        keys: vec![integral_key(1.0, 10), integral_key(3.0, 30)],
+        default_value: 5,
+        ..Default::default()
+    };
+    assert_eq!(integral.eval(0.0), 10);
+    assert_eq!(integral.eval(2.0), 10);
+    assert_eq!(integral.eval(3.0), 30);
+    assert_eq!(integral.eval(4.0), 30);
+    integral.use_default_value_before_first_key = true;
+    assert_eq!(integral.eval(0.0), 5);
+    assert_eq!(integral.eval(1.0), 10);
+    integral.keys.clear();
+    assert_eq!(integral.eval(2.0), 5);
+
+    let string_key = |time: f32, value: &str| StringCurveKeyProperty {
+        time: time.into(),
+        value: Some(value.to_string()),
+        ..Default::default()
+    };
+    let string = StringCurveProperty {
+        default_value: Some("Idle".to_string()),
+        keys: vec![string_key(1.0, "Walk"), string_key(2.0, "Run")],
+        ..Default::default()
+    };
+    assert_eq!(string.eval(0.0), Some("Idle"));
+    assert_eq!(string.eval(1.5), Some("Walk"));
+    assert_eq!(string.eval(5.0), Some("Run"));
+}
+
+#[test]
+fn rich_curve_from_asset() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(CURVE_ASSET),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let curve = asset.asset_data.exports[0]
+        .get_normal_export()
+        .and_then(|e| e.properties.first())
+        .and_then(|e| cast!(Property, StructProperty, e))
+        .and_then(|e| e.value.first())
+        .and_then(|e| cast!(Property, RichCurveProperty, e))
+        .expect("Failed to find rich curve");
+
+    assert_eq!(curve.keys.len(), 3);
+    for key in &curve.keys {
+        assert_near(curve.eval(key.time.0), key.value.0);
+    }
+    let values = [0.1, 0.3, 0.5, 0.7, 0.9].map(|e| curve.eval(e));
+    assert!(values.windows(2).all(|e| e[0] < e[1]), "{values:?}");
+
+    shared::verify_binary_equality(CURVE_ASSET, None, &mut asset)?;
+    Ok(())
+}
+
+#[test]
+fn curve_properties() -> Result<(), Error> {
+    let (data, bulk) = TEST_ASSET;
+    let mut asset = Asset::new(
+        Cursor::new(data.to_vec()),
+        Some(Cursor::new(bulk.to_vec())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    let export_index = asset
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| {
+            e.get_normal_export()
+                .is_some_and(|e| !e.properties.is_empty())
+        })
+        .expect("Failed to find export with properties");
+    let ancestry = asset.asset_data.exports[export_index]
+        .get_normal_export()
+        .and_then(|e| e.properties.first())
+        .map(|e| e.get_ancestry().clone())
+        .unwrap_or_default();
+
+    let mut name_map = asset.get_name_map();
+    let mut name_map = name_map.get_mut();
+    let mut curve = |name: &str, struct_type: &str, value: Property| {
+        let name = name_map.add_fname(name);
+        let mut value = value;
+        *value.get_name_mut() = name.clone();
+        shared::typed_struct(name, &ancestry, name_map.add_fname(struct_type), value)
+    };
+
+    let properties = vec![
+        curve(
+            "JumpCurve",
+            "RichCurve",
+            RichCurveProperty {
+                post_infinity_extrap: RichCurveExtrapolation::Cycle,
+                keys: vec![
+                    rich_key(0.0, 0.0, RichCurveInterpMode::Cubic),
+                    rich_key(0.5, 2.0, RichCurveInterpMode::Linear),
+                    rich_key(1.0, 0.0, RichCurveInterpMode::Constant),
+                ],
+                ..Default::default()
+            }
+            .into(),
+        ),
+        curve(
+            "SpeedCurve",
+            "SimpleCurve",
+            SimpleCurveProperty {
+                default_value: 1.0.into(),
+                interp_mode: RichCurveInterpMode::Constant,
+                keys: vec![simple_key(0.0, 1.0), simple_key(2.0, 3.0)],
+                ..Default::default()
+            }
+            .into(),
+        ),
+        curve(
+            "LevelCurve",
+            "IntegralCurve",
+            IntegralCurveProperty {
+                keys: vec![
+                    IntegralKeyProperty {
+                        time: 10.0.into(),
+                        value: 2,
+                        ..Default::default()
+                    },
+                    // a zero time is only written if it was serialized
+                    IntegralKeyProperty {
+                        value: 1,
+                        ..Default::default()
+                    },
+                ],
+                use_default_value_before_first_key: true,
+                ..Default::default()
+            }
+            .into(),
+        ),
+        curve(
+            "StateCurve",
+            "StringCurve",
+            StringCurveProperty {
+                keys: vec![StringCurveKeyProperty {
+                    time: 1.0.into(),
+                    value: Some("Dash".to_string()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }
+            .into(),
+        ),
+    ];
+
+    drop(name_map);
+    asset.asset_data.exports[export_index]
+        .get_normal_export_mut()
+        .expect("Failed to find export")
+        .properties
+        .extend(properties);
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+    let (data, bulk) = (cursor.into_inner(), bulk_cursor.into_inner());
+
+    let mut reparsed = Asset::new(
+        Cursor::new(data.clone()),
+        Some(Cursor::new(bulk.clone())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    match shared::find_typed(&reparsed, "JumpCurve") {
+        Property::RichCurveProperty(e) => {
+            assert_eq!(e.keys.len(), 3);
+            assert_eq!(e.keys[1].interp_mode, RichCurveInterpMode::Linear);
+            assert_eq!(e.post_infinity_extrap, RichCurveExtrapolation::Cycle);
+            assert_eq!(e.pre_infinity_extrap, RichCurveExtrapolation::Constant);
+            assert_near(e.eval(0.75), 1.0);
+            assert_near(e.eval(1.75), 1.0);
+        }
+        e => panic!("Expected RichCurveProperty, got {e:?}"),
+    }
+
+    match shared::find_typed(&reparsed, "SpeedCurve") {
+        Property::SimpleCurveProperty(e) => {
+            assert_eq!(e.interp_mode, RichCurveInterpMode::Constant);
+            assert_eq!(e.default_value.0, 1.0);
+            assert_near(e.eval(1.0), 1.0);
+        }
+        e => panic!("Expected SimpleCurveProperty, got {e:?}"),
+    }
+
+    match shared::find_typed(&reparsed, "LevelCurve") {
+        Property::IntegralCurveProperty(e) => {
+            assert_eq!(e.keys.len(), 2);
+            assert_eq!(e.keys[1].value, 1);
+            assert_eq!(e.default_value, i32::MAX);
+            assert!(e.use_default_value_before_first_key);
+            assert_eq!(
+                e.keys[1]
+                    .serialized_fields
+                    .iter()
+                    .map(|e| e.get_owned_content())
+                    .collect::<Vec<_>>(),
+                ["Value"]
+            );
+        }
+        e => panic!("Expected IntegralCurveProperty, got {e:?}"),
+    }
+
+    match shared::find_typed(&reparsed, "StateCurve") {
+        Property::StringCurveProperty(e) => {
+            assert_eq!(e.default_value, None);
+            assert_eq!(e.eval(2.0), Some("Dash"));
+        }
+        e => panic!("Expected StringCurveProperty, got {e:?}"),
+    }
+
+    shared::verify_binary_equality(&data, Some(&bulk), &mut reparsed)?;
+    Ok(())
+}
+
+fn curve_table_round_trip(
+    mode: CurveTableMode,
+    shrink_curve_table_size: bool,
+) -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(CURVE_ASSET.to_vec()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    if !shrink_curve_table_size {
+        // write the lowered custom version into the summary
+        asset.asset_data.summary.unversioned = false;
+        for custom_version in &mut asset.asset_data.summary.custom_versions {
+            if custom_version.guid == FFortniteMainBranchObjectVersion::GUID {
+                custom_version.version =
+                    FFortniteMainBranchObjectVersion::ShrinkCurveTableSize as i32 - 1;
+            }
+        }
+    }
+
+    // turn the curve float into a curve table
+    let (curve_table, damage, health) = {
+        let mut name_map = asset.get_name_map();
+        let mut name_map = name_map.get_mut();
+        (
+            name_map.add_fname("CurveTable"),
+            name_map.add_fname("Damage"),
+            name_map.add_fname("Health"),
+        )
+    };
+    asset
+        .imports
+        .iter_mut()
+        .find(|e| e.object_name == "CurveFloat")
+        .expect("Failed to find curve float class")
+        .object_name = curve_table;
+
+    let rows = match mode {
+        CurveTableMode::RichCurves => vec![
+            CurveTableRow::RichCurve(RichCurveProperty {
+                name: damage,
+                keys: vec![
+                    rich_key(1.0, 10.0, RichCurveInterpMode::Linear),
+                    rich_key(10.0, 100.0, RichCurveInterpMode::Linear),
+                ],
+                ..Default::default()
+            }),
+            CurveTableRow::RichCurve(RichCurveProperty {
+                name: health,
+                default_value: 50.0.into(),
+                ..Default::default()
+            }),
+        ],
+        _ => vec![
+            CurveTableRow::SimpleCurve(SimpleCurveProperty {
+                name: damage,
+                keys: vec![simple_key(1.0, 10.0), simple_key(10.0, 100.0)],
+                ..Default::default()
+            }),
+            CurveTableRow::SimpleCurve(SimpleCurveProperty {
+                name: health,
+                default_value: 50.0.into(),
+                ..Default::default()
+            }),
+        ],
+    };
+
+    let mut normal_export = asset.asset_data.exports[0]
+        .get_normal_export()
+        .expect("Failed to find export")
+        .clone();
+    normal_export.properties.clear();
+    asset.asset_data.exports[0] = CurveTableExport {
+        normal_export,
+        table: CurveTable::new(mode, rows),
+    }
+    .into();
+
+    let mut cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, None)?;
+    let data = cursor.into_inner();
+
+    let mut reparsed = Asset::new(
+        Cursor::new(data.clone()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let curve_table = cast!(Export, CurveTableExport, &reparsed.asset_data.exports[0])
+        .expect("First export is not a CurveTableExport after serializing and deserializing");
+    assert_eq!(curve_table.table.mode, mode);
+    assert_eq!(curve_table.table.rows.len(), 2);
+    let damage = curve_table
+        .table
+        .find_row("Damage")
+        .expect("Failed to find damage row");
+    assert_near(damage.eval(5.5), 55.0);
+    let health = curve_table
+        .table
+        .find_row("Health")
+        .expect("Failed to find health row");
+    assert_near(health.eval(5.5), 50.0);
+
+    shared::verify_binary_equality(&data, None, &mut reparsed)?;
+    Ok(())
+}
+
+#[test]
+fn rich_curve_table() -> Result<(), Error> {
+    curve_table_round_trip(CurveTableMode::RichCurves, true)
+}
+
+#[test]
+fn simple_curve_table() -> Result<(), Error> {
+    curve_table_round_trip(CurveTableMode::SimpleCurves, true)
+}
+
+#[test]
+fn legacy_curve_table() -> Result<(), Error> {
+    curve_table_round_trip(CurveTableMode::RichCurves, false)
+}
@@ -0,0 +1,151 @@
+use unreal_asset::{
+    custom_version::{
+        CustomVersion, CustomVersionTrait, FFortniteMainBranchObjectVersion,
+        FUE5MainStreamObjectVersion, FUE5ReleaseStreamObjectVersion,
+    },
+    engine_version::{
+        get_object_versions, get_possible_versions, guess_engine_version, EngineVersion,
+    },
+    object_version::{ObjectVersion, ObjectVersionUE5},
+    Guid,
+};
+
+#[test]
+fn ue5_object_versions() {
+    for (engine_version, object_version_ue5) in [
+        (EngineVersion::VER_UE5_2, ObjectVersionUE5::DATA_RESOURCES),
+        (
+            EngineVersion::VER_UE5_3,
+            ObjectVersionUE5::PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION,
+        ),
+        (
+            EngineVersion::VER_UE5_4,
+            ObjectVersionUE5::PROPERTY_TAG_COMPLETE_TYPE_NAME,
+        ),
+        (
+            EngineVersion::VER_UE5_5,
+            ObjectVersionUE5::PACKAGE_SAVED_HASH,
+        ),
+    ] {
+        assert_eq!(
+            get_object_versions(engine_version),
+            (
+                ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG,
+                object_version_ue5
+            )
+        );
+    }
+}
+
+#[test]
+fn ue5_possible_versions() {
+    assert_eq!(
+        get_possible_versions(
+            ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG,
+            ObjectVersionUE5::DATA_RESOURCES
+        ),
+        vec![EngineVersion::VER_UE5_2]
+    );
+    assert_eq!(
+        get_possible_versions(
+            ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG,
+            ObjectVersionUE5::SCRIPT_SERIALIZATION_OFFSET
+        ),
+        vec![EngineVersion::VER_UE5_2]
+    );
+    assert_eq!(
+        get_possible_versions(
+            ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG,
+            ObjectVersionUE5::UNKNOWN
+        ),
+        vec![EngineVersion::VER_UE4_27]
+    );
+
+    assert_eq!(
+        guess_engine_version(
+            ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG,
+            ObjectVersionUE5::PROPERTY_TAG_COMPLETE_TYPE_NAME,
+            &[]
+        ),
+        EngineVersion::VER_UE5_4
+    );
+    assert_eq!(
+        guess_engine_version(
+            ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG,
+            ObjectVersionUE5::ASSETREGISTRY_PACKAGEBUILDDEPENDENCIES,
+            &[]
+        ),
+        EngineVersion::VER_UE5_4
+    );
+    assert_eq!(
+        guess_engine_version(
+            ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG,
+            ObjectVersionUE5::PACKAGE_SAVED_HASH,
+            &[]
+        ),
+        EngineVersion::VER_UE5_5
+    );
+}
+
+fn default_custom_version(engine_version: EngineVersion, guid: Guid) -> Option<i32> {
+    CustomVersion::get_default_custom_version_container(engine_version)
+        .iter()
+        .find(|e| e.guid == guid)
+        .map(|e| e.version)
+}
+
+#[test]
+fn ue5_default_custom_versions() {
+    assert_eq!(
+        default_custom_version(
+            EngineVersion::VER_UE5_0,
+            FFortniteMainBranchObjectVersion::GUID
+        ),
+        Some(FFortniteMainBranchObjectVersion::GravityOverrideDefinedInWorldSpace as i32)
+    );
+    assert_eq!(
+        default_custom_version(EngineVersion::VER_UE5_0, FUE5MainStreamObjectVersion::GUID),
+        Some(FUE5MainStreamObjectVersion::VolumetricCloudReflectionSampleCountDefaultUpdate as i32)
+    );
+
+    for (engine_version, release_stream_version) in [
+        (
+            EngineVersion::VER_UE5_0,
+            FUE5ReleaseStreamObjectVersion::SerializeFloatPinDefaultValuesAsSinglePrecision,
+        ),
+        (
+            EngineVersion::VER_UE5_1,
+            FUE5ReleaseStreamObjectVersion::LinkedAnimGraphMemberReference,
+        ),
+        (
+            EngineVersion::VER_UE5_2,
+            FUE5ReleaseStreamObjectVersion::RigVMSaveDebugMapInGraphFunctionData,
+        ),
+        (
+            EngineVersion::VER_UE5_3,
+            FUE5ReleaseStreamObjectVersion::OpenColorIODisabledDisplayConfigurationDefault,
+        ),
+        (
+            EngineVersion::VER_UE5_4,
+            FUE5ReleaseStreamObjectVersion::WorldPartitionExternalDataLayers,
+        ),
+        (
+            EngineVersion::VER_UE5_5,
+            FUE5ReleaseStreamObjectVersion::ChaosClothFictitiousAngularVelocitySubspaceBugFix,
+        ),
+    ] {
+        assert_eq!(
+            default_custom_version(engine_version, FUE5ReleaseStreamObjectVersion::GUID),
+            Some(release_stream_version as i32)
+        );
+    }
+
+    // ue5 stream versions don't exist before ue5
+    assert_eq!(
+        default_custom_version(
+            EngineVersion::VER_UE4_27,
+            FUE5ReleaseStreamObjectVersion::GUID
+        ),
+        None
+    );
+}
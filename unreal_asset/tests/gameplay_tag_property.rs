@@ -0,0 +1,239 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{
+        gameplay_attribute_property::GameplayAttributeProperty,
+        gameplay_tag_container_property::GameplayTagContainerProperty,
+        gameplay_tag_property::{
+            EGameplayTagQueryExprType, GameplayTagProperty, GameplayTagQueryProperty,
+        },
+        Property, PropertyDataTrait, PropertyTagExtensions,
+    },
+    types::{FName, FieldPath, PackageIndex},
+    Asset, Error,
+};
+
+mod shared;
+
+const TEST_ASSET: (&[u8], &[u8]) = (
+    include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/assets/general/pseudoregalia/BP_PlayerGoatMain.uasset"
+    )),
+    include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/assets/general/pseudoregalia/BP_PlayerGoatMain.uexp"
+    )),
+);
+
+/// All of: any of `Ability.Attack`, none of `State.Stunned`
+fn attack_query_tokens() -> Vec<u8> {
+    vec![
+        0,
+        1,
+        EGameplayTagQueryExprType::AllExprMatch.into(),
+        2,
+        EGameplayTagQueryExprType::AnyTagsMatch.into(),
+        1,
+        0,
+        EGameplayTagQueryExprType::NoTagsMatch.into(),
+        1,
+        1,
+    ]
+}
+
+fn add_gameplay_properties(asset: &mut Asset<Cursor<Vec<u8>>>) {
+    let export_index = asset
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| {
+            e.get_normal_export()
+                .is_some_and(|e| !e.properties.is_empty())
+        })
+        .expect("Failed to find export with properties");
+
+    let ancestry = asset.asset_data.exports[export_index]
+        .get_normal_export()
+        .and_then(|e| e.properties.first())
+        .map(|e| e.get_ancestry().clone())
+        .unwrap_or_default();
+
+    let mut name_map = asset.get_name_map();
+    let mut name_map = name_map.get_mut();
+
+    let tag_name = name_map.add_fname("AttackTag");
+    let tag = shared::typed_struct(
+        tag_name.clone(),
+        &ancestry,
+        name_map.add_fname("GameplayTag"),
+        GameplayTagProperty {
+            name: tag_name.clone(),
+            ancestry: ancestry.with_parent(tag_name),
+            property_guid: None,
+            property_tag_extensions: PropertyTagExtensions::default(),
+            duplication_index: 0,
+            value: name_map.add_fname("Ability.Attack.Heavy"),
+        }
+        .into(),
+    );
+
+    let query_name = name_map.add_fname("AttackQuery");
+    let query = shared::typed_struct(
+        query_name.clone(),
+        &ancestry,
+        name_map.add_fname("GameplayTagQuery"),
+        GameplayTagQueryProperty {
+            name: query_name.clone(),
+            ancestry: ancestry.with_parent(query_name),
+            property_guid: None,
+            property_tag_extensions: PropertyTagExtensions::default(),
+            duplication_index: 0,
+            token_stream_version: 0,
+            tag_dictionary: vec![
+                name_map.add_fname("Ability.Attack"),
+                name_map.add_fname("State.Stunned"),
+            ],
+            query_token_stream: attack_query_tokens(),
+            user_description: Some("Attacking while not stunned".to_string()),
+            auto_description: None,
+            // default values are still written for serialized fields
+            serialized_fields: vec![name_map.add_fname("TokenStreamVersion")],
+        }
+        .into(),
+    );
+
+    let attribute_name = name_map.add_fname("DamageAttribute");
+    let attribute = shared::typed_struct(
+        attribute_name.clone(),
+        &ancestry,
+        name_map.add_fname("GameplayAttribute"),
+        GameplayAttributeProperty {
+            name: attribute_name.clone(),
+            ancestry: ancestry.with_parent(attribute_name),
+            property_guid: None,
+            property_tag_extensions: PropertyTagExtensions::default(),
+            duplication_index: 0,
+            attribute_name: Some("Damage".to_string()),
+            attribute: FieldPath::new(vec![name_map.add_fname("Damage")], PackageIndex::new(-1)),
+            attribute_owner: PackageIndex::new(-1),
+            serialized_fields: Vec::new(),
+        }
+        .into(),
+    );
+
+    drop(name_map);
+    asset.asset_data.exports[export_index]
+        .get_normal_export_mut()
+        .expect("Failed to find export")
+        .properties
+        .extend([tag, query, attribute]);
+}
+
+#[test]
+fn gameplay_tag_properties() -> Result<(), Error> {
+    let (data, bulk) = TEST_ASSET;
+    let mut asset = Asset::new(
+        Cursor::new(data.to_vec()),
+        Some(Cursor::new(bulk.to_vec())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    add_gameplay_properties(&mut asset);
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+    let (data, bulk) = (cursor.into_inner(), bulk_cursor.into_inner());
+
+    let mut reparsed = Asset::new(
+        Cursor::new(data.clone()),
+        Some(Cursor::new(bulk.clone())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    match shared::find_typed(&reparsed, "AttackTag") {
+        Property::GameplayTagProperty(e) => {
+            assert_eq!(e.value.get_owned_content(), "Ability.Attack.Heavy");
+            assert!(e.matches_tag(&FName::from_slice("Ability.Attack")));
+        }
+        e => panic!("Expected GameplayTagProperty, got {e:?}"),
+    }
+
+    match shared::find_typed(&reparsed, "AttackQuery") {
+        Property::GameplayTagQueryProperty(e) => {
+            assert_eq!(e.query_token_stream, attack_query_tokens());
+            assert_eq!(e.tag_dictionary.len(), 2);
+            assert_eq!(
+                e.user_description.as_deref(),
+                Some("Attacking while not stunned")
+            );
+            assert_eq!(e.auto_description, None);
+            assert_eq!(
+                e.serialized_fields
+                    .iter()
+                    .map(|e| e.get_owned_content())
+                    .collect::<Vec<_>>(),
+                [
+                    "TokenStreamVersion",
+                    "TagDictionary",
+                    "QueryTokenStream",
+                    "UserDescription"
+                ]
+            );
+            assert!(e.matches_tags(&[FName::from_slice("Ability.Attack.Heavy")]));
+        }
+        e => panic!("Expected GameplayTagQueryProperty, got {e:?}"),
+    }
+
+    match shared::find_typed(&reparsed, "DamageAttribute") {
+        Property::GameplayAttributeProperty(e) => {
+            assert_eq!(e.attribute_name.as_deref(), Some("Damage"));
+            assert_eq!(e.attribute.path.len(), 1);
+            assert_eq!(e.attribute.resolved_owner, PackageIndex::new(-1));
+            assert_eq!(e.attribute_owner, PackageIndex::new(-1));
+        }
+        e => panic!("Expected GameplayAttributeProperty, got {e:?}"),
+    }
+
+    shared::verify_binary_equality(&data, Some(&bulk), &mut reparsed)?;
+    Ok(())
+}
+
+#[test]
+fn gameplay_tag_query_matching() {
+    let query = GameplayTagQueryProperty {
+        tag_dictionary: vec![
+            FName::from_slice("Ability.Attack"),
+            FName::from_slice("State.Stunned"),
+        ],
+        query_token_stream: attack_query_tokens(),
+        ..Default::default()
+    };
+    let container = |tags: &[&str]| GameplayTagContainerProperty {
+        value: tags.iter().map(|e| FName::from_slice(e)).collect(),
+        ..Default::default()
+    };
+
+    assert!(query.matches(&container(&["Ability.Attack"])));
+    assert!(query.matches(&container(&["ability.attack.heavy", "State.Dazed"])));
+    assert!(!query.matches(&container(&["Ability.Attack", "State.Stunned.Long"])));
+    assert!(!query.matches(&container(&["Ability.AttackSpeed"])));
+    assert!(!query.matches(&container(&[])));
+
+    let attacking = container(&["Ability.Attack.Heavy"]);
+    assert!(attacking.has_tag(&FName::from_slice("Ability")));
+    assert!(!attacking.has_tag_exact(&FName::from_slice("Ability")));
+    assert!(attacking.matches_query(&query));
+
+    // empty and malformed queries never match
+    assert!(!GameplayTagQueryProperty::default().matches(&attacking));
+    let truncated = GameplayTagQueryProperty {
+        query_token_stream: attack_query_tokens()[..6].to_vec(),
+        ..query.clone()
+    };
+    assert!(!truncated.matches(&attacking));
+}
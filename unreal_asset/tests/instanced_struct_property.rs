@@ -0,0 +1,273 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    custom_version::{CustomVersion, CustomVersionTrait, FInstancedStructCustomVersion},
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{
+        instanced_struct_property::InstancedStructProperty, int_property::IntProperty,
+        struct_property::StructProperty, vector_property::VectorProperty, Property,
+        PropertyDataTrait, PropertyTagExtensions,
+    },
+    types::{vector::Vector, FName, PackageIndex},
+    unversioned::Ancestry,
+    Asset, Error, Guid, Import,
+};
+
+mod shared;
+
+const TEST_ASSET: (&[u8], &[u8]) = (
+    include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/assets/general/pseudoregalia/BP_PlayerGoatMain.uasset"
+    )),
+    include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/assets/general/pseudoregalia/BP_PlayerGoatMain.uexp"
+    )),
+);
+
+/// Wraps an instanced struct in the `FInstancedStruct` struct property it is stored as
+fn instanced_struct(
+    name: FName,
+    struct_type: FName,
+    ancestry: &Ancestry,
+    struct_index: PackageIndex,
+    value: Option<(FName, Property)>,
+) -> Property {
+    let value = value.map(|(inner_type, value)| {
+        let mut inner = StructProperty::dummy(
+            name.clone(),
+            ancestry.with_parent(name.clone()),
+            inner_type,
+            None,
+        );
+        inner.value.push(value);
+        inner
+    });
+
+    let mut property = StructProperty::dummy(
+        name.clone(),
+        ancestry.clone(),
+        struct_type,
+        Some(Guid::default()),
+    );
+    property.value.push(
+        InstancedStructProperty {
+            name,
+            ancestry: ancestry.clone(),
+            property_guid: None,
+            property_tag_extensions: PropertyTagExtensions::default(),
+            duplication_index: 0,
+            version: 0,
+            struct_index,
+            value,
+        }
+        .into(),
+    );
+    property.into()
+}
+
+fn add_instanced_structs(asset: &mut Asset<Cursor<Vec<u8>>>) {
+    let export_index = asset
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| {
+            e.get_normal_export()
+                .is_some_and(|e| !e.properties.is_empty())
+        })
+        .expect("Failed to find export with properties");
+
+    let ancestry = asset.asset_data.exports[export_index]
+        .get_normal_export()
+        .and_then(|e| e.properties.first())
+        .map(|e| e.get_ancestry().clone())
+        .unwrap_or_default();
+
+    let (core_uobject, script_struct, vector, goat_stats) = {
+        let mut name_map = asset.get_name_map();
+        let mut name_map = name_map.get_mut();
+        for type_name in ["StructProperty", "IntProperty", "None"] {
+            name_map.add_fname(type_name);
+        }
+        (
+            name_map.add_fname("/Script/CoreUObject"),
+            name_map.add_fname("ScriptStruct"),
+            name_map.add_fname("Vector"),
+            name_map.add_fname("GoatStats"),
+        )
+    };
+
+    let vector_index = asset.add_import(Import::new(
+        core_uobject.clone(),
+        script_struct.clone(),
+        PackageIndex::new(0),
+        vector.clone(),
+        false,
+    ));
+    let goat_stats_index = asset.add_import(Import::new(
+        core_uobject,
+        script_struct,
+        PackageIndex::new(0),
+        goat_stats.clone(),
+        false,
+    ));
+
+    let mut name_map = asset.get_name_map();
+    let mut name_map = name_map.get_mut();
+    let struct_type = name_map.add_fname("InstancedStruct");
+
+    let location = name_map.add_fname("InstancedLocation");
+    let stats = name_map.add_fname("InstancedStats");
+    let health = name_map.add_fname("Health");
+    let properties = vec![
+        instanced_struct(
+            location.clone(),
+            struct_type.clone(),
+            &ancestry,
+            vector_index,
+            Some((
+                vector,
+                VectorProperty {
+                    name: location.clone(),
+                    ancestry: ancestry.with_parent(location.clone()),
+                    property_guid: None,
+                    property_tag_extensions: PropertyTagExtensions::default(),
+                    duplication_index: 0,
+                    value: Vector::new(1.0.into(), 2.0.into(), 3.0.into()),
+                }
+                .into(),
+            )),
+        ),
+        instanced_struct(
+            stats.clone(),
+            struct_type.clone(),
+            &ancestry,
+            goat_stats_index,
+            Some((
+                goat_stats,
+                IntProperty {
+                    name: health,
+                    ancestry: ancestry.with_parent(stats.clone()),
+                    property_guid: Some(Guid::default()),
+                    property_tag_extensions: PropertyTagExtensions::default(),
+                    duplication_index: 0,
+                    value: 100,
+                }
+                .into(),
+            )),
+        ),
+        instanced_struct(
+            name_map.add_fname("InstancedEmpty"),
+            struct_type,
+            &ancestry,
+            PackageIndex::new(0),
+            None,
+        ),
+    ];
+
+    drop(name_map);
+    asset.asset_data.exports[export_index]
+        .get_normal_export_mut()
+        .expect("Failed to find export")
+        .properties
+        .extend(properties);
+}
+
+fn find_instanced_struct<'a>(
+    asset: &'a Asset<Cursor<Vec<u8>>>,
+    name: &str,
+) -> &'a InstancedStructProperty {
+    let property = asset
+        .asset_data
+        .exports
+        .iter()
+        .filter_map(|e| e.get_normal_export())
+        .flat_map(|e| e.properties.iter())
+        .find(|e| e.get_name() == name)
+        .expect("Failed to find property");
+
+    match property {
+        Property::StructProperty(e) => match e.value.first() {
+            Some(Property::InstancedStructProperty(e)) => e,
+            e => panic!("Expected InstancedStructProperty, got {e:?}"),
+        },
+        e => panic!("Expected StructProperty, got {e:?}"),
+    }
+}
+
+fn instanced_struct_round_trip(custom_version_added: bool) -> Result<(), Error> {
+    let (data, bulk) = TEST_ASSET;
+    let mut asset = Asset::new(
+        Cursor::new(data.to_vec()),
+        Some(Cursor::new(bulk.to_vec())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    add_instanced_structs(&mut asset);
+    if custom_version_added {
+        // cooked test assets are unversioned, store the versions so the reparse can pick them up
+        asset.asset_data.summary.unversioned = false;
+        asset
+            .asset_data
+            .summary
+            .custom_versions
+            .push(CustomVersion::new(
+                FInstancedStructCustomVersion::GUID,
+                FInstancedStructCustomVersion::CustomVersionAdded as i32,
+            ));
+    }
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+    let (data, bulk) = (cursor.into_inner(), bulk_cursor.into_inner());
+
+    let mut reparsed = Asset::new(
+        Cursor::new(data.clone()),
+        Some(Cursor::new(bulk.clone())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    let location = find_instanced_struct(&reparsed, "InstancedLocation");
+    let location_value = location.value.as_ref().expect("Expected struct value");
+    assert!(location_value
+        .struct_type
+        .as_ref()
+        .is_some_and(|e| e == "Vector"));
+    match location_value.value.first() {
+        Some(Property::VectorProperty(e)) => {
+            assert_eq!(e.value, Vector::new(1.0.into(), 2.0.into(), 3.0.into()))
+        }
+        e => panic!("Expected VectorProperty, got {e:?}"),
+    }
+
+    let stats = find_instanced_struct(&reparsed, "InstancedStats");
+    let stats_value = stats.value.as_ref().expect("Expected struct value");
+    assert!(stats_value
+        .struct_type
+        .as_ref()
+        .is_some_and(|e| e == "GoatStats"));
+    match stats_value.value.first() {
+        Some(Property::IntProperty(e)) => assert_eq!(e.value, 100),
+        e => panic!("Expected IntProperty, got {e:?}"),
+    }
+
+    let empty = find_instanced_struct(&reparsed, "InstancedEmpty");
+    assert!(empty.value.is_none());
+
+    shared::verify_binary_equality(&data, Some(&bulk), &mut reparsed)?;
+    Ok(())
+}
+
+#[test]
+fn instanced_struct_property() -> Result<(), Error> {
+    instanced_struct_round_trip(false)
+}
+
+#[test]
+fn instanced_struct_property_custom_version() -> Result<(), Error> {
+    instanced_struct_round_trip(true)
+}
@@ -0,0 +1,198 @@
+use std::io::Cursor;
+
+use unreal_asset::compression::CompressionMethod;
+use unreal_asset::encryption::{AesKey, EncryptionKeys};
+use unreal_asset::error::{Error, IoStoreError};
+use unreal_asset::io_store::{
+    toc::{
+        IoOffsetAndLength, IoStoreTocCompressedBlockEntry, IoStoreTocEntryMeta, IoStoreTocHeader,
+    },
+    EIoChunkType, EIoContainerFlags, EIoStoreTocVersion, IoChunkId, IoDirectoryIndex,
+    IoStoreReader, IoStoreToc,
+};
+use unreal_asset::Guid;
+
+const BLOCK_SIZE: usize = 16;
+
+/// Pad data to the aes block size and encrypt it
+fn encrypt(data: &[u8], key: &AesKey) -> Vec<u8> {
+    let mut data = data.to_vec();
+    data.resize(data.len().next_multiple_of(16), 0);
+    key.encrypt(&mut data).unwrap();
+    data
+}
+
+/// Builds an uncompressed container from a list of (path, package name, data)
+fn build_container(files: &[(&str, &str, &[u8])]) -> (Vec<u8>, Vec<u8>) {
+    build_encrypted_container(files, None)
+}
+
+/// Builds an uncompressed container from a list of (path, package name, data), encrypting it with a key
+fn build_encrypted_container(
+    files: &[(&str, &str, &[u8])],
+    key: Option<&AesKey>,
+) -> (Vec<u8>, Vec<u8>) {
+    let mut ucas = Vec::new();
+    let mut chunk_ids = Vec::new();
+    let mut chunk_offset_lengths = Vec::new();
+    let mut compression_blocks = Vec::new();
+
+    for (_, package_name, data) in files {
+        chunk_ids.push(IoChunkId::from_package_name(
+            package_name,
+            EIoChunkType::ExportBundleData,
+        ));
+        chunk_offset_lengths.push(IoOffsetAndLength {
+            offset: (compression_blocks.len() * BLOCK_SIZE) as u64,
+            length: data.len() as u64,
+        });
+
+        for block in data.chunks(BLOCK_SIZE) {
+            compression_blocks.push(IoStoreTocCompressedBlockEntry {
+                offset: ucas.len() as u64,
+                compressed_size: block.len() as u32,
+                uncompressed_size: block.len() as u32,
+                compression_method_index: 0,
+            });
+            match key {
+                Some(key) => ucas.extend_from_slice(&encrypt(block, key)),
+                None => ucas.extend_from_slice(block),
+            }
+        }
+    }
+
+    let directory_index = IoDirectoryIndex::from_files(
+        Some("../../../".to_string()),
+        files
+            .iter()
+            .enumerate()
+            .map(|(i, (path, _, _))| (*path, i as u32)),
+    );
+
+    let mut directory_index_buffer = directory_index.to_buffer().unwrap();
+    let mut container_flags = EIoContainerFlags::INDEXED;
+    if let Some(key) = key {
+        directory_index_buffer = encrypt(&directory_index_buffer, key);
+        container_flags |= EIoContainerFlags::ENCRYPTED;
+    }
+
+    let toc = IoStoreToc {
+        header: IoStoreTocHeader {
+            version: EIoStoreTocVersion::PerfectHashWithOverflow,
+            toc_entry_count: 0,
+            toc_compressed_block_entry_count: 0,
+            toc_compressed_block_entry_size: 0,
+            compression_method_name_count: 0,
+            compression_method_name_length: 0,
+            compression_block_size: BLOCK_SIZE as u32,
+            directory_index_size: 0,
+            partition_count: 1,
+            container_id: 0x1234,
+            encryption_key_guid: Guid::default(),
+            container_flags,
+            toc_chunk_perfect_hash_seeds_count: 0,
+            partition_size: u64::MAX,
+            toc_chunks_without_perfect_hash_count: 0,
+        },
+        chunk_metas: vec![IoStoreTocEntryMeta::default(); chunk_ids.len()],
+        chunk_ids,
+        chunk_offset_lengths,
+        chunk_perfect_hash_seeds: Vec::new(),
+        chunk_indices_without_perfect_hash: Vec::new(),
+        compression_blocks,
+        compression_methods: vec![CompressionMethod::Zlib],
+        signatures: None,
+        directory_index_buffer,
+    };
+
+    let mut utoc = Vec::new();
+    toc.write(&mut utoc).unwrap();
+    (utoc, ucas)
+}
+
+#[test]
+fn read_container() -> Result<(), Error> {
+    let small = b"small file";
+    let large = (0..100u8).collect::<Vec<_>>();
+
+    let (utoc, ucas) = build_container(&[
+        ("Game/Content/Small.uasset", "/Game/Small", small),
+        ("Game/Content/Maps/Large.uasset", "/Game/Maps/Large", &large),
+    ]);
+
+    let mut reader = IoStoreReader::new(&mut Cursor::new(&utoc), vec![Cursor::new(ucas)], None)?;
+
+    assert_eq!(reader.get_mount_point(), Some("../../../"));
+    assert_eq!(
+        reader.toc.compression_methods,
+        vec![CompressionMethod::Zlib]
+    );
+    assert_eq!(reader.get_file_names().count(), 2);
+
+    assert_eq!(reader.read_file("Game/Content/Small.uasset")?, small);
+    assert_eq!(
+        reader.read_file("../../../Game/Content/Maps/Large.uasset")?,
+        large
+    );
+    assert_eq!(reader.read_package("/Game/Maps/Large")?, large);
+
+    let chunk_id = reader.get_chunk_id("/Game/Content/Small.uasset").unwrap();
+    assert_eq!(chunk_id.get_chunk_type()?, EIoChunkType::ExportBundleData);
+    assert_eq!(reader.read_chunk(&chunk_id)?, small);
+
+    assert!(matches!(
+        reader.read_file("Game/Content/Missing.uasset"),
+        Err(Error::IoStore(IoStoreError::NoFile(_)))
+    ));
+
+    // toc round trip
+    let mut written = Vec::new();
+    reader.toc.write(&mut written)?;
+    assert_eq!(written, utoc);
+
+    Ok(())
+}
+
+#[test]
+fn read_encrypted_container() -> Result<(), Error> {
+    let key = AesKey::new([7u8; 32]);
+    let large = (0..100u8).collect::<Vec<_>>();
+    let files: [(&str, &str, &[u8]); 2] = [
+        ("Game/Content/Small.uasset", "/Game/Small", b"small file"),
+        ("Game/Content/Maps/Large.uasset", "/Game/Maps/Large", &large),
+    ];
+    let (utoc, ucas) = build_encrypted_container(&files, Some(&key));
+
+    assert!(matches!(
+        IoStoreReader::new(
+            &mut Cursor::new(&utoc),
+            vec![Cursor::new(ucas.clone())],
+            None
+        ),
+        Err(Error::IoStore(IoStoreError::NoEncryptionKey))
+    ));
+
+    let mut reader = IoStoreReader::new(
+        &mut Cursor::new(&utoc),
+        vec![Cursor::new(ucas)],
+        Some(EncryptionKeys::with_default_key(key)),
+    )?;
+    assert!(reader.is_encrypted());
+    assert_eq!(reader.get_file_names().count(), 2);
+    for (path, package_name, data) in files {
+        assert_eq!(reader.read_file(path)?, data);
+        assert_eq!(reader.read_package(package_name)?, data);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn invalid_magic() {
+    let mut utoc = vec![0u8; IoStoreTocHeader::SIZE as usize];
+    utoc[0] = b'x';
+    assert!(matches!(
+        IoStoreToc::from_buffer(&utoc),
+        Err(Error::IoStore(IoStoreError::InvalidTocMagic(_)))
+    ));
+}
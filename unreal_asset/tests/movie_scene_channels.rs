@@ -0,0 +1,419 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::{Export, ExportNormalTrait},
+    properties::{
+        movies::{
+            frame_number_property::FrameNumberProperty,
+            movie_scene_bool_channel_property::MovieSceneBoolChannelProperty,
+            movie_scene_byte_channel_property::MovieSceneByteChannelProperty,
+            movie_scene_double_channel_property::{
+                MovieSceneDoubleChannel, MovieSceneDoubleChannelProperty,
+            },
+            movie_scene_double_value_property::MovieSceneDoubleValue,
+            movie_scene_event_channel_property::MovieSceneEventChannelProperty,
+            movie_scene_integer_channel_property::MovieSceneIntegerChannelProperty,
+            movie_scene_object_path_channel_property::MovieSceneObjectPathChannelProperty,
+            movie_scene_string_channel_property::MovieSceneStringChannelProperty,
+            movie_scene_time_warp_variant_property::{
+                MovieSceneTimeWarpVariant, MovieSceneTimeWarpVariantProperty,
+            },
+            MovieSceneTangentData,
+        },
+        object_property::ObjectProperty,
+        rich_curve_key_property::{RichCurveExtrapolation, RichCurveInterpMode},
+        struct_property::StructProperty,
+        Property, PropertyDataTrait, PropertyTagExtensions,
+    },
+    types::{
+        movie::{FrameNumber, FrameRate},
+        FName, PackageIndex,
+    },
+    unversioned::Ancestry,
+    Asset, Error,
+};
+
+mod shared;
+
+const TEST_ASSET: (&[u8], &[u8]) = (
+    include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/assets/general/pseudoregalia/BP_PlayerGoatMain.uasset"
+    )),
+    include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/assets/general/pseudoregalia/BP_PlayerGoatMain.uexp"
+    )),
+);
+
+/// Widget blueprint with float and 2D transform movie scene sections
+const ANIMATION_ASSET: (&[u8], &[u8]) = (
+    include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/assets/general/pseudoregalia/UI_HUD.uasset"
+    )),
+    include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/assets/general/pseudoregalia/UI_HUD.uexp"
+    )),
+);
+
+fn frames(values: &[i32]) -> Vec<FrameNumber> {
+    values.iter().copied().map(FrameNumber::new).collect()
+}
+
+#[test]
+fn movie_scene_float_sections() -> Result<(), Error> {
+    let (data, bulk) = ANIMATION_ASSET;
+    let mut asset = Asset::new(
+        Cursor::new(data),
+        Some(Cursor::new(bulk)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    // float channels in UE5 sections have padded values and a show curve flag
+    let mut channels = 0;
+    for export in &asset.asset_data.exports {
+        let Export::NormalExport(export) = export else {
+            continue;
+        };
+        for property in &export.properties {
+            let Property::StructProperty(property) = property else {
+                continue;
+            };
+            for value in &property.value {
+                if let Property::MovieSceneFloatChannelProperty(e) = value {
+                    assert_eq!(e.value.values_struct_length, 28);
+                    assert_eq!(e.value.times.len(), e.value.values.len());
+                    assert_eq!(e.value.tick_resolution, FrameRate::new(60000, 1));
+                    channels += 1;
+                }
+            }
+        }
+    }
+    assert!(channels > 0, "Failed to find float channels");
+
+    shared::verify_binary_equality(data, Some(bulk), &mut asset)?;
+    Ok(())
+}
+
+#[test]
+fn movie_scene_channels() -> Result<(), Error> {
+    let (data, bulk) = TEST_ASSET;
+    let mut asset = Asset::new(
+        Cursor::new(data.to_vec()),
+        Some(Cursor::new(bulk.to_vec())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    let export_index = asset
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| {
+            e.get_normal_export()
+                .is_some_and(|e| !e.properties.is_empty())
+        })
+        .expect("Failed to find export with properties");
+    let ancestry = asset.asset_data.exports[export_index]
+        .get_normal_export()
+        .and_then(|e| e.properties.first())
+        .map(|e| e.get_ancestry().clone())
+        .unwrap_or_default();
+
+    let mut name_map = asset.get_name_map();
+    let mut name_map = name_map.get_mut();
+    let key_value_type = name_map.add_fname("MovieSceneObjectPathChannelKeyValue");
+    let hard_ptr = name_map.add_fname("HardPtr");
+    let event_type = name_map.add_fname("MovieSceneEvent");
+    let mut channel = |name: &str, struct_type: &str, value: Property| {
+        let name = name_map.add_fname(name);
+        let mut value = value;
+        *value.get_name_mut() = name.clone();
+        shared::typed_struct(name, &ancestry, name_map.add_fname(struct_type), value)
+    };
+
+    let double_value = |value: f64, interp_mode| MovieSceneDoubleValue {
+        value: value.into(),
+        tangent: MovieSceneTangentData::default(),
+        interp_mode,
+        ..Default::default()
+    };
+    let double_channel = |values_struct_length| {
+        MovieSceneDoubleChannelProperty {
+            value: MovieSceneDoubleChannel {
+                post_infinity_extrap: RichCurveExtrapolation::Cycle,
+                times_struct_length: 4,
+                times: frames(&[0, 30000]),
+                values_struct_length,
+                values: vec![
+                    double_value(1.5, RichCurveInterpMode::Cubic),
+                    double_value(-2.25, RichCurveInterpMode::Linear),
+                ],
+                default_value: 0.5.into(),
+                has_default_value: true,
+                tick_resolution: FrameRate::new(60000, 1),
+                show_curve: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+        .into()
+    };
+    let key_value = {
+        let mut key_value =
+            StructProperty::dummy(FName::default(), Ancestry::default(), key_value_type, None);
+        key_value.value.push(
+            ObjectProperty {
+                name: hard_ptr,
+                ancestry: Ancestry::default(),
+                property_guid: None,
+                property_tag_extensions: PropertyTagExtensions::default(),
+                duplication_index: 0,
+                property_type_name: None,
+                value: PackageIndex::new(-1),
+            }
+            .into(),
+        );
+        key_value
+    };
+    let time_warp = |value| {
+        MovieSceneTimeWarpVariantProperty {
+            value,
+            ..Default::default()
+        }
+        .into()
+    };
+
+    let properties = vec![
+        channel(
+            "PackedTranslation",
+            "MovieSceneDoubleChannel",
+            double_channel(27),
+        ),
+        channel(
+            "PaddedTranslation",
+            "MovieSceneDoubleChannel",
+            double_channel(32),
+        ),
+        channel(
+            "Visibility",
+            "MovieSceneBoolChannel",
+            MovieSceneBoolChannelProperty {
+                times: frames(&[0, 100]),
+                values: vec![true, false],
+                ..Default::default()
+            }
+            .into(),
+        ),
+        channel(
+            "State",
+            "MovieSceneByteChannel",
+            MovieSceneByteChannelProperty {
+                times: frames(&[10]),
+                values: vec![3],
+                default_value: 1,
+                has_default_value: true,
+                ..Default::default()
+            }
+            .into(),
+        ),
+        channel(
+            "Count",
+            "MovieSceneIntegerChannel",
+            MovieSceneIntegerChannelProperty {
+                times: frames(&[0, 50, 100]),
+                values: vec![1, 5, 10],
+                interpolate_linear_keys: true,
+                ..Default::default()
+            }
+            .into(),
+        ),
+        channel(
+            "Label",
+            "MovieSceneStringChannel",
+            MovieSceneStringChannelProperty {
+                times: frames(&[20]),
+                values: vec![Some("Dash".to_string())],
+                default_value: Some("Idle".to_string()),
+                has_default_value: true,
+                ..Default::default()
+            }
+            .into(),
+        ),
+        channel(
+            "Target",
+            "MovieSceneObjectPathChannel",
+            MovieSceneObjectPathChannelProperty {
+                times: frames(&[5]),
+                values: vec![key_value],
+                ..Default::default()
+            }
+            .into(),
+        ),
+        channel(
+            "Events",
+            "MovieSceneEventChannel",
+            MovieSceneEventChannelProperty {
+                key_times: frames(&[40, 80]),
+                key_values: vec![
+                    StructProperty::dummy(
+                        FName::default(),
+                        Ancestry::default(),
+                        event_type.clone(),
+                        None,
+                    ),
+                    StructProperty::dummy(FName::default(), Ancestry::default(), event_type, None),
+                ],
+                ..Default::default()
+            }
+            .into(),
+        ),
+        channel(
+            "PlayRate",
+            "MovieSceneTimeWarpVariant",
+            time_warp(MovieSceneTimeWarpVariant::FixedPlayRate(2.0.into())),
+        ),
+        channel(
+            "RetimeRate",
+            "MovieSceneTimeWarpVariant",
+            time_warp(MovieSceneTimeWarpVariant::FrameRate(FrameRate::new(30, 1))),
+        ),
+        channel(
+            "RetimeLoop",
+            "MovieSceneTimeWarpVariant",
+            time_warp(MovieSceneTimeWarpVariant::Loop(FrameNumber::new(120))),
+        ),
+        channel(
+            "StartFrame",
+            "FrameNumber",
+            FrameNumberProperty {
+                value: FrameNumber::new(-12),
+                ..Default::default()
+            }
+            .into(),
+        ),
+    ];
+
+    drop(name_map);
+    asset.asset_data.exports[export_index]
+        .get_normal_export_mut()
+        .expect("Failed to find export")
+        .properties
+        .extend(properties);
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+    let (data, bulk) = (cursor.into_inner(), bulk_cursor.into_inner());
+
+    let mut reparsed = Asset::new(
+        Cursor::new(data.clone()),
+        Some(Cursor::new(bulk.clone())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    for (name, values_struct_length) in [("PackedTranslation", 27), ("PaddedTranslation", 32)] {
+        match shared::find_typed(&reparsed, name) {
+            Property::MovieSceneDoubleChannelProperty(e) => {
+                assert_eq!(e.value.values_struct_length, values_struct_length);
+                assert_eq!(e.value.times, frames(&[0, 30000]));
+                assert_eq!(e.value.values[0].value.0, 1.5);
+                assert_eq!(e.value.values[1].value.0, -2.25);
+                assert_eq!(e.value.values[1].interp_mode, RichCurveInterpMode::Linear);
+                assert_eq!(e.value.post_infinity_extrap, RichCurveExtrapolation::Cycle);
+                assert_eq!(e.value.default_value.0, 0.5);
+                assert!(e.value.show_curve);
+            }
+            e => panic!("Expected MovieSceneDoubleChannelProperty, got {e:?}"),
+        }
+    }
+
+    match shared::find_typed(&reparsed, "Visibility") {
+        Property::MovieSceneBoolChannelProperty(e) => {
+            assert_eq!(e.times, frames(&[0, 100]));
+            assert_eq!(e.values, [true, false]);
+            assert!(!e.has_default_value);
+        }
+        e => panic!("Expected MovieSceneBoolChannelProperty, got {e:?}"),
+    }
+
+    match shared::find_typed(&reparsed, "State") {
+        Property::MovieSceneByteChannelProperty(e) => {
+            assert_eq!(e.values, [3]);
+            assert_eq!(e.default_value, 1);
+            assert!(e.has_default_value);
+        }
+        e => panic!("Expected MovieSceneByteChannelProperty, got {e:?}"),
+    }
+
+    match shared::find_typed(&reparsed, "Count") {
+        Property::MovieSceneIntegerChannelProperty(e) => {
+            assert_eq!(e.times, frames(&[0, 50, 100]));
+            assert_eq!(e.values, [1, 5, 10]);
+            assert!(e.interpolate_linear_keys);
+        }
+        e => panic!("Expected MovieSceneIntegerChannelProperty, got {e:?}"),
+    }
+
+    match shared::find_typed(&reparsed, "Label") {
+        Property::MovieSceneStringChannelProperty(e) => {
+            assert_eq!(e.values, [Some("Dash".to_string())]);
+            assert_eq!(e.default_value.as_deref(), Some("Idle"));
+        }
+        e => panic!("Expected MovieSceneStringChannelProperty, got {e:?}"),
+    }
+
+    match shared::find_typed(&reparsed, "Target") {
+        Property::MovieSceneObjectPathChannelProperty(e) => {
+            assert_eq!(e.times, frames(&[5]));
+            assert_eq!(e.values.len(), 1);
+            assert!(matches!(
+                e.values[0].value.as_slice(),
+                [Property::ObjectProperty(e)] if e.value.index == -1
+            ));
+            assert_eq!(e.default_value, None);
+        }
+        e => panic!("Expected MovieSceneObjectPathChannelProperty, got {e:?}"),
+    }
+
+    match shared::find_typed(&reparsed, "Events") {
+        Property::MovieSceneEventChannelProperty(e) => {
+            assert_eq!(e.key_times, frames(&[40, 80]));
+            assert_eq!(e.key_values.len(), 2);
+        }
+        e => panic!("Expected MovieSceneEventChannelProperty, got {e:?}"),
+    }
+
+    for (name, expected) in [
+        (
+            "PlayRate",
+            MovieSceneTimeWarpVariant::FixedPlayRate(2.0.into()),
+        ),
+        (
+            "RetimeRate",
+            MovieSceneTimeWarpVariant::FrameRate(FrameRate::new(30, 1)),
+        ),
+        (
+            "RetimeLoop",
+            MovieSceneTimeWarpVariant::Loop(FrameNumber::new(120)),
+        ),
+    ] {
+        match shared::find_typed(&reparsed, name) {
+            Property::MovieSceneTimeWarpVariantProperty(e) => assert_eq!(e.value, expected),
+            e => panic!("Expected MovieSceneTimeWarpVariantProperty, got {e:?}"),
+        }
+    }
+
+    match shared::find_typed(&reparsed, "StartFrame") {
+        Property::FrameNumberProperty(e) => assert_eq!(e.value, FrameNumber::new(-12)),
+        e => panic!("Expected FrameNumberProperty, got {e:?}"),
+    }
+
+    shared::verify_binary_equality(&data, Some(&bulk), &mut reparsed)?;
+    Ok(())
+}
@@ -0,0 +1,205 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::{ExportBaseTrait, ExportNormalTrait},
+    properties::{
+        field_path_property::FieldPathProperty,
+        object_property::{InterfaceProperty, LazyObjectProperty, WeakObjectProperty},
+        Property, PropertyDataTrait, PropertyTagExtensions,
+    },
+    types::{FieldPath, PackageIndex},
+    Asset, Error, Guid,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/assets/")
+    };
+}
+
+const PREBULK_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "general/Astroneer_prebulk/Augment_BroadBrush.uasset"
+));
+const DETPACK_ASSET: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "unknown_properties/BP_DetPack_Charge.uasset"
+));
+const DETPACK_BULK: &[u8] = include_bytes!(concat!(
+    assets_folder!(),
+    "unknown_properties/BP_DetPack_Charge.uexp"
+));
+
+fn cdo_index(asset: &Asset<Cursor<Vec<u8>>>) -> usize {
+    asset
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| {
+            e.get_normal_export().is_some()
+                && e.get_base_export()
+                    .object_name
+                    .get_content(|name| name.starts_with("Default__"))
+        })
+        .expect("Failed to find cdo export")
+}
+
+/// Adds one property of each type to the cdo, writes the asset and checks the reparsed values
+fn object_properties_round_trip(
+    data: &[u8],
+    bulk: Option<&[u8]>,
+    engine_version: EngineVersion,
+    has_resolved_owner: bool,
+) -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(data.to_vec()),
+        bulk.map(|e| Cursor::new(e.to_vec())),
+        engine_version,
+        None,
+    )?;
+
+    let cdo_index = cdo_index(&asset);
+    let ancestry = asset.asset_data.exports[cdo_index]
+        .get_normal_export()
+        .and_then(|e| e.properties.first())
+        .map(|e| e.get_ancestry().clone())
+        .unwrap_or_default();
+
+    let (weak_name, lazy_name, interface_name, field_path_name, path_name) = {
+        let mut name_map = asset.get_name_map();
+        let mut name_map = name_map.get_mut();
+        // serialized type names must be in the name map before writing
+        for type_name in [
+            "WeakObjectProperty",
+            "LazyObjectProperty",
+            "InterfaceProperty",
+            "FieldPathProperty",
+        ] {
+            name_map.add_fname(type_name);
+        }
+        (
+            name_map.add_fname("WeakTarget"),
+            name_map.add_fname("LazyTarget"),
+            name_map.add_fname("InterfaceTarget"),
+            name_map.add_fname("FieldPathTarget"),
+            name_map.add_fname("Health"),
+        )
+    };
+
+    let lazy_guid = Guid::from([
+        0x10, 0x32, 0x54, 0x76, 0x98, 0xba, 0xdc, 0xfe, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd,
+        0xef,
+    ]);
+    let field_path = FieldPath::new(vec![path_name], PackageIndex::new(-1));
+
+    let properties: Vec<Property> = vec![
+        WeakObjectProperty {
+            name: weak_name,
+            ancestry: ancestry.clone(),
+            property_guid: Some(Guid::default()),
+            property_tag_extensions: PropertyTagExtensions::default(),
+            duplication_index: 0,
+            property_type_name: None,
+            value: PackageIndex::new(-1),
+        }
+        .into(),
+        LazyObjectProperty {
+            name: lazy_name,
+            ancestry: ancestry.clone(),
+            property_guid: Some(Guid::default()),
+            property_tag_extensions: PropertyTagExtensions::default(),
+            duplication_index: 0,
+            property_type_name: None,
+            value: lazy_guid,
+        }
+        .into(),
+        InterfaceProperty {
+            name: interface_name,
+            ancestry: ancestry.clone(),
+            property_guid: Some(Guid::default()),
+            property_tag_extensions: PropertyTagExtensions::default(),
+            duplication_index: 0,
+            property_type_name: None,
+            value: PackageIndex::new(1),
+        }
+        .into(),
+        FieldPathProperty {
+            name: field_path_name,
+            ancestry,
+            property_guid: Some(Guid::default()),
+            property_tag_extensions: PropertyTagExtensions::default(),
+            duplication_index: 0,
+            property_type_name: None,
+            value: field_path.clone(),
+        }
+        .into(),
+    ];
+
+    asset.asset_data.exports[cdo_index]
+        .get_normal_export_mut()
+        .expect("Failed to find cdo export")
+        .properties
+        .extend(properties);
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = bulk.map(|_| Cursor::new(Vec::new()));
+    asset.write_data(&mut cursor, bulk_cursor.as_mut())?;
+    let data = cursor.into_inner();
+    let bulk = bulk_cursor.map(|e| e.into_inner());
+
+    let mut reparsed = Asset::new(
+        Cursor::new(data.clone()),
+        bulk.clone().map(Cursor::new),
+        engine_version,
+        None,
+    )?;
+    assert!(shared::verify_all_exports_parsed(&reparsed));
+
+    match shared::find_property(&reparsed, "WeakTarget") {
+        Property::WeakObjectProperty(e) => assert_eq!(e.value, PackageIndex::new(-1)),
+        e => panic!("Expected WeakObjectProperty, got {e:?}"),
+    }
+    match shared::find_property(&reparsed, "LazyTarget") {
+        Property::LazyObjectProperty(e) => assert_eq!(e.value, lazy_guid),
+        e => panic!("Expected LazyObjectProperty, got {e:?}"),
+    }
+    match shared::find_property(&reparsed, "InterfaceTarget") {
+        Property::InterfaceProperty(e) => assert_eq!(e.value, PackageIndex::new(1)),
+        e => panic!("Expected InterfaceProperty, got {e:?}"),
+    }
+    match shared::find_property(&reparsed, "FieldPathTarget") {
+        Property::FieldPathProperty(e) => {
+            assert_eq!(e.value.path.len(), 1);
+            assert!(e.value.path[0] == "Health");
+
+            let expected_owner = match has_resolved_owner {
+                true => field_path.resolved_owner,
+                false => PackageIndex::new(0),
+            };
+            assert_eq!(e.value.resolved_owner, expected_owner);
+        }
+        e => panic!("Expected FieldPathProperty, got {e:?}"),
+    }
+
+    shared::verify_binary_equality(&data, bulk.as_deref(), &mut reparsed)?;
+
+    Ok(())
+}
+
+#[test]
+fn object_properties() -> Result<(), Error> {
+    object_properties_round_trip(PREBULK_ASSET, None, EngineVersion::VER_UE4_23, false)
+}
+
+#[test]
+fn object_properties_field_path_owner() -> Result<(), Error> {
+    object_properties_round_trip(
+        DETPACK_ASSET,
+        Some(DETPACK_BULK),
+        EngineVersion::VER_UE4_25,
+        true,
+    )
+}
@@ -0,0 +1,178 @@
+use std::io::Cursor;
+
+use byteorder::{WriteBytesExt, LE};
+
+use unreal_asset::compression::{self, CompressionLevel, CompressionMethod};
+use unreal_asset::error::Error;
+#[cfg(not(feature = "oodle"))]
+use unreal_asset::error::UsmapError;
+#[cfg(feature = "oodle")]
+use unreal_asset::pak::{EPakVersion, PakCompressedBlock, PakEntry, PakIndex, PakInfo, PakReader};
+use unreal_asset::unversioned::Usmap;
+
+/// Oodle header of a stored Kraken block
+const STORED_KRAKEN_HEADER: [u8; 2] = [0x4c, 0x06];
+
+/// Wrap data in a stored Kraken block
+fn stored_kraken(data: &[u8]) -> Vec<u8> {
+    [&STORED_KRAKEN_HEADER, data].concat()
+}
+
+/// Build a usmap with a single name in its name map
+fn build_usmap(compression_method: u8, data: &[u8], decompressed_size: u32) -> Vec<u8> {
+    let mut usmap = Vec::new();
+    usmap.extend_from_slice(&[0xc4, 0x30]);
+    // initial version
+    usmap.push(0);
+    usmap.push(compression_method);
+    usmap.write_u32::<LE>(data.len() as u32).unwrap();
+    usmap.write_u32::<LE>(decompressed_size).unwrap();
+    usmap.extend_from_slice(data);
+    usmap
+}
+
+fn usmap_data() -> Vec<u8> {
+    let mut data = Vec::new();
+    data.write_i32::<LE>(1).unwrap();
+    data.push(3);
+    data.extend_from_slice(b"Foo");
+    // enums
+    data.write_u32::<LE>(0).unwrap();
+    // schemas
+    data.write_u32::<LE>(0).unwrap();
+    data
+}
+
+#[test]
+fn uncompressed_usmap() -> Result<(), Error> {
+    let data = usmap_data();
+    let usmap = Usmap::new(Cursor::new(build_usmap(0, &data, data.len() as u32)))?;
+    assert_eq!(usmap.name_map, vec!["Foo".to_string()]);
+
+    Ok(())
+}
+
+#[test]
+fn oodle_method_name() {
+    assert_eq!(CompressionMethod::new("Oodle"), CompressionMethod::Oodle);
+    assert_eq!(CompressionMethod::Oodle.to_string(), "Oodle");
+    assert!(
+        compression::compress(CompressionMethod::Oodle, b"data", CompressionLevel::Default)
+            .is_err()
+    );
+}
+
+#[cfg(not(feature = "oodle"))]
+#[test]
+fn oodle_disabled() {
+    let data = b"oodle compressed data";
+    let mut decompressed = vec![0u8; data.len()];
+    assert!(compression::decompress(
+        CompressionMethod::Oodle,
+        &stored_kraken(data),
+        &mut decompressed
+    )
+    .is_err());
+
+    let data = usmap_data();
+    assert!(matches!(
+        Usmap::new(Cursor::new(build_usmap(
+            1,
+            &stored_kraken(&data),
+            data.len() as u32
+        ))),
+        Err(Error::Usmap(UsmapError::UnsupportedCompression(1)))
+    ));
+}
+
+#[cfg(feature = "oodle")]
+#[test]
+fn oodle_decompress() -> Result<(), Error> {
+    let data = b"oodle compressed data";
+    let mut decompressed = vec![0u8; data.len()];
+    compression::decompress(
+        CompressionMethod::Oodle,
+        &stored_kraken(data),
+        &mut decompressed,
+    )?;
+    assert_eq!(decompressed, data);
+
+    // truncated input
+    assert!(matches!(
+        compression::decompress(
+            CompressionMethod::Oodle,
+            &stored_kraken(&data[..4]),
+            &mut decompressed
+        ),
+        Err(Error::Oodle)
+    ));
+
+    Ok(())
+}
+
+#[cfg(feature = "oodle")]
+#[test]
+fn oodle_usmap() -> Result<(), Error> {
+    let data = usmap_data();
+    let usmap = Usmap::new(Cursor::new(build_usmap(
+        1,
+        &stored_kraken(&data),
+        data.len() as u32,
+    )))?;
+    assert_eq!(usmap.name_map, vec!["Foo".to_string()]);
+
+    Ok(())
+}
+
+#[cfg(feature = "oodle")]
+#[test]
+fn oodle_pak() -> Result<(), Error> {
+    let data = b"oodle compressed pak file data".repeat(3);
+    let blocks = data.chunks(32).map(stored_kraken).collect::<Vec<_>>();
+
+    let mut info = PakInfo::new(EPakVersion::Fnv64BugFix);
+    info.compression_methods = vec![CompressionMethod::Oodle];
+
+    let mut entry = PakEntry {
+        offset: 0,
+        uncompressed_size: data.len() as u64,
+        compression_method_index: 1,
+        compression_block_size: 32,
+        compression_blocks: vec![PakCompressedBlock::default(); blocks.len()],
+        ..Default::default()
+    };
+    let mut block_offset = entry.get_serialized_size(&info);
+    for (block, compressed) in entry.compression_blocks.iter_mut().zip(&blocks) {
+        *block = PakCompressedBlock {
+            compressed_start: block_offset,
+            compressed_end: block_offset + compressed.len() as u64,
+        };
+        block_offset += compressed.len() as u64;
+    }
+    entry.size = blocks.iter().map(|e| e.len() as u64).sum();
+
+    let mut pak = Cursor::new(Vec::new());
+    entry.write(&mut pak, &info)?;
+    for block in &blocks {
+        std::io::Write::write_all(&mut pak, block)?;
+    }
+
+    let mut index = PakIndex {
+        mount_point: Some("../../../".to_string()),
+        ..Default::default()
+    };
+    index
+        .entries
+        .push(("Game/Content/Oodle.uexp".to_string(), entry));
+    index.write(&mut pak, &mut info)?;
+    info.write(&mut pak)?;
+
+    let mut reader = PakReader::new(Cursor::new(pak.into_inner()), None)?;
+    assert_eq!(
+        reader.info.get_compression_method(1),
+        Some(CompressionMethod::Oodle)
+    );
+    assert_eq!(reader.read_file("Game/Content/Oodle.uexp")?, data);
+
+    Ok(())
+}
@@ -0,0 +1,200 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    object_version::ObjectVersionUE5,
+    properties::{
+        int_property::IntProperty, optional_property::OptionalProperty,
+        struct_property::StructProperty, vector_property::VectorProperty, Property,
+        PropertyDataTrait, PropertyTagExtensions,
+    },
+    types::{vector::Vector, FName},
+    unversioned::Ancestry,
+    Asset, Error, Guid,
+};
+
+mod shared;
+
+const TEST_ASSET: (&[u8], &[u8]) = (
+    include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/assets/general/pseudoregalia/BP_PlayerGoatMain.uasset"
+    )),
+    include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/assets/general/pseudoregalia/BP_PlayerGoatMain.uexp"
+    )),
+);
+
+fn optional(name: FName, ancestry: &Ancestry, value: Option<Property>) -> Property {
+    OptionalProperty {
+        name,
+        ancestry: ancestry.clone(),
+        property_guid: Some(Guid::default()),
+        property_tag_extensions: PropertyTagExtensions::default(),
+        duplication_index: 0,
+        property_type_name: None,
+        inner_type: None,
+        value: value.map(Box::new),
+    }
+    .into()
+}
+
+/// Adds optional properties to the first export with properties,
+/// struct values are only added with >= UE5.4 property tags as older tags don't store the struct type
+fn add_optional_properties(asset: &mut Asset<Cursor<Vec<u8>>>, with_struct: bool) {
+    let export_index = asset
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| {
+            e.get_normal_export()
+                .is_some_and(|e| !e.properties.is_empty())
+        })
+        .expect("Failed to find export with properties");
+
+    let ancestry = asset.asset_data.exports[export_index]
+        .get_normal_export()
+        .and_then(|e| e.properties.first())
+        .map(|e| e.get_ancestry().clone())
+        .unwrap_or_default();
+
+    let mut name_map = asset.get_name_map();
+    let mut name_map = name_map.get_mut();
+    let int_type = name_map.add_fname("IntProperty");
+    name_map.add_fname("OptionalProperty");
+
+    let set_name = name_map.add_fname("OptionalCount");
+    let mut properties = vec![optional(
+        set_name.clone(),
+        &ancestry,
+        Some(
+            IntProperty {
+                name: set_name,
+                ancestry: ancestry.clone(),
+                property_guid: None,
+                property_tag_extensions: PropertyTagExtensions::default(),
+                duplication_index: 0,
+                value: 42,
+            }
+            .into(),
+        ),
+    )];
+
+    let mut unset = optional(name_map.add_fname("OptionalUnset"), &ancestry, None);
+    if let Property::OptionalProperty(ref mut unset) = unset {
+        unset.inner_type = Some(int_type);
+    }
+    properties.push(unset);
+
+    if with_struct {
+        let struct_name = name_map.add_fname("OptionalLocation");
+        let mut location = StructProperty::dummy(
+            struct_name.clone(),
+            ancestry.with_parent(struct_name.clone()),
+            name_map.add_fname("Vector"),
+            Some(Guid::default()),
+        );
+        location.value.push(
+            VectorProperty {
+                name: struct_name.clone(),
+                ancestry: ancestry.with_parent(struct_name.clone()),
+                property_guid: None,
+                property_tag_extensions: PropertyTagExtensions::default(),
+                duplication_index: 0,
+                value: Vector::new(1.0.into(), 2.0.into(), 3.0.into()),
+            }
+            .into(),
+        );
+        name_map.add_fname("StructProperty");
+        properties.push(optional(struct_name, &ancestry, Some(location.into())));
+    }
+
+    drop(name_map);
+    asset.asset_data.exports[export_index]
+        .get_normal_export_mut()
+        .expect("Failed to find export")
+        .properties
+        .extend(properties);
+}
+
+fn find_optional<'a>(asset: &'a Asset<Cursor<Vec<u8>>>, name: &str) -> &'a OptionalProperty {
+    let property = asset
+        .asset_data
+        .exports
+        .iter()
+        .filter_map(|e| e.get_normal_export())
+        .flat_map(|e| e.properties.iter())
+        .find(|e| e.get_name() == name)
+        .expect("Failed to find property");
+
+    match property {
+        Property::OptionalProperty(e) => e,
+        e => panic!("Expected OptionalProperty, got {e:?}"),
+    }
+}
+
+fn optional_round_trip(complete_type_name: bool) -> Result<(), Error> {
+    let (data, bulk) = TEST_ASSET;
+    let mut asset = Asset::new(
+        Cursor::new(data.to_vec()),
+        Some(Cursor::new(bulk.to_vec())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    add_optional_properties(&mut asset, complete_type_name);
+    // cooked test assets are unversioned, store the versions so the reparse can pick them up
+    asset.asset_data.summary.unversioned = false;
+    if complete_type_name {
+        asset.asset_data.object_version_ue5 = ObjectVersionUE5::PROPERTY_TAG_COMPLETE_TYPE_NAME;
+    }
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+    let (data, bulk) = (cursor.into_inner(), bulk_cursor.into_inner());
+
+    let mut reparsed = Asset::new(
+        Cursor::new(data.clone()),
+        Some(Cursor::new(bulk.clone())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    match find_optional(&reparsed, "OptionalCount").value.as_deref() {
+        Some(Property::IntProperty(e)) => assert_eq!(e.value, 42),
+        e => panic!("Expected IntProperty, got {e:?}"),
+    }
+    let unset = find_optional(&reparsed, "OptionalUnset");
+    assert!(unset.value.is_none());
+    assert!(unset
+        .inner_type
+        .as_ref()
+        .is_some_and(|e| e == "IntProperty"));
+    if complete_type_name {
+        match find_optional(&reparsed, "OptionalLocation")
+            .value
+            .as_deref()
+        {
+            Some(Property::StructProperty(e)) => {
+                assert!(e.struct_type.as_ref().is_some_and(|e| e == "Vector"));
+                assert!(matches!(e.value.first(), Some(Property::VectorProperty(_))));
+            }
+            e => panic!("Expected StructProperty, got {e:?}"),
+        }
+    }
+
+    shared::verify_binary_equality(&data, Some(&bulk), &mut reparsed)?;
+    Ok(())
+}
+
+#[test]
+fn optional_property() -> Result<(), Error> {
+    optional_round_trip(false)
+}
+
+#[test]
+fn optional_property_complete_type_name() -> Result<(), Error> {
+    optional_round_trip(true)
+}
@@ -0,0 +1,625 @@
+use std::io::Cursor;
+
+use byteorder::{WriteBytesExt, LE};
+
+use unreal_asset::compression::{CompressionLevel, CompressionMethod};
+use unreal_asset::encryption::{AesKey, EncryptionKeys};
+use unreal_asset::engine_version::EngineVersion;
+use unreal_asset::error::{Error, PakError};
+use unreal_asset::exports::ExportNormalTrait;
+use unreal_asset::pak::{
+    EPakEntryFlags, EPakVersion, PakCompressedBlock, PakEntry, PakIndex, PakInfo, PakReader,
+    PakWriter,
+};
+use unreal_asset::{Asset, Guid};
+
+const MOUNT_POINT: &str = "../../../";
+const BLOCK_SIZE: u32 = 64;
+
+/// `COMPRESSED_DATA` split into 64 byte blocks and zlib compressed
+const COMPRESSED_BLOCKS: [&[u8]; 3] = [
+    &[
+        120, 156, 75, 206, 207, 45, 40, 74, 45, 46, 78, 77, 81, 40, 72, 204, 86, 72, 203, 204, 73,
+        85, 72, 73, 44, 73, 84, 72, 38, 78, 2, 0, 13, 40, 23, 232,
+    ],
+    &[
+        120, 156, 83, 72, 203, 204, 73, 85, 72, 73, 44, 73, 84, 72, 206, 207, 45, 40, 74, 45, 46,
+        78, 77, 81, 40, 72, 204, 86, 32, 78, 2, 0, 239, 93, 23, 48,
+    ],
+    &[
+        120, 156, 43, 40, 74, 45, 46, 78, 77, 81, 40, 72, 204, 86, 72, 203, 204, 73, 85, 72, 73,
+        44, 73, 84, 0, 0, 96, 31, 7, 237,
+    ],
+];
+
+fn compressed_data() -> Vec<u8> {
+    b"compressed pak file data ".repeat(6)
+}
+
+fn write_fstring(buf: &mut Vec<u8>, value: &str) {
+    buf.write_i32::<LE>(value.len() as i32 + 1).unwrap();
+    buf.extend_from_slice(value.as_bytes());
+    buf.push(0);
+}
+
+/// Pak file to build, either stored as is or as zlib compressed blocks
+struct TestFile<'a> {
+    path: &'a str,
+    data: Vec<u8>,
+    compressed_blocks: Option<&'a [&'a [u8]]>,
+}
+
+/// Pad data to the aes block size and encrypt it
+fn encrypt(data: &[u8], key: &AesKey) -> Vec<u8> {
+    let mut data = data.to_vec();
+    data.resize(data.len().next_multiple_of(16), 0);
+    key.encrypt(&mut data).unwrap();
+    data
+}
+
+/// Write file data and return the entries, entries are encrypted if a key is passed
+fn write_entries(
+    pak: &mut Vec<u8>,
+    info: &PakInfo,
+    files: &[TestFile],
+    key: Option<&AesKey>,
+) -> Vec<PakEntry> {
+    let mut entries = Vec::new();
+    for file in files {
+        let mut entry = PakEntry {
+            offset: pak.len() as u64,
+            uncompressed_size: file.data.len() as u64,
+            flags: match key {
+                Some(_) => EPakEntryFlags::ENCRYPTED,
+                None => EPakEntryFlags::empty(),
+            },
+            ..Default::default()
+        };
+
+        let data = match file.compressed_blocks {
+            Some(blocks) => {
+                entry.compression_method_index = 1;
+                entry.compression_block_size = BLOCK_SIZE;
+                entry.compression_blocks = vec![PakCompressedBlock::default(); blocks.len()];
+
+                let mut block_offset = match info.version >= EPakVersion::RelativeChunkOffsets {
+                    true => 0,
+                    false => entry.offset,
+                } + entry.get_serialized_size(info);
+                let mut data = Vec::new();
+                for (i, block) in blocks.iter().enumerate() {
+                    entry.compression_blocks[i] = PakCompressedBlock {
+                        compressed_start: block_offset,
+                        compressed_end: block_offset + block.len() as u64,
+                    };
+                    let block = match key {
+                        Some(key) => encrypt(block, key),
+                        None => block.to_vec(),
+                    };
+                    block_offset += block.len() as u64;
+                    data.extend_from_slice(&block);
+                }
+                data
+            }
+            None => file.data.clone(),
+        };
+        entry.size = data.len() as u64;
+        let data = match (key, file.compressed_blocks) {
+            (Some(key), None) => encrypt(&data, key),
+            _ => data,
+        };
+
+        entry.write(pak, info).unwrap();
+        pak.extend_from_slice(&data);
+        entries.push(entry);
+    }
+    entries
+}
+
+/// Build a pak with an index before [`EPakVersion::PathHashIndex`]
+fn build_legacy_pak(version: EPakVersion, files: &[TestFile]) -> Vec<u8> {
+    build_encrypted_legacy_pak(version, files, None)
+}
+
+/// Build a pak with an index before [`EPakVersion::PathHashIndex`], encrypting entries and the index with a key
+fn build_encrypted_legacy_pak(
+    version: EPakVersion,
+    files: &[TestFile],
+    key: Option<(Guid, &AesKey)>,
+) -> Vec<u8> {
+    let mut info = PakInfo::new(version);
+    if version >= EPakVersion::FNameBasedCompressionMethod {
+        info.compression_methods = vec![CompressionMethod::Zlib];
+    }
+    if let Some((guid, _)) = key {
+        info.encryption_key_guid = guid;
+        info.encrypted_index = true;
+    }
+
+    let mut pak = Vec::new();
+    let entries = write_entries(&mut pak, &info, files, key.map(|(_, key)| key));
+
+    let mut index = Vec::new();
+    write_fstring(&mut index, MOUNT_POINT);
+    index.write_u32::<LE>(entries.len() as u32).unwrap();
+    for (file, entry) in files.iter().zip(&entries) {
+        write_fstring(&mut index, file.path);
+        entry.write(&mut index, &info).unwrap();
+    }
+    if let Some((_, key)) = key {
+        index = encrypt(&index, key);
+    }
+
+    info.index_offset = pak.len() as u64;
+    info.index_size = index.len() as u64;
+    pak.extend_from_slice(&index);
+    info.write(&mut pak).unwrap();
+    pak
+}
+
+/// Build a pak with encoded entries and a full directory index
+fn build_path_hash_pak(files: &[TestFile]) -> Vec<u8> {
+    let mut info = PakInfo::new(EPakVersion::Fnv64BugFix);
+    info.compression_methods = vec![CompressionMethod::Zlib];
+
+    let mut pak = Vec::new();
+    let entries = write_entries(&mut pak, &info, files, None);
+
+    let mut encoded_entries = Vec::new();
+    let mut directories: Vec<(String, Vec<(String, i32)>)> = Vec::new();
+    for (file, entry) in files.iter().zip(&entries) {
+        let location = encoded_entries.len() as i32;
+        entry.write_encoded(&mut encoded_entries).unwrap();
+
+        let (directory, file_name) = match file.path.rfind('/') {
+            Some(i) => (format!("/{}", &file.path[..=i]), &file.path[i + 1..]),
+            None => ("/".to_string(), file.path),
+        };
+        match directories.iter_mut().find(|(e, _)| *e == directory) {
+            Some((_, files)) => files.push((file_name.to_string(), location)),
+            None => directories.push((directory, vec![(file_name.to_string(), location)])),
+        }
+    }
+
+    let directory_index_offset = pak.len() as u64;
+    pak.write_u32::<LE>(directories.len() as u32).unwrap();
+    for (directory, files) in &directories {
+        write_fstring(&mut pak, directory);
+        pak.write_u32::<LE>(files.len() as u32).unwrap();
+        for (file_name, location) in files {
+            write_fstring(&mut pak, file_name);
+            pak.write_i32::<LE>(*location).unwrap();
+        }
+    }
+    let directory_index_size = pak.len() as u64 - directory_index_offset;
+
+    let mut index = Vec::new();
+    write_fstring(&mut index, MOUNT_POINT);
+    index.write_u32::<LE>(entries.len() as u32).unwrap();
+    index.write_u64::<LE>(0x1234).unwrap();
+    // no path hash index
+    index.write_u32::<LE>(0).unwrap();
+    index.write_u32::<LE>(1).unwrap();
+    index.write_u64::<LE>(directory_index_offset).unwrap();
+    index.write_u64::<LE>(directory_index_size).unwrap();
+    index.extend_from_slice(&[0u8; 20]);
+    index.write_u32::<LE>(encoded_entries.len() as u32).unwrap();
+    index.extend_from_slice(&encoded_entries);
+    index.write_u32::<LE>(0).unwrap();
+
+    info.index_offset = pak.len() as u64;
+    info.index_size = index.len() as u64;
+    pak.extend_from_slice(&index);
+    info.write(&mut pak).unwrap();
+    pak
+}
+
+fn test_files() -> Vec<TestFile<'static>> {
+    vec![
+        TestFile {
+            path: "Game/Content/Small.uasset",
+            data: b"small file".to_vec(),
+            compressed_blocks: None,
+        },
+        TestFile {
+            path: "Game/Content/Maps/Compressed.uexp",
+            data: compressed_data(),
+            compressed_blocks: Some(&COMPRESSED_BLOCKS),
+        },
+    ]
+}
+
+fn check_test_files<R: std::io::Read + std::io::Seek>(
+    reader: &mut PakReader<R>,
+) -> Result<(), Error> {
+    assert_eq!(reader.get_mount_point(), Some(MOUNT_POINT));
+    assert_eq!(reader.get_file_names().count(), 2);
+
+    assert_eq!(
+        reader.read_file("Game/Content/Small.uasset")?,
+        b"small file"
+    );
+    assert_eq!(
+        reader.read_file("../../../Game/Content/Maps/Compressed.uexp")?,
+        compressed_data()
+    );
+    assert!(reader.has_file("/Game/Content/Maps/Compressed.uexp"));
+
+    assert!(matches!(
+        reader.read_file("Game/Content/Missing.uasset"),
+        Err(Error::Pak(PakError::NoFile(_)))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn read_legacy_pak() -> Result<(), Error> {
+    for version in [
+        EPakVersion::CompressionEncryption,
+        EPakVersion::RelativeChunkOffsets,
+        EPakVersion::FNameBasedCompressionMethod,
+        EPakVersion::FrozenIndex,
+    ] {
+        let pak = build_legacy_pak(version, &test_files());
+        let mut reader = PakReader::new(Cursor::new(pak), None)?;
+
+        assert_eq!(reader.info.version, version);
+        assert_eq!(
+            reader.info.get_compression_method(1),
+            Some(CompressionMethod::Zlib)
+        );
+        check_test_files(&mut reader)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn read_path_hash_pak() -> Result<(), Error> {
+    let pak = build_path_hash_pak(&test_files());
+    let mut reader = PakReader::new(Cursor::new(pak), None)?;
+
+    assert_eq!(reader.info.version, EPakVersion::Fnv64BugFix);
+    assert_eq!(reader.index.path_hash_seed, 0x1234);
+    check_test_files(&mut reader)?;
+
+    let entry = reader
+        .get_entry("Game/Content/Maps/Compressed.uexp")
+        .unwrap();
+    assert_eq!(entry.compression_blocks.len(), COMPRESSED_BLOCKS.len());
+    assert_eq!(entry.compression_block_size, BLOCK_SIZE);
+
+    Ok(())
+}
+
+#[test]
+fn read_asset_from_pak() -> Result<(), Error> {
+    let asset_data = include_bytes!("assets/ue5/PublicHousingPlot_Root.umap");
+    let bulk_data = include_bytes!("assets/ue5/PublicHousingPlot_Root.uexp");
+
+    let pak = build_path_hash_pak(&[
+        TestFile {
+            path: "Game/Content/PublicHousingPlot_Root.umap",
+            data: asset_data.to_vec(),
+            compressed_blocks: None,
+        },
+        TestFile {
+            path: "Game/Content/PublicHousingPlot_Root.uexp",
+            data: bulk_data.to_vec(),
+            compressed_blocks: None,
+        },
+    ]);
+    let mut reader = PakReader::new(Cursor::new(pak), None)?;
+
+    let asset = Asset::new(
+        reader.open_file("Game/Content/PublicHousingPlot_Root.umap")?,
+        Some(reader.open_file("Game/Content/PublicHousingPlot_Root.uexp")?),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    assert!(!asset.asset_data.exports.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn invalid_footer() {
+    assert!(matches!(
+        PakReader::new(Cursor::new(vec![0u8; 256]), None),
+        Err(Error::Pak(PakError::InvalidFooter))
+    ));
+    assert!(matches!(
+        PakIndex::read(
+            &mut Cursor::new(vec![0u8; 16]),
+            &{
+                let mut info = PakInfo::new(EPakVersion::Fnv64BugFix);
+                info.encrypted_index = true;
+                info.index_size = 16;
+                info
+            },
+            None
+        ),
+        Err(Error::Pak(PakError::NoEncryptionKey))
+    ));
+}
+
+#[test]
+fn read_encrypted_pak() -> Result<(), Error> {
+    let key =
+        AesKey::from_hex("0x0123456789ABCDEF0123456789abcdef0123456789abcdef0123456789abcdef")?;
+    let guid = Guid([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]);
+    let pak = build_encrypted_legacy_pak(
+        EPakVersion::FNameBasedCompressionMethod,
+        &test_files(),
+        Some((guid, &key)),
+    );
+
+    assert!(matches!(
+        PakReader::new(Cursor::new(pak.clone()), None),
+        Err(Error::Pak(PakError::NoEncryptionKey))
+    ));
+    assert!(matches!(
+        PakReader::new(
+            Cursor::new(pak.clone()),
+            Some(EncryptionKeys::with_default_key(key.clone()))
+        ),
+        Err(Error::Pak(PakError::NoEncryptionKey))
+    ));
+
+    let mut keys = EncryptionKeys::with_default_key(AesKey::new([0u8; 32]));
+    keys.add_key(guid, key);
+    let mut reader = PakReader::new(Cursor::new(pak), Some(keys))?;
+
+    assert_eq!(reader.info.encryption_key_guid, guid);
+    assert!(reader
+        .get_entry("Game/Content/Small.uasset")
+        .unwrap()
+        .is_encrypted());
+    check_test_files(&mut reader)?;
+
+    Ok(())
+}
+
+#[test]
+fn aes_key_from_hex() {
+    assert!(AesKey::from_hex("0123").is_err());
+    assert!(AesKey::from_hex(&"zz".repeat(32)).is_err());
+
+    let key = AesKey::from_hex(&"00".repeat(32)).unwrap();
+    let mut data = *b"sixteen byte blk";
+    key.encrypt(&mut data).unwrap();
+    assert_ne!(&data, b"sixteen byte blk");
+    key.decrypt(&mut data).unwrap();
+    assert_eq!(&data, b"sixteen byte blk");
+
+    assert!(key.decrypt(&mut [0u8; 15]).is_err());
+}
+
+#[test]
+fn write_pak() -> Result<(), Error> {
+    let large = (0..200u8).collect::<Vec<_>>();
+    let files: [(&str, &[u8], CompressionMethod); 4] = [
+        (
+            "Game/Content/Small.uasset",
+            b"small file",
+            CompressionMethod::None,
+        ),
+        (
+            "Game/Content/Maps/Zlib.uexp",
+            &large,
+            CompressionMethod::Zlib,
+        ),
+        (
+            "Game/Content/Maps/Lz4.ubulk",
+            &large,
+            CompressionMethod::Lz4,
+        ),
+        ("Game/Empty.txt", &[], CompressionMethod::Zlib),
+    ];
+
+    for version in [
+        EPakVersion::CompressionEncryption,
+        EPakVersion::RelativeChunkOffsets,
+        EPakVersion::FNameBasedCompressionMethod,
+        EPakVersion::PathHashIndex,
+        EPakVersion::Fnv64BugFix,
+    ] {
+        let mut writer = PakWriter::new(Cursor::new(Vec::new()), version, MOUNT_POINT.to_string());
+        writer.compression_block_size = BLOCK_SIZE;
+        for (path, data, method) in &files {
+            let method = match version < EPakVersion::FNameBasedCompressionMethod
+                && *method == CompressionMethod::Lz4
+            {
+                true => CompressionMethod::Gzip,
+                false => method.clone(),
+            };
+            writer.write_file(path, data, method)?;
+        }
+        let pak = writer.finish()?.into_inner();
+
+        let mut reader = PakReader::new(Cursor::new(pak.clone()), None)?;
+        assert_eq!(reader.info.version, version);
+        assert_eq!(reader.get_mount_point(), Some(MOUNT_POINT));
+        assert_eq!(reader.get_file_names().count(), files.len());
+
+        let index_start = reader.info.index_offset as usize;
+        let index_end = index_start + reader.info.index_size as usize;
+        assert_eq!(
+            reader.info.index_hash,
+            sha1_smol::Sha1::from(&pak[index_start..index_end])
+                .digest()
+                .bytes()
+        );
+
+        for (path, data, _) in &files {
+            assert_eq!(reader.read_file(path)?, *data);
+        }
+
+        let entry = reader.get_entry("Game/Content/Maps/Zlib.uexp").unwrap();
+        assert_eq!(entry.compression_blocks.len(), large.len().div_ceil(64));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn write_pak_compression_level() -> Result<(), Error> {
+    let data = compressed_data();
+
+    let mut writer = PakWriter::new(
+        Cursor::new(Vec::new()),
+        EPakVersion::Fnv64BugFix,
+        MOUNT_POINT.to_string(),
+    );
+    writer.compression_block_size = BLOCK_SIZE;
+    writer.compression_level = CompressionLevel::Best;
+    writer.write_file("Game/Zstd.uexp", &data, CompressionMethod::Zstd)?;
+    writer.write_file("Game/Brotli.uexp", &data, CompressionMethod::Brotli)?;
+    let pak = writer.finish()?.into_inner();
+
+    let mut reader = PakReader::new(Cursor::new(pak), None)?;
+    assert_eq!(
+        reader.info.compression_methods,
+        vec![CompressionMethod::Zstd, CompressionMethod::Brotli]
+    );
+    assert_eq!(reader.read_file("Game/Zstd.uexp")?, data);
+    assert_eq!(reader.read_file("Game/Brotli.uexp")?, data);
+
+    Ok(())
+}
+
+#[test]
+fn write_incompatible_compression() {
+    let mut writer = PakWriter::new(
+        Cursor::new(Vec::new()),
+        EPakVersion::RelativeChunkOffsets,
+        MOUNT_POINT.to_string(),
+    );
+    assert!(writer
+        .write_file("Game/File.uasset", b"data", CompressionMethod::Lz4)
+        .is_err());
+
+    let mut writer = PakWriter::new(
+        Cursor::new(Vec::new()),
+        EPakVersion::NoTimestamps,
+        MOUNT_POINT.to_string(),
+    );
+    assert!(writer
+        .write_file("Game/File.uasset", b"data", CompressionMethod::Zlib)
+        .is_err());
+}
+
+#[test]
+fn write_oodle_compression() -> Result<(), Error> {
+    let mut writer = PakWriter::new(
+        Cursor::new(Vec::new()),
+        EPakVersion::Fnv64BugFix,
+        MOUNT_POINT.to_string(),
+    );
+    assert!(matches!(
+        writer.write_file("Game/File.uasset", b"data", CompressionMethod::Oodle),
+        Err(Error::Unimplemented(_))
+    ));
+    // the rejected file doesn't leave anything behind
+    assert!(writer.info.compression_methods.is_empty());
+
+    writer.write_file("Game/File.uasset", b"data", CompressionMethod::Zlib)?;
+    let pak = writer.finish()?.into_inner();
+
+    let mut reader = PakReader::new(Cursor::new(pak), None)?;
+    assert_eq!(
+        reader.info.compression_methods,
+        vec![CompressionMethod::Zlib]
+    );
+    assert_eq!(reader.read_file("Game/File.uasset")?, b"data");
+
+    Ok(())
+}
+
+#[test]
+fn repackage_asset() -> Result<(), Error> {
+    let asset_data: &[u8] = include_bytes!("assets/unknown_properties/BP_DetPack_Charge.uasset");
+    let bulk_data: &[u8] = include_bytes!("assets/unknown_properties/BP_DetPack_Charge.uexp");
+    let mut asset = Asset::new(
+        Cursor::new(asset_data),
+        Some(Cursor::new(bulk_data)),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+
+    let export_index = asset
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| {
+            e.get_normal_export()
+                .is_some_and(|e| e.properties.len() > 1)
+        })
+        .unwrap();
+    asset.asset_data.exports[export_index]
+        .get_normal_export_mut()
+        .unwrap()
+        .properties
+        .remove(0);
+
+    let mut uasset = Cursor::new(Vec::new());
+    let mut uexp = Cursor::new(Vec::new());
+    asset.write_data(&mut uasset, Some(&mut uexp))?;
+
+    let mut writer = PakWriter::new(
+        Cursor::new(Vec::new()),
+        EPakVersion::Fnv64BugFix,
+        MOUNT_POINT.to_string(),
+    );
+    writer.write_file(
+        "Game/Content/BP_DetPack_Charge.uasset",
+        uasset.get_ref(),
+        CompressionMethod::Zlib,
+    )?;
+    writer.write_file(
+        "Game/Content/BP_DetPack_Charge.uexp",
+        uexp.get_ref(),
+        CompressionMethod::Zlib,
+    )?;
+    let pak = writer.finish()?.into_inner();
+
+    let mut reader = PakReader::new(Cursor::new(pak), None)?;
+    let patched = Asset::new(
+        reader.open_file("Game/Content/BP_DetPack_Charge.uasset")?,
+        Some(reader.open_file("Game/Content/BP_DetPack_Charge.uexp")?),
+        EngineVersion::VER_UE4_25,
+        None,
+    )?;
+
+    assert_eq!(
+        patched.asset_data.exports[export_index]
+            .get_normal_export()
+            .map(|e| &e.properties),
+        asset.asset_data.exports[export_index]
+            .get_normal_export()
+            .map(|e| &e.properties)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn hash_path() {
+    assert_eq!(
+        PakIndex::hash_path("Content/Maps/Foo.umap", 0, EPakVersion::Fnv64BugFix),
+        0xb62d2748228831ca
+    );
+    assert_eq!(
+        PakIndex::hash_path("Content/Maps/Foo.umap", 0, EPakVersion::Fnv64BugFix),
+        PakIndex::hash_path("content/maps/foo.umap", 0, EPakVersion::Fnv64BugFix)
+    );
+    assert_ne!(
+        PakIndex::hash_path("Content/Maps/Foo.umap", 0, EPakVersion::PathHashIndex),
+        PakIndex::hash_path("Content/Maps/Foo.umap", 0, EPakVersion::Fnv64BugFix)
+    );
+    assert_ne!(
+        PakIndex::hash_path("Content/Maps/Foo.umap", 1, EPakVersion::Fnv64BugFix),
+        PakIndex::hash_path("Content/Maps/Foo.umap", 0, EPakVersion::Fnv64BugFix)
+    );
+}
@@ -0,0 +1,404 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    enums::EOverriddenPropertyOperation,
+    exports::ExportNormalTrait,
+    flags::EPropertyTagExtension,
+    object_version::ObjectVersionUE5,
+    properties::{
+        array_property::ArrayProperty,
+        empty_property::EmptyProperty,
+        int_property::{BoolProperty, IntProperty},
+        map_property::MapProperty,
+        property_tag::PropertyTypeName,
+        struct_property::StructProperty,
+        vector_property::VectorProperty,
+        Property, PropertyDataTrait, PropertyTagExtensions,
+    },
+    types::{vector::Vector, FName},
+    unversioned::Ancestry,
+    Asset, Error, Guid,
+};
+
+mod shared;
+
+macro_rules! assets_folder {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/pseudoregalia/"
+        )
+    };
+}
+
+const TEST_ASSETS: [(&[u8], &[u8]); 2] = [
+    (
+        include_bytes!(concat!(assets_folder!(), "BP_PlayerGoatMain.uasset")),
+        include_bytes!(concat!(assets_folder!(), "BP_PlayerGoatMain.uexp")),
+    ),
+    (
+        include_bytes!(concat!(assets_folder!(), "Zone_Library.umap")),
+        include_bytes!(concat!(assets_folder!(), "Zone_Library.uexp")),
+    ),
+];
+
+const STRUCT_GUID: Guid = Guid([
+    0x10, 0x32, 0x54, 0x76, 0x98, 0xba, 0xdc, 0xfe, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef,
+]);
+
+const OVERRIDABLE_EXTENSIONS: PropertyTagExtensions = PropertyTagExtensions {
+    flags: EPropertyTagExtension::OVERRIDABLE_INFORMATION,
+    overridden_operation: EOverriddenPropertyOperation::Replaced,
+    experimental_overridable_logic: true,
+};
+
+fn write_asset(asset: &mut Asset<Cursor<Vec<u8>>>) -> Result<(Vec<u8>, Vec<u8>), Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+    Ok((cursor.into_inner(), bulk_cursor.into_inner()))
+}
+
+fn parsed_exports(asset: &Asset<Cursor<Vec<u8>>>) -> usize {
+    asset
+        .asset_data
+        .exports
+        .iter()
+        .filter(|e| e.get_normal_export().is_some())
+        .count()
+}
+
+fn vector_struct(name: FName, ancestry: &Ancestry, vector: &FName, value: f64) -> StructProperty {
+    let mut property = StructProperty::dummy(
+        name.clone(),
+        ancestry.clone(),
+        vector.clone(),
+        Some(Guid::default()),
+    );
+    property.value.push(
+        VectorProperty {
+            name,
+            ancestry: ancestry.clone(),
+            property_guid: None,
+            property_tag_extensions: PropertyTagExtensions::default(),
+            duplication_index: 0,
+            value: Vector::new(value.into(), (value * 2.0).into(), (value * 3.0).into()),
+        }
+        .into(),
+    );
+    property
+}
+
+/// Adds properties exercising the tag flags and type name parameters to the first export with properties
+fn add_tagged_properties(asset: &mut Asset<Cursor<Vec<u8>>>) {
+    let export_index = asset
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| {
+            e.get_normal_export()
+                .is_some_and(|e| !e.properties.is_empty())
+        })
+        .expect("Failed to find export with properties");
+
+    let ancestry = asset.asset_data.exports[export_index]
+        .get_normal_export()
+        .and_then(|e| e.properties.first())
+        .map(|e| e.get_ancestry().clone())
+        .unwrap_or_default();
+
+    let mut name_map = asset.get_name_map();
+    let mut name_map = name_map.get_mut();
+    let mut add_fname = |name: &str| name_map.add_fname(name);
+
+    let vector = add_fname("Vector");
+    let vector_type = PropertyTypeName::new(
+        vector.clone(),
+        vec![PropertyTypeName::new(
+            add_fname("/Script/CoreUObject"),
+            Vec::new(),
+        )],
+    );
+    let struct_type = PropertyTypeName::new(add_fname("StructProperty"), vec![vector_type]);
+
+    let guid_struct_name = add_fname("TaggedGuidStruct");
+    let mut guid_struct = vector_struct(guid_struct_name, &ancestry, &vector, 1.0);
+    guid_struct.struct_guid = Some(STRUCT_GUID);
+    guid_struct.property_type_name = Some(PropertyTypeName::new(
+        struct_type.name.clone(),
+        vec![
+            struct_type.parameters[0].clone(),
+            PropertyTypeName::new(
+                add_fname(&PropertyTypeName::guid_to_string(&STRUCT_GUID)),
+                Vec::new(),
+            ),
+        ],
+    ));
+
+    let array_name = add_fname("TaggedVectorArray");
+    let array_ancestry = ancestry.with_parent(array_name.clone());
+    let mut array = ArrayProperty::from_arr(
+        array_name.clone(),
+        ancestry.clone(),
+        Some(struct_type.name.clone()),
+        vec![
+            vector_struct(array_name.clone(), &array_ancestry, &vector, 4.0).into(),
+            vector_struct(array_name, &array_ancestry, &vector, 5.0).into(),
+        ],
+    );
+    array.property_type_name = Some(PropertyTypeName::new(
+        add_fname("ArrayProperty"),
+        vec![struct_type.clone()],
+    ));
+
+    let map_name = add_fname("TaggedVectorMap");
+    let map_ancestry = ancestry.with_parent(map_name.clone());
+    let int_type = add_fname("IntProperty");
+    let mut map = MapProperty {
+        name: map_name.clone(),
+        ancestry: ancestry.clone(),
+        key_type: int_type.clone(),
+        value_type: struct_type.name.clone(),
+        property_type_name: Some(PropertyTypeName::new(
+            add_fname("MapProperty"),
+            vec![PropertyTypeName::new(int_type, Vec::new()), struct_type],
+        )),
+        ..Default::default()
+    };
+    for i in 0..2 {
+        map.value.insert(
+            IntProperty {
+                name: map_name.clone(),
+                ancestry: map_ancestry.clone(),
+                property_guid: None,
+                property_tag_extensions: PropertyTagExtensions::default(),
+                duplication_index: 0,
+                value: i,
+            }
+            .into(),
+            vector_struct(map_name.clone(), &map_ancestry, &vector, i as f64).into(),
+        );
+    }
+
+    let flag = BoolProperty {
+        name: add_fname("bTaggedFlag"),
+        ancestry,
+        property_guid: Some(STRUCT_GUID),
+        property_tag_extensions: OVERRIDABLE_EXTENSIONS,
+        duplication_index: 2,
+        value: true,
+    };
+    add_fname("BoolProperty");
+
+    drop(name_map);
+    asset.asset_data.exports[export_index]
+        .get_normal_export_mut()
+        .expect("Failed to find export")
+        .properties
+        .extend([guid_struct.into(), array.into(), map.into(), flag.into()]);
+}
+
+/// Converts an asset to >= UE5.4 property tags and back, checking nothing is lost
+fn convert_asset(data: &[u8], bulk: &[u8]) -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(data.to_vec()),
+        Some(Cursor::new(bulk.to_vec())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    add_tagged_properties(&mut asset);
+    // cooked test assets are unversioned, store the versions so the reparse can pick them up
+    asset.asset_data.summary.unversioned = false;
+    let original_version = asset.asset_data.object_version_ue5;
+    let exports = parsed_exports(&asset);
+
+    asset.asset_data.object_version_ue5 = ObjectVersionUE5::PROPERTY_TAG_COMPLETE_TYPE_NAME;
+    let (data, bulk) = write_asset(&mut asset)?;
+
+    asset.asset_data.object_version_ue5 = original_version;
+    let (original_data, original_bulk) = write_asset(&mut asset)?;
+
+    let mut reparsed = Asset::new(
+        Cursor::new(data.clone()),
+        Some(Cursor::new(bulk.clone())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    assert_eq!(
+        reparsed.asset_data.object_version_ue5,
+        ObjectVersionUE5::PROPERTY_TAG_COMPLETE_TYPE_NAME
+    );
+    assert_eq!(parsed_exports(&reparsed), exports);
+
+    match shared::find_property(&reparsed, "TaggedGuidStruct") {
+        Property::StructProperty(e) => {
+            assert_eq!(e.struct_guid, Some(STRUCT_GUID));
+            assert!(e.struct_type.as_ref().is_some_and(|e| e == "Vector"));
+        }
+        e => panic!("Expected StructProperty, got {e:?}"),
+    }
+    match shared::find_property(&reparsed, "TaggedVectorMap") {
+        Property::MapProperty(e) => {
+            assert_eq!(e.value.len(), 2);
+            assert!(e.value.iter().all(|(_, _, value)| matches!(
+                value,
+                Property::StructProperty(e) if e.struct_type.as_ref().is_some_and(|e| e == "Vector")
+            )));
+        }
+        e => panic!("Expected MapProperty, got {e:?}"),
+    }
+    match shared::find_property(&reparsed, "bTaggedFlag") {
+        Property::BoolProperty(e) => {
+            assert!(e.value);
+            assert_eq!(e.duplication_index, 2);
+            assert_eq!(e.property_guid, Some(STRUCT_GUID));
+            assert_eq!(e.property_tag_extensions, OVERRIDABLE_EXTENSIONS);
+        }
+        e => panic!("Expected BoolProperty, got {e:?}"),
+    }
+
+    shared::verify_binary_equality(&data, Some(&bulk), &mut reparsed)?;
+
+    reparsed.asset_data.object_version_ue5 = original_version;
+    shared::verify_binary_equality(&original_data, Some(&original_bulk), &mut reparsed)?;
+
+    Ok(())
+}
+
+#[test]
+fn property_tag_complete_type_name() -> Result<(), Error> {
+    for (data, bulk) in TEST_ASSETS {
+        convert_asset(data, bulk)?;
+    }
+    Ok(())
+}
+
+/// Writes an asset with property tag extensions and checks they are read back
+fn extensions_asset(data: &[u8], bulk: &[u8]) -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(data.to_vec()),
+        Some(Cursor::new(bulk.to_vec())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    asset.asset_data.summary.unversioned = false;
+    asset.asset_data.object_version_ue5 =
+        ObjectVersionUE5::PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION;
+
+    for property in asset
+        .asset_data
+        .exports
+        .iter_mut()
+        .filter_map(|e| e.get_normal_export_mut())
+        .filter_map(|e| e.properties.first_mut())
+    {
+        property.set_property_tag_extensions(OVERRIDABLE_EXTENSIONS);
+    }
+
+    let (data, bulk) = write_asset(&mut asset)?;
+    let mut reparsed = Asset::new(
+        Cursor::new(data.clone()),
+        Some(Cursor::new(bulk.clone())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    assert_eq!(
+        reparsed.asset_data.object_version_ue5,
+        ObjectVersionUE5::PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION
+    );
+    assert_eq!(parsed_exports(&reparsed), parsed_exports(&asset));
+
+    let first_properties = reparsed
+        .asset_data
+        .exports
+        .iter()
+        .filter_map(|e| e.get_normal_export())
+        .filter_map(|e| e.properties.first())
+        .collect::<Vec<_>>();
+    assert!(!first_properties.is_empty());
+    for property in first_properties {
+        assert_eq!(
+            property.get_property_tag_extensions(),
+            OVERRIDABLE_EXTENSIONS
+        );
+    }
+
+    shared::verify_binary_equality(&data, Some(&bulk), &mut reparsed)?;
+
+    Ok(())
+}
+
+#[test]
+fn property_tag_extensions() -> Result<(), Error> {
+    for (data, bulk) in TEST_ASSETS {
+        extensions_asset(data, bulk)?;
+    }
+    Ok(())
+}
+
+/// Writes an asset with a skipped >= UE5.4 property tag and checks it is read back
+fn skipped_asset(data: &[u8], bulk: &[u8]) -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(data.to_vec()),
+        Some(Cursor::new(bulk.to_vec())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    asset.asset_data.summary.unversioned = false;
+    asset.asset_data.object_version_ue5 = ObjectVersionUE5::PROPERTY_TAG_COMPLETE_TYPE_NAME;
+
+    let export = asset
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| {
+            e.get_normal_export()
+                .is_some_and(|e| !e.properties.is_empty())
+        })
+        .expect("Failed to find export with properties");
+    let ancestry = asset.asset_data.exports[export]
+        .get_normal_export()
+        .and_then(|e| e.properties.first())
+        .map(|e| e.get_ancestry().clone())
+        .unwrap_or_default();
+
+    let int_type = asset.get_name_map().get_mut().add_fname("IntProperty");
+    let skipped = EmptyProperty {
+        type_name: int_type.clone(),
+        name: asset.get_name_map().get_mut().add_fname("SkippedValue"),
+        ancestry,
+        property_type_name: Some(PropertyTypeName::new(int_type, Vec::new())),
+    };
+    asset.asset_data.exports[export]
+        .get_normal_export_mut()
+        .expect("Failed to find export")
+        .properties
+        .push(skipped.clone().into());
+
+    let (data, bulk) = write_asset(&mut asset)?;
+    let mut reparsed = Asset::new(
+        Cursor::new(data.clone()),
+        Some(Cursor::new(bulk.clone())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    assert_eq!(parsed_exports(&reparsed), parsed_exports(&asset));
+
+    match shared::find_property(&reparsed, "SkippedValue") {
+        Property::EmptyProperty(e) => assert_eq!(e, &skipped),
+        e => panic!("Expected EmptyProperty, got {e:?}"),
+    }
+
+    shared::verify_binary_equality(&data, Some(&bulk), &mut reparsed)?;
+
+    Ok(())
+}
+
+#[test]
+fn property_tag_skipped_serialize() -> Result<(), Error> {
+    for (data, bulk) in TEST_ASSETS {
+        skipped_asset(data, bulk)?;
+    }
+    Ok(())
+}
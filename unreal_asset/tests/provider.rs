@@ -0,0 +1,349 @@
+use std::fs;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+use unreal_asset::compression::CompressionMethod;
+use unreal_asset::engine_version::EngineVersion;
+use unreal_asset::error::{Error, FileProviderError};
+use unreal_asset::io_store::{
+    toc::{
+        IoOffsetAndLength, IoStoreTocCompressedBlockEntry, IoStoreTocEntryMeta, IoStoreTocHeader,
+    },
+    EIoChunkType, EIoContainerFlags, EIoStoreTocVersion, IoChunkId, IoDirectoryIndex,
+    IoStoreReader, IoStoreToc,
+};
+use unreal_asset::pak::{EPakVersion, PakReader, PakWriter};
+use unreal_asset::provider::{FileProvider, GameFileProvider, LooseFileProvider, ProviderAsset};
+use unreal_asset::{Asset, Guid};
+
+const GAME_NAME: &str = "TestGame";
+
+/// Temporary game install, removed on drop
+struct TestInstall {
+    root: PathBuf,
+}
+
+impl TestInstall {
+    fn new(name: &str) -> Self {
+        let root = std::env::temp_dir().join(format!(
+            "unreal_asset_provider_{name}_{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        TestInstall { root }
+    }
+
+    fn write_loose(&self, path: &str, data: &[u8]) {
+        let path = self.root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, data).unwrap();
+    }
+
+    fn write_pak(&self, name: &str, files: &[(&str, &[u8])]) {
+        let mut writer = PakWriter::new(
+            Cursor::new(Vec::new()),
+            EPakVersion::Fnv64BugFix,
+            "../../../".to_string(),
+        );
+        for (path, data) in files {
+            writer
+                .write_file(path, data, CompressionMethod::Zlib)
+                .unwrap();
+        }
+        let pak = writer.finish().unwrap().into_inner();
+        self.write_loose(&format!("{GAME_NAME}/Content/Paks/{name}"), &pak);
+    }
+}
+
+impl Drop for TestInstall {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+#[test]
+fn container_priority() {
+    assert_eq!(
+        GameFileProvider::get_container_priority("pakchunk0-Windows.pak"),
+        0
+    );
+    assert_eq!(
+        GameFileProvider::get_container_priority("pakchunk0-Windows_P.pak"),
+        100
+    );
+    assert_eq!(
+        GameFileProvider::get_container_priority(Path::new(
+            "Paks/~mods/pakchunk0-Windows_2_P.utoc"
+        )),
+        300
+    );
+}
+
+#[test]
+fn loose_mount_point() -> Result<(), Error> {
+    let install = TestInstall::new("loose");
+    install.write_loose("Maps/Foo.umap", b"map");
+
+    let mut provider = LooseFileProvider::new(&install.root, "../../../TestGame/Content");
+    assert_eq!(provider.mount_point, "TestGame/Content/");
+
+    assert!(provider.has_file("TestGame/Content/Maps/Foo.umap"));
+    assert!(provider.has_file("/TestGame/Content/Maps/Foo.umap"));
+    assert!(!provider.has_file("Maps/Foo.umap"));
+    assert!(!provider.has_file("TestGame/Content/Maps/../../Content/Maps/Foo.umap"));
+
+    assert_eq!(
+        provider.read_file("TestGame/Content/Maps/Foo.umap")?,
+        b"map"
+    );
+    assert_eq!(
+        provider.get_file_names(),
+        vec!["TestGame/Content/Maps/Foo.umap".to_string()]
+    );
+    assert!(matches!(
+        provider.read_file("TestGame/Content/Maps/Bar.umap"),
+        Err(Error::FileProvider(FileProviderError::NoFile(_)))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn open_install() -> Result<(), Error> {
+    let asset_data: &[u8] = include_bytes!("assets/unknown_properties/BP_DetPack_Charge.uasset");
+    let bulk_data: &[u8] = include_bytes!("assets/unknown_properties/BP_DetPack_Charge.uexp");
+
+    let install = TestInstall::new("install");
+    install.write_loose("TestGame/Content/Config.txt", b"loose");
+    install.write_loose("TestGame/Content/Loose.txt", b"loose only");
+    install.write_loose("TestGame/Binaries/Outside.txt", b"outside of content");
+    install.write_pak(
+        "pakchunk0-Windows.pak",
+        &[
+            ("TestGame/Content/Config.txt", b"base"),
+            ("TestGame/Content/Patched.txt", b"base"),
+            (
+                "TestGame/Content/Blueprints/BP_DetPack_Charge.uasset",
+                asset_data,
+            ),
+            (
+                "TestGame/Content/Blueprints/BP_DetPack_Charge.uexp",
+                bulk_data,
+            ),
+        ],
+    );
+    // patches are mounted before the base pak when sorted by name, priority has to override that
+    install.write_pak(
+        "pakchunk0-Windows_1_P.pak",
+        &[("TestGame/Content/Patched.txt", b"patch 1")],
+    );
+    install.write_pak(
+        "~mods/pakchunk0-Windows_P.pak",
+        &[("TestGame/Content/Patched.txt", b"patch 0")],
+    );
+
+    let mut provider = GameFileProvider::open_install(&install.root, GAME_NAME, None)?;
+    assert_eq!(provider.provider_count(), 4);
+
+    assert_eq!(provider.read_file("TestGame/Content/Config.txt")?, b"base");
+    assert_eq!(
+        provider.read_file("TestGame/Content/Loose.txt")?,
+        b"loose only"
+    );
+    assert_eq!(
+        provider.read_file("../../../TestGame/Content/Patched.txt")?,
+        b"patch 1"
+    );
+    assert_eq!(
+        provider
+            .get_file_names()
+            .iter()
+            .filter(|e| e.ends_with(".txt"))
+            .count(),
+        3
+    );
+
+    // only content is provided as loose files, containers are only mounted
+    assert!(!provider.has_file("TestGame/Binaries/Outside.txt"));
+    assert!(!provider.has_file("TestGame/Content/Paks/pakchunk0-Windows.pak"));
+    assert!(!provider
+        .get_file_names()
+        .iter()
+        .any(|e| e.contains("/Paks/")));
+
+    assert_eq!(
+        provider.resolve_package("/Game/Blueprints/BP_DetPack_Charge"),
+        Some("TestGame/Content/Blueprints/BP_DetPack_Charge.uasset".to_string())
+    );
+    let streams = provider.open_package("/Game/Blueprints/BP_DetPack_Charge")?;
+    assert_eq!(streams.asset.get_ref(), asset_data);
+    assert_eq!(streams.exports.map(|e| e.into_inner()).unwrap(), bulk_data);
+    assert!(streams.bulk.is_none());
+
+    match provider.read_asset(
+        "/Game/Blueprints/BP_DetPack_Charge",
+        EngineVersion::VER_UE4_25,
+        None,
+    )? {
+        ProviderAsset::Legacy(asset) => assert!(!asset.asset_data.exports.is_empty()),
+        ProviderAsset::Zen(_) => panic!("Expected a legacy asset"),
+    }
+
+    assert!(matches!(
+        provider.open_package("/Game/Missing"),
+        Err(Error::FileProvider(FileProviderError::NoFile(_)))
+    ));
+    assert!(matches!(
+        provider.open_package("/Engine/Missing"),
+        Err(Error::FileProvider(FileProviderError::InvalidGamePath(_)))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn add_provider_priority() -> Result<(), Error> {
+    let pak = |data: &[u8]| {
+        let mut writer = PakWriter::new(
+            Cursor::new(Vec::new()),
+            EPakVersion::Fnv64BugFix,
+            "../../../".to_string(),
+        );
+        writer
+            .write_file("TestGame/Content/File.txt", data, CompressionMethod::None)
+            .unwrap();
+        PakReader::new(Cursor::new(writer.finish().unwrap().into_inner()), None).unwrap()
+    };
+
+    let mut provider = GameFileProvider::new(GAME_NAME);
+    provider.add_provider(pak(b"high"), 10);
+    provider.add_provider(pak(b"low"), 0);
+    assert_eq!(provider.read_file("TestGame/Content/File.txt")?, b"high");
+
+    // same priority, mounted last wins
+    provider.add_provider(pak(b"later"), 10);
+    assert_eq!(provider.read_file("TestGame/Content/File.txt")?, b"later");
+
+    Ok(())
+}
+
+/// Builds an uncompressed IoStore container with a single zen package
+fn build_zen_container(
+    path: &str,
+    package_name: &str,
+    data: &[u8],
+) -> IoStoreReader<Cursor<Vec<u8>>> {
+    const BLOCK_SIZE: usize = 0x10000;
+
+    let compression_blocks = data
+        .chunks(BLOCK_SIZE)
+        .enumerate()
+        .map(|(i, e)| IoStoreTocCompressedBlockEntry {
+            offset: (i * BLOCK_SIZE) as u64,
+            compressed_size: e.len() as u32,
+            uncompressed_size: e.len() as u32,
+            compression_method_index: 0,
+        })
+        .collect();
+    let directory_index =
+        IoDirectoryIndex::from_files(Some("../../../".to_string()), [(path, 0u32)]);
+
+    let toc = IoStoreToc {
+        header: IoStoreTocHeader {
+            version: EIoStoreTocVersion::PerfectHashWithOverflow,
+            toc_entry_count: 0,
+            toc_compressed_block_entry_count: 0,
+            toc_compressed_block_entry_size: 0,
+            compression_method_name_count: 0,
+            compression_method_name_length: 0,
+            compression_block_size: BLOCK_SIZE as u32,
+            directory_index_size: 0,
+            partition_count: 1,
+            container_id: 0x1234,
+            encryption_key_guid: Guid::default(),
+            container_flags: EIoContainerFlags::INDEXED,
+            toc_chunk_perfect_hash_seeds_count: 0,
+            partition_size: u64::MAX,
+            toc_chunks_without_perfect_hash_count: 0,
+        },
+        chunk_ids: vec![IoChunkId::from_package_name(
+            package_name,
+            EIoChunkType::ExportBundleData,
+        )],
+        chunk_offset_lengths: vec![IoOffsetAndLength {
+            offset: 0,
+            length: data.len() as u64,
+        }],
+        chunk_perfect_hash_seeds: Vec::new(),
+        chunk_indices_without_perfect_hash: Vec::new(),
+        compression_blocks,
+        compression_methods: vec![CompressionMethod::Zlib],
+        signatures: None,
+        chunk_metas: vec![IoStoreTocEntryMeta::default()],
+        directory_index_buffer: directory_index.to_buffer().unwrap(),
+    };
+
+    let mut utoc = Vec::new();
+    toc.write(&mut utoc).unwrap();
+    IoStoreReader::new(
+        &mut Cursor::new(utoc),
+        vec![Cursor::new(data.to_vec())],
+        None,
+    )
+    .unwrap()
+}
+
+#[test]
+fn read_zen_asset() -> Result<(), Error> {
+    let asset_data: &[u8] = include_bytes!("assets/ue5/PublicHousingPlot_Root.umap");
+    let bulk_data: &[u8] = include_bytes!("assets/ue5/PublicHousingPlot_Root.uexp");
+    let asset = Asset::new(
+        Cursor::new(asset_data),
+        Some(Cursor::new(bulk_data)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    let mut zen_data = Cursor::new(Vec::new());
+    asset
+        .to_zen("/Game/Maps/PublicHousingPlot_Root", None)?
+        .write_data(&mut zen_data)?;
+
+    let mut provider = GameFileProvider::new(GAME_NAME);
+    provider.add_provider(
+        build_zen_container(
+            "TestGame/Content/Maps/PublicHousingPlot_Root.umap",
+            "/Game/Maps/PublicHousingPlot_Root",
+            zen_data.get_ref(),
+        ),
+        0,
+    );
+    assert!(provider.has_zen_package("/Game/Maps/PublicHousingPlot_Root"));
+    assert!(!provider.has_zen_package("/Game/Maps/Missing"));
+
+    match provider.read_asset(
+        "/Game/Maps/PublicHousingPlot_Root",
+        EngineVersion::VER_UE5_1,
+        None,
+    )? {
+        ProviderAsset::Zen(zen_asset) => {
+            assert_eq!(
+                zen_asset.package_name.get_owned_content(),
+                "/Game/Maps/PublicHousingPlot_Root"
+            );
+            assert_eq!(
+                zen_asset.asset_data.exports.len(),
+                asset.asset_data.exports.len()
+            );
+        }
+        ProviderAsset::Legacy(_) => panic!("Expected a zen asset"),
+    }
+
+    assert!(matches!(
+        provider.read_zen_asset("/Game/Maps/Missing", EngineVersion::VER_UE5_1, None),
+        Err(Error::FileProvider(FileProviderError::NoFile(_)))
+    ));
+
+    Ok(())
+}
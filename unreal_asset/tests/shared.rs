@@ -1,6 +1,14 @@
 use std::io::{Cursor, Read, Seek};
 
-use unreal_asset::{cast, engine_version::EngineVersion, Asset, Error, Export};
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{struct_property::StructProperty, Property, PropertyDataTrait},
+    types::FName,
+    unversioned::Ancestry,
+    Asset, Error, Export, Guid,
+};
 
 #[allow(dead_code)]
 pub(crate) fn verify_reparse<C: Read + Seek>(
@@ -61,3 +69,36 @@ pub(crate) fn verify_all_exports_parsed<C: Read + Seek>(asset: &Asset<C>) -> boo
 
     true
 }
+
+#[allow(dead_code)]
+pub(crate) fn find_property<'a, C: Read + Seek>(asset: &'a Asset<C>, name: &str) -> &'a Property {
+    asset
+        .asset_data
+        .exports
+        .iter()
+        .filter_map(|e| e.get_normal_export())
+        .flat_map(|e| e.properties.iter())
+        .find(|e| e.get_name() == name)
+        .expect("Failed to find property")
+}
+
+#[allow(dead_code)]
+pub(crate) fn typed_struct(
+    name: FName,
+    ancestry: &Ancestry,
+    struct_type: FName,
+    value: Property,
+) -> Property {
+    let mut property =
+        StructProperty::dummy(name, ancestry.clone(), struct_type, Some(Guid::default()));
+    property.value.push(value);
+    property.into()
+}
+
+#[allow(dead_code)]
+pub(crate) fn find_typed<'a, C: Read + Seek>(asset: &'a Asset<C>, name: &str) -> &'a Property {
+    match find_property(asset, name) {
+        Property::StructProperty(e) if e.value.len() == 1 => &e.value[0],
+        e => panic!("Expected StructProperty with a single value, got {e:?}"),
+    }
+}
@@ -0,0 +1,276 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    engine_version::EngineVersion,
+    exports::ExportNormalTrait,
+    properties::{
+        str_property::{AnsiStrProperty, StrProperty, Utf8StrProperty},
+        Property, PropertyDataTrait, PropertyTagExtensions,
+    },
+    reader::FStringEncoding,
+    types::FName,
+    unversioned::Ancestry,
+    Asset, Error, Guid,
+};
+use unreal_helpers::error::FStringError;
+
+mod shared;
+
+const TEST_ASSET: (&[u8], &[u8]) = (
+    include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/assets/general/pseudoregalia/BP_PlayerGoatMain.uasset"
+    )),
+    include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/assets/general/pseudoregalia/BP_PlayerGoatMain.uexp"
+    )),
+);
+
+fn str_property(
+    name: FName,
+    ancestry: &Ancestry,
+    value: &str,
+    encoding: FStringEncoding,
+) -> Property {
+    StrProperty {
+        name,
+        ancestry: ancestry.clone(),
+        property_guid: Some(Guid::default()),
+        property_tag_extensions: PropertyTagExtensions::default(),
+        duplication_index: 0,
+        value: Some(value.to_string()),
+        encoding,
+    }
+    .into()
+}
+
+fn find_str<'a>(asset: &'a Asset<Cursor<Vec<u8>>>, name: &str) -> &'a StrProperty {
+    match shared::find_property(asset, name) {
+        Property::StrProperty(e) => e,
+        e => panic!("Expected StrProperty, got {e:?}"),
+    }
+}
+
+fn property_export(asset: &Asset<Cursor<Vec<u8>>>) -> (usize, Ancestry) {
+    let export_index = asset
+        .asset_data
+        .exports
+        .iter()
+        .position(|e| {
+            e.get_normal_export()
+                .is_some_and(|e| !e.properties.is_empty())
+        })
+        .expect("Failed to find export with properties");
+    let ancestry = asset.asset_data.exports[export_index]
+        .get_normal_export()
+        .and_then(|e| e.properties.first())
+        .map(|e| e.get_ancestry().clone())
+        .unwrap_or_default();
+    (export_index, ancestry)
+}
+
+fn write_asset(asset: &Asset<Cursor<Vec<u8>>>) -> Result<Asset<Cursor<Vec<u8>>>, Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+
+    Asset::new(
+        Cursor::new(cursor.into_inner()),
+        Some(Cursor::new(bulk_cursor.into_inner())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )
+}
+
+fn find_str_mut<'a>(asset: &'a mut Asset<Cursor<Vec<u8>>>, name: &str) -> &'a mut StrProperty {
+    asset
+        .asset_data
+        .exports
+        .iter_mut()
+        .filter_map(|e| e.get_normal_export_mut())
+        .flat_map(|e| e.properties.iter_mut())
+        .find_map(|e| match e {
+            Property::StrProperty(e) if e.get_name() == name => Some(e),
+            _ => None,
+        })
+        .expect("Failed to find StrProperty")
+}
+
+#[test]
+fn str_property_encoding() -> Result<(), Error> {
+    let (data, bulk) = TEST_ASSET;
+    let mut asset = Asset::new(
+        Cursor::new(data.to_vec()),
+        Some(Cursor::new(bulk.to_vec())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    let (export_index, ancestry) = property_export(&asset);
+
+    let properties: Vec<Property> = {
+        let mut name_map = asset.get_name_map();
+        let mut name_map = name_map.get_mut();
+
+        vec![
+            str_property(
+                name_map.add_fname("WideAscii"),
+                &ancestry,
+                "Goat",
+                FStringEncoding::Utf16,
+            ),
+            str_property(
+                name_map.add_fname("AutoUnicode"),
+                &ancestry,
+                "Ziegenbock \u{1F410}",
+                FStringEncoding::Auto,
+            ),
+            str_property(
+                name_map.add_fname("NarrowAscii"),
+                &ancestry,
+                "Goat",
+                FStringEncoding::Ascii,
+            ),
+            Utf8StrProperty {
+                name: name_map.add_fname("Utf8Value"),
+                ancestry: ancestry.clone(),
+                property_guid: Some(Guid::default()),
+                property_tag_extensions: PropertyTagExtensions::default(),
+                duplication_index: 0,
+                value: Some("Cabra \u{00E9}".to_string()),
+            }
+            .into(),
+            AnsiStrProperty {
+                name: name_map.add_fname("AnsiValue"),
+                ancestry: ancestry.clone(),
+                property_guid: Some(Guid::default()),
+                property_tag_extensions: PropertyTagExtensions::default(),
+                duplication_index: 0,
+                value: None,
+            }
+            .into(),
+        ]
+    };
+
+    asset.asset_data.exports[export_index]
+        .get_normal_export_mut()
+        .expect("Failed to find export")
+        .properties
+        .extend(properties);
+
+    let mut cursor = Cursor::new(Vec::new());
+    let mut bulk_cursor = Cursor::new(Vec::new());
+    asset.write_data(&mut cursor, Some(&mut bulk_cursor))?;
+    let (data, bulk) = (cursor.into_inner(), bulk_cursor.into_inner());
+
+    let mut reparsed = Asset::new(
+        Cursor::new(data.clone()),
+        Some(Cursor::new(bulk.clone())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+
+    // ascii strings keep their wide encoding
+    let wide_ascii = find_str(&reparsed, "WideAscii");
+    assert_eq!(wide_ascii.value.as_deref(), Some("Goat"));
+    assert_eq!(wide_ascii.encoding, FStringEncoding::Utf16);
+
+    let auto_unicode = find_str(&reparsed, "AutoUnicode");
+    assert_eq!(auto_unicode.value.as_deref(), Some("Ziegenbock \u{1F410}"));
+    assert_eq!(auto_unicode.encoding, FStringEncoding::Utf16);
+
+    // narrow strings are read with automatic encoding so editing them can't corrupt them
+    let narrow_ascii = find_str(&reparsed, "NarrowAscii");
+    assert_eq!(narrow_ascii.value.as_deref(), Some("Goat"));
+    assert_eq!(narrow_ascii.encoding, FStringEncoding::Auto);
+
+    match shared::find_property(&reparsed, "Utf8Value") {
+        Property::Utf8StrProperty(e) => assert_eq!(e.value.as_deref(), Some("Cabra \u{00E9}")),
+        e => panic!("Expected Utf8StrProperty, got {e:?}"),
+    }
+    match shared::find_property(&reparsed, "AnsiValue") {
+        Property::AnsiStrProperty(e) => assert_eq!(e.value, None),
+        e => panic!("Expected AnsiStrProperty, got {e:?}"),
+    }
+
+    shared::verify_binary_equality(&data, Some(&bulk), &mut reparsed)?;
+    Ok(())
+}
+
+#[test]
+fn narrow_str_property_edit() -> Result<(), Error> {
+    let (data, bulk) = TEST_ASSET;
+    let mut asset = Asset::new(
+        Cursor::new(data.to_vec()),
+        Some(Cursor::new(bulk.to_vec())),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    let (export_index, ancestry) = property_export(&asset);
+
+    let (name, ansi_name) = {
+        let mut name_map = asset.get_name_map();
+        let mut name_map = name_map.get_mut();
+        (
+            name_map.add_fname("Narrow"),
+            name_map.add_fname("AnsiValue"),
+        )
+    };
+    let properties = vec![
+        str_property(name, &ancestry, "Goat", FStringEncoding::Ascii),
+        AnsiStrProperty {
+            name: ansi_name,
+            ancestry: ancestry.clone(),
+            property_guid: Some(Guid::default()),
+            property_tag_extensions: PropertyTagExtensions::default(),
+            duplication_index: 0,
+            value: Some("Goat".to_string()),
+        }
+        .into(),
+    ];
+    asset.asset_data.exports[export_index]
+        .get_normal_export_mut()
+        .expect("Failed to find export")
+        .properties
+        .extend(properties);
+
+    let mut reparsed = write_asset(&asset)?;
+    let narrow = find_str_mut(&mut reparsed, "Narrow");
+    assert_eq!(narrow.encoding, FStringEncoding::Auto);
+
+    // an edited narrow string is written wide once it isn't ascii anymore
+    narrow.value = Some("Chèvre \u{1F410}".to_string());
+    let mut edited = write_asset(&reparsed)?;
+    let narrow = find_str(&edited, "Narrow");
+    assert_eq!(narrow.value.as_deref(), Some("Chèvre \u{1F410}"));
+    assert_eq!(narrow.encoding, FStringEncoding::Utf16);
+
+    // explicitly narrow strings can't hold non-ascii text
+    find_str_mut(&mut edited, "Narrow").encoding = FStringEncoding::Ascii;
+    let err = write_asset(&edited).expect_err("Expected non-ascii narrow string to fail");
+    assert!(matches!(
+        err,
+        Error::FString(FStringError::NonAsciiString(_))
+    ));
+
+    let mut asset = reparsed;
+    for property in asset
+        .asset_data
+        .exports
+        .iter_mut()
+        .filter_map(|e| e.get_normal_export_mut())
+        .flat_map(|e| e.properties.iter_mut())
+    {
+        if let Property::AnsiStrProperty(e) = property {
+            e.value = Some("Chèvre".to_string());
+        }
+    }
+    let err = write_asset(&asset).expect_err("Expected non-ascii ansi string to fail");
+    assert!(matches!(
+        err,
+        Error::FString(FStringError::NonAsciiString(_))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,265 @@
+use std::io::Cursor;
+
+use unreal_asset::{
+    cast,
+    engine_version::EngineVersion,
+    exports::{Export, ExportNormalTrait, NormalExport},
+    properties::{
+        str_property::{TextHistoryType, TextProperty},
+        text_history::{
+            ArgumentFormatHistory, DateTimeHistory, DateTimeStyle, FText, FormatArgumentData,
+            FormatArgumentValue, FormatNumberHistory, NamedFormatHistory, NumberFormattingOptions,
+            OrderedFormatHistory, RoundingMode, TextGender, TextGeneratorHistory, TextHistory,
+            TransformHistory, TransformType,
+        },
+        Property, PropertyDataTrait,
+    },
+    types::fname::FName,
+    Asset, Error,
+};
+
+mod shared;
+
+macro_rules! test_asset {
+    () => {
+        concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/assets/general/Astroneer_prebulk/Augment_BroadBrush"
+        )
+    };
+}
+
+const TEST_ASSET: &[u8] = include_bytes!(concat!(test_asset!(), ".uasset"));
+const TEXT_PROPERTY: &str = "Description";
+
+fn base_text(value: &str) -> FText {
+    FText {
+        namespace: Some(String::new()),
+        value: Some("2755431C40892885955F6CA4D41BCA98".to_string()),
+        culture_invariant_string: Some(value.to_string()),
+        history_type: TextHistoryType::Base,
+        ..Default::default()
+    }
+}
+
+fn format_text(history_type: TextHistoryType, history: TextHistory) -> FText {
+    FText {
+        history_type,
+        history: Some(history),
+        ..Default::default()
+    }
+}
+
+fn text_histories(generator_type_id: FName, none: FName) -> Vec<FText> {
+    let format_options = NumberFormattingOptions {
+        always_sign: true,
+        use_grouping: false,
+        rounding_mode: RoundingMode::ToZero,
+        minimum_integral_digits: 1,
+        maximum_integral_digits: 10,
+        minimum_fractional_digits: 0,
+        maximum_fractional_digits: 2,
+    };
+    let date_time = |date_style, time_style| DateTimeHistory {
+        source_date_time: 637_134_336_000_000_000,
+        date_style,
+        time_style,
+        time_zone: Some("UTC".to_string()),
+        target_culture: Some("en".to_string()),
+    };
+
+    vec![
+        format_text(
+            TextHistoryType::NamedFormat,
+            TextHistory::NamedFormat(NamedFormatHistory {
+                source_format: Box::new(base_text("{Count} of {Name} ({Ratio})")),
+                arguments: vec![
+                    (Some("Count".to_string()), FormatArgumentValue::Int(-3)),
+                    (
+                        Some("Name".to_string()),
+                        FormatArgumentValue::Text(Box::new(base_text("Broad Brush"))),
+                    ),
+                    (
+                        Some("Ratio".to_string()),
+                        FormatArgumentValue::Double(0.25.into()),
+                    ),
+                ],
+            }),
+        ),
+        format_text(
+            TextHistoryType::OrderedFormat,
+            TextHistory::OrderedFormat(OrderedFormatHistory {
+                source_format: Box::new(base_text("{0} {1} {2}")),
+                arguments: vec![
+                    FormatArgumentValue::UInt(u64::MAX),
+                    FormatArgumentValue::Float(1.5.into()),
+                    FormatArgumentValue::Gender(TextGender::Neuter),
+                ],
+            }),
+        ),
+        format_text(
+            TextHistoryType::ArgumentFormat,
+            TextHistory::ArgumentFormat(ArgumentFormatHistory {
+                source_format: Box::new(base_text("{A}")),
+                arguments: vec![FormatArgumentData {
+                    name: Some("A".to_string()),
+                    value: FormatArgumentValue::Text(Box::new(base_text("Argument"))),
+                }],
+            }),
+        ),
+        format_text(
+            TextHistoryType::AsNumber,
+            TextHistory::FormatNumber(FormatNumberHistory {
+                currency_code: None,
+                source_value: FormatArgumentValue::Int(1234),
+                format_options: Some(format_options.clone()),
+                target_culture: None,
+            }),
+        ),
+        format_text(
+            TextHistoryType::AsPercent,
+            TextHistory::FormatNumber(FormatNumberHistory {
+                currency_code: None,
+                source_value: FormatArgumentValue::Float(0.5.into()),
+                format_options: None,
+                target_culture: Some("de".to_string()),
+            }),
+        ),
+        format_text(
+            TextHistoryType::AsCurrency,
+            TextHistory::FormatNumber(FormatNumberHistory {
+                currency_code: Some("EUR".to_string()),
+                source_value: FormatArgumentValue::Double(9.99.into()),
+                format_options: Some(format_options),
+                target_culture: None,
+            }),
+        ),
+        format_text(
+            TextHistoryType::AsDate,
+            TextHistory::DateTime(date_time(DateTimeStyle::Long, DateTimeStyle::Default)),
+        ),
+        format_text(
+            TextHistoryType::AsTime,
+            TextHistory::DateTime(date_time(DateTimeStyle::Default, DateTimeStyle::Short)),
+        ),
+        format_text(
+            TextHistoryType::AsDateTime,
+            TextHistory::DateTime(date_time(DateTimeStyle::Full, DateTimeStyle::Medium)),
+        ),
+        format_text(
+            TextHistoryType::Transform,
+            TextHistory::Transform(TransformHistory {
+                source_text: Box::new(base_text("Upper")),
+                transform_type: TransformType::ToUpper,
+            }),
+        ),
+        format_text(
+            TextHistoryType::TextGenerator,
+            TextHistory::TextGenerator(TextGeneratorHistory {
+                generator_type_id,
+                generator_contents: Some(vec![1, 2, 3, 4]),
+            }),
+        ),
+        format_text(
+            TextHistoryType::TextGenerator,
+            TextHistory::TextGenerator(TextGeneratorHistory {
+                generator_type_id: none,
+                generator_contents: None,
+            }),
+        ),
+    ]
+}
+
+fn get_cdo(
+    asset: &mut Asset<Cursor<Vec<u8>>>,
+) -> &mut NormalExport<unreal_asset::types::PackageIndex> {
+    asset
+        .asset_data
+        .exports
+        .get_mut(1)
+        .and_then(|e| cast!(Export, NormalExport, e))
+        .expect("Failed to find cdo export")
+}
+
+fn get_text(asset: &Asset<Cursor<Vec<u8>>>) -> &TextProperty {
+    asset.asset_data.exports[1]
+        .get_normal_export()
+        .and_then(|e| {
+            e.properties.iter().find_map(|e| match e {
+                Property::TextProperty(e) if e.get_name() == TEXT_PROPERTY => Some(e),
+                _ => None,
+            })
+        })
+        .expect("Failed to find text property")
+}
+
+#[test]
+fn text_histories_round_trip() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET.to_vec()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+    shared::verify_binary_equality(TEST_ASSET, None, &mut asset)?;
+
+    let generator_type_id = asset.get_name_map().get_mut().add_fname("TextGenerator");
+    let none = asset.get_name_map().get_mut().add_fname("None");
+
+    for text in text_histories(generator_type_id, none) {
+        let property = get_cdo(&mut asset)
+            .properties
+            .iter_mut()
+            .find_map(|e| match e {
+                Property::TextProperty(e) if e.get_name() == TEXT_PROPERTY => Some(e),
+                _ => None,
+            })
+            .expect("Failed to find text property");
+        property.flags = text.flags;
+        property.history_type = text.history_type;
+        property.history = text.history.clone();
+        property.table_id = None;
+        property.value = None;
+
+        let mut cursor = Cursor::new(Vec::new());
+        asset.write_data(&mut cursor, None)?;
+        let data = cursor.into_inner();
+
+        let mut reparsed = Asset::new(
+            Cursor::new(data.clone()),
+            None,
+            EngineVersion::VER_UE4_23,
+            None,
+        )?;
+        assert!(shared::verify_all_exports_parsed(&reparsed));
+        assert_eq!(get_text(&reparsed).to_text(), text);
+        shared::verify_binary_equality(&data, None, &mut reparsed)?;
+    }
+
+    Ok(())
+}
+
+#[test]
+fn text_history_mismatch() -> Result<(), Error> {
+    let mut asset = Asset::new(
+        Cursor::new(TEST_ASSET.to_vec()),
+        None,
+        EngineVersion::VER_UE4_23,
+        None,
+    )?;
+
+    let property = get_cdo(&mut asset)
+        .properties
+        .iter_mut()
+        .find_map(|e| match e {
+            Property::TextProperty(e) if e.get_name() == TEXT_PROPERTY => Some(e),
+            _ => None,
+        })
+        .expect("Failed to find text property");
+    property.history_type = TextHistoryType::AsDate;
+
+    let mut cursor = Cursor::new(Vec::new());
+    assert!(asset.write_data(&mut cursor, None).is_err());
+
+    Ok(())
+}
@@ -0,0 +1,431 @@
+use std::io::Cursor;
+
+use unreal_asset::containers::IndexedMap;
+use unreal_asset::custom_version::CustomVersion;
+use unreal_asset::engine_version::{self, EngineVersion};
+use unreal_asset::error::{Error, UsmapError, UsmapValidationError};
+use unreal_asset::object_version::{ObjectVersion, ObjectVersionUE5};
+use unreal_asset::unversioned::{
+    properties::{
+        array_property::UsmapArrayPropertyData, enum_property::UsmapEnumPropertyData,
+        map_property::UsmapMapPropertyData, optional_property::UsmapOptionalPropertyData,
+        set_property::UsmapSetPropertyData, shallow_property::UsmapShallowPropertyData,
+        struct_property::UsmapStructPropertyData, EPropertyType, UsmapProperty, UsmapPropertyData,
+    },
+    usmap_merge::{UsmapConflict, UsmapEntryKind, UsmapMergeStrategy},
+    EUsmapCompressionMethod, EUsmapVersion, Usmap, UsmapExtensionVersion, UsmapSchema,
+};
+use unreal_asset::Guid;
+
+/// Mappings with the core usmap layout shared by usmap dumpers: an uncompressed initial version file
+/// with an enum, schemas without a super type and array, struct, enum, map and static array properties
+const MAPPINGS: &[u8] = include_bytes!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/assets/usmap/Mappings.usmap"
+));
+
+fn shallow(property_type: EPropertyType) -> UsmapPropertyData {
+    UsmapShallowPropertyData { property_type }.into()
+}
+
+/// Build a schema, expanding properties with an array size the same way they are read
+fn schema(
+    name: &str,
+    super_type: &str,
+    module_path: Option<&str>,
+    properties: Vec<(&str, u8, UsmapPropertyData)>,
+) -> UsmapSchema {
+    let mut map = IndexedMap::new();
+    let mut schema_index = 0;
+    for (property_name, array_size, property_data) in properties {
+        for array_index in 0..array_size as u16 {
+            let property = UsmapProperty {
+                name: property_name.to_string(),
+                schema_index,
+                array_size,
+                array_index,
+                property_data: property_data.clone(),
+            };
+            map.insert((property.name.clone(), schema_index as u32), property);
+            schema_index += 1;
+        }
+    }
+
+    UsmapSchema {
+        name: name.to_string(),
+        super_type: super_type.to_string(),
+        prop_count: schema_index,
+        module_path: module_path.map(|e| e.to_string()),
+        properties: map,
+    }
+}
+
+fn build_usmap(version: EUsmapVersion, extension_version: UsmapExtensionVersion) -> Usmap {
+    let paths = extension_version.contains(UsmapExtensionVersion::PATHS);
+    let module_path = |path: &'static str| paths.then_some(path);
+
+    let mut enum_map = IndexedMap::new();
+    enum_map.insert(
+        "EColor".to_string(),
+        vec![
+            "EColor::Red".to_string(),
+            "EColor::Green".to_string(),
+            "EColor::EColor_MAX".to_string(),
+        ],
+    );
+
+    let mut schemas = IndexedMap::new();
+    for schema in [
+        schema("Object", "", module_path("/Script/CoreUObject"), vec![]),
+        schema(
+            "Vector",
+            "",
+            module_path("/Script/CoreUObject"),
+            vec![
+                ("X", 1, shallow(EPropertyType::DoubleProperty)),
+                ("Y", 1, shallow(EPropertyType::DoubleProperty)),
+                ("Z", 1, shallow(EPropertyType::DoubleProperty)),
+            ],
+        ),
+        schema(
+            "TestActor",
+            "Object",
+            module_path("/Script/TestGame"),
+            vec![
+                ("Health", 1, shallow(EPropertyType::FloatProperty)),
+                ("Slots", 3, shallow(EPropertyType::IntProperty)),
+                (
+                    "Location",
+                    1,
+                    UsmapStructPropertyData {
+                        struct_type: "Vector".to_string(),
+                    }
+                    .into(),
+                ),
+                (
+                    "Color",
+                    1,
+                    UsmapEnumPropertyData {
+                        inner_property: Box::new(shallow(EPropertyType::ByteProperty)),
+                        name: "EColor".to_string(),
+                    }
+                    .into(),
+                ),
+                (
+                    "Names",
+                    1,
+                    UsmapArrayPropertyData {
+                        inner_type: Box::new(shallow(EPropertyType::NameProperty)),
+                    }
+                    .into(),
+                ),
+                (
+                    "Tags",
+                    1,
+                    UsmapSetPropertyData {
+                        inner_type: Box::new(shallow(EPropertyType::StrProperty)),
+                    }
+                    .into(),
+                ),
+                (
+                    "Lookup",
+                    1,
+                    UsmapMapPropertyData {
+                        inner_type: Box::new(shallow(EPropertyType::NameProperty)),
+                        value_type: Box::new(
+                            UsmapStructPropertyData {
+                                struct_type: "Vector".to_string(),
+                            }
+                            .into(),
+                        ),
+                    }
+                    .into(),
+                ),
+                (
+                    "Target",
+                    1,
+                    UsmapOptionalPropertyData {
+                        inner_type: Box::new(
+                            UsmapStructPropertyData {
+                                struct_type: "Vector".to_string(),
+                            }
+                            .into(),
+                        ),
+                    }
+                    .into(),
+                ),
+            ],
+        ),
+    ] {
+        schemas.insert(schema.name.clone(), schema);
+    }
+
+    let (object_version, object_version_ue5, custom_versions) = match version {
+        EUsmapVersion::Initial => (ObjectVersion::UNKNOWN, ObjectVersionUE5::UNKNOWN, vec![]),
+        _ => {
+            let (object_version, object_version_ue5) =
+                engine_version::get_object_versions(EngineVersion::VER_UE5_1);
+            (
+                object_version,
+                object_version_ue5,
+                vec![CustomVersion::new(Guid([1; 16]), 3)],
+            )
+        }
+    };
+
+    Usmap {
+        version,
+        name_map: Vec::new(),
+        enum_map,
+        schemas,
+        extension_version,
+        object_version,
+        object_version_ue5,
+        custom_versions,
+        compression_method: EUsmapCompressionMethod::None,
+        net_cl: match version {
+            EUsmapVersion::Initial => 0,
+            _ => 12345,
+        },
+    }
+}
+
+fn write(usmap: &Usmap) -> Result<Vec<u8>, Error> {
+    let mut cursor = Cursor::new(Vec::new());
+    usmap.write(&mut cursor)?;
+    Ok(cursor.into_inner())
+}
+
+#[test]
+fn write_usmap() -> Result<(), Error> {
+    for version in [
+        EUsmapVersion::Initial,
+        EUsmapVersion::PackageVersioning,
+        EUsmapVersion::Latest,
+    ] {
+        for extension_version in [UsmapExtensionVersion::NONE, UsmapExtensionVersion::PATHS] {
+            for compression_method in [
+                EUsmapCompressionMethod::None,
+                EUsmapCompressionMethod::Brotli,
+                EUsmapCompressionMethod::ZStandard,
+            ] {
+                let mut usmap = build_usmap(version, extension_version);
+                usmap.compression_method = compression_method;
+
+                let data = write(&usmap)?;
+                let read = Usmap::new(Cursor::new(data.clone()))?;
+
+                // names referenced by the usmap are added to the name map
+                assert!(read.name_map.iter().any(|e| e == "EColor::Green"));
+                assert!(!read.name_map.iter().any(|e| e == "/Script/TestGame"));
+                usmap.name_map = read.name_map.clone();
+                assert_eq!(read, usmap);
+
+                // writing a read usmap gives back the same file
+                assert_eq!(write(&read)?, data);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn read_write_mappings() -> Result<(), Error> {
+    let usmap = Usmap::new(Cursor::new(MAPPINGS.to_vec()))?;
+    assert_eq!(usmap.version, EUsmapVersion::Initial);
+    assert_eq!(usmap.name_map.len(), 20);
+    assert_eq!(
+        usmap.enum_map.get_by_key("EMovementMode").map(|e| e.len()),
+        Some(4)
+    );
+
+    let object = usmap.schemas.get_by_key("Object").expect("Missing Object");
+    assert_eq!(object.super_type, "");
+
+    let actor = usmap.schemas.get_by_key("Actor").expect("Missing Actor");
+    assert_eq!(actor.super_type, "Object");
+    assert_eq!(actor.prop_count, 9);
+    assert_eq!(actor.properties.len(), 9);
+    let slots = actor.get_property("Slots", 8).expect("Missing Slots");
+    assert_eq!((slots.schema_index, slots.array_index), (8, 2));
+    assert!(matches!(
+        &actor.get_property("MovementMode", 4).expect("Missing MovementMode").property_data,
+        UsmapPropertyData::UsmapEnumPropertyData(e) if e.name == "EMovementMode"
+    ));
+
+    assert_eq!(write(&usmap)?, MAPPINGS);
+
+    Ok(())
+}
+
+#[test]
+fn write_existing_name_map() -> Result<(), Error> {
+    let mut usmap = build_usmap(EUsmapVersion::Latest, UsmapExtensionVersion::NONE);
+    usmap.name_map = vec!["Unused".to_string(), "Vector".to_string()];
+
+    let read = Usmap::new(Cursor::new(write(&usmap)?))?;
+    assert_eq!(read.name_map[..2], usmap.name_map);
+    assert_eq!(read.name_map.iter().filter(|e| *e == "Vector").count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn write_unsupported() {
+    let mut usmap = build_usmap(EUsmapVersion::Latest, UsmapExtensionVersion::NONE);
+    usmap.compression_method = EUsmapCompressionMethod::Oodle;
+    assert!(matches!(
+        write(&usmap),
+        Err(Error::Usmap(UsmapError::UnsupportedCompression(1)))
+    ));
+
+    let mut usmap = build_usmap(EUsmapVersion::Latest, UsmapExtensionVersion::NONE);
+    usmap
+        .enum_map
+        .insert("ELong".to_string(), vec!["a".repeat(256)]);
+    assert!(write(&usmap).is_err());
+}
+
+/// Build a patched version of the test usmap
+fn patched_usmap() -> Usmap {
+    let mut usmap = build_usmap(EUsmapVersion::Latest, UsmapExtensionVersion::NONE);
+
+    usmap
+        .enum_map
+        .get_by_key_mut("EColor")
+        .unwrap()
+        .insert(2, "EColor::Blue".to_string());
+    usmap.enum_map.insert(
+        "EMode".to_string(),
+        vec!["EMode::A".to_string(), "EMode::EMode_MAX".to_string()],
+    );
+
+    usmap.schemas.insert(
+        "TestActor".to_string(),
+        schema(
+            "TestActor",
+            "Object",
+            None,
+            vec![
+                ("Health", 1, shallow(EPropertyType::DoubleProperty)),
+                ("Slots", 3, shallow(EPropertyType::IntProperty)),
+                ("Armor", 1, shallow(EPropertyType::FloatProperty)),
+            ],
+        ),
+    );
+    usmap.schemas.remove_by_key("Vector");
+    usmap
+        .schemas
+        .insert("Rotator".to_string(), schema("Rotator", "", None, vec![]));
+
+    usmap
+}
+
+#[test]
+fn diff_usmap() {
+    let old = build_usmap(EUsmapVersion::Latest, UsmapExtensionVersion::NONE);
+    let new = patched_usmap();
+
+    assert!(old.diff(&old).is_empty());
+
+    let diff = old.diff(&new);
+    assert_eq!(diff.added_enums, ["EMode"]);
+    assert!(diff.removed_enums.is_empty());
+    assert_eq!(diff.changed_enums, ["EColor"]);
+    assert_eq!(diff.added_schemas, ["Rotator"]);
+    assert_eq!(diff.removed_schemas, ["Vector"]);
+
+    assert_eq!(diff.changed_schemas.len(), 1);
+    let schema_diff = &diff.changed_schemas[0];
+    assert_eq!(schema_diff.name, "TestActor");
+    assert_eq!(schema_diff.super_type, None);
+    assert_eq!(schema_diff.prop_count, Some((10, 5)));
+    assert_eq!(schema_diff.added_properties, ["Armor"]);
+    assert_eq!(
+        schema_diff.removed_properties,
+        ["Location", "Color", "Names", "Tags", "Lookup", "Target"]
+    );
+    assert_eq!(schema_diff.changed_properties, ["Health"]);
+
+    let reverse = new.diff(&old);
+    assert_eq!(reverse.added_schemas, diff.removed_schemas);
+    assert_eq!(reverse.removed_enums, diff.added_enums);
+}
+
+#[test]
+fn merge_usmap() {
+    let base = build_usmap(EUsmapVersion::Latest, UsmapExtensionVersion::NONE);
+    let patched = patched_usmap();
+    let conflicts = vec![
+        UsmapConflict {
+            kind: UsmapEntryKind::Enum,
+            name: "EColor".to_string(),
+        },
+        UsmapConflict {
+            kind: UsmapEntryKind::Schema,
+            name: "TestActor".to_string(),
+        },
+    ];
+
+    let mut merged = base.clone();
+    assert_eq!(
+        merged.merge(&patched, UsmapMergeStrategy::KeepExisting),
+        conflicts
+    );
+    assert_eq!(
+        merged.schemas.get_by_key("TestActor"),
+        base.schemas.get_by_key("TestActor")
+    );
+    assert!(merged.schemas.contains_key(&"Vector".to_string()));
+    assert!(merged.schemas.contains_key(&"Rotator".to_string()));
+    assert!(merged.enum_map.contains_key(&"EMode".to_string()));
+
+    let mut merged = base.clone();
+    assert_eq!(
+        merged.merge(&patched, UsmapMergeStrategy::Overwrite),
+        conflicts
+    );
+    assert_eq!(
+        merged.schemas.get_by_key("TestActor"),
+        patched.schemas.get_by_key("TestActor")
+    );
+    assert_eq!(
+        merged.enum_map.get_by_key("EColor"),
+        patched.enum_map.get_by_key("EColor")
+    );
+
+    // merging identical entries has no conflicts
+    assert!(merged
+        .merge(&patched, UsmapMergeStrategy::KeepExisting)
+        .is_empty());
+}
+
+#[test]
+fn validate_usmap() {
+    let usmap = build_usmap(EUsmapVersion::Latest, UsmapExtensionVersion::NONE);
+    assert!(usmap.validate().is_ok());
+
+    let mut usmap = usmap;
+    usmap.schemas.remove_by_key("Vector");
+    usmap.schemas.remove_by_key("Object");
+    usmap.enum_map.remove_by_key("EColor");
+    usmap
+        .schemas
+        .insert("Loop".to_string(), schema("Loop", "Loop", None, vec![]));
+
+    let Err(Error::Usmap(UsmapError::InvalidMappings(errors))) = usmap.validate() else {
+        panic!("expected invalid mappings");
+    };
+    assert_eq!(
+        errors.as_ref(),
+        [
+            UsmapValidationError::unresolved_super_type("TestActor", "Object"),
+            UsmapValidationError::unresolved_struct("TestActor", "Location", "Vector"),
+            UsmapValidationError::unresolved_enum("TestActor", "Color", "EColor"),
+            UsmapValidationError::unresolved_struct("TestActor", "Lookup", "Vector"),
+            UsmapValidationError::unresolved_struct("TestActor", "Target", "Vector"),
+            UsmapValidationError::cyclic_super_type("Loop"),
+        ]
+    );
+}
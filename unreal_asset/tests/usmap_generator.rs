@@ -0,0 +1,177 @@
+use std::fs::File;
+use std::io::Cursor;
+
+use unreal_asset::containers::IndexedMap;
+use unreal_asset::engine_version::EngineVersion;
+use unreal_asset::error::Error;
+use unreal_asset::object_version::{ObjectVersion, ObjectVersionUE5};
+use unreal_asset::unversioned::{
+    properties::{EPropertyType, UsmapPropertyData},
+    EUsmapCompressionMethod, EUsmapVersion, Usmap, UsmapExtensionVersion,
+};
+use unreal_asset::usmap_generator::UsmapGenerator;
+use unreal_asset::Asset;
+
+fn load_asset(path: &str, bulk: bool, engine_version: EngineVersion) -> Result<Asset<File>, Error> {
+    let asset_file = File::open(format!("{path}.uasset"))?;
+    let bulk_file = match bulk {
+        true => Some(File::open(format!("{path}.uexp"))?),
+        false => None,
+    };
+    Asset::new(asset_file, bulk_file, engine_version, None)
+}
+
+fn empty_usmap() -> Usmap {
+    Usmap {
+        version: EUsmapVersion::Latest,
+        name_map: Vec::new(),
+        enum_map: IndexedMap::new(),
+        schemas: IndexedMap::new(),
+        extension_version: UsmapExtensionVersion::PATHS,
+        object_version: ObjectVersion::UNKNOWN,
+        object_version_ue5: ObjectVersionUE5::UNKNOWN,
+        custom_versions: Vec::new(),
+        compression_method: EUsmapCompressionMethod::None,
+        net_cl: 0,
+    }
+}
+
+fn shallow_type(property_data: &UsmapPropertyData) -> Option<EPropertyType> {
+    match property_data {
+        UsmapPropertyData::UsmapShallowPropertyData(data) => Some(data.property_type),
+        _ => None,
+    }
+}
+
+#[test]
+fn user_defined_struct() -> Result<(), Error> {
+    let asset = load_asset(
+        "tests/assets/user_defined_struct/achievements_STRUCT_entry",
+        true,
+        EngineVersion::VER_UE4_26,
+    )?;
+
+    let mut generator = UsmapGenerator::new();
+    generator.add_asset(&asset, Some("/Game/achievements_STRUCT_entry"))?;
+
+    let schema = generator
+        .schemas
+        .get_by_key(&"achievements_STRUCT_entry".to_string())
+        .expect("missing schema");
+    assert_eq!(schema.super_type, "");
+    assert_eq!(schema.prop_count, 2);
+    assert_eq!(
+        schema.module_path.as_deref(),
+        Some("/Game/achievements_STRUCT_entry")
+    );
+
+    let types = schema
+        .properties
+        .values()
+        .map(|e| shallow_type(&e.property_data))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        types,
+        [
+            Some(EPropertyType::StrProperty),
+            Some(EPropertyType::IntProperty)
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn blueprint_class() -> Result<(), Error> {
+    let asset = load_asset(
+        "tests/assets/unknown_properties/BP_DetPack_Charge",
+        true,
+        EngineVersion::VER_UE4_25,
+    )?;
+
+    let mut generator = UsmapGenerator::new();
+    generator.add_asset(&asset, None)?;
+
+    let schema = generator
+        .schemas
+        .get_by_key(&"BP_DetPack_Charge_C".to_string())
+        .expect("missing schema");
+    assert_eq!(schema.super_type, "DetPack");
+    assert_eq!(schema.prop_count as usize, schema.properties.len());
+
+    let direction = schema
+        .properties
+        .values()
+        .find(|e| e.name.starts_with("SphereTimeline__Direction"))
+        .expect("missing property");
+    match &direction.property_data {
+        UsmapPropertyData::UsmapEnumPropertyData(data) => {
+            assert_eq!(data.name, "ETimelineDirection");
+            assert_eq!(
+                shallow_type(&data.inner_property),
+                Some(EPropertyType::ByteProperty)
+            );
+        }
+        _ => panic!("expected an enum property"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn legacy_property_exports() -> Result<(), Error> {
+    let asset = load_asset(
+        "tests/assets/general/Astroneer_prebulk/DebugMenu",
+        false,
+        EngineVersion::VER_UE4_23,
+    )?;
+
+    let mut generator = UsmapGenerator::new();
+    generator.add_asset(&asset, None)?;
+
+    let schema = generator
+        .schemas
+        .get_by_key(&"DebugMenu_C".to_string())
+        .expect("missing schema");
+    assert_eq!(schema.super_type, "UserWidget");
+    assert!(schema.prop_count > 0);
+    assert!(schema
+        .properties
+        .values()
+        .any(|e| shallow_type(&e.property_data) == Some(EPropertyType::ObjectProperty)));
+
+    Ok(())
+}
+
+#[test]
+fn merge_and_write() -> Result<(), Error> {
+    let mut generator = UsmapGenerator::new();
+    for (path, engine_version) in [
+        (
+            "tests/assets/user_defined_struct/achievements_STRUCT_entry",
+            EngineVersion::VER_UE4_26,
+        ),
+        (
+            "tests/assets/unknown_properties/BP_DetPack_Charge",
+            EngineVersion::VER_UE4_25,
+        ),
+    ] {
+        let module_path = path.replace("tests/assets", "/Game");
+        generator.add_asset(&load_asset(path, true, engine_version)?, Some(&module_path))?;
+    }
+
+    let mut usmap = empty_usmap();
+    let added = generator.merge_into(&mut usmap);
+    assert_eq!(added, generator.schemas.len() + generator.enum_map.len());
+    assert_eq!(generator.merge_into(&mut usmap), 0);
+
+    let mut cursor = Cursor::new(Vec::new());
+    usmap.write(&mut cursor)?;
+    let read = Usmap::new(Cursor::new(cursor.into_inner()))?;
+
+    for (_, name, schema) in generator.schemas.iter() {
+        assert_eq!(read.schemas.get_by_key(name), Some(schema));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "json")]
+
+use std::io::Cursor;
+
+use unreal_asset::error::{Error, UsmapError};
+use unreal_asset::unversioned::{
+    properties::{EPropertyType, UsmapPropertyData, UsmapPropertyDataTrait},
+    Usmap, UsmapExtensionVersion,
+};
+
+const MAPPINGS: &str = r#"{
+  "enums": [
+    { "name": "EColor", "values": ["EColor::Red", "EColor::Green", "EColor::EColor_MAX"] }
+  ],
+  "schemas": [
+    { "name": "Vector", "module_path": "/Script/CoreUObject", "properties": [
+      { "name": "X", "type": { "type": "DoubleProperty" } },
+      { "name": "Y", "type": { "type": "DoubleProperty" } },
+      { "name": "Z", "type": { "type": "DoubleProperty" } }
+    ] },
+    { "name": "TestActor", "super_type": "Vector", "module_path": "/Script/TestGame", "properties": [
+      { "name": "Health", "type": { "type": "FloatProperty" } },
+      { "name": "Slots", "array_size": 2, "type": { "type": "IntProperty" } },
+      { "name": "Location", "schema_index": 5, "type": { "type": "StructProperty", "struct_type": "Vector" } },
+      { "name": "Colors", "type": { "type": "ArrayProperty", "inner": {
+        "type": "EnumProperty", "enum_name": "EColor", "inner": { "type": "ByteProperty" }
+      } } },
+      { "name": "Lookup", "type": { "type": "MapProperty",
+        "key": { "type": "NameProperty" },
+        "value": { "type": "SetProperty", "inner": { "type": "StrProperty" } }
+      } },
+      { "name": "Target", "type": { "type": "OptionalProperty", "inner": {
+        "type": "StructProperty", "struct_type": "Vector"
+      } } }
+    ] }
+  ]
+}"#;
+
+#[test]
+fn read_json() -> Result<(), Error> {
+    let usmap = Usmap::from_json(MAPPINGS.as_bytes())?;
+
+    assert_eq!(usmap.extension_version, UsmapExtensionVersion::PATHS);
+    assert_eq!(
+        usmap.enum_map.get_by_key("EColor").map(|e| e.len()),
+        Some(3)
+    );
+    assert_eq!(
+        usmap.schemas.keys().collect::<Vec<_>>(),
+        ["Vector", "TestActor"]
+    );
+
+    let schema = usmap.schemas.get_by_key("TestActor").unwrap();
+    assert_eq!(schema.super_type, "Vector");
+    assert_eq!(schema.module_path.as_deref(), Some("/Script/TestGame"));
+    // schema indices continue after explicitly specified ones
+    assert_eq!(schema.prop_count, 9);
+    assert_eq!(schema.properties.len(), 7);
+
+    let slot = schema.get_property("Slots", 2).unwrap();
+    assert_eq!((slot.array_size, slot.array_index), (2, 1));
+    assert!(matches!(
+        &slot.property_data,
+        UsmapPropertyData::UsmapShallowPropertyData(e) if e.property_type == EPropertyType::IntProperty
+    ));
+    assert!(schema.get_property("Location", 5).is_some());
+    assert!(schema.get_property("Colors", 6).is_some());
+    assert!(schema.get_property("Lookup", 7).is_some());
+    assert!(matches!(
+        &schema.get_property("Target", 8).unwrap().property_data,
+        UsmapPropertyData::UsmapOptionalPropertyData(e)
+            if e.inner_type.get_property_type() == EPropertyType::StructProperty
+    ));
+
+    usmap.validate()
+}
+
+#[test]
+fn json_round_trip() -> Result<(), Error> {
+    let usmap = Usmap::from_json(MAPPINGS.as_bytes())?;
+
+    let mut json = Vec::new();
+    usmap.write_json(&mut json)?;
+    assert_eq!(Usmap::from_json(json.as_slice())?, usmap);
+
+    // json mappings can be written as a binary usmap
+    let mut cursor = Cursor::new(Vec::new());
+    usmap.write(&mut cursor)?;
+    let binary = Usmap::new(Cursor::new(cursor.into_inner()))?;
+    assert!(usmap.diff(&binary).is_empty());
+
+    let mut from_binary = Vec::new();
+    binary.write_json(&mut from_binary)?;
+    assert_eq!(from_binary, json);
+
+    Ok(())
+}
+
+#[test]
+fn invalid_json() {
+    for json in [
+        "{",
+        r#"{ "schemas": [ { "name": "A", "properties": [ { "name": "B", "type": { "type": "FooProperty" } } ] } ] }"#,
+        r#"{ "schemas": [ { "name": "A", "properties": [ { "name": "B", "type": { "type": "StructProperty" } } ] } ] }"#,
+        r#"{ "schemas": [ { "name": "A", "properties": [ { "name": "B", "type": { "type": "MapProperty", "key": { "type": "IntProperty" } } } ] } ] }"#,
+    ] {
+        assert!(matches!(
+            Usmap::from_json(json.as_bytes()),
+            Err(Error::Usmap(UsmapError::InvalidJson(_)))
+        ));
+    }
+}
@@ -0,0 +1,571 @@
+use std::collections::HashMap;
+use std::io::{Cursor, Seek, SeekFrom};
+
+use byteorder::{WriteBytesExt, LE};
+
+use unreal_asset::containers::SharedResource;
+use unreal_asset::engine_version::EngineVersion;
+use unreal_asset::enums::{EZenPackageVersion, HASH_VERSION_CITYHASH64};
+use unreal_asset::error::Error;
+use unreal_asset::exports::{base_export::EExportFilterFlags, ExportBaseTrait, ExportNormalTrait};
+use unreal_asset::flags::EObjectFlags;
+use unreal_asset::object_version::{ObjectVersion, ObjectVersionUE5};
+use unreal_asset::properties::Property;
+use unreal_asset::reader::ArchiveTrait;
+use unreal_asset::types::{fname::EMappedNameType, PackageIndex, PackageObjectIndex};
+use unreal_asset::zen::{
+    MappedName, ScriptObjectEntry, ScriptObjects, ZenAsset, ZenExportMapEntry,
+};
+use unreal_asset::{cast, crc, Asset};
+
+const NAMES: [&str; 6] = [
+    "/Game/Test",
+    "None",
+    "IntProperty",
+    "MyInt",
+    "TestObject",
+    "Other",
+];
+
+fn write_name_batch(buf: &mut Vec<u8>, names: &[&str]) {
+    buf.write_u32::<LE>(names.len() as u32).unwrap();
+    if names.is_empty() {
+        return;
+    }
+    buf.write_u32::<LE>(names.iter().map(|e| e.len() as u32).sum())
+        .unwrap();
+    buf.write_u64::<LE>(HASH_VERSION_CITYHASH64).unwrap();
+    for name in names {
+        buf.write_u64::<LE>(crc::cityhash64_to_lower_name(name, false))
+            .unwrap();
+    }
+    for name in names {
+        buf.write_u8((name.len() >> 8) as u8).unwrap();
+        buf.write_u8(name.len() as u8).unwrap();
+    }
+    for name in names {
+        buf.extend_from_slice(name.as_bytes());
+    }
+}
+
+fn write_name(buf: &mut Vec<u8>, name: &str) {
+    let index = NAMES.iter().position(|e| *e == name).unwrap();
+    buf.write_u32::<LE>(index as u32).unwrap();
+    buf.write_u32::<LE>(0).unwrap();
+}
+
+fn build_script_objects() -> Vec<u8> {
+    let mut buf = Vec::new();
+    write_name_batch(&mut buf, &["/Script/CoreUObject", "Object"]);
+    buf.write_i32::<LE>(2).unwrap();
+
+    let package = PackageObjectIndex::from_script_path("/Script/CoreUObject");
+    let class = PackageObjectIndex::from_script_path("/Script/CoreUObject.Object");
+    for (name, global_index, outer_index) in [
+        (0u32, package, PackageObjectIndex::null()),
+        (1u32, class, package),
+    ] {
+        buf.write_u32::<LE>(name | (2 << 30)).unwrap();
+        buf.write_u32::<LE>(0).unwrap();
+        buf.write_u64::<LE>(global_index.type_and_id).unwrap();
+        buf.write_u64::<LE>(outer_index.type_and_id).unwrap();
+        buf.write_u64::<LE>(PackageObjectIndex::null().type_and_id)
+            .unwrap();
+    }
+
+    buf
+}
+
+/// Builds a versioned zen package with two exports of class `/Script/CoreUObject.Object`
+fn build_package(zen_version: EZenPackageVersion) -> Vec<u8> {
+    let class = PackageObjectIndex::from_script_path("/Script/CoreUObject.Object");
+
+    // export data, the second export is serialized first
+    let mut other_data = Vec::new();
+    write_name(&mut other_data, "None");
+
+    let mut test_data = Vec::new();
+    write_name(&mut test_data, "MyInt");
+    write_name(&mut test_data, "IntProperty");
+    test_data.write_i32::<LE>(4).unwrap();
+    test_data.write_i32::<LE>(0).unwrap();
+    test_data.write_u8(0).unwrap();
+    test_data.write_i32::<LE>(42).unwrap();
+    write_name(&mut test_data, "None");
+    test_data.write_i32::<LE>(0).unwrap();
+
+    let new_layout = zen_version >= EZenPackageVersion::ImportedPackageNames;
+    let summary_size = match new_layout {
+        true => 52,
+        false => 44,
+    };
+
+    let mut header = vec![0u8; summary_size];
+
+    // versioning info
+    header.write_u32::<LE>(zen_version.into()).unwrap();
+    header
+        .write_i32::<LE>(ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG.into())
+        .unwrap();
+    header
+        .write_i32::<LE>(ObjectVersionUE5::DATA_RESOURCES.into())
+        .unwrap();
+    header.write_i32::<LE>(0).unwrap();
+    header.write_i32::<LE>(0).unwrap();
+
+    write_name_batch(&mut header, &NAMES);
+
+    // bulk data map
+    header.write_u64::<LE>(0).unwrap();
+
+    let imported_public_export_hashes_offset = header.len();
+    let import_map_offset = header.len();
+    header.write_u64::<LE>(class.type_and_id).unwrap();
+
+    let export_map_offset = header.len();
+    for (name, offset, size) in [
+        ("TestObject", 0, test_data.len()),
+        ("Other", test_data.len(), other_data.len()),
+    ] {
+        header.write_u64::<LE>(offset as u64).unwrap();
+        header.write_u64::<LE>(size as u64).unwrap();
+        write_name(&mut header, name);
+        header
+            .write_u64::<LE>(PackageObjectIndex::null().type_and_id)
+            .unwrap();
+        header.write_u64::<LE>(class.type_and_id).unwrap();
+        header
+            .write_u64::<LE>(PackageObjectIndex::null().type_and_id)
+            .unwrap();
+        header
+            .write_u64::<LE>(PackageObjectIndex::null().type_and_id)
+            .unwrap();
+        header.write_u64::<LE>(0).unwrap();
+        header.write_u32::<LE>(0).unwrap();
+        header.write_u8(0).unwrap();
+        header.extend_from_slice(&[0u8; 3]);
+    }
+
+    let export_bundle_entries_offset = header.len();
+    for (export, command) in [(0u32, 0u32), (1, 0), (1, 1), (0, 1)] {
+        header.write_u32::<LE>(export).unwrap();
+        header.write_u32::<LE>(command).unwrap();
+    }
+
+    let mut offsets = [0i32; 4];
+    match new_layout {
+        true => {
+            offsets[1] = header.len() as i32;
+            // TestObject depends on import 0 being created before it's created and on Other being serialized before it's serialized
+            header.write_i32::<LE>(0).unwrap();
+            for count in [1u32, 0, 0, 1] {
+                header.write_u32::<LE>(count).unwrap();
+            }
+            header.write_i32::<LE>(-1).unwrap();
+            for count in [0u32, 0, 0, 0] {
+                header.write_u32::<LE>(count).unwrap();
+            }
+
+            offsets[2] = header.len() as i32;
+            header.write_i32::<LE>(-1).unwrap();
+            header.write_i32::<LE>(2).unwrap();
+
+            offsets[3] = header.len() as i32;
+            write_name_batch(&mut header, &["/Script/CoreUObject"]);
+            header.write_i32::<LE>(0).unwrap();
+        }
+        false => {
+            offsets[0] = header.len() as i32;
+            header.write_u64::<LE>(0).unwrap();
+            header.write_u32::<LE>(0).unwrap();
+            header.write_u32::<LE>(4).unwrap();
+
+            // internal arcs
+            header.write_i32::<LE>(0).unwrap();
+
+            // external arcs of a single imported package
+            header.write_i32::<LE>(1).unwrap();
+            header.write_i32::<LE>(0).unwrap();
+            header.write_u8(0).unwrap();
+            header.write_i32::<LE>(0).unwrap();
+        }
+    }
+
+    let header_size = header.len();
+
+    let mut summary = Vec::new();
+    summary.write_u32::<LE>(1).unwrap();
+    summary.write_u32::<LE>(header_size as u32).unwrap();
+    write_name(&mut summary, "/Game/Test");
+    summary.write_u32::<LE>(0).unwrap();
+    summary.write_u32::<LE>(0).unwrap();
+    summary
+        .write_i32::<LE>(imported_public_export_hashes_offset as i32)
+        .unwrap();
+    summary.write_i32::<LE>(import_map_offset as i32).unwrap();
+    summary.write_i32::<LE>(export_map_offset as i32).unwrap();
+    summary
+        .write_i32::<LE>(export_bundle_entries_offset as i32)
+        .unwrap();
+    match new_layout {
+        true => {
+            for offset in &offsets[1..] {
+                summary.write_i32::<LE>(*offset).unwrap();
+            }
+        }
+        false => summary.write_i32::<LE>(offsets[0]).unwrap(),
+    }
+    header[..summary_size].copy_from_slice(&summary);
+
+    header.extend_from_slice(&other_data);
+    header.extend_from_slice(&test_data);
+    header
+}
+
+fn read_package(zen_version: EZenPackageVersion) -> Result<ZenAsset<Cursor<Vec<u8>>>, Error> {
+    let script_objects = ScriptObjects::from_buffer(&build_script_objects())?;
+    ZenAsset::new(
+        Cursor::new(build_package(zen_version)),
+        EngineVersion::UNKNOWN,
+        None,
+        Some(SharedResource::new(script_objects)),
+    )
+}
+
+#[test]
+fn read_zen_package() -> Result<(), Error> {
+    let asset = read_package(EZenPackageVersion::DataResourceTable)?;
+
+    assert_eq!(asset.zen_version, EZenPackageVersion::DataResourceTable);
+    assert_eq!(
+        asset.get_object_version_ue5(),
+        ObjectVersionUE5::DATA_RESOURCES
+    );
+    assert_eq!(asset.package_name.get_owned_content(), "/Game/Test");
+    assert_eq!(asset.import_map.len(), 1);
+    assert_eq!(asset.export_bundle_headers.len(), 1);
+    assert_eq!(asset.external_arcs.len(), 1);
+    assert_eq!(
+        asset
+            .get_object_name_packageindex(PackageIndex::new(-1))
+            .map(|e| e.get_owned_content()),
+        Some("Object".to_string())
+    );
+
+    assert_eq!(asset.asset_data.exports.len(), 2);
+
+    let test_object = asset.asset_data.exports[0]
+        .get_normal_export()
+        .expect("TestObject is not a normal export");
+    assert_eq!(
+        test_object.base_export.object_name.get_owned_content(),
+        "TestObject"
+    );
+    assert_eq!(test_object.properties.len(), 1);
+    let int_property = cast!(Property, IntProperty, &test_object.properties[0])
+        .expect("MyInt is not an IntProperty");
+    assert_eq!(int_property.value, 42);
+    assert_eq!(test_object.extras, vec![0u8; 4]);
+
+    let other = asset.asset_data.exports[1].get_base_export();
+    assert_eq!(other.object_name.get_owned_content(), "Other");
+    assert_eq!(other.serial_offset, asset.summary.header_size as i64);
+
+    Ok(())
+}
+
+#[test]
+fn read_zen_package_dependency_bundles() -> Result<(), Error> {
+    let asset = read_package(EZenPackageVersion::ImportedPackageNames)?;
+
+    assert_eq!(asset.zen_version, EZenPackageVersion::ImportedPackageNames);
+    assert_eq!(asset.dependency_bundle_headers.len(), 2);
+    assert_eq!(asset.imported_package_names.len(), 1);
+    assert_eq!(
+        asset.imported_package_names[0].get_owned_content(),
+        "/Script/CoreUObject"
+    );
+
+    let test_object = asset.asset_data.exports[0].get_base_export();
+    assert_eq!(
+        test_object.create_before_create_dependencies,
+        vec![PackageIndex::new(-1)]
+    );
+    assert_eq!(
+        test_object.serialization_before_serialization_dependencies,
+        vec![PackageIndex::new(2)]
+    );
+    assert!(test_object
+        .create_before_serialization_dependencies
+        .is_empty());
+
+    assert!(asset.asset_data.exports[0].get_normal_export().is_some());
+
+    Ok(())
+}
+
+#[test]
+fn write_zen_package() -> Result<(), Error> {
+    for zen_version in [
+        EZenPackageVersion::DataResourceTable,
+        EZenPackageVersion::ImportedPackageNames,
+    ] {
+        let asset = read_package(zen_version)?;
+
+        let mut cursor = Cursor::new(Vec::new());
+        asset.write_data(&mut cursor)?;
+
+        assert_eq!(cursor.into_inner(), build_package(zen_version));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn write_edited_zen_package() -> Result<(), Error> {
+    for zen_version in [
+        EZenPackageVersion::DataResourceTable,
+        EZenPackageVersion::ImportedPackageNames,
+    ] {
+        let mut asset = read_package(zen_version)?;
+
+        let test_object = asset.asset_data.exports[0]
+            .get_normal_export_mut()
+            .expect("TestObject is not a normal export");
+        let int_property = cast!(Property, IntProperty, &mut test_object.properties[0])
+            .expect("MyInt is not an IntProperty");
+        int_property.value = 1234;
+        test_object.extras = vec![1u8; 12];
+
+        let mut cursor = Cursor::new(Vec::new());
+        asset.write_data(&mut cursor)?;
+        cursor.seek(SeekFrom::Start(0))?;
+
+        let script_objects = ScriptObjects::from_buffer(&build_script_objects())?;
+        let asset = ZenAsset::new(
+            cursor,
+            EngineVersion::UNKNOWN,
+            None,
+            Some(SharedResource::new(script_objects)),
+        )?;
+
+        let test_object = asset.asset_data.exports[0]
+            .get_normal_export()
+            .expect("TestObject is not a normal export");
+        let int_property = cast!(Property, IntProperty, &test_object.properties[0])
+            .expect("MyInt is not an IntProperty");
+        assert_eq!(int_property.value, 1234);
+        assert_eq!(test_object.extras, vec![1u8; 12]);
+        assert_eq!(
+            asset.export_map[0].cooked_serial_size,
+            asset.export_map[1].cooked_serial_offset
+        );
+
+        let other = asset.asset_data.exports[1].get_base_export();
+        assert_eq!(other.object_name.get_owned_content(), "Other");
+    }
+
+    Ok(())
+}
+
+/// Builds script objects for all `/Script` imports of a legacy asset
+fn build_legacy_script_objects(asset: &Asset<Cursor<&[u8]>>) -> ScriptObjects {
+    let mut script_objects = ScriptObjects::new();
+
+    for i in 0..asset.imports.len() {
+        let mut path = Vec::new();
+        let mut current = asset.get_import(PackageIndex::from_import(i as i32).unwrap());
+        while let Some(import) = current {
+            path.push(import.object_name.get_owned_content());
+            current = asset.get_import(import.outer_index);
+        }
+        path.reverse();
+
+        if !path[0].starts_with("/Script/") {
+            continue;
+        }
+
+        let mut outer_index = PackageObjectIndex::null();
+        for depth in 0..path.len() {
+            let object_path = match depth {
+                0 => path[0].clone(),
+                _ => format!("{}.{}", path[0], path[1..=depth].join(":")),
+            };
+            let global_index = PackageObjectIndex::from_script_path(&object_path);
+            if script_objects.get_entry(global_index).is_none() {
+                let name_index = script_objects
+                    .name_map
+                    .get_mut()
+                    .add_name_reference(path[depth].clone(), false);
+                script_objects.add_entry(ScriptObjectEntry {
+                    object_name: MappedName::new(name_index as u32, 0, EMappedNameType::Global),
+                    global_index,
+                    outer_index,
+                    cdo_class_index: PackageObjectIndex::null(),
+                });
+            }
+            outer_index = global_index;
+        }
+    }
+
+    script_objects
+}
+
+/// Build stand-in zen packages exporting every package import of a legacy asset
+fn build_imported_packages(
+    asset: &Asset<Cursor<&[u8]>>,
+) -> Result<Vec<ZenAsset<Cursor<Vec<u8>>>>, Error> {
+    let mut packages: Vec<ZenAsset<Cursor<Vec<u8>>>> = Vec::new();
+    let mut export_indices = HashMap::new();
+    let mut outers = Vec::new();
+
+    for i in 0..asset.imports.len() {
+        let index = PackageIndex::from_import(i as i32).unwrap();
+        let mut path = Vec::new();
+        let mut current = asset.get_import(index);
+        while let Some(import) = current {
+            path.push(import.object_name.get_owned_content());
+            current = asset.get_import(import.outer_index);
+        }
+        path.reverse();
+
+        if path[0].starts_with("/Script/") || path.len() < 2 {
+            continue;
+        }
+
+        let package_index = match packages
+            .iter()
+            .position(|e| e.package_name.get_owned_content() == path[0])
+        {
+            Some(package_index) => package_index,
+            None => {
+                let mut package = read_package(EZenPackageVersion::Initial)?;
+                package.package_name = package.add_fname(&path[0]);
+                package.export_map.clear();
+                packages.push(package);
+                packages.len() - 1
+            }
+        };
+
+        let import = asset.get_import(index).unwrap();
+        let package = &mut packages[package_index];
+        let object_name = package.add_fname(&import.object_name.get_owned_content());
+        export_indices.insert(index.index, (package_index, package.export_map.len()));
+        outers.push((index.index, import.outer_index.index));
+        package.export_map.push(ZenExportMapEntry {
+            cooked_serial_offset: 0,
+            cooked_serial_size: 0,
+            object_name,
+            outer_index: PackageObjectIndex::null(),
+            class_index: PackageObjectIndex::null(),
+            super_index: PackageObjectIndex::null(),
+            template_index: PackageObjectIndex::null(),
+            public_export_hash: ZenExportMapEntry::get_public_export_hash(&path[1..].join("/")),
+            object_flags: EObjectFlags::RF_PUBLIC,
+            filter_flags: EExportFilterFlags::None,
+        });
+    }
+
+    // outers can come after their inners in the import map
+    for (index, outer_index) in outers {
+        let Some(&(_, outer_export_index)) = export_indices.get(&outer_index) else {
+            continue;
+        };
+        let (package_index, export_index) = export_indices[&index];
+        packages[package_index].export_map[export_index].outer_index =
+            PackageObjectIndex::from_export(outer_export_index as u32);
+    }
+
+    Ok(packages)
+}
+
+#[test]
+fn convert_legacy_asset() -> Result<(), Error> {
+    let asset_data: &[u8] = include_bytes!("assets/ue5/PublicHousingPlot_Root.umap");
+    let bulk_data: &[u8] = include_bytes!("assets/ue5/PublicHousingPlot_Root.uexp");
+    let asset = Asset::new(
+        Cursor::new(asset_data),
+        Some(Cursor::new(bulk_data)),
+        EngineVersion::VER_UE5_1,
+        None,
+    )?;
+    let script_objects = SharedResource::new(build_legacy_script_objects(&asset));
+
+    let zen_asset = asset.to_zen("/Game/PublicHousingPlot_Root", Some(script_objects.clone()))?;
+    assert_eq!(
+        zen_asset.package_name.get_owned_content(),
+        "/Game/PublicHousingPlot_Root"
+    );
+    assert_eq!(zen_asset.import_map.len(), asset.imports.len());
+    assert_eq!(
+        zen_asset.asset_data.exports.len(),
+        asset.asset_data.exports.len()
+    );
+
+    for (i, import) in asset.imports.iter().enumerate() {
+        let zen_import = zen_asset.import_map[i];
+        if zen_import.is_script_import() {
+            assert_eq!(
+                script_objects
+                    .get_ref()
+                    .get_object_name(zen_import)
+                    .map(|e| e.get_owned_content()),
+                Some(import.object_name.get_owned_content())
+            );
+        }
+    }
+
+    for (legacy, zen) in asset
+        .asset_data
+        .exports
+        .iter()
+        .zip(&zen_asset.asset_data.exports)
+    {
+        assert_eq!(
+            legacy.get_base_export().object_name,
+            zen.get_base_export().object_name
+        );
+        assert_eq!(
+            legacy.get_normal_export().map(|e| &e.properties),
+            zen.get_normal_export().map(|e| &e.properties)
+        );
+    }
+
+    let imported_packages = build_imported_packages(&asset)?;
+    let converted = zen_asset.to_asset(&imported_packages.iter().collect::<Vec<_>>())?;
+    assert_eq!(
+        converted.asset_data.exports.len(),
+        asset.asset_data.exports.len()
+    );
+    for (i, import) in asset.imports.iter().enumerate() {
+        if !zen_asset.import_map[i].is_null() {
+            let converted_import = &converted.imports[i];
+            assert_eq!(converted_import.object_name, import.object_name);
+            assert_eq!(
+                converted
+                    .get_import(converted_import.outer_index)
+                    .map(|e| e.object_name.get_owned_content()),
+                asset
+                    .get_import(import.outer_index)
+                    .map(|e| e.object_name.get_owned_content())
+            );
+        }
+    }
+    for (legacy, converted) in asset
+        .asset_data
+        .exports
+        .iter()
+        .zip(&converted.asset_data.exports)
+    {
+        assert_eq!(
+            legacy.get_base_export().class_index,
+            converted.get_base_export().class_index
+        );
+        assert_eq!(
+            legacy.get_normal_export().map(|e| &e.properties),
+            converted.get_normal_export().map(|e| &e.properties)
+        );
+    }
+
+    Ok(())
+}
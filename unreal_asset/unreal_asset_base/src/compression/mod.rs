@@ -1,11 +1,25 @@
-//! Unreal decompression
+//! Unreal compression and decompression
 
-use std::io::Read;
+use std::io::{Read, Write};
 
 use flate2::bufread::{GzDecoder, ZlibDecoder};
+use flate2::write::{GzEncoder, ZlibEncoder};
+use flate2::Compression;
 
 use crate::Error;
 
+#[cfg(feature = "oodle")]
+pub mod oodle;
+
+/// Legacy zlib compression flag
+pub const COMPRESS_ZLIB: u32 = 0x01;
+/// Legacy gzip compression flag
+pub const COMPRESS_GZIP: u32 = 0x02;
+/// Legacy custom compression flag, used for oodle
+pub const COMPRESS_CUSTOM: u32 = 0x04;
+/// Mask of the legacy compression method flags, other flags are compression hints
+pub const COMPRESSION_FLAGS_TYPE_MASK: u32 = 0x0f;
+
 /// Compression method
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub enum CompressionMethod {
@@ -18,6 +32,12 @@ pub enum CompressionMethod {
     Gzip,
     /// Lz4 compression
     Lz4,
+    /// Oodle compression, only decompression is supported and requires the `oodle` feature
+    Oodle,
+    /// Zstandard compression
+    Zstd,
+    /// Brotli compression
+    Brotli,
     /// Unknown compression format
     Unknown(Box<str>),
 }
@@ -30,9 +50,36 @@ impl CompressionMethod {
             "Zlib" => Self::Zlib,
             "Gzip" => Self::Gzip,
             "LZ4" => Self::Lz4,
+            "Oodle" => Self::Oodle,
+            "Zstd" => Self::Zstd,
+            "Brotli" => Self::Brotli,
             _ => Self::Unknown(name.to_string().into_boxed_str()),
         }
     }
+
+    /// Create a new `CompressionMethod` from legacy `ECompressionFlags`
+    ///
+    /// Returns `None` for flags without a compression method
+    pub fn from_legacy_flags(flags: u32) -> Option<Self> {
+        match flags & COMPRESSION_FLAGS_TYPE_MASK {
+            COMPRESS_ZLIB => Some(Self::Zlib),
+            COMPRESS_GZIP => Some(Self::Gzip),
+            COMPRESS_CUSTOM => Some(Self::Oodle),
+            _ => None,
+        }
+    }
+
+    /// Get legacy `ECompressionFlags` of this compression method
+    ///
+    /// Returns `None` if the method can't be represented with legacy flags
+    pub fn to_legacy_flags(&self) -> Option<u32> {
+        match self {
+            Self::Zlib => Some(COMPRESS_ZLIB),
+            Self::Gzip => Some(COMPRESS_GZIP),
+            Self::Oodle => Some(COMPRESS_CUSTOM),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for CompressionMethod {
@@ -42,11 +89,65 @@ impl std::fmt::Display for CompressionMethod {
             CompressionMethod::Zlib => f.write_str("Zlib"),
             CompressionMethod::Gzip => f.write_str("Gzip"),
             CompressionMethod::Lz4 => f.write_str("LZ4"),
+            CompressionMethod::Oodle => f.write_str("Oodle"),
+            CompressionMethod::Zstd => f.write_str("Zstd"),
+            CompressionMethod::Brotli => f.write_str("Brotli"),
             CompressionMethod::Unknown(e) => write!(f, "{e}"),
         }
     }
 }
 
+/// Compression level
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompressionLevel {
+    /// Fastest compression
+    Fastest,
+    /// Default level of the compression method
+    #[default]
+    Default,
+    /// Best compression
+    Best,
+    /// Compression method specific level, clamped to the range supported by the method
+    Level(u32),
+}
+
+impl CompressionLevel {
+    /// Get the flate2 compression level
+    fn to_flate2(self) -> Compression {
+        match self {
+            CompressionLevel::Fastest => Compression::fast(),
+            CompressionLevel::Default => Compression::default(),
+            CompressionLevel::Best => Compression::best(),
+            CompressionLevel::Level(level) => Compression::new(level.min(9)),
+        }
+    }
+
+    /// Get the zstd compression level
+    fn to_zstd(self) -> i32 {
+        match self {
+            CompressionLevel::Fastest => 1,
+            CompressionLevel::Default => zstd::DEFAULT_COMPRESSION_LEVEL,
+            // highest level that doesn't need a larger decompression window
+            CompressionLevel::Best => 19,
+            CompressionLevel::Level(level) => level.clamp(1, 22) as i32,
+        }
+    }
+
+    /// Get the brotli quality
+    fn to_brotli(self) -> u32 {
+        match self {
+            CompressionLevel::Fastest => 0,
+            CompressionLevel::Default | CompressionLevel::Best => 11,
+            CompressionLevel::Level(level) => level.min(11),
+        }
+    }
+}
+
+/// Brotli buffer size
+const BROTLI_BUFFER_SIZE: usize = 4096;
+/// Brotli window size
+const BROTLI_WINDOW_SIZE: u32 = 22;
+
 /// Decompress data with the given compression method
 pub fn decompress(
     method: CompressionMethod,
@@ -64,6 +165,58 @@ pub fn decompress(
             lz4_flex::block::decompress_into(compressed, decompressed)?;
             Ok(())
         }
+        CompressionMethod::Zstd => {
+            Ok(zstd::stream::read::Decoder::new(compressed)?.read_exact(decompressed)?)
+        }
+        CompressionMethod::Brotli => {
+            Ok(brotli::Decompressor::new(compressed, BROTLI_BUFFER_SIZE)
+                .read_exact(decompressed)?)
+        }
+        #[cfg(feature = "oodle")]
+        CompressionMethod::Oodle => oodle::decompress(compressed, decompressed),
+        #[cfg(not(feature = "oodle"))]
+        CompressionMethod::Oodle => Err(Error::unimplemented(
+            "Oodle decompression requires the `oodle` feature".to_string(),
+        )),
+        CompressionMethod::Unknown(name) => Err(Error::UnknownCompressionMethod(name)),
+    }
+}
+
+/// Compress data with the given compression method
+///
+/// Lz4 doesn't support compression levels, the level is ignored
+pub fn compress(
+    method: CompressionMethod,
+    data: &[u8],
+    level: CompressionLevel,
+) -> Result<Vec<u8>, Error> {
+    match method {
+        CompressionMethod::None => Ok(data.to_vec()),
+        CompressionMethod::Zlib => {
+            let mut encoder = ZlibEncoder::new(Vec::new(), level.to_flate2());
+            encoder.write_all(data)?;
+            Ok(encoder.finish()?)
+        }
+        CompressionMethod::Gzip => {
+            let mut encoder = GzEncoder::new(Vec::new(), level.to_flate2());
+            encoder.write_all(data)?;
+            Ok(encoder.finish()?)
+        }
+        CompressionMethod::Lz4 => Ok(lz4_flex::block::compress(data)),
+        CompressionMethod::Zstd => Ok(zstd::bulk::compress(data, level.to_zstd())?),
+        CompressionMethod::Brotli => {
+            let mut encoder = brotli::CompressorWriter::new(
+                Vec::new(),
+                BROTLI_BUFFER_SIZE,
+                level.to_brotli(),
+                BROTLI_WINDOW_SIZE,
+            );
+            encoder.write_all(data)?;
+            Ok(encoder.into_inner())
+        }
+        CompressionMethod::Oodle => Err(Error::unimplemented(
+            "Oodle compression is not supported".to_string(),
+        )),
         CompressionMethod::Unknown(name) => Err(Error::UnknownCompressionMethod(name)),
     }
 }
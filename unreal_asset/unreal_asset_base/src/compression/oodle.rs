@@ -0,0 +1,22 @@
+//! Oodle decompression
+//!
+//! Uses a pure-Rust Kraken/Mermaid/Selkie/Leviathan decoder, so no Oodle library has to be linked.
+//! There is no encoder, Oodle compression is not supported.
+
+use oozextract::Extractor;
+
+use crate::Error;
+
+/// Decompress an Oodle compressed buffer
+///
+/// `decompressed` must be exactly the size of the decompressed data
+pub fn decompress(compressed: &[u8], decompressed: &mut [u8]) -> Result<(), Error> {
+    let decompressed_count = Extractor::new()
+        .read_from_slice(compressed, decompressed)
+        .map_err(|_| Error::Oodle)?;
+
+    match decompressed_count == decompressed.len() {
+        true => Ok(()),
+        false => Err(Error::Oodle),
+    }
+}
@@ -1,10 +1,36 @@
 //! Chain for chaining two `Read` + `Seek` implementations
 
-use std::io::{Read, Result, Seek, SeekFrom};
+use std::io::{Cursor, Read, Result, Seek, SeekFrom};
+
+/// First stream of a chain
+enum ChainFirst<C: Read + Seek> {
+    /// Stream the chain was created with
+    Stream(C),
+    /// In-memory data replacing the original stream
+    Data(Cursor<Vec<u8>>),
+}
+
+impl<C: Read + Seek> Read for ChainFirst<C> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        match self {
+            ChainFirst::Stream(e) => e.read(buf),
+            ChainFirst::Data(e) => e.read(buf),
+        }
+    }
+}
+
+impl<C: Read + Seek> Seek for ChainFirst<C> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        match self {
+            ChainFirst::Stream(e) => e.seek(pos),
+            ChainFirst::Data(e) => e.seek(pos),
+        }
+    }
+}
 
 /// Chain for chaining two `Read` + `Seek` implementations
 pub struct Chain<C: Read + Seek> {
-    first: C,
+    first: ChainFirst<C>,
     second: Option<C>,
     first_len: u64,
     second_len: u64,
@@ -26,13 +52,23 @@ impl<C: Read + Seek> Chain<C> {
             None => 0,
         };
         Self {
-            first,
+            first: ChainFirst::Stream(first),
             second,
             first_len,
             second_len,
             pos: 0,
         }
     }
+
+    /// Replace the first stream with in-memory data, e.g. a decompressed package
+    ///
+    /// The chain is rewound afterwards
+    pub fn replace_first(&mut self, data: Vec<u8>) -> Result<()> {
+        self.first_len = data.len() as u64;
+        self.first = ChainFirst::Data(Cursor::new(data));
+        self.rewind()?;
+        Ok(())
+    }
 }
 
 impl<C: Read + Seek> Read for Chain<C> {
@@ -188,6 +188,16 @@ pub fn cityhash64_to_lower(string: &str) -> u64 {
     cityhash64(aligned)
 }
 
+/// Generates a cityhash64 hash for a lowercase variant of a name batch string
+///
+/// Ansi strings are hashed as single bytes, wide strings are hashed as utf-16
+pub fn cityhash64_to_lower_name(string: &str, is_wide: bool) -> u64 {
+    match is_wide {
+        true => cityhash64_to_lower(string),
+        false => cityhash64(string.to_ascii_lowercase().as_bytes()),
+    }
+}
+
 fn to_upper(character: u16) -> u16 {
     if character.saturating_sub('a' as u16) < 26u16 {
         (character as u8 as char).to_uppercase().next().unwrap() as u16
@@ -197,7 +207,7 @@ fn to_upper(character: u16) -> u16 {
 }
 
 fn to_lower(character: u16) -> u16 {
-    if character.saturating_sub('a' as u16) < 26u16 {
+    if character.saturating_sub('A' as u16) < 26u16 {
         (character as u8 as char).to_lowercase().next().unwrap() as u16
     } else {
         character
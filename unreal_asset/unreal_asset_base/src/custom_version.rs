@@ -79,6 +79,9 @@ lazy_static! {
         ( FFortniteMainBranchObjectVersion::GUID,                   (String::from(FFortniteMainBranchObjectVersion::FRIENDLY_NAME), Some(FFortniteMainBranchObjectVersion::VERSION_MAPPINGS)) ),
         ( FReleaseObjectVersion::GUID,                              (String::from(FReleaseObjectVersion::FRIENDLY_NAME), Some(FReleaseObjectVersion::VERSION_MAPPINGS)) ),
         ( FSequencerObjectVersion::GUID,                            (String::from(FSequencerObjectVersion::FRIENDLY_NAME), Some(FSequencerObjectVersion::VERSION_MAPPINGS)) ),
+        ( FInstancedStructCustomVersion::GUID,                      (String::from(FInstancedStructCustomVersion::FRIENDLY_NAME), Some(FInstancedStructCustomVersion::VERSION_MAPPINGS)) ),
+        ( FUE5MainStreamObjectVersion::GUID,                        (String::from(FUE5MainStreamObjectVersion::FRIENDLY_NAME), Some(FUE5MainStreamObjectVersion::VERSION_MAPPINGS)) ),
+        ( FUE5ReleaseStreamObjectVersion::GUID,                     (String::from(FUE5ReleaseStreamObjectVersion::FRIENDLY_NAME), Some(FUE5ReleaseStreamObjectVersion::VERSION_MAPPINGS)) ),
     ]);
 }
 
@@ -397,52 +400,53 @@ pub enum FFortniteMainBranchObjectVersion {
 
     // CHANGES BEYOND HERE ARE UE5 ONLY //
     /// Added the weighted value property type to store the cloths weight maps' low/high ranges
-    /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
+    /// Introduced: EngineVersion.VER_UE5_0
     ChaosClothAddWeightedValue,
 
     /// Added the Long Range Attachment stiffness weight map
-    /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
+    /// Introduced: EngineVersion.VER_UE5_0
     ChaosClothAddTetherStiffnessWeightMap,
 
     /// Fix corrupted LOD transition maps
-    /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
+    /// Introduced: EngineVersion.VER_UE5_0
     ChaosClothFixLODTransitionMaps,
 
     /// Enable a few more weight maps to better art direct the cloth simulation
-    /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
+    /// Introduced: EngineVersion.VER_UE5_0
     ChaosClothAddTetherScaleAndDragLiftWeightMaps,
 
     /// Enable material (edge, bending, and area stiffness) weight maps
-    /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
+    /// Introduced: EngineVersion.VER_UE5_0
     ChaosClothAddMaterialWeightMaps,
 
     /// Added bShowCurve for movie scene float channel serialization
-    /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
+    /// Introduced: EngineVersion.VER_UE5_0
     SerializeFloatChannelShowCurve,
 
     /// Minimize slack waste by using a single array for grass data
-    /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
+    /// Introduced: EngineVersion.VER_UE5_0
     LandscapeGrassSingleArray,
 
     /// Add loop counters to sequencer's compiled sub-sequence data
-    /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
+    /// Introduced: EngineVersion.VER_UE5_0
     AddedSubSequenceEntryWarpCounter,
 
     /// Water plugin is now component-based rather than actor based
-    /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
+    /// Introduced: EngineVersion.VER_UE5_0
     WaterBodyComponentRefactor,
 
     /// Cooked BPGC storing editor-only asset tags
-    /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
+    /// Introduced: EngineVersion.VER_UE5_0
     BPGCCookedEditorTags,
 
     /// Terrain layer weights are no longer considered material parameters
-    /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
+    /// Introduced: EngineVersion.VER_UE5_0
     TerrainLayerWeightsAreNotParameters,
 
     /// Anim Dynamics Node Gravity Override vector is now defined in world space, not simulation space.
     /// Legacy behavior can be maintained with a flag, which is set false by default for new nodes,
     /// true for nodes predating this change.
+    /// Introduced: EngineVersion.VER_UE5_0
     GravityOverrideDefinedInWorldSpace,
 
     /// Introduced: ObjectVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
@@ -457,6 +461,7 @@ impl_custom_version_trait!(
     Guid::from_ints(0x601D1886, 0xAC644F84, 0xAA16D3DE, 0x0DEAC7D6),
     VER_UE4_AUTOMATIC_VERSION: LatestVersion,
     VER_UE4_AUTOMATIC_VERSION_PLUS_ONE: VersionPlusOne,
+    VER_UE5_0: GravityOverrideDefinedInWorldSpace,
     VER_UE4_27: RemoveLandscapeWaterInfo,
     VER_UE4_26: ChaosSolverPropertiesMoved,
     VER_UE4_24: AnimLayerGuidConformation,
@@ -1278,6 +1283,520 @@ impl_custom_version_trait!(
     VER_UE4_OLDEST_LOADABLE_PACKAGE: BeforeCustomVersionWasAdded
 );
 
+/// Custom serialization version for FInstancedStruct
+#[derive(IntoPrimitive)]
+#[repr(i32)]
+pub enum FInstancedStructCustomVersion {
+    /// Before any version changes were made, a version byte is serialized before the struct
+    /// Introduced: EngineVersion.VER_UE5_0
+    BeforeCustomVersionWasAdded = 0,
+
+    /// Added custom version, the version byte is no longer serialized
+    /// Introduced: EngineVersion.VER_UE5_4
+    CustomVersionAdded,
+
+    /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
+    VersionPlusOne,
+    /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION
+    LatestVersion = (FInstancedStructCustomVersion::VersionPlusOne as i32) + 1,
+}
+
+impl_custom_version_trait!(
+    FInstancedStructCustomVersion,
+    "FInstancedStructCustomVersion",
+    Guid::from_ints(0xE21E1CAA, 0xAF47425E, 0x89BF6AD4, 0x4C44A8BB),
+    VER_UE4_AUTOMATIC_VERSION: LatestVersion,
+    VER_UE4_AUTOMATIC_VERSION_PLUS_ONE: VersionPlusOne,
+    VER_UE5_4: CustomVersionAdded
+);
+
+/// Custom serialization version for changes made in the //UE5/Main stream
+#[derive(IntoPrimitive)]
+#[repr(i32)]
+pub enum FUE5MainStreamObjectVersion {
+    /// Before any version changes were made
+    /// Introduced: EngineVersion.VER_UE5_0
+    BeforeCustomVersionWasAdded = 0,
+
+    /// Nanite data added to Chaos geometry collections
+    /// Introduced: EngineVersion.VER_UE5_0
+    GeometryCollectionNaniteData,
+
+    /// Nanite Geometry Collection data moved to DDC
+    /// Introduced: EngineVersion.VER_UE5_0
+    GeometryCollectionNaniteDDC,
+
+    /// Removing SourceAnimationData, animation layering is now applied during compression
+    /// Introduced: EngineVersion.VER_UE5_0
+    RemovingSourceAnimationData,
+
+    /// New MeshDescription format
+    /// Introduced: EngineVersion.VER_UE5_0
+    MeshDescriptionNewFormat,
+
+    /// Serialize GridGuid in PartitionActorDesc
+    /// Introduced: EngineVersion.VER_UE5_0
+    PartitionActorDescSerializeGridGuid,
+
+    /// Set PKG_ContainsMapData on external actor packages
+    /// Introduced: EngineVersion.VER_UE5_0
+    ExternalActorsMapDataPackageFlag,
+
+    /// Added a new configurable BlendProfileMode that the user can setup to control the behavior of blend profiles
+    /// Introduced: EngineVersion.VER_UE5_0
+    AnimationAddedBlendProfileModes,
+
+    /// Serialize DataLayers in WorldPartitionActorDesc
+    /// Introduced: EngineVersion.VER_UE5_0
+    WorldPartitionActorDescSerializeDataLayers,
+
+    /// Renaming UAnimSequence::NumFrames to NumberOfKeys, as that what is actually contains
+    /// Introduced: EngineVersion.VER_UE5_0
+    RenamingAnimationNumFrames,
+
+    /// Serialize HLODLayer in WorldPartition HLODActorDesc
+    /// Introduced: EngineVersion.VER_UE5_0
+    WorldPartitionHLODActorDescSerializeHLODLayer,
+
+    /// Fixed Nanite Geometry Collection cooked data
+    /// Introduced: EngineVersion.VER_UE5_0
+    GeometryCollectionNaniteCooked,
+
+    /// Added bCooked to UFontFace assets
+    /// Introduced: EngineVersion.VER_UE5_0
+    AddedCookedBoolFontFaceAssets,
+
+    /// Serialize CellHash in WorldPartition HLODActorDesc
+    /// Introduced: EngineVersion.VER_UE5_0
+    WorldPartitionHLODActorDescSerializeCellHash,
+
+    /// Nanite data is now transient in Geometry Collection similar to how RenderData is transient in StaticMesh
+    /// Introduced: EngineVersion.VER_UE5_0
+    GeometryCollectionNaniteTransient,
+
+    /// Added FLandscapeSplineActorDesc
+    /// Introduced: EngineVersion.VER_UE5_0
+    AddedLandscapeSplineActorDesc,
+
+    /// Added support for per-object collision constraint flag
+    /// Introduced: EngineVersion.VER_UE5_0
+    AddCollisionConstraintFlag,
+
+    /// Initial Mantle Serialize Version
+    /// Introduced: EngineVersion.VER_UE5_0
+    MantleDbSerialize,
+
+    /// Animation sync groups explicitly specify sync method
+    /// Introduced: EngineVersion.VER_UE5_0
+    AnimSyncGroupsExplicitSyncMethod,
+
+    /// Fixup FLandscapeActorDesc Grid indices
+    /// Introduced: EngineVersion.VER_UE5_0
+    FLandscapeActorDescFixupGridIndices,
+
+    /// FoliageType with HLOD support
+    /// Introduced: EngineVersion.VER_UE5_0
+    FoliageTypeIncludeInHLOD,
+
+    /// Introducing UAnimDataModel sub-object for UAnimSequenceBase containing all animation source data
+    /// Introduced: EngineVersion.VER_UE5_0
+    IntroducingAnimationDataModel,
+
+    /// Serialize ActorLabel in WorldPartitionActorDesc
+    /// Introduced: EngineVersion.VER_UE5_0
+    WorldPartitionActorDescSerializeActorLabel,
+
+    /// Fix WorldPartitionActorDesc serialization archive not persistent
+    /// Introduced: EngineVersion.VER_UE5_0
+    WorldPartitionActorDescSerializeArchivePersistent,
+
+    /// Fix potentially duplicated actors when using ForceExternalActorLevelReference
+    /// Introduced: EngineVersion.VER_UE5_0
+    FixForceExternalActorLevelReferenceDuplicates,
+
+    /// Make UMeshDescriptionBase serializable
+    /// Introduced: EngineVersion.VER_UE5_0
+    SerializeMeshDescriptionBase,
+
+    /// Chaos FConvex uses array of FVec3s for vertices instead of particles
+    /// Introduced: EngineVersion.VER_UE5_0
+    ConvexUsesVerticesArray,
+
+    /// Serialize HLOD info in WorldPartitionActorDesc
+    /// Introduced: EngineVersion.VER_UE5_0
+    WorldPartitionActorDescSerializeHLODInfo,
+
+    /// Expose particle Disabled flag to the game thread
+    /// Introduced: EngineVersion.VER_UE5_0
+    AddDisabledFlag,
+
+    /// Moving animation custom attributes from AnimationSequence to UAnimDataModel
+    /// Introduced: EngineVersion.VER_UE5_0
+    MoveCustomAttributesToDataModel,
+
+    /// Use of triangulation at runtime in BlendSpace
+    /// Introduced: EngineVersion.VER_UE5_0
+    BlendSpaceRuntimeTriangulation,
+
+    /// Fix to the Cubic smoothing, plus introduction of new smoothing types
+    /// Introduced: EngineVersion.VER_UE5_0
+    BlendSpaceSmoothingImprovements,
+
+    /// Removing Tessellation parameters from Materials
+    /// Introduced: EngineVersion.VER_UE5_0
+    RemovingTessellationParameters,
+
+    /// Sparse class data serializes its associated structure to allow for BP types to be used
+    /// Introduced: EngineVersion.VER_UE5_0
+    SparseClassDataStructSerialization,
+
+    /// PackedLevelInstance bounds fix
+    /// Introduced: EngineVersion.VER_UE5_0
+    PackedLevelInstanceBoundsFix,
+
+    /// Initial set of anim nodes converted to use constants held in sparse class data
+    /// Introduced: EngineVersion.VER_UE5_0
+    AnimNodeConstantDataRefactorPhase0,
+
+    /// Explicitly serialized bSavedCachedExpressionData for Material(Instance)
+    /// Introduced: EngineVersion.VER_UE5_0
+    MaterialSavedCachedData,
+
+    /// Remove explicit decal blend mode
+    /// Introduced: EngineVersion.VER_UE5_0
+    RemoveDecalBlendMode,
+
+    /// Made directional lights be atmosphere lights by default
+    /// Introduced: EngineVersion.VER_UE5_0
+    DirLightsAreAtmosphereLightsByDefault,
+
+    /// Changed how WorldPartition generates streaming cells names
+    /// Introduced: EngineVersion.VER_UE5_0
+    WorldPartitionStreamingCellsNamingShortened,
+
+    /// Changed how actor descriptors compute their bounds
+    /// Introduced: EngineVersion.VER_UE5_0
+    WorldPartitionActorDescGetStreamingBounds,
+
+    /// Switch FMeshDescriptionBulkData to use virtualized bulkdata
+    /// Introduced: EngineVersion.VER_UE5_0
+    MeshDescriptionVirtualization,
+
+    /// Switch FTextureSource to use virtualized bulkdata
+    /// Introduced: EngineVersion.VER_UE5_0
+    TextureSourceVirtualization,
+
+    /// RigVM to store more information alongside the Copy Operator
+    /// Introduced: EngineVersion.VER_UE5_0
+    RigVMCopyOpStoreNumBytes,
+
+    /// Expanded separate translucency into multiple passes
+    /// Introduced: EngineVersion.VER_UE5_0
+    MaterialTranslucencyPass,
+
+    /// Chaos FGeometryCollectionObject user defined collision shapes support
+    /// Introduced: EngineVersion.VER_UE5_0
+    GeometryCollectionUserDefinedCollisionShapes,
+
+    /// Removed the AtmosphericFog component with conversion to SkyAtmosphere component
+    /// Introduced: EngineVersion.VER_UE5_0
+    RemovedAtmosphericFog,
+
+    /// The SkyAtmosphere now light up the heightfog by default, and by default the height fog has a black color
+    /// Introduced: EngineVersion.VER_UE5_0
+    SkyAtmosphereAffectsHeightFogWithBetterDefault,
+
+    /// Ordering of samples in BlendSpace
+    /// Introduced: EngineVersion.VER_UE5_0
+    BlendSpaceSampleOrdering,
+
+    /// No longer bake MassToLocal transform into recorded transforms in GeometryCollection caches
+    /// Introduced: EngineVersion.VER_UE5_0
+    GeometryCollectionCacheRemovesMassToLocal,
+
+    /// UEdGraphPin serializes SourceIndex
+    /// Introduced: EngineVersion.VER_UE5_0
+    EdGraphPinSourceIndex,
+
+    /// Change texture bulkdatas to have unique guids
+    /// Introduced: EngineVersion.VER_UE5_0
+    VirtualizedBulkDataHaveUniqueGuids,
+
+    /// Introduce RigVM Memory Class Object
+    /// Introduced: EngineVersion.VER_UE5_0
+    RigVMMemoryStorageObject,
+
+    /// Ray tracing shadows have three states now (Disabled, Use Project Settings, Enabled)
+    /// Introduced: EngineVersion.VER_UE5_0
+    RayTracedShadowsType,
+
+    /// Add bVisibleInRayTracing flag to Skeletal Mesh Sections
+    /// Introduced: EngineVersion.VER_UE5_0
+    SkelMeshSectionVisibleInRayTracingFlagAdded,
+
+    /// Add tagging information to UAnimGraphNode_Base
+    /// Introduced: EngineVersion.VER_UE5_0
+    AnimGraphNodeTaggingAdded,
+
+    /// Add custom version to FDynamicMesh3
+    /// Introduced: EngineVersion.VER_UE5_0
+    DynamicMeshCompactedSerialization,
+
+    /// Remove the inline reduction bulkdata and replace it by a simple vertex and triangle count cache
+    /// Introduced: EngineVersion.VER_UE5_0
+    ConvertReductionBaseSkeletalMeshBulkDataToInlineReductionCacheData,
+
+    /// Added some new MeshInfo to the FSkeletalMeshLODModel class
+    /// Introduced: EngineVersion.VER_UE5_0
+    SkeletalMeshLODModelMeshInfo,
+
+    /// Add Texture DoScaleMipsForAlphaCoverage
+    /// Introduced: EngineVersion.VER_UE5_0
+    TextureDoScaleMipsForAlphaCoverage,
+
+    /// Fixed default value of volumetric cloud to be exact match with main view, more expenssive but we let users choosing the trade off
+    /// Introduced: EngineVersion.VER_UE5_0
+    VolumetricCloudReflectionSampleCountDefaultUpdate,
+
+    /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
+    VersionPlusOne,
+    /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION
+    LatestVersion = (FUE5MainStreamObjectVersion::VersionPlusOne as i32) + 1,
+}
+
+impl_custom_version_trait!(
+    FUE5MainStreamObjectVersion,
+    "FUE5MainStreamObjectVersion",
+    Guid::from_ints(0x697DD581, 0xE64F41AB, 0xAA4A51EC, 0xBEB7B628),
+    VER_UE4_AUTOMATIC_VERSION: LatestVersion,
+    VER_UE4_AUTOMATIC_VERSION_PLUS_ONE: VersionPlusOne,
+    VER_UE5_0: VolumetricCloudReflectionSampleCountDefaultUpdate
+);
+
+/// Custom serialization version for changes made in the //UE5/Release-* stream
+#[derive(IntoPrimitive)]
+#[repr(i32)]
+pub enum FUE5ReleaseStreamObjectVersion {
+    /// Before any version changes were made
+    /// Introduced: EngineVersion.VER_UE5_0
+    BeforeCustomVersionWasAdded = 0,
+
+    /// Added Lumen reflections to new reflection enum, changed defaults
+    /// Introduced: EngineVersion.VER_UE5_0
+    ReflectionMethodEnum,
+
+    /// Serialize HLOD info in WorldPartitionActorDesc
+    /// Introduced: EngineVersion.VER_UE5_0
+    WorldPartitionActorDescSerializeHLODInfo,
+
+    /// Removing Tessellation from materials and meshes
+    /// Introduced: EngineVersion.VER_UE5_0
+    RemovingTessellation,
+
+    /// LevelInstance serialize runtime behavior
+    /// Introduced: EngineVersion.VER_UE5_0
+    LevelInstanceSerializeRuntimeBehavior,
+
+    /// Refactoring Pose Asset runtime data structures
+    /// Introduced: EngineVersion.VER_UE5_0
+    PoseAssetRuntimeRefactor,
+
+    /// Serialize the folder path of actor descs
+    /// Introduced: EngineVersion.VER_UE5_0
+    WorldPartitionActorDescSerializeActorFolderPath,
+
+    /// Change hair strands vertex format
+    /// Introduced: EngineVersion.VER_UE5_0
+    HairStrandsVertexFormatChange,
+
+    /// Added max linear and angular speed to Chaos bodies
+    /// Introduced: EngineVersion.VER_UE5_0
+    AddChaosMaxLinearAngularSpeed,
+
+    /// PackedLevelInstance version
+    /// Introduced: EngineVersion.VER_UE5_0
+    PackedLevelInstanceVersion,
+
+    /// PackedLevelInstance bounds fix
+    /// Introduced: EngineVersion.VER_UE5_0
+    PackedLevelInstanceBoundsFix,
+
+    /// Custom property anim graph nodes (linked anim graphs, control rig etc.) now use optional pin manager
+    /// Introduced: EngineVersion.VER_UE5_0
+    CustomPropertyAnimGraphNodesUseOptionalPinManager,
+
+    /// Add native double and int64 support to FFormatArgumentData
+    /// Introduced: EngineVersion.VER_UE5_0
+    TextFormatArgumentData64bitSupport,
+
+    /// Material layer stacks are no longer considered 'static parameters'
+    /// Introduced: EngineVersion.VER_UE5_0
+    MaterialLayerStacksAreNotParameters,
+
+    /// CachedExpressionData is moved from UMaterial to UMaterialInterface
+    /// Introduced: EngineVersion.VER_UE5_0
+    MaterialInterfaceSavedCachedData,
+
+    /// Add support for multiple cloth deformer LODs to be able to raytrace cloth with a different LOD than the one it is rendered with
+    /// Introduced: EngineVersion.VER_UE5_0
+    AddClothMappingLODBias,
+
+    /// Add support for different external actor packaging schemes
+    /// Introduced: EngineVersion.VER_UE5_0
+    AddLevelActorPackagingScheme,
+
+    /// Add support for linking to the attached parent actor in WorldPartitionActorDesc
+    /// Introduced: EngineVersion.VER_UE5_0
+    WorldPartitionActorDescSerializeAttachParent,
+
+    /// Converted AActor GridPlacement to bIsSpatiallyLoaded flag
+    /// Introduced: EngineVersion.VER_UE5_0
+    ConvertedActorGridPlacementToSpatiallyLoadedFlag,
+
+    /// Fixup for bad default value for GridPlacement_DEPRECATED
+    /// Introduced: EngineVersion.VER_UE5_0
+    ActorGridPlacementDeprecateDefaultValueFixup,
+
+    /// PackedLevelActor started using FWorldPartitionActorDesc
+    /// Introduced: EngineVersion.VER_UE5_0
+    PackedLevelActorUseWorldPartitionActorDesc,
+
+    /// Add support for actor folder objects
+    /// Introduced: EngineVersion.VER_UE5_0
+    AddLevelActorFolders,
+
+    /// Remove FSkeletalMeshLODModel bulk datas
+    /// Introduced: EngineVersion.VER_UE5_0
+    RemoveSkeletalMeshLODModelBulkDatas,
+
+    /// Exclude brightness from the EncodedHDRCubemap
+    /// Introduced: EngineVersion.VER_UE5_0
+    ExcludeBrightnessFromEncodedHDRCubemap,
+
+    /// Unified volumetric cloud component quality sample count slider between main and reflection views for consistency
+    /// Introduced: EngineVersion.VER_UE5_0
+    VolumetricCloudSampleCountUnification,
+
+    /// Pose asset GUID generated from source AnimationSequence
+    /// Introduced: EngineVersion.VER_UE5_0
+    PoseAssetRawDataGUID,
+
+    /// Convolution bloom now take into account FPostProcessSettings::BloomIntensity for scatter dispersion
+    /// Introduced: EngineVersion.VER_UE5_0
+    ConvolutionBloomIntensity,
+
+    /// Serialize FHLODSubActors instead of FGuids in WorldPartition HLODActorDesc
+    /// Introduced: EngineVersion.VER_UE5_0
+    WorldPartitionHLODActorDescSerializeHLODSubActors,
+
+    /// Large Worlds - serialize double types as doubles
+    /// Introduced: EngineVersion.VER_UE5_0
+    LargeWorldCoordinates,
+
+    /// Deserialize old BP float and double types as real numbers for pins
+    /// Introduced: EngineVersion.VER_UE5_0
+    BlueprintPinsUseRealNumbers,
+
+    /// Changed shadow defaults for directional light components, version needed to not affect old things
+    /// Introduced: EngineVersion.VER_UE5_0
+    UpdatedDirectionalLightShadowDefaults,
+
+    /// Refresh geometry collections that had not already generated convex bodies
+    /// Introduced: EngineVersion.VER_UE5_0
+    GeometryCollectionConvexDefaults,
+
+    /// Add faster damping calculations to the cloth simulation and rename previous Damping parameter to LocalDamping
+    /// Introduced: EngineVersion.VER_UE5_0
+    ChaosClothFasterDamping,
+
+    /// Serialize LandscapeActorGuid in FLandscapeActorDesc sub class
+    /// Introduced: EngineVersion.VER_UE5_0
+    WorldPartitionLandscapeActorDescSerializeLandscapeActorGuid,
+
+    /// Add inertia tensor and rotation of mass to convex
+    /// Introduced: EngineVersion.VER_UE5_0
+    AddedInertiaTensorAndRotationOfMassAddedToConvex,
+
+    /// Storing inertia tensor as vec3 instead of matrix
+    /// Introduced: EngineVersion.VER_UE5_0
+    ChaosInertiaConvertedToVec3,
+
+    /// For Blueprint real numbers, ensure that legacy float data is serialized as single-precision
+    /// Introduced: EngineVersion.VER_UE5_0
+    SerializeFloatPinDefaultValuesAsSinglePrecision,
+
+    /// Upgrade the BlendMasks array in existing LayeredBoneBlend nodes
+    /// Introduced: EngineVersion.VER_UE5_1
+    AnimLayeredBoneBlendMasks,
+
+    /// Uses RG11B10 format to store the encoded reflection capture data on mobile
+    /// Introduced: EngineVersion.VER_UE5_1
+    StoreReflectionCaptureEncodedHDRDataInRG11B10Format,
+
+    /// Add WithSerializer type trait and implementation for FRawAnimSequenceTrack
+    /// Introduced: EngineVersion.VER_UE5_1
+    RawAnimSequenceTrackSerializer,
+
+    /// Removed font from FEditableTextBoxStyle, and added FTextBlockStyle instead
+    /// Introduced: EngineVersion.VER_UE5_1
+    RemoveDuplicatedStyleInfo,
+
+    /// Added member reference to linked anim graphs
+    /// Introduced: EngineVersion.VER_UE5_1
+    LinkedAnimGraphMemberReference,
+
+    /// Changed default tangent behavior for new dynamic mesh components
+    /// Introduced: EngineVersion.VER_UE5_2
+    DynamicMeshComponentsDefaultUseExternalTangents,
+
+    /// Added resize methods to media capture
+    /// Introduced: EngineVersion.VER_UE5_2
+    MediaCaptureNewResizeMethods,
+
+    /// Function data stores a map from work to debug operands
+    /// Introduced: EngineVersion.VER_UE5_2
+    RigVMSaveDebugMapInGraphFunctionData,
+
+    /// Changed default Local Exposure Contrast Scale from 1.0 to 0.8
+    /// Introduced: EngineVersion.VER_UE5_3
+    LocalExposureDefaultChangeFrom1,
+
+    /// Serialize bActorIsListedInSceneOutliner in WorldPartitionActorDesc
+    /// Introduced: EngineVersion.VER_UE5_3
+    WorldPartitionActorDescSerializeActorIsListedInSceneOutliner,
+
+    /// Disabled opencolorio display configuration by default
+    /// Introduced: EngineVersion.VER_UE5_3
+    OpenColorIODisabledDisplayConfigurationDefault,
+
+    /// Serialize ExternalDataLayerAsset in WorldPartitionActorDesc
+    /// Introduced: EngineVersion.VER_UE5_4
+    WorldPartitionExternalDataLayers,
+
+    /// Fix Chaos Cloth fictitious angular scale bug that requires existing parameter rescaling
+    /// Introduced: EngineVersion.VER_UE5_5
+    ChaosClothFictitiousAngularVelocitySubspaceBugFix,
+
+    /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION_PLUS_ONE
+    VersionPlusOne,
+    /// Introduced: EngineVersion.VER_UE4_AUTOMATIC_VERSION
+    LatestVersion = (FUE5ReleaseStreamObjectVersion::VersionPlusOne as i32) + 1,
+}
+
+impl_custom_version_trait!(
+    FUE5ReleaseStreamObjectVersion,
+    "FUE5ReleaseStreamObjectVersion",
+    Guid::from_ints(0xD89B5E42, 0x24BD4D46, 0x8412ACA8, 0xDF641779),
+    VER_UE4_AUTOMATIC_VERSION: LatestVersion,
+    VER_UE4_AUTOMATIC_VERSION_PLUS_ONE: VersionPlusOne,
+    VER_UE5_5: ChaosClothFictitiousAngularVelocitySubspaceBugFix,
+    VER_UE5_4: WorldPartitionExternalDataLayers,
+    VER_UE5_3: OpenColorIODisabledDisplayConfigurationDefault,
+    VER_UE5_2: RigVMSaveDebugMapInGraphFunctionData,
+    VER_UE5_1: LinkedAnimGraphMemberReference,
+    VER_UE5_0: SerializeFloatPinDefaultValuesAsSinglePrecision
+);
+
 /// Asset registry version
 #[derive(IntoPrimitive, TryFromPrimitive, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Debug)]
 #[repr(i32)]
@@ -0,0 +1,130 @@
+//! Unreal AES encryption
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+
+use aes::cipher::{generic_array::GenericArray, BlockDecrypt, BlockEncrypt, KeyInit};
+use aes::Aes256;
+
+use unreal_helpers::Guid;
+
+use crate::Error;
+
+/// AES block size, encrypted data is padded to a multiple of this
+pub const AES_BLOCK_SIZE: usize = 16;
+
+/// AES-256 encryption key
+#[derive(Clone)]
+pub struct AesKey {
+    /// Cipher
+    cipher: Aes256,
+}
+
+impl AesKey {
+    /// Create a new `AesKey` from raw key bytes
+    pub fn new(key: [u8; 32]) -> Self {
+        AesKey {
+            cipher: Aes256::new(&GenericArray::from(key)),
+        }
+    }
+
+    /// Create a new `AesKey` from a hex string, with or without a `0x` prefix
+    pub fn from_hex(key: &str) -> Result<Self, Error> {
+        let key = key.trim();
+        let key = key
+            .strip_prefix("0x")
+            .or_else(|| key.strip_prefix("0X"))
+            .unwrap_or(key);
+
+        if key.len() != 64 || !key.is_ascii() {
+            return Err(Error::invalid_aes_key(format!(
+                "expected 64 hex characters, got {}",
+                key.len()
+            )));
+        }
+
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&key[i * 2..i * 2 + 2], 16)
+                .map_err(|e| Error::invalid_aes_key(e.to_string()))?;
+        }
+
+        Ok(AesKey::new(bytes))
+    }
+
+    /// Decrypt data in place, data length must be a multiple of [`AES_BLOCK_SIZE`]
+    pub fn decrypt(&self, data: &mut [u8]) -> Result<(), Error> {
+        Self::check_length(data)?;
+        for block in data.chunks_exact_mut(AES_BLOCK_SIZE) {
+            self.cipher
+                .decrypt_block(GenericArray::from_mut_slice(block));
+        }
+        Ok(())
+    }
+
+    /// Encrypt data in place, data length must be a multiple of [`AES_BLOCK_SIZE`]
+    pub fn encrypt(&self, data: &mut [u8]) -> Result<(), Error> {
+        Self::check_length(data)?;
+        for block in data.chunks_exact_mut(AES_BLOCK_SIZE) {
+            self.cipher
+                .encrypt_block(GenericArray::from_mut_slice(block));
+        }
+        Ok(())
+    }
+
+    /// Check that data can be encrypted or decrypted
+    fn check_length(data: &[u8]) -> Result<(), Error> {
+        match data.len() % AES_BLOCK_SIZE {
+            0 => Ok(()),
+            _ => Err(Error::invalid_file(format!(
+                "Encrypted data length {} is not a multiple of the AES block size",
+                data.len()
+            ))),
+        }
+    }
+}
+
+impl Debug for AesKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // don't leak keys into logs
+        f.write_str("AesKey(..)")
+    }
+}
+
+/// Encryption keys by their guid
+///
+/// Containers store the guid of the key they are encrypted with, the default key has an all-zero guid
+#[derive(Debug, Clone, Default)]
+pub struct EncryptionKeys {
+    /// Keys by guid
+    keys: HashMap<Guid, AesKey>,
+}
+
+impl EncryptionKeys {
+    /// Create a new empty `EncryptionKeys` instance
+    pub fn new() -> Self {
+        EncryptionKeys::default()
+    }
+
+    /// Create a new `EncryptionKeys` instance with only a default key
+    pub fn with_default_key(key: AesKey) -> Self {
+        let mut keys = EncryptionKeys::new();
+        keys.add_key(Guid::default(), key);
+        keys
+    }
+
+    /// Add a key, replacing any existing key with the same guid
+    pub fn add_key(&mut self, guid: Guid, key: AesKey) {
+        self.keys.insert(guid, key);
+    }
+
+    /// Get a key by its guid
+    pub fn get_key(&self, guid: &Guid) -> Option<&AesKey> {
+        self.keys.get(guid)
+    }
+
+    /// Check if there are no keys
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
@@ -84,6 +84,12 @@ pub enum EngineVersion {
     VER_UE5_1,
     /// 5.2
     VER_UE5_2,
+    /// 5.3
+    VER_UE5_3,
+    /// 5.4
+    VER_UE5_4,
+    /// 5.5
+    VER_UE5_5,
 
     /// The newest specified version of the Unreal Engine.
     VER_UE4_AUTOMATIC_VERSION,
@@ -217,6 +223,18 @@ lazy_static! {
             ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG,
             EngineVersion::VER_UE5_2
         ),
+        (
+            ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG,
+            EngineVersion::VER_UE5_3
+        ),
+        (
+            ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG,
+            EngineVersion::VER_UE5_4
+        ),
+        (
+            ObjectVersion::VER_UE4_CORRECT_LICENSEE_FLAG,
+            EngineVersion::VER_UE5_5
+        ),
     ]);
     static ref OBJECT_VERSION_TO_ENGINE_VERSION_UE5: Vec<(ObjectVersionUE5, EngineVersion)> =
         Vec::from([
@@ -228,9 +246,18 @@ lazy_static! {
                 ObjectVersionUE5::ADD_SOFTOBJECTPATH_LIST,
                 EngineVersion::VER_UE5_1
             ),
+            (ObjectVersionUE5::DATA_RESOURCES, EngineVersion::VER_UE5_2),
+            (
+                ObjectVersionUE5::PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION,
+                EngineVersion::VER_UE5_3
+            ),
+            (
+                ObjectVersionUE5::PROPERTY_TAG_COMPLETE_TYPE_NAME,
+                EngineVersion::VER_UE5_4
+            ),
             (
-                ObjectVersionUE5::AUTOMATIC_VERSION,
-                EngineVersion::VER_UE5_2
+                ObjectVersionUE5::PACKAGE_SAVED_HASH,
+                EngineVersion::VER_UE5_5
             )
         ]);
 }
@@ -240,23 +267,31 @@ pub fn get_possible_versions(
     object_version: ObjectVersion,
     object_version_ue5: ObjectVersionUE5,
 ) -> Vec<EngineVersion> {
-    let mut possible_versions = Vec::new();
-    if let Some(ue5_version) = OBJECT_VERSION_TO_ENGINE_VERSION_UE5
+    // a ue5 object version narrows the engine version down to ue5 releases,
+    // versions between releases belong to the latest release before them
+    let release_version = OBJECT_VERSION_TO_ENGINE_VERSION_UE5
+        .iter()
+        .map(|(version, _)| *version)
+        .filter(|version| *version <= object_version_ue5)
+        .max();
+
+    let possible_versions = OBJECT_VERSION_TO_ENGINE_VERSION_UE5
         .iter()
-        .find(|(version, _)| *version == object_version_ue5)
+        .filter(|(version, _)| Some(*version) == release_version)
         .map(|(_, engine_version)| *engine_version)
-    {
-        possible_versions.push(ue5_version);
-    }
+        .collect::<Vec<_>>();
 
-    possible_versions.extend(
-        OBJECT_VERSION_TO_ENGINE_VERSION
-            .iter()
-            .find(|(version, _)| *version == object_version)
-            .map(|(_, engine_version)| *engine_version),
-    );
+    if !possible_versions.is_empty() {
+        return possible_versions;
+    }
 
-    possible_versions
+    OBJECT_VERSION_TO_ENGINE_VERSION
+        .iter()
+        .filter(|(version, engine_version)| {
+            *version == object_version && *engine_version < EngineVersion::VER_UE5_0
+        })
+        .map(|(_, engine_version)| *engine_version)
+        .collect()
 }
 
 /// Get object versions for a given engine version
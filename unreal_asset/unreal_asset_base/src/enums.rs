@@ -57,6 +57,25 @@ pub enum ELifetimeCondition {
     CondMax = 16,
 }
 
+/// Overridable serialization operation
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum EOverriddenPropertyOperation {
+    /// No overridden operation was recorded
+    #[default]
+    None = 0,
+    /// Some sub property has recorded overridden operation
+    Modified,
+    /// Everything has been overridden from this property down to every sub property/sub object
+    Replaced,
+    /// The element was added in the container
+    Added,
+    /// The element was removed from the container
+    Removed,
+    /// This element is shadowing a subobject
+    SubObjectsShadowing,
+}
+
 /// Custom version serialization format
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
 pub enum ECustomVersionSerializationFormat {
@@ -72,7 +91,9 @@ pub enum ECustomVersionSerializationFormat {
 }
 
 /// Zen package version
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, TryFromPrimitive, IntoPrimitive)]
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, TryFromPrimitive, IntoPrimitive,
+)]
 #[repr(u32)]
 pub enum EZenPackageVersion {
     /// Initial
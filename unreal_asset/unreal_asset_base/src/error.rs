@@ -46,6 +46,18 @@ pub enum UsmapError {
     /// Name map index out of range
     #[error("Name map index out of range, name map size: {0}, got: {1}")]
     NameMapIndexOutOfRange(usize, i32),
+    /// Name is not in the name map
+    #[error("Name {0} is not in the name map")]
+    NameNotFound(Box<str>),
+    /// Usmap contains unresolved references
+    #[error(
+        "Invalid mappings: {}",
+        .0.iter().map(|e| e.to_string()).collect::<Vec<_>>().join(", ")
+    )]
+    InvalidMappings(Box<[UsmapValidationError]>),
+    /// Invalid json mappings
+    #[error("Invalid json mappings: {0}")]
+    InvalidJson(Box<str>),
 }
 
 impl UsmapError {
@@ -63,6 +75,60 @@ impl UsmapError {
     pub fn name_map_index_out_of_range(name_map_size: usize, index: i32) -> Self {
         UsmapError::NameMapIndexOutOfRange(name_map_size, index)
     }
+
+    /// Create an `UsmapError` for a name that is not in the name map
+    pub fn name_not_found(name: &str) -> Self {
+        UsmapError::NameNotFound(name.to_string().into_boxed_str())
+    }
+
+    /// Create an `UsmapError` for mappings that failed validation
+    pub fn invalid_mappings(errors: Vec<UsmapValidationError>) -> Self {
+        UsmapError::InvalidMappings(errors.into_boxed_slice())
+    }
+
+    /// Create an `UsmapError` for invalid json mappings
+    pub fn invalid_json(msg: String) -> Self {
+        UsmapError::InvalidJson(msg.into_boxed_str())
+    }
+}
+
+/// Thrown when a usmap reference could not be resolved
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum UsmapValidationError {
+    /// Schema super type was not found
+    #[error("Super type {1} of schema {0} was not found")]
+    UnresolvedSuperType(Box<str>, Box<str>),
+    /// Schema super types form a cycle
+    #[error("Super types of schema {0} form a cycle")]
+    CyclicSuperType(Box<str>),
+    /// Struct referenced by a property was not found
+    #[error("Struct {2} referenced by property {0}.{1} was not found")]
+    UnresolvedStruct(Box<str>, Box<str>, Box<str>),
+    /// Enum referenced by a property was not found
+    #[error("Enum {2} referenced by property {0}.{1} was not found")]
+    UnresolvedEnum(Box<str>, Box<str>, Box<str>),
+}
+
+impl UsmapValidationError {
+    /// Create a `UsmapValidationError` for a super type that was not found
+    pub fn unresolved_super_type(schema: &str, super_type: &str) -> Self {
+        UsmapValidationError::UnresolvedSuperType(schema.into(), super_type.into())
+    }
+
+    /// Create a `UsmapValidationError` for super types that form a cycle
+    pub fn cyclic_super_type(schema: &str) -> Self {
+        UsmapValidationError::CyclicSuperType(schema.into())
+    }
+
+    /// Create a `UsmapValidationError` for a struct that was not found
+    pub fn unresolved_struct(schema: &str, property: &str, struct_type: &str) -> Self {
+        UsmapValidationError::UnresolvedStruct(schema.into(), property.into(), struct_type.into())
+    }
+
+    /// Create a `UsmapValidationError` for an enum that was not found
+    pub fn unresolved_enum(schema: &str, property: &str, enum_name: &str) -> Self {
+        UsmapValidationError::UnresolvedEnum(schema.into(), property.into(), enum_name.into())
+    }
 }
 
 /// Thrown when asset registry failed to deserialize
@@ -293,6 +359,44 @@ impl<T: TryFromPrimitive> From<TryFromPrimitiveError<T>> for IoStoreError {
     }
 }
 
+/// Pak error
+#[derive(Error, Debug)]
+pub enum PakError {
+    /// No valid pak footer was found
+    #[error("No valid pak footer was found")]
+    InvalidFooter,
+    /// Invalid enum value
+    #[error("{0}")]
+    InvalidEnumValue(Box<str>),
+    /// Tried to get a non-existent file from a pak
+    #[error("Tried to get a non-existent file {0}")]
+    NoFile(Box<str>),
+    /// Pak has no full directory index, file paths can't be resolved
+    #[error("Pak has no full directory index")]
+    NoDirectoryIndex,
+
+    /// No encryption key was provided for an encrypted file
+    #[error("No encryption key was provided for an encrypted file")]
+    NoEncryptionKey,
+}
+
+impl<T: TryFromPrimitive> From<TryFromPrimitiveError<T>> for PakError {
+    fn from(e: TryFromPrimitiveError<T>) -> Self {
+        PakError::InvalidEnumValue(e.to_string().into_boxed_str())
+    }
+}
+
+/// File provider error
+#[derive(Error, Debug)]
+pub enum FileProviderError {
+    /// Tried to get a non-existent file from a file provider
+    #[error("Tried to get a non-existent file {0}")]
+    NoFile(Box<str>),
+    /// A game path couldn't be resolved to a file path
+    #[error("Invalid game path {0}")]
+    InvalidGamePath(Box<str>),
+}
+
 /// Error type
 #[derive(Error, Debug)]
 pub enum Error {
@@ -350,6 +454,12 @@ pub enum Error {
     /// A `IoStoreError` occured
     #[error(transparent)]
     IoStore(#[from] IoStoreError),
+    /// A `PakError` occured
+    #[error(transparent)]
+    Pak(#[from] PakError),
+    /// A `FileProviderError` occured
+    #[error(transparent)]
+    FileProvider(#[from] FileProviderError),
 
     /// Tried to decompress data with an unknown compression method
     #[error("Unknown compression method {0}")]
@@ -363,6 +473,9 @@ pub enum Error {
     /// Oodle library not initialized
     #[error("Oodle decompression library is not initialized")]
     OodleNotInitialized,
+    /// An AES key was invalid
+    #[error("Invalid AES key: {0}")]
+    InvalidAesKey(Box<str>),
 
     /// A `ZenError` occured
     #[error(transparent)]
@@ -385,6 +498,11 @@ impl Error {
         Error::InvalidPackageIndex(msg.into_boxed_str())
     }
 
+    /// Create an `Error` for an invalid AES key
+    pub fn invalid_aes_key(msg: String) -> Self {
+        Error::InvalidAesKey(msg.into_boxed_str())
+    }
+
     /// Create an `Error` when a part of the library is not implemented
     pub fn unimplemented(msg: String) -> Self {
         Error::Unimplemented(msg.into_boxed_str())
@@ -369,6 +369,28 @@ bitflags! {
         const FUNC_ALLFLAGS = 0xFFFFFFFF;
     }
 
+    /// Property tag extensions, serialized after the property guid
+    #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+    pub struct EPropertyTagExtension : u8 {
+        /// No extension
+        const NO_EXTENSION = 0x00;
+        /// Reserved for future use
+        const RESERVE_FOR_FUTURE_USE = 0x01;
+        /// Overridable serialization information follows
+        const OVERRIDABLE_INFORMATION = 0x02;
+    }
+
+    /// Class serialization control extension, serialized before tagged properties
+    #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+    pub struct EClassSerializationControlExtension : u8 {
+        /// No extension
+        const NO_EXTENSION = 0x00;
+        /// Reserved for future use
+        const RESERVE_FOR_FUTURE_USE = 0x01;
+        /// Overridable serialization information follows
+        const OVERRIDABLE_SERIALIZATION_INFORMATION = 0x02;
+    }
+
     /// Asset registry dependency propety
     #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
     pub struct EDependencyProperty : u32 {
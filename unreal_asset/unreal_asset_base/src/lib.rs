@@ -7,6 +7,7 @@ pub mod compression;
 pub mod containers;
 pub mod crc;
 pub mod custom_version;
+pub mod encryption;
 pub mod engine_version;
 pub mod enums;
 pub mod error;
@@ -696,6 +696,29 @@ pub enum ObjectVersionUE5 {
     /// Added bulk/data resource table
     DATA_RESOURCES,
 
+    /// Added script property serialization offset to export table entries for saved, versioned packages
+    SCRIPT_SERIALIZATION_OFFSET,
+
+    /// Adding property tag extension,
+    /// Support for overridable serialization on UObject,
+    /// Support for overridable logic in containers
+    PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION,
+
+    /// Added property tag complete type name and serialization type
+    PROPERTY_TAG_COMPLETE_TYPE_NAME,
+
+    /// Changed UE::AssetRegistry::WritePackageData to include PackageBuildDependencies
+    ASSETREGISTRY_PACKAGEBUILDDEPENDENCIES,
+
+    /// Added meta data serialization offset to for saved, versioned packages
+    METADATA_SERIALIZATION_OFFSET,
+
+    /// Added VCells to the object graph
+    VERSE_CELLS,
+
+    /// Changed PackageFileSummary to write FIoHash PackageSavedHash instead of FGuid Guid
+    PACKAGE_SAVED_HASH,
+
     /// -----<new versions can be added before this line>-------------------------------------------------
     AUTOMATIC_VERSION,
     /// Automatic version plus one
@@ -10,11 +10,13 @@ use crate::custom_version::CustomVersion;
 use crate::enums;
 use crate::enums::ECustomVersionSerializationFormat;
 use crate::error::{Error, FNameError};
-use crate::object_version::ObjectVersion;
+use crate::object_version::{ObjectVersion, ObjectVersionUE5};
 use crate::reader::ArchiveTrait;
-use crate::types::{FName, PackageIndexTrait, SerializedNameHeader};
+use crate::types::{FName, PackageIndexTrait, PropertyTagExtensions, SerializedNameHeader};
 use crate::Guid;
 
+use super::FStringEncoding;
+
 /// A trait that allows reading from an archive in an asset-specific way
 pub trait ArchiveReader<Index: PackageIndexTrait>: ArchiveTrait<Index> + Read {
     /// Read a `Guid` property
@@ -27,6 +29,17 @@ pub trait ArchiveReader<Index: PackageIndexTrait>: ArchiveTrait<Index> + Read {
         }
         Ok(None)
     }
+
+    /// Read property tag extensions, serialized after the property guid
+    fn read_property_tag_extensions(&mut self) -> Result<PropertyTagExtensions, Error> {
+        match self.get_object_version_ue5()
+            >= ObjectVersionUE5::PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION
+        {
+            true => PropertyTagExtensions::read(self),
+            false => Ok(PropertyTagExtensions::default()),
+        }
+    }
+
     /// Read an `FName`
     fn read_fname(&mut self) -> Result<FName, Error> {
         let index = self.read_i32::<LE>()?;
@@ -121,12 +134,12 @@ pub trait ArchiveReader<Index: PackageIndexTrait>: ArchiveTrait<Index> + Read {
 
     /// Read `FName` name batch
     fn read_name_batch(&mut self, verify_hashes: bool) -> Result<(Vec<String>, u64), Error> {
-        let num_strings = self.read_i32::<LE>()?;
+        let num_strings = self.read_u32::<LE>()?;
         if num_strings == 0 {
             return Ok((Vec::new(), 0));
         }
 
-        let _strings_length = self.read_u64::<LE>()?;
+        let _num_string_bytes = self.read_u32::<LE>()?;
         let hash_version = self.read_u64::<LE>()?;
 
         let hashes = match hash_version {
@@ -149,22 +162,16 @@ pub trait ArchiveReader<Index: PackageIndexTrait>: ArchiveTrait<Index> + Read {
         }
 
         let mut name_batch = Vec::with_capacity(num_strings as usize);
-
-        for name_header in name_headers {
-            name_batch.push(self.read_name_map_string(Some(name_header)).map(|e| e.0)?);
+        for name_header in &name_headers {
+            name_batch.push(
+                self.read_fstring_name_header(*name_header)?
+                    .unwrap_or_default(),
+            );
         }
 
         if verify_hashes {
             for (i, entry) in name_batch.iter().enumerate() {
-                let hash = match hash_version {
-                    hash if hash == enums::HASH_VERSION_CITYHASH64 => {
-                        Ok(crc::cityhash64_to_lower(entry))
-                    }
-                    _ => Err(Error::unimplemented(format!(
-                        "Unimplemented name batch algorithm: {}",
-                        hash_version
-                    ))),
-                }?;
+                let hash = crc::cityhash64_to_lower_name(entry, name_headers[i].is_wide);
 
                 if hash != hashes[i] {
                     return Err(Error::name_batch_hash_mismatch(
@@ -216,6 +223,8 @@ pub trait ArchiveReader<Index: PackageIndexTrait>: ArchiveTrait<Index> + Read {
 
     /// Read an FString
     fn read_fstring(&mut self) -> Result<Option<String>, Error>;
+    /// Read an FString along with the encoding to write it back with
+    fn read_fstring_with_encoding(&mut self) -> Result<(Option<String>, FStringEncoding), Error>;
     /// Read an FString with a `SerializedNameHeader`
     fn read_fstring_name_header(
         &mut self,
@@ -236,6 +245,13 @@ macro_rules! passthrough_archive_reader {
             self.$passthrough.read_fstring()
         }
 
+        #[inline(always)]
+        fn read_fstring_with_encoding(
+            &mut self,
+        ) -> Result<(Option<String>, $crate::reader::FStringEncoding), Error> {
+            self.$passthrough.read_fstring_with_encoding()
+        }
+
         #[inline(always)]
         fn read_fstring_name_header(
             &mut self,
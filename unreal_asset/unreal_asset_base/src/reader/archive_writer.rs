@@ -4,12 +4,16 @@ use std::io::{self, Write};
 
 use byteorder::{WriteBytesExt, LE};
 
+use crate::crc;
+use crate::enums;
 use crate::error::{Error, FNameError};
-use crate::object_version::ObjectVersion;
+use crate::object_version::{ObjectVersion, ObjectVersionUE5};
 use crate::reader::ArchiveTrait;
-use crate::types::{FName, PackageIndexTrait};
+use crate::types::{FName, PackageIndexTrait, PropertyTagExtensions, SerializedNameHeader};
 use crate::Guid;
 
+use super::FStringEncoding;
+
 /// A trait that allows for writing to an archive in an asset-specific way
 pub trait ArchiveWriter<Index: PackageIndexTrait>: ArchiveTrait<Index> + Write {
     /// Write a `Guid` property
@@ -20,9 +24,22 @@ pub trait ArchiveWriter<Index: PackageIndexTrait>: ArchiveTrait<Index> + Write {
                 self.write_guid(data)?;
             }
         }
+        Ok(())
+    }
 
+    /// Write property tag extensions, serialized after the property guid
+    fn write_property_tag_extensions(
+        &mut self,
+        extensions: &PropertyTagExtensions,
+    ) -> Result<(), Error> {
+        if self.get_object_version_ue5()
+            >= ObjectVersionUE5::PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION
+        {
+            extensions.write(self)?;
+        }
         Ok(())
     }
+
     /// Write an `FName`
     fn write_fname(&mut self, fname: &FName) -> Result<(), Error> {
         match fname {
@@ -42,8 +59,74 @@ pub trait ArchiveWriter<Index: PackageIndexTrait>: ArchiveTrait<Index> + Write {
         }
     }
 
+    /// Write `FName` name batch
+    fn write_name_batch(&mut self, names: &[String], hash_version: u64) -> Result<(), Error> {
+        self.write_u32::<LE>(names.len() as u32)?;
+        if names.is_empty() {
+            return Ok(());
+        }
+
+        if hash_version != enums::HASH_VERSION_CITYHASH64 {
+            return Err(Error::unimplemented(format!(
+                "Unimplemented name batch algorithm: {}",
+                hash_version
+            )));
+        }
+
+        let name_headers = names
+            .iter()
+            .map(|e| match e.is_ascii() {
+                true => SerializedNameHeader {
+                    is_wide: false,
+                    len: e.len() as i32,
+                },
+                false => SerializedNameHeader {
+                    is_wide: true,
+                    len: e.encode_utf16().count() as i32,
+                },
+            })
+            .collect::<Vec<_>>();
+
+        let num_string_bytes = name_headers
+            .iter()
+            .map(|e| match e.is_wide {
+                true => e.len as u32 * 2,
+                false => e.len as u32,
+            })
+            .sum::<u32>();
+        self.write_u32::<LE>(num_string_bytes)?;
+        self.write_u64::<LE>(hash_version)?;
+
+        for (name, name_header) in names.iter().zip(&name_headers) {
+            self.write_u64::<LE>(crc::cityhash64_to_lower_name(name, name_header.is_wide))?;
+        }
+
+        for name_header in &name_headers {
+            name_header.write(self)?;
+        }
+
+        for (name, name_header) in names.iter().zip(&name_headers) {
+            match name_header.is_wide {
+                true => {
+                    for character in name.encode_utf16() {
+                        self.write_u16::<LE>(character)?;
+                    }
+                }
+                false => self.write_all(name.as_bytes())?,
+            }
+        }
+
+        Ok(())
+    }
+
     /// Write an FString
     fn write_fstring(&mut self, value: Option<&str>) -> Result<usize, Error>;
+    /// Write an FString with the given encoding
+    fn write_fstring_with_encoding(
+        &mut self,
+        value: Option<&str>,
+        encoding: FStringEncoding,
+    ) -> Result<usize, Error>;
     /// Write a guid.
     fn write_guid(&mut self, guid: &Guid) -> io::Result<()>;
     /// Write `bool`
@@ -59,6 +142,16 @@ macro_rules! passthrough_archive_writer {
             self.$passthrough.write_fstring(value)
         }
 
+        #[inline(always)]
+        fn write_fstring_with_encoding(
+            &mut self,
+            value: Option<&str>,
+            encoding: $crate::reader::FStringEncoding,
+        ) -> Result<usize, Error> {
+            self.$passthrough
+                .write_fstring_with_encoding(value, encoding)
+        }
+
         #[inline(always)]
         fn write_guid(&mut self, guid: &unreal_helpers::Guid) -> std::io::Result<()> {
             self.$passthrough.write_guid(guid)
@@ -15,3 +15,5 @@ pub use raw_reader::RawReader;
 
 pub mod raw_writer;
 pub use raw_writer::RawWriter;
+
+pub use unreal_helpers::FStringEncoding;
@@ -3,7 +3,7 @@
 use std::io::{self, Read, Seek};
 use std::marker::PhantomData;
 
-use unreal_helpers::{read_ext::read_fstring_len, Guid, UnrealReadExt};
+use unreal_helpers::{read_ext::read_fstring_len_noterm, FStringEncoding, Guid, UnrealReadExt};
 
 use crate::containers::{Chain, IndexedMap, NameMap, SharedResource};
 use crate::custom_version::{CustomVersion, CustomVersionTrait};
@@ -56,6 +56,13 @@ impl<Index: PackageIndexTrait, C: Read + Seek> RawReader<Index, C> {
             _marker: PhantomData,
         }
     }
+
+    /// Replace the data read before the bulk data stream with in-memory data, e.g. a decompressed package
+    ///
+    /// The reader is rewound afterwards
+    pub fn replace_data(&mut self, data: Vec<u8>) -> io::Result<()> {
+        self.cursor.replace_first(data)
+    }
 }
 
 impl<Index: PackageIndexTrait, C: Read + Seek> ArchiveTrait<Index> for RawReader<Index, C> {
@@ -133,6 +140,10 @@ impl<Index: PackageIndexTrait, C: Read + Seek> ArchiveReader<Index> for RawReade
         Ok(self.cursor.read_fstring()?)
     }
 
+    fn read_fstring_with_encoding(&mut self) -> Result<(Option<String>, FStringEncoding), Error> {
+        Ok(self.cursor.read_fstring_with_encoding()?)
+    }
+
     fn read_fstring_name_header(
         &mut self,
         serialized_name_header: SerializedNameHeader,
@@ -141,7 +152,7 @@ impl<Index: PackageIndexTrait, C: Read + Seek> ArchiveReader<Index> for RawReade
             return Ok(None);
         }
 
-        Ok(read_fstring_len(
+        Ok(read_fstring_len_noterm(
             &mut self.cursor,
             serialized_name_header.len,
             serialized_name_header.is_wide,
@@ -3,7 +3,7 @@
 use std::io::{self, Seek, Write};
 use std::marker::PhantomData;
 
-use unreal_helpers::{Guid, UnrealWriteExt};
+use unreal_helpers::{FStringEncoding, Guid, UnrealWriteExt};
 
 use crate::containers::{IndexedMap, NameMap, SharedResource};
 use crate::custom_version::{CustomVersion, CustomVersionTrait};
@@ -136,6 +136,14 @@ impl<Index: PackageIndexTrait, W: Write + Seek> ArchiveWriter<Index>
         Ok(self.cursor.write_fstring(value)?)
     }
 
+    fn write_fstring_with_encoding(
+        &mut self,
+        value: Option<&str>,
+        encoding: FStringEncoding,
+    ) -> Result<usize, Error> {
+        Ok(self.cursor.write_fstring_with_encoding(value, encoding)?)
+    }
+
     fn write_guid(&mut self, guid: &Guid) -> io::Result<()> {
         self.cursor.write_guid(guid)
     }
@@ -0,0 +1,31 @@
+//! Field path
+
+use crate::types::{FName, PackageIndex};
+use crate::FNameContainer;
+
+/// Field path
+///
+/// Points to an `FProperty` by a short path relative to its owner
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct FieldPath {
+    /// Path
+    pub path: Vec<FName>,
+    /// Path owner
+    #[container_ignore]
+    pub resolved_owner: PackageIndex,
+}
+
+// silly `FNameContainer` fix
+mod unreal_asset_base {
+    pub use crate::types;
+}
+
+impl FieldPath {
+    /// Create a new `FieldPath` instance
+    pub fn new(path: Vec<FName>, resolved_owner: PackageIndex) -> Self {
+        FieldPath {
+            path,
+            resolved_owner,
+        }
+    }
+}
@@ -1,10 +1,16 @@
 //! Unreal types
 
+pub mod field_path;
+pub use field_path::FieldPath;
 pub mod fname;
 use byteorder::{ReadBytesExt, WriteBytesExt};
 pub use fname::FName;
 
 pub mod movie;
+pub mod package_object_index;
+pub use package_object_index::PackageObjectIndex;
+pub mod property_tag_extensions;
+pub use property_tag_extensions::PropertyTagExtensions;
 pub mod vector;
 
 use std::hash::Hash;
@@ -37,7 +43,7 @@ impl SerializedNameHeader {
     }
 
     /// Write a `SerializedNameHeader` to an archive
-    pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+    pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait> + ?Sized>(
         &self,
         writer: &mut Writer,
     ) -> Result<(), Error> {
@@ -0,0 +1,176 @@
+//! PackageObjectIndex is used by Zen packages to reference objects
+//!
+//! Unlike [`PackageIndex`](super::PackageIndex) it can reference objects outside of the current package directly
+
+use std::hash::Hash;
+
+use naive_cityhash::cityhash64;
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use crate::types::PackageIndexTrait;
+
+/// PackageObjectIndex type
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, TryFromPrimitive, IntoPrimitive)]
+#[repr(u8)]
+pub enum EPackageObjectIndexType {
+    /// Export of the current package
+    Export,
+    /// Script import
+    ScriptImport,
+    /// Import from another package
+    PackageImport,
+    /// Null
+    Null,
+}
+
+/// PackageObjectIndex is used by Zen packages to reference objects
+///
+/// The top 2 bits store the [`EPackageObjectIndexType`], the rest store an export index,
+/// a script object path hash or an imported package index and public export hash index pair.
+#[derive(Debug, Hash, Copy, Clone, PartialEq, Eq)]
+pub struct PackageObjectIndex {
+    /// Type and id
+    pub type_and_id: u64,
+}
+
+impl PackageObjectIndex {
+    /// Index bits
+    pub const INDEX_BITS: u64 = 62;
+    /// Index mask
+    pub const INDEX_MASK: u64 = (1u64 << Self::INDEX_BITS) - 1;
+    /// Type shift
+    pub const TYPE_SHIFT: u64 = Self::INDEX_BITS;
+    /// Invalid id
+    pub const INVALID: u64 = u64::MAX;
+
+    /// Create a new `PackageObjectIndex` from a raw value
+    pub fn new(type_and_id: u64) -> Self {
+        PackageObjectIndex { type_and_id }
+    }
+
+    /// Create a new null `PackageObjectIndex`
+    pub fn null() -> Self {
+        PackageObjectIndex::new(Self::INVALID)
+    }
+
+    /// Create a new `PackageObjectIndex` from a type and a value
+    pub fn from_type(ty: EPackageObjectIndexType, value: u64) -> Self {
+        PackageObjectIndex::new(
+            ((u8::from(ty) as u64) << Self::TYPE_SHIFT) | (value & Self::INDEX_MASK),
+        )
+    }
+
+    /// Create a new `PackageObjectIndex` from an export index
+    pub fn from_export(export_index: u32) -> Self {
+        PackageObjectIndex::from_type(EPackageObjectIndexType::Export, export_index as u64)
+    }
+
+    /// Create a new script import `PackageObjectIndex` from an object path, e.g. `/Script/Engine.Actor`
+    pub fn from_script_path(object_path: &str) -> Self {
+        PackageObjectIndex::from_type(
+            EPackageObjectIndexType::ScriptImport,
+            Self::generate_import_hash_from_object_path(object_path),
+        )
+    }
+
+    /// Create a new package import `PackageObjectIndex`
+    ///
+    /// # Arguments
+    ///
+    /// * `imported_package_index` - index of the imported package
+    /// * `imported_public_export_hash_index` - index into the imported public export hashes
+    pub fn from_package_import(
+        imported_package_index: u32,
+        imported_public_export_hash_index: u32,
+    ) -> Self {
+        PackageObjectIndex::from_type(
+            EPackageObjectIndexType::PackageImport,
+            ((imported_package_index as u64) << 32) | imported_public_export_hash_index as u64,
+        )
+    }
+
+    /// Generate an import hash from an object path
+    pub fn generate_import_hash_from_object_path(object_path: &str) -> u64 {
+        let encoded = object_path
+            .encode_utf16()
+            .map(|e| match e == '.' as u16 || e == ':' as u16 {
+                true => '/' as u16,
+                false => match e.saturating_sub('A' as u16) < 26 {
+                    true => e + ('a' as u16 - 'A' as u16),
+                    false => e,
+                },
+            })
+            .flat_map(|e| e.to_le_bytes())
+            .collect::<Vec<_>>();
+
+        cityhash64(&encoded) & Self::INDEX_MASK
+    }
+
+    /// Get index type
+    pub fn get_type(&self) -> EPackageObjectIndexType {
+        match self.type_and_id >> Self::TYPE_SHIFT {
+            0 => EPackageObjectIndexType::Export,
+            1 => EPackageObjectIndexType::ScriptImport,
+            2 => EPackageObjectIndexType::PackageImport,
+            _ => EPackageObjectIndexType::Null,
+        }
+    }
+
+    /// Get index value without the type
+    pub fn get_value(&self) -> u64 {
+        self.type_and_id & Self::INDEX_MASK
+    }
+
+    /// Get export index if this is an export
+    pub fn get_export_index(&self) -> Option<u32> {
+        match self.is_export() {
+            true => Some(self.get_value() as u32),
+            false => None,
+        }
+    }
+
+    /// Get imported package index and imported public export hash index if this is a package import
+    pub fn get_package_import(&self) -> Option<(u32, u32)> {
+        match self.is_package_import() {
+            true => Some(((self.get_value() >> 32) as u32, self.get_value() as u32)),
+            false => None,
+        }
+    }
+
+    /// Check if this index is null
+    pub fn is_null(&self) -> bool {
+        self.type_and_id == Self::INVALID
+    }
+
+    /// Check if this index is a script import
+    pub fn is_script_import(&self) -> bool {
+        self.get_type() == EPackageObjectIndexType::ScriptImport
+    }
+
+    /// Check if this index is a package import
+    pub fn is_package_import(&self) -> bool {
+        self.get_type() == EPackageObjectIndexType::PackageImport
+    }
+}
+
+impl Default for PackageObjectIndex {
+    fn default() -> Self {
+        PackageObjectIndex::null()
+    }
+}
+
+impl PackageIndexTrait for PackageObjectIndex {
+    fn is_import(&self) -> bool {
+        self.is_script_import() || self.is_package_import()
+    }
+
+    fn is_export(&self) -> bool {
+        self.get_type() == EPackageObjectIndexType::Export
+    }
+}
+
+impl std::fmt::Display for PackageObjectIndex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}({:#x})", self.get_type(), self.get_value())
+    }
+}
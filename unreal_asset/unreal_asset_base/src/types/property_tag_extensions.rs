@@ -0,0 +1,68 @@
+//! Property tag extensions
+
+use byteorder::{ReadBytesExt, WriteBytesExt};
+
+use crate::enums::EOverriddenPropertyOperation;
+use crate::error::Error;
+use crate::flags::EPropertyTagExtension;
+use crate::reader::{ArchiveReader, ArchiveWriter};
+use crate::types::fname::{FName, FNameContainer};
+
+use super::PackageIndexTrait;
+
+/// Property tag extensions
+///
+/// Serialized after the property guid since `ObjectVersionUE5::PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION`
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PropertyTagExtensions {
+    /// Extension flags
+    pub flags: EPropertyTagExtension,
+    /// Overridden operation, only present with overridable information
+    pub overridden_operation: EOverriddenPropertyOperation,
+    /// Is experimental overridable logic enabled, only present with overridable information
+    pub experimental_overridable_logic: bool,
+}
+
+impl PropertyTagExtensions {
+    /// Read `PropertyTagExtensions` from an archive
+    pub fn read<Reader: ArchiveReader<impl PackageIndexTrait> + ?Sized>(
+        reader: &mut Reader,
+    ) -> Result<Self, Error> {
+        let flags = EPropertyTagExtension::from_bits_retain(reader.read_u8()?);
+        if flags.contains(EPropertyTagExtension::RESERVE_FOR_FUTURE_USE) {
+            return Err(Error::unimplemented(format!(
+                "Unsupported property tag extensions {:#x}",
+                flags.bits()
+            )));
+        }
+
+        let mut extensions = PropertyTagExtensions {
+            flags,
+            ..Default::default()
+        };
+        if flags.contains(EPropertyTagExtension::OVERRIDABLE_INFORMATION) {
+            extensions.overridden_operation =
+                EOverriddenPropertyOperation::try_from(reader.read_u8()?)?;
+            extensions.experimental_overridable_logic = reader.read_bool()?;
+        }
+
+        Ok(extensions)
+    }
+
+    /// Write `PropertyTagExtensions` to an archive
+    pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait> + ?Sized>(
+        &self,
+        writer: &mut Writer,
+    ) -> Result<(), Error> {
+        writer.write_u8(self.flags.bits())?;
+        if self.flags.contains(EPropertyTagExtension::OVERRIDABLE_INFORMATION) {
+            writer.write_u8(self.overridden_operation.into())?;
+            writer.write_bool(self.experimental_overridable_logic)?;
+        }
+        Ok(())
+    }
+}
+
+impl FNameContainer for PropertyTagExtensions {
+    fn traverse_fnames<F: FnMut(&mut FName)>(&mut self, _: &mut F) {}
+}
@@ -1,31 +1,37 @@
 //! Allows reading unversioned assets using mappings
 
+use std::collections::HashSet;
 use std::hash::Hash;
-use std::io::{Cursor, Read, Seek};
+use std::io::{Cursor, Read, Seek, Write};
 
 use bitflags::bitflags;
-use byteorder::{ReadBytesExt, LE};
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
+use crate::compression::{self, CompressionLevel, CompressionMethod};
 use crate::containers::{Chain, IndexedMap, NameMap};
 use crate::custom_version::CustomVersion;
 use crate::error::{Error, UsmapError};
 use crate::object_version::{ObjectVersion, ObjectVersionUE5};
-use crate::reader::{ArchiveReader, ArchiveTrait, RawReader};
+use crate::reader::{ArchiveReader, ArchiveTrait, ArchiveWriter, RawReader, RawWriter};
 
 use crate::types::{FName, PackageIndex};
 
 pub mod ancestry;
 pub mod header;
-#[cfg(feature = "oodle")]
-pub(crate) mod oodle;
 pub mod properties;
+pub mod usmap_diff;
+#[cfg(feature = "json")]
+pub mod usmap_json;
+pub mod usmap_merge;
 pub mod usmap_reader;
+pub mod usmap_validation;
 pub mod usmap_writer;
 
 pub use self::ancestry::Ancestry;
-use self::properties::UsmapProperty;
+use self::properties::{UsmapProperty, UsmapPropertyData};
 use self::usmap_reader::UsmapReader;
+use self::usmap_writer::UsmapWriter;
 
 /// Usmap file version
 #[derive(
@@ -96,7 +102,8 @@ impl UsmapSchema {
         reader: &mut UsmapReader<'_, '_, R>,
     ) -> Result<UsmapSchema, Error> {
         let name = reader.read_name()?;
-        let super_type = reader.read_name()?;
+        // classes and structs without a super type store -1
+        let super_type = reader.read_optional_name()?.unwrap_or_default();
 
         let prop_count = reader.read_u16::<LE>()?;
         let serializable_property_count = reader.read_u16::<LE>()?;
@@ -127,6 +134,31 @@ impl UsmapSchema {
         })
     }
 
+    /// Write a `UsmapSchema` to an archive
+    pub fn write<W: ArchiveWriter<PackageIndex>>(
+        &self,
+        writer: &mut UsmapWriter<'_, '_, W>,
+    ) -> Result<(), Error> {
+        writer.write_name(&self.name)?;
+        writer.write_optional_name(Some(self.super_type.as_str()).filter(|e| !e.is_empty()))?;
+
+        // properties with an array size are expanded when reading, only the first element is serialized
+        let properties = self
+            .properties
+            .values()
+            .filter(|e| e.array_index == 0)
+            .collect::<Vec<_>>();
+
+        writer.write_u16::<LE>(self.prop_count)?;
+        writer.write_u16::<LE>(properties.len() as u16)?;
+
+        for property in properties {
+            property.write(writer)?;
+        }
+
+        Ok(())
+    }
+
     /// Gets a usmap property
     pub fn get_property(&self, name: &str, duplication_index: u32) -> Option<&UsmapProperty> {
         // todo: remove to_string
@@ -161,7 +193,7 @@ pub struct Usmap {
 }
 
 impl Usmap {
-    const ASSET_MAGIC: u16 = u16::from_be_bytes([0xc4, 0x30]);
+    const ASSET_MAGIC: u16 = 0x30c4;
 
     /// Gets usmap property for a given property name + ancestry
     pub fn get_property(
@@ -248,9 +280,9 @@ impl Usmap {
             ));
         }
 
-        let usmap_version = EUsmapVersion::try_from(reader.read_u8()?)?;
+        self.version = EUsmapVersion::try_from(reader.read_u8()?)?;
 
-        let mut has_versioning = usmap_version >= EUsmapVersion::PackageVersioning;
+        let mut has_versioning = self.version >= EUsmapVersion::PackageVersioning;
         if has_versioning {
             has_versioning = reader.read_bool()?;
         }
@@ -304,16 +336,10 @@ impl Usmap {
 
                 #[cfg(feature = "oodle")]
                 {
-                    let compressed = vec![0u8; compressed_size as usize];
-
-                    let decompressed = oodle::decompress(
-                        &compressed,
-                        compressed_size as u64,
-                        decompressed_size as u64,
-                    )
-                    .ok_or_else(|| UsmapError::invalid_compression_data())?;
-
-                    decompressed
+                    let mut decompressed_data = vec![0u8; decompressed_size as usize];
+                    compression::oodle::decompress(&compressed_data, &mut decompressed_data)
+                        .map_err(|_| UsmapError::invalid_compression_data())?;
+                    decompressed_data
                 }
             }
             EUsmapCompressionMethod::Unknown => {
@@ -333,7 +359,7 @@ impl Usmap {
 
         self.name_map = reader.read_array(|reader| {
             let name_length = reader.read_u8()?;
-            let mut buf = vec![0u8; name_length as usize - 1];
+            let mut buf = vec![0u8; name_length as usize];
             reader.read_exact(&mut buf)?;
             Ok(String::from_utf8(buf)?)
         })?;
@@ -393,6 +419,185 @@ impl Usmap {
         Ok(())
     }
 
+    /// Write usmap file
+    ///
+    /// The name map is extended with names that are referenced but not in it yet
+    pub fn write<W: Write + Seek>(&self, cursor: &mut W) -> Result<(), Error> {
+        let name_map = self.build_name_map();
+        let data = self.write_data(&name_map)?;
+
+        let compressed_data = match self.compression_method {
+            EUsmapCompressionMethod::None => None,
+            EUsmapCompressionMethod::Brotli => Some(compression::compress(
+                CompressionMethod::Brotli,
+                &data,
+                CompressionLevel::Default,
+            )?),
+            EUsmapCompressionMethod::ZStandard => Some(compression::compress(
+                CompressionMethod::Zstd,
+                &data,
+                CompressionLevel::Default,
+            )?),
+            EUsmapCompressionMethod::Oodle | EUsmapCompressionMethod::Unknown => {
+                return Err(
+                    UsmapError::unsupported_compression(self.compression_method as u8).into(),
+                );
+            }
+        };
+        let compressed_data = compressed_data.as_deref().unwrap_or(&data);
+
+        let mut writer = RawWriter::<PackageIndex, W>::new(
+            cursor,
+            self.object_version,
+            self.object_version_ue5,
+            false,
+            NameMap::new(),
+        );
+
+        writer.write_u16::<LE>(Self::ASSET_MAGIC)?;
+        writer.write_u8(self.version as u8)?;
+
+        if self.version >= EUsmapVersion::PackageVersioning {
+            let has_versioning = self.object_version != ObjectVersion::UNKNOWN;
+            writer.write_bool(has_versioning)?;
+
+            if has_versioning {
+                writer.write_i32::<LE>(self.object_version as i32)?;
+                writer.write_i32::<LE>(self.object_version_ue5 as i32)?;
+                writer.write_i32::<LE>(self.custom_versions.len() as i32)?;
+                for custom_version in &self.custom_versions {
+                    custom_version.write(&mut writer)?;
+                }
+                writer.write_u32::<LE>(self.net_cl)?;
+            }
+        }
+
+        writer.write_u8(self.compression_method as u8)?;
+        writer.write_u32::<LE>(compressed_data.len() as u32)?;
+        writer.write_u32::<LE>(data.len() as u32)?;
+        writer.write_all(compressed_data)?;
+
+        Ok(())
+    }
+
+    /// Write usmap data, this is the part of the file that gets compressed
+    fn write_data(&self, name_map: &[String]) -> Result<Vec<u8>, Error> {
+        let mut cursor = Cursor::new(Vec::new());
+        let mut raw_writer = RawWriter::<PackageIndex, _>::new(
+            &mut cursor,
+            self.object_version,
+            self.object_version_ue5,
+            false,
+            NameMap::new(),
+        );
+
+        raw_writer.write_i32::<LE>(name_map.len() as i32)?;
+        for name in name_map {
+            let name_length = u8::try_from(name.len()).map_err(|_| {
+                Error::invalid_file(format!("Name {name} is too long for a usmap name map"))
+            })?;
+            raw_writer.write_u8(name_length)?;
+            raw_writer.write_all(name.as_bytes())?;
+        }
+
+        raw_writer.write_u32::<LE>(self.enum_map.len() as u32)?;
+
+        let mut writer = UsmapWriter::new(&mut raw_writer, name_map, &self.custom_versions);
+
+        for (_, enum_name, enum_names) in self.enum_map.iter() {
+            let enum_names_len = u8::try_from(enum_names.len()).map_err(|_| {
+                Error::invalid_file(format!("Enum {enum_name} has too many values"))
+            })?;
+
+            writer.write_name(enum_name)?;
+            writer.write_u8(enum_names_len)?;
+            for name in enum_names {
+                writer.write_name(name)?;
+            }
+        }
+
+        writer.write_u32::<LE>(self.schemas.len() as u32)?;
+        for schema in self.schemas.values() {
+            schema.write(&mut writer)?;
+        }
+
+        // write extensions
+
+        if !self.extension_version.is_empty() {
+            writer.write_u32::<LE>(self.extension_version.bits())?;
+
+            if self
+                .extension_version
+                .contains(UsmapExtensionVersion::PATHS)
+            {
+                let mut module_paths: Vec<&str> = Vec::new();
+                let module_path_indices = self
+                    .schemas
+                    .values()
+                    .map(|schema| {
+                        let module_path = schema.module_path.as_deref().unwrap_or_default();
+                        match module_paths.iter().position(|e| *e == module_path) {
+                            Some(index) => index,
+                            None => {
+                                module_paths.push(module_path);
+                                module_paths.len() - 1
+                            }
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                let num_module_paths = u16::try_from(module_paths.len())
+                    .map_err(|_| Error::invalid_file("Too many usmap module paths".to_string()))?;
+                writer.write_u16::<LE>(num_module_paths)?;
+                for module_path in &module_paths {
+                    writer.write_fstring(Some(module_path))?;
+                }
+
+                for index in module_path_indices {
+                    match num_module_paths > u8::MAX as u16 {
+                        true => writer.write_u16::<LE>(index as u16)?,
+                        false => writer.write_u8(index as u8)?,
+                    };
+                }
+            }
+        }
+
+        Ok(cursor.into_inner())
+    }
+
+    /// Build the name map for writing
+    ///
+    /// Names already in the name map keep their index, new names are appended
+    fn build_name_map(&self) -> Vec<String> {
+        let mut referenced = Vec::new();
+
+        for (_, enum_name, enum_names) in self.enum_map.iter() {
+            referenced.push(enum_name.as_str());
+            referenced.extend(enum_names.iter().map(|e| e.as_str()));
+        }
+
+        for schema in self.schemas.values() {
+            referenced.push(schema.name.as_str());
+            if !schema.super_type.is_empty() {
+                referenced.push(schema.super_type.as_str());
+            }
+            for property in schema.properties.values() {
+                referenced.push(property.name.as_str());
+                collect_property_names(&property.property_data, &mut referenced);
+            }
+        }
+
+        let mut name_map = self.name_map.clone();
+        let mut names = name_map.iter().cloned().collect::<HashSet<_>>();
+        for name in referenced {
+            if names.insert(name.to_string()) {
+                name_map.push(name.to_string());
+            }
+        }
+
+        name_map
+    }
+
     /// Create a new usmap file
     pub fn new(cursor: Cursor<Vec<u8>>) -> Result<Self, Error> {
         let mut usmap = Usmap {
@@ -411,3 +616,28 @@ impl Usmap {
         Ok(usmap)
     }
 }
+
+/// Collect all names referenced by usmap property data
+fn collect_property_names<'a>(property_data: &'a UsmapPropertyData, names: &mut Vec<&'a str>) {
+    match property_data {
+        UsmapPropertyData::UsmapEnumPropertyData(data) => {
+            collect_property_names(&data.inner_property, names);
+            names.push(data.name.as_str());
+        }
+        UsmapPropertyData::UsmapStructPropertyData(data) => names.push(data.struct_type.as_str()),
+        UsmapPropertyData::UsmapSetPropertyData(data) => {
+            collect_property_names(&data.inner_type, names)
+        }
+        UsmapPropertyData::UsmapArrayPropertyData(data) => {
+            collect_property_names(&data.inner_type, names)
+        }
+        UsmapPropertyData::UsmapOptionalPropertyData(data) => {
+            collect_property_names(&data.inner_type, names)
+        }
+        UsmapPropertyData::UsmapMapPropertyData(data) => {
+            collect_property_names(&data.inner_type, names);
+            collect_property_names(&data.value_type, names);
+        }
+        UsmapPropertyData::UsmapShallowPropertyData(_) => {}
+    }
+}
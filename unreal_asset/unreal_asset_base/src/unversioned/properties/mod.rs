@@ -2,8 +2,9 @@
 
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::mem::size_of;
 
-use byteorder::{ReadBytesExt, LE};
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
 use enum_dispatch::enum_dispatch;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 
@@ -15,14 +16,16 @@ use crate::Error;
 pub mod array_property;
 pub mod enum_property;
 pub mod map_property;
+pub mod optional_property;
 pub mod set_property;
 pub mod shallow_property;
 pub mod struct_property;
 
 use self::{
     array_property::UsmapArrayPropertyData, enum_property::UsmapEnumPropertyData,
-    map_property::UsmapMapPropertyData, set_property::UsmapSetPropertyData,
-    shallow_property::UsmapShallowPropertyData, struct_property::UsmapStructPropertyData,
+    map_property::UsmapMapPropertyData, optional_property::UsmapOptionalPropertyData,
+    set_property::UsmapSetPropertyData, shallow_property::UsmapShallowPropertyData,
+    struct_property::UsmapStructPropertyData,
 };
 
 /// Usmap property type
@@ -85,6 +88,12 @@ pub enum EPropertyType {
     EnumProperty,
     /// FieldPath
     FieldPathProperty,
+    /// Optional
+    OptionalProperty,
+    /// Utf8Str
+    Utf8StrProperty,
+    /// AnsiStr
+    AnsiStrProperty,
 
     /// Unknown
     Unknown = 0xFF,
@@ -121,11 +130,55 @@ impl std::fmt::Display for EPropertyType {
             EPropertyType::SetProperty => "SetProperty",
             EPropertyType::EnumProperty => "EnumProperty",
             EPropertyType::FieldPathProperty => "FieldPathProperty",
+            EPropertyType::OptionalProperty => "OptionalProperty",
+            EPropertyType::Utf8StrProperty => "Utf8StrProperty",
+            EPropertyType::AnsiStrProperty => "AnsiStrProperty",
             EPropertyType::Unknown => "Unknown",
         })
     }
 }
 
+impl EPropertyType {
+    /// Get a property type by its name, e.g. `IntProperty`
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "ByteProperty" => EPropertyType::ByteProperty,
+            "BoolProperty" => EPropertyType::BoolProperty,
+            "IntProperty" => EPropertyType::IntProperty,
+            "FloatProperty" => EPropertyType::FloatProperty,
+            "ObjectProperty" => EPropertyType::ObjectProperty,
+            "NameProperty" => EPropertyType::NameProperty,
+            "DelegateProperty" => EPropertyType::DelegateProperty,
+            "DoubleProperty" => EPropertyType::DoubleProperty,
+            "ArrayProperty" => EPropertyType::ArrayProperty,
+            "StructProperty" => EPropertyType::StructProperty,
+            "StrProperty" => EPropertyType::StrProperty,
+            "TextProperty" => EPropertyType::TextProperty,
+            "InterfaceProperty" => EPropertyType::InterfaceProperty,
+            "MulticastDelegateProperty" => EPropertyType::MulticastDelegateProperty,
+            "WeakObjectProperty" => EPropertyType::WeakObjectProperty,
+            "LazyObjectProperty" => EPropertyType::LazyObjectProperty,
+            "AssetObjectProperty" => EPropertyType::AssetObjectProperty,
+            "SoftObjectProperty" => EPropertyType::SoftObjectProperty,
+            "UInt64Property" => EPropertyType::UInt64Property,
+            "UInt32Property" => EPropertyType::UInt32Property,
+            "UInt16Property" => EPropertyType::UInt16Property,
+            "Int64Property" => EPropertyType::Int64Property,
+            "Int16Property" => EPropertyType::Int16Property,
+            "Int8Property" => EPropertyType::Int8Property,
+            "MapProperty" => EPropertyType::MapProperty,
+            "SetProperty" => EPropertyType::SetProperty,
+            "EnumProperty" => EPropertyType::EnumProperty,
+            "FieldPathProperty" => EPropertyType::FieldPathProperty,
+            "OptionalProperty" => EPropertyType::OptionalProperty,
+            "Utf8StrProperty" => EPropertyType::Utf8StrProperty,
+            "AnsiStrProperty" => EPropertyType::AnsiStrProperty,
+            "Unknown" => EPropertyType::Unknown,
+            _ => return None,
+        })
+    }
+}
+
 /// This must be implemented for all UsmapPropertyDatas
 #[enum_dispatch]
 pub trait UsmapPropertyDataTrait: Debug + Hash + Clone + PartialEq + Eq {
@@ -152,6 +205,8 @@ pub enum UsmapPropertyData {
     UsmapArrayPropertyData,
     /// Map
     UsmapMapPropertyData,
+    /// Optional
+    UsmapOptionalPropertyData,
 
     /// Shallow
     UsmapShallowPropertyData,
@@ -170,6 +225,7 @@ impl UsmapPropertyData {
             EPropertyType::MapProperty => UsmapMapPropertyData::new(asset)?.into(),
             EPropertyType::SetProperty => UsmapSetPropertyData::new(asset)?.into(),
             EPropertyType::EnumProperty => UsmapEnumPropertyData::new(asset)?.into(),
+            EPropertyType::OptionalProperty => UsmapOptionalPropertyData::new(asset)?.into(),
             _ => UsmapShallowPropertyData {
                 property_type: prop_type,
             }
@@ -213,4 +269,17 @@ impl UsmapProperty {
             property_data,
         })
     }
+
+    /// Write an `UsmapProperty` to an asset
+    pub fn write<W: ArchiveWriter<PackageIndex>>(
+        &self,
+        asset: &mut UsmapWriter<'_, '_, W>,
+    ) -> Result<usize, Error> {
+        asset.write_u16::<LE>(self.schema_index)?;
+        asset.write_u8(self.array_size)?;
+        asset.write_name(&self.name)?;
+
+        let size = self.property_data.write(asset)?;
+        Ok(size + size_of::<u16>() + size_of::<u8>() + size_of::<i32>())
+    }
 }
@@ -0,0 +1,154 @@
+//! Usmap diffing
+
+use std::collections::HashMap;
+
+use super::{properties::UsmapProperty, Usmap, UsmapSchema};
+
+/// Differences between two versions of a schema
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct UsmapSchemaDiff {
+    /// Schema name
+    pub name: String,
+    /// Old and new super type, if changed
+    pub super_type: Option<(String, String)>,
+    /// Old and new properties count, if changed
+    pub prop_count: Option<(u16, u16)>,
+    /// Old and new module path, if changed
+    pub module_path: Option<(Option<String>, Option<String>)>,
+    /// Added property names
+    pub added_properties: Vec<String>,
+    /// Removed property names
+    pub removed_properties: Vec<String>,
+    /// Names of properties with a different type, array size or schema index
+    pub changed_properties: Vec<String>,
+}
+
+impl UsmapSchemaDiff {
+    /// Compare two versions of a schema, returns `None` if they are equal
+    pub fn new(old: &UsmapSchema, new: &UsmapSchema) -> Option<Self> {
+        // properties with an array size are expanded, only the first element is compared
+        let old_properties = first_elements(old);
+        let new_properties = first_elements(new);
+
+        let mut diff = UsmapSchemaDiff {
+            name: new.name.clone(),
+            super_type: changed(old.super_type.clone(), new.super_type.clone()),
+            prop_count: changed(old.prop_count, new.prop_count),
+            module_path: changed(old.module_path.clone(), new.module_path.clone()),
+            ..Default::default()
+        };
+
+        for property in old.properties.values().filter(|e| e.array_index == 0) {
+            match new_properties.get(property.name.as_str()) {
+                Some(new_property) if *new_property != property => {
+                    diff.changed_properties.push(property.name.clone())
+                }
+                Some(_) => {}
+                None => diff.removed_properties.push(property.name.clone()),
+            }
+        }
+
+        for property in new.properties.values().filter(|e| e.array_index == 0) {
+            if !old_properties.contains_key(property.name.as_str()) {
+                diff.added_properties.push(property.name.clone());
+            }
+        }
+
+        match diff.is_empty() {
+            true => None,
+            false => Some(diff),
+        }
+    }
+
+    /// Check if the schemas are equal
+    pub fn is_empty(&self) -> bool {
+        self.super_type.is_none()
+            && self.prop_count.is_none()
+            && self.module_path.is_none()
+            && self.added_properties.is_empty()
+            && self.removed_properties.is_empty()
+            && self.changed_properties.is_empty()
+    }
+}
+
+/// Get the old and new value if they differ
+fn changed<T: PartialEq>(old: T, new: T) -> Option<(T, T)> {
+    (old != new).then_some((old, new))
+}
+
+/// Get the first element of every property in a schema by name
+fn first_elements(schema: &UsmapSchema) -> HashMap<&str, &UsmapProperty> {
+    schema
+        .properties
+        .values()
+        .filter(|e| e.array_index == 0)
+        .map(|e| (e.name.as_str(), e))
+        .collect()
+}
+
+/// Differences between two usmaps
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct UsmapDiff {
+    /// Added enum names
+    pub added_enums: Vec<String>,
+    /// Removed enum names
+    pub removed_enums: Vec<String>,
+    /// Names of enums with different values
+    pub changed_enums: Vec<String>,
+    /// Added schema names
+    pub added_schemas: Vec<String>,
+    /// Removed schema names
+    pub removed_schemas: Vec<String>,
+    /// Changed schemas
+    pub changed_schemas: Vec<UsmapSchemaDiff>,
+}
+
+impl UsmapDiff {
+    /// Check if the usmaps have equal enums and schemas
+    pub fn is_empty(&self) -> bool {
+        self.added_enums.is_empty()
+            && self.removed_enums.is_empty()
+            && self.changed_enums.is_empty()
+            && self.added_schemas.is_empty()
+            && self.removed_schemas.is_empty()
+            && self.changed_schemas.is_empty()
+    }
+}
+
+impl Usmap {
+    /// Compare the enums and schemas of this usmap to a newer one
+    pub fn diff(&self, new: &Usmap) -> UsmapDiff {
+        let mut diff = UsmapDiff::default();
+
+        for (_, name, values) in self.enum_map.iter() {
+            match new.enum_map.get_by_key(name) {
+                Some(new_values) if new_values != values => diff.changed_enums.push(name.clone()),
+                Some(_) => {}
+                None => diff.removed_enums.push(name.clone()),
+            }
+        }
+
+        for (_, name, _) in new.enum_map.iter() {
+            if !self.enum_map.contains_key(name) {
+                diff.added_enums.push(name.clone());
+            }
+        }
+
+        for (_, name, schema) in self.schemas.iter() {
+            match new.schemas.get_by_key(name) {
+                Some(new_schema) => diff
+                    .changed_schemas
+                    .extend(UsmapSchemaDiff::new(schema, new_schema)),
+                None => diff.removed_schemas.push(name.clone()),
+            }
+        }
+
+        for (_, name, _) in new.schemas.iter() {
+            if !self.schemas.contains_key(name) {
+                diff.added_schemas.push(name.clone());
+            }
+        }
+
+        diff
+    }
+}
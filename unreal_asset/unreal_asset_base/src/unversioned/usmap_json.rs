@@ -0,0 +1,333 @@
+//! Usmap json import and export
+//!
+//! Mappings are stored as a json object with the following layout:
+//!
+//! ```json
+//! {
+//!   "enums": [
+//!     { "name": "EColor", "values": ["EColor::Red", "EColor::Green", "EColor::EColor_MAX"] }
+//!   ],
+//!   "schemas": [
+//!     {
+//!       "name": "TestActor",
+//!       "super_type": "Actor",
+//!       "module_path": "/Script/TestGame",
+//!       "prop_count": 4,
+//!       "properties": [
+//!         { "name": "Health", "schema_index": 0, "type": { "type": "FloatProperty" } },
+//!         { "name": "Slots", "array_size": 2, "type": { "type": "IntProperty" } },
+//!         { "name": "Location", "type": { "type": "StructProperty", "struct_type": "Vector" } },
+//!         {
+//!           "name": "Colors",
+//!           "type": {
+//!             "type": "ArrayProperty",
+//!             "inner": {
+//!               "type": "EnumProperty",
+//!               "enum_name": "EColor",
+//!               "inner": { "type": "ByteProperty" }
+//!             }
+//!           }
+//!         }
+//!       ]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! * `super_type` and `module_path` are optional.
+//! * `schema_index` defaults to the index after the previous property,
+//!   `array_size` defaults to 1 and `prop_count` defaults to the amount of schema indices used by the properties.
+//! * `type` is a property type name such as `IntProperty`.
+//!   `StructProperty` requires `struct_type`, `EnumProperty` requires `enum_name` and `inner`,
+//!   `ArrayProperty`, `SetProperty` and `OptionalProperty` require `inner`, `MapProperty` requires `key` and `value`.
+//!
+//! Enum and schema order is preserved. Versioning information is not stored.
+
+use std::io::{Read, Write};
+
+use serde::{Deserialize, Serialize};
+
+use crate::containers::IndexedMap;
+use crate::error::{Error, UsmapError};
+use crate::object_version::{ObjectVersion, ObjectVersionUE5};
+
+use super::properties::{
+    array_property::UsmapArrayPropertyData, enum_property::UsmapEnumPropertyData,
+    map_property::UsmapMapPropertyData, optional_property::UsmapOptionalPropertyData,
+    set_property::UsmapSetPropertyData, shallow_property::UsmapShallowPropertyData,
+    struct_property::UsmapStructPropertyData, EPropertyType, UsmapProperty, UsmapPropertyData,
+    UsmapPropertyDataTrait,
+};
+use super::{EUsmapCompressionMethod, EUsmapVersion, Usmap, UsmapExtensionVersion, UsmapSchema};
+
+/// Json mappings
+#[derive(Serialize, Deserialize)]
+struct JsonUsmap {
+    #[serde(default)]
+    enums: Vec<JsonEnum>,
+    #[serde(default)]
+    schemas: Vec<JsonSchema>,
+}
+
+/// Json enum
+#[derive(Serialize, Deserialize)]
+struct JsonEnum {
+    name: String,
+    values: Vec<String>,
+}
+
+/// Json schema
+#[derive(Serialize, Deserialize)]
+struct JsonSchema {
+    name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    super_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    module_path: Option<String>,
+    #[serde(default)]
+    prop_count: Option<u16>,
+    #[serde(default)]
+    properties: Vec<JsonProperty>,
+}
+
+/// Json property
+#[derive(Serialize, Deserialize)]
+struct JsonProperty {
+    name: String,
+    #[serde(default)]
+    schema_index: Option<u16>,
+    #[serde(default = "default_array_size")]
+    array_size: u8,
+    #[serde(rename = "type")]
+    property_type: JsonPropertyType,
+}
+
+/// Default json property array size
+fn default_array_size() -> u8 {
+    1
+}
+
+/// Json property type
+#[derive(Serialize, Deserialize)]
+struct JsonPropertyType {
+    #[serde(rename = "type")]
+    property_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    struct_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    enum_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    inner: Option<Box<JsonPropertyType>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    key: Option<Box<JsonPropertyType>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value: Option<Box<JsonPropertyType>>,
+}
+
+impl JsonPropertyType {
+    /// Create a json property type with only a type name
+    fn new(property_type: EPropertyType) -> Self {
+        JsonPropertyType {
+            property_type: property_type.to_string(),
+            struct_type: None,
+            enum_name: None,
+            inner: None,
+            key: None,
+            value: None,
+        }
+    }
+
+    /// Convert usmap property data to a json property type
+    fn from_property_data(property_data: &UsmapPropertyData) -> Self {
+        let boxed = |property_data: &UsmapPropertyData| {
+            Some(Box::new(Self::from_property_data(property_data)))
+        };
+
+        let mut json = Self::new(property_data.get_property_type());
+        match property_data {
+            UsmapPropertyData::UsmapStructPropertyData(data) => {
+                json.struct_type = Some(data.struct_type.clone())
+            }
+            UsmapPropertyData::UsmapEnumPropertyData(data) => {
+                json.enum_name = Some(data.name.clone());
+                json.inner = boxed(&data.inner_property);
+            }
+            UsmapPropertyData::UsmapArrayPropertyData(data) => json.inner = boxed(&data.inner_type),
+            UsmapPropertyData::UsmapSetPropertyData(data) => json.inner = boxed(&data.inner_type),
+            UsmapPropertyData::UsmapOptionalPropertyData(data) => {
+                json.inner = boxed(&data.inner_type)
+            }
+            UsmapPropertyData::UsmapMapPropertyData(data) => {
+                json.key = boxed(&data.inner_type);
+                json.value = boxed(&data.value_type);
+            }
+            UsmapPropertyData::UsmapShallowPropertyData(_) => {}
+        }
+        json
+    }
+
+    /// Convert a json property type to usmap property data
+    fn into_property_data(self) -> Result<UsmapPropertyData, UsmapError> {
+        let property_type = EPropertyType::from_name(&self.property_type).ok_or_else(|| {
+            UsmapError::invalid_json(format!("Unknown property type {}", self.property_type))
+        })?;
+
+        let required = |field: Option<Box<JsonPropertyType>>, name: &str| {
+            field
+                .ok_or_else(|| {
+                    UsmapError::invalid_json(format!("{} requires {}", self.property_type, name))
+                })?
+                .into_property_data()
+                .map(Box::new)
+        };
+
+        Ok(match property_type {
+            EPropertyType::StructProperty => UsmapStructPropertyData {
+                struct_type: self.struct_type.ok_or_else(|| {
+                    UsmapError::invalid_json("StructProperty requires struct_type".to_string())
+                })?,
+            }
+            .into(),
+            EPropertyType::EnumProperty => UsmapEnumPropertyData {
+                name: self.enum_name.ok_or_else(|| {
+                    UsmapError::invalid_json("EnumProperty requires enum_name".to_string())
+                })?,
+                inner_property: required(self.inner, "inner")?,
+            }
+            .into(),
+            EPropertyType::ArrayProperty => UsmapArrayPropertyData {
+                inner_type: required(self.inner, "inner")?,
+            }
+            .into(),
+            EPropertyType::SetProperty => UsmapSetPropertyData {
+                inner_type: required(self.inner, "inner")?,
+            }
+            .into(),
+            EPropertyType::OptionalProperty => UsmapOptionalPropertyData {
+                inner_type: required(self.inner, "inner")?,
+            }
+            .into(),
+            EPropertyType::MapProperty => UsmapMapPropertyData {
+                inner_type: required(self.key, "key")?,
+                value_type: required(self.value, "value")?,
+            }
+            .into(),
+            property_type => UsmapShallowPropertyData { property_type }.into(),
+        })
+    }
+}
+
+impl JsonSchema {
+    /// Convert a usmap schema to a json schema
+    fn from_schema(schema: &UsmapSchema) -> Self {
+        JsonSchema {
+            name: schema.name.clone(),
+            super_type: schema.super_type.clone(),
+            module_path: schema.module_path.clone(),
+            prop_count: Some(schema.prop_count),
+            // properties with an array size are expanded, only the first element is stored
+            properties: schema
+                .properties
+                .values()
+                .filter(|e| e.array_index == 0)
+                .map(|e| JsonProperty {
+                    name: e.name.clone(),
+                    schema_index: Some(e.schema_index),
+                    array_size: e.array_size,
+                    property_type: JsonPropertyType::from_property_data(&e.property_data),
+                })
+                .collect(),
+        }
+    }
+
+    /// Convert a json schema to a usmap schema
+    fn into_schema(self) -> Result<UsmapSchema, UsmapError> {
+        let mut properties = IndexedMap::new();
+        let mut next_index = 0u16;
+
+        for property in self.properties {
+            let schema_index = property.schema_index.unwrap_or(next_index);
+            let array_size = property.array_size.max(1);
+            let property_data = property.property_type.into_property_data()?;
+
+            for array_index in 0..array_size as u16 {
+                let property = UsmapProperty {
+                    name: property.name.clone(),
+                    schema_index: schema_index + array_index,
+                    array_size,
+                    array_index,
+                    property_data: property_data.clone(),
+                };
+                properties.insert(
+                    (property.name.clone(), property.schema_index as u32),
+                    property,
+                );
+            }
+
+            next_index = next_index.max(schema_index + array_size as u16);
+        }
+
+        Ok(UsmapSchema {
+            name: self.name,
+            super_type: self.super_type,
+            prop_count: self.prop_count.unwrap_or(next_index),
+            module_path: self.module_path,
+            properties,
+        })
+    }
+}
+
+impl Usmap {
+    /// Read a usmap from json mappings
+    pub fn from_json<R: Read>(reader: R) -> Result<Self, Error> {
+        let json: JsonUsmap =
+            serde_json::from_reader(reader).map_err(|e| UsmapError::invalid_json(e.to_string()))?;
+
+        let mut enum_map = IndexedMap::with_capacity(json.enums.len());
+        for json_enum in json.enums {
+            enum_map.insert(json_enum.name, json_enum.values);
+        }
+
+        let mut schemas = IndexedMap::with_capacity(json.schemas.len());
+        for json_schema in json.schemas {
+            let schema = json_schema.into_schema()?;
+            schemas.insert(schema.name.clone(), schema);
+        }
+
+        let extension_version = match schemas.values().any(|e| e.module_path.is_some()) {
+            true => UsmapExtensionVersion::PATHS,
+            false => UsmapExtensionVersion::NONE,
+        };
+
+        Ok(Usmap {
+            version: EUsmapVersion::Latest,
+            name_map: Vec::new(),
+            enum_map,
+            schemas,
+            extension_version,
+            object_version: ObjectVersion::UNKNOWN,
+            object_version_ue5: ObjectVersionUE5::UNKNOWN,
+            custom_versions: Vec::new(),
+            compression_method: EUsmapCompressionMethod::None,
+            net_cl: 0,
+        })
+    }
+
+    /// Write this usmap as json mappings
+    pub fn write_json<W: Write>(&self, writer: W) -> Result<(), Error> {
+        let json = JsonUsmap {
+            enums: self
+                .enum_map
+                .iter()
+                .map(|(_, name, values)| JsonEnum {
+                    name: name.clone(),
+                    values: values.clone(),
+                })
+                .collect(),
+            schemas: self.schemas.values().map(JsonSchema::from_schema).collect(),
+        };
+
+        serde_json::to_writer_pretty(writer, &json)
+            .map_err(|e| UsmapError::invalid_json(e.to_string()).into())
+    }
+}
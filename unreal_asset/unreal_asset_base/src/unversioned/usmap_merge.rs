@@ -0,0 +1,75 @@
+//! Usmap merging
+
+use super::Usmap;
+
+/// Strategy used when both usmaps contain an entry with the same name but different contents
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum UsmapMergeStrategy {
+    /// Keep the entry that is already present
+    #[default]
+    KeepExisting,
+    /// Replace the entry with the incoming one
+    Overwrite,
+}
+
+/// Usmap entry kind
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum UsmapEntryKind {
+    /// Enum
+    Enum,
+    /// Schema
+    Schema,
+}
+
+/// An entry that is present in both usmaps with different contents
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct UsmapConflict {
+    /// Entry kind
+    pub kind: UsmapEntryKind,
+    /// Entry name
+    pub name: String,
+}
+
+impl Usmap {
+    /// Merge another usmap into this one
+    ///
+    /// Entries missing from this usmap are added, conflicting entries are resolved with `strategy`.
+    /// Returns all conflicting entries.
+    pub fn merge(&mut self, other: &Usmap, strategy: UsmapMergeStrategy) -> Vec<UsmapConflict> {
+        let mut conflicts = Vec::new();
+
+        for (_, name, values) in other.enum_map.iter() {
+            match self.enum_map.get_by_key_mut(name) {
+                Some(existing) if existing != values => {
+                    conflicts.push(UsmapConflict {
+                        kind: UsmapEntryKind::Enum,
+                        name: name.clone(),
+                    });
+                    if strategy == UsmapMergeStrategy::Overwrite {
+                        *existing = values.clone();
+                    }
+                }
+                Some(_) => {}
+                None => self.enum_map.insert(name.clone(), values.clone()),
+            }
+        }
+
+        for (_, name, schema) in other.schemas.iter() {
+            match self.schemas.get_by_key_mut(name) {
+                Some(existing) if existing != schema => {
+                    conflicts.push(UsmapConflict {
+                        kind: UsmapEntryKind::Schema,
+                        name: name.clone(),
+                    });
+                    if strategy == UsmapMergeStrategy::Overwrite {
+                        *existing = schema.clone();
+                    }
+                }
+                Some(_) => {}
+                None => self.schemas.insert(name.clone(), schema.clone()),
+            }
+        }
+
+        conflicts
+    }
+}
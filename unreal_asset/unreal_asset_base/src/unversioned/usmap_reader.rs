@@ -50,6 +50,19 @@ impl<'parent_reader, 'asset, R: ArchiveReader<PackageIndex>>
     /// Read a name from this archive
     pub fn read_name(&mut self) -> Result<String, Error> {
         let index = self.read_i32::<LE>()?;
+        self.get_name(index)
+    }
+
+    /// Read a name that can be missing from this archive, missing names are stored as -1
+    pub fn read_optional_name(&mut self) -> Result<Option<String>, Error> {
+        match self.read_i32::<LE>()? {
+            -1 => Ok(None),
+            index => self.get_name(index).map(Some),
+        }
+    }
+
+    /// Get a name from the name map
+    fn get_name(&self, index: i32) -> Result<String, Error> {
         if index < 0 {
             return Err(UsmapError::name_map_index_out_of_range(self.name_map.len(), index).into());
         }
@@ -0,0 +1,113 @@
+//! Usmap validation
+
+use std::collections::HashSet;
+
+use crate::error::{Error, UsmapError, UsmapValidationError};
+
+use super::{properties::UsmapPropertyData, Usmap};
+
+impl Usmap {
+    /// Check that every super type, struct and enum referenced by the usmap is present
+    ///
+    /// Returns all unresolved references as a single error.
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut errors = Vec::new();
+
+        for schema in self.schemas.values() {
+            if let Err(error) = self.validate_super_types(&schema.name) {
+                errors.push(error);
+            }
+
+            for property in schema.properties.values().filter(|e| e.array_index == 0) {
+                self.validate_property_data(
+                    &schema.name,
+                    &property.name,
+                    &property.property_data,
+                    &mut errors,
+                );
+            }
+        }
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(UsmapError::invalid_mappings(errors).into()),
+        }
+    }
+
+    /// Check that the super type chain of a schema resolves and ends
+    fn validate_super_types(&self, schema_name: &str) -> Result<(), UsmapValidationError> {
+        let mut visited = HashSet::new();
+        let mut current = schema_name;
+
+        while let Some(schema) = self.schemas.get_by_key(current) {
+            if !visited.insert(current) {
+                return Err(UsmapValidationError::cyclic_super_type(schema_name));
+            }
+
+            if schema.super_type.is_empty() {
+                return Ok(());
+            }
+
+            if !self.schemas.contains_key(&schema.super_type) {
+                return Err(UsmapValidationError::unresolved_super_type(
+                    &schema.name,
+                    &schema.super_type,
+                ));
+            }
+
+            current = &schema.super_type;
+        }
+
+        Ok(())
+    }
+
+    /// Check that the structs and enums referenced by property data resolve
+    fn validate_property_data(
+        &self,
+        schema_name: &str,
+        property_name: &str,
+        property_data: &UsmapPropertyData,
+        errors: &mut Vec<UsmapValidationError>,
+    ) {
+        match property_data {
+            UsmapPropertyData::UsmapStructPropertyData(data) => {
+                if !self.schemas.contains_key(&data.struct_type) {
+                    errors.push(UsmapValidationError::unresolved_struct(
+                        schema_name,
+                        property_name,
+                        &data.struct_type,
+                    ));
+                }
+            }
+            UsmapPropertyData::UsmapEnumPropertyData(data) => {
+                if !self.enum_map.contains_key(&data.name) {
+                    errors.push(UsmapValidationError::unresolved_enum(
+                        schema_name,
+                        property_name,
+                        &data.name,
+                    ));
+                }
+                self.validate_property_data(
+                    schema_name,
+                    property_name,
+                    &data.inner_property,
+                    errors,
+                );
+            }
+            UsmapPropertyData::UsmapArrayPropertyData(data) => {
+                self.validate_property_data(schema_name, property_name, &data.inner_type, errors)
+            }
+            UsmapPropertyData::UsmapSetPropertyData(data) => {
+                self.validate_property_data(schema_name, property_name, &data.inner_type, errors)
+            }
+            UsmapPropertyData::UsmapOptionalPropertyData(data) => {
+                self.validate_property_data(schema_name, property_name, &data.inner_type, errors)
+            }
+            UsmapPropertyData::UsmapMapPropertyData(data) => {
+                self.validate_property_data(schema_name, property_name, &data.inner_type, errors);
+                self.validate_property_data(schema_name, property_name, &data.value_type, errors);
+            }
+            UsmapPropertyData::UsmapShallowPropertyData(_) => {}
+        }
+    }
+}
@@ -87,6 +87,11 @@ pub struct BaseExport<Index: PackageIndexTrait> {
     /// Dependencies that should be created before this export is created
     #[container_ignore]
     pub create_before_create_dependencies: Vec<PackageIndex>,
+
+    /// Start of the tagged properties relative to the serial offset
+    pub script_serialization_start_offset: i64,
+    /// End of the tagged properties relative to the serial offset
+    pub script_serialization_end_offset: i64,
 }
 
 impl<Index: PackageIndexTrait> BaseExport<Index> {
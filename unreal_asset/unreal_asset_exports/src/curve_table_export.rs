@@ -0,0 +1,181 @@
+//! Curve table export
+
+use byteorder::{ReadBytesExt, WriteBytesExt, LE};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+
+use unreal_asset_base::{
+    custom_version::FFortniteMainBranchObjectVersion,
+    reader::{ArchiveReader, ArchiveWriter},
+    types::{FName, PackageIndexTrait},
+    unversioned::Ancestry,
+    Error, FNameContainer,
+};
+use unreal_asset_properties::{
+    curve_property::{RichCurveProperty, SimpleCurveProperty},
+    PropertyDataTrait, PropertyTrait,
+};
+
+use crate::implement_get;
+use crate::ExportTrait;
+use crate::{BaseExport, NormalExport};
+
+/// Curve table mode
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum CurveTableMode {
+    /// Empty, the table has no rows
+    #[default]
+    Empty,
+    /// Rows are simple curves
+    SimpleCurves,
+    /// Rows are rich curves
+    RichCurves,
+}
+
+/// Curve table row
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CurveTableRow {
+    /// Rich curve row
+    RichCurve(RichCurveProperty),
+    /// Simple curve row
+    SimpleCurve(SimpleCurveProperty),
+}
+
+impl CurveTableRow {
+    /// Get the row name
+    pub fn get_name(&self) -> FName {
+        match self {
+            CurveTableRow::RichCurve(e) => e.get_name(),
+            CurveTableRow::SimpleCurve(e) => e.get_name(),
+        }
+    }
+
+    /// Evaluate the row curve at a time
+    pub fn eval(&self, time: f32) -> f32 {
+        match self {
+            CurveTableRow::RichCurve(e) => e.eval(time),
+            CurveTableRow::SimpleCurve(e) => e.eval(time),
+        }
+    }
+}
+
+/// Curve table
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CurveTable {
+    /// Curve table mode, all rows must match the mode
+    #[container_ignore]
+    pub mode: CurveTableMode,
+    /// Rows
+    pub rows: Vec<CurveTableRow>,
+}
+
+impl CurveTable {
+    /// Create a new `CurveTable` instance
+    pub fn new(mode: CurveTableMode, rows: Vec<CurveTableRow>) -> Self {
+        CurveTable { mode, rows }
+    }
+
+    /// Find a row by name
+    pub fn find_row(&self, name: &str) -> Option<&CurveTableRow> {
+        self.rows.iter().find(|e| e.get_name() == name)
+    }
+}
+
+/// Curve table export
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CurveTableExport<Index: PackageIndexTrait> {
+    /// Base normal export
+    pub normal_export: NormalExport<Index>,
+    /// Curve table
+    pub table: CurveTable,
+}
+
+implement_get!(CurveTableExport);
+
+impl<Index: PackageIndexTrait> CurveTableExport<Index> {
+    /// Read a `CurveTableExport` from an asset
+    pub fn from_base<Reader: ArchiveReader<Index>>(
+        base: &BaseExport<Index>,
+        asset: &mut Reader,
+    ) -> Result<Self, Error> {
+        let normal_export = NormalExport::from_base(base, asset)?;
+
+        let num_rows = asset.read_i32::<LE>()?;
+        let mode = match asset
+            .get_custom_version::<FFortniteMainBranchObjectVersion>()
+            .version
+            < FFortniteMainBranchObjectVersion::ShrinkCurveTableSize as i32
+        {
+            true => match num_rows > 0 {
+                true => CurveTableMode::RichCurves,
+                false => CurveTableMode::Empty,
+            },
+            false => CurveTableMode::try_from(asset.read_u8()?)?,
+        };
+
+        let ancestry = Ancestry::new(base.get_class_type_for_ancestry(asset));
+        let mut rows = Vec::with_capacity(num_rows.max(0) as usize);
+        for _i in 0..num_rows {
+            let row_name = asset.read_fname()?;
+            let row_ancestry = ancestry.with_parent(row_name.clone());
+
+            rows.push(match mode {
+                CurveTableMode::RichCurves => CurveTableRow::RichCurve(RichCurveProperty::new(
+                    asset,
+                    row_name,
+                    row_ancestry,
+                    false,
+                    0,
+                )?),
+                CurveTableMode::SimpleCurves => CurveTableRow::SimpleCurve(
+                    SimpleCurveProperty::new(asset, row_name, row_ancestry, false, 0)?,
+                ),
+                CurveTableMode::Empty => {
+                    return Err(Error::invalid_file(
+                        "Empty curve table has rows".to_string(),
+                    ))
+                }
+            });
+        }
+
+        Ok(CurveTableExport {
+            normal_export,
+            table: CurveTable::new(mode, rows),
+        })
+    }
+}
+
+impl<Index: PackageIndexTrait> ExportTrait<Index> for CurveTableExport<Index> {
+    fn write<Writer: ArchiveWriter<Index>>(&self, asset: &mut Writer) -> Result<(), Error> {
+        self.normal_export.write(asset)?;
+
+        asset.write_i32::<LE>(self.table.rows.len() as i32)?;
+        if asset
+            .get_custom_version::<FFortniteMainBranchObjectVersion>()
+            .version
+            >= FFortniteMainBranchObjectVersion::ShrinkCurveTableSize as i32
+        {
+            asset.write_u8(self.table.mode.into())?;
+        }
+
+        for row in &self.table.rows {
+            asset.write_fname(&row.get_name())?;
+            match (self.table.mode, row) {
+                (CurveTableMode::RichCurves, CurveTableRow::RichCurve(curve)) => {
+                    curve.write(asset, false)?
+                }
+                (CurveTableMode::SimpleCurves, CurveTableRow::SimpleCurve(curve)) => {
+                    curve.write(asset, false)?
+                }
+                _ => {
+                    return Err(Error::invalid_file(format!(
+                        "Curve table row doesn't match curve table mode {:?}",
+                        self.table.mode
+                    )))
+                }
+            };
+        }
+
+        Ok(())
+    }
+}
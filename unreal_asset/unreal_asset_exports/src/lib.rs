@@ -11,6 +11,7 @@ pub mod properties;
 
 pub mod base_export;
 pub mod class_export;
+pub mod curve_table_export;
 pub mod data_table_export;
 pub mod enum_export;
 pub mod function_export;
@@ -24,7 +25,8 @@ pub mod user_defined_struct_export;
 pub mod world_export;
 
 pub use self::{
-    base_export::BaseExport, class_export::ClassExport, data_table_export::DataTableExport,
+    base_export::BaseExport, class_export::ClassExport, curve_table_export::CurveTableExport,
+    data_table_export::DataTableExport,
     enum_export::EnumExport, function_export::FunctionExport, level_export::LevelExport,
     normal_export::NormalExport, property_export::PropertyExport, raw_export::RawExport,
     string_table_export::StringTableExport, struct_export::StructExport,
@@ -113,6 +115,8 @@ pub enum Export<Index: PackageIndexTrait> {
     FunctionExport(FunctionExport<Index>),
     /// Data table export
     DataTableExport(DataTableExport<Index>),
+    /// Curve table export
+    CurveTableExport(CurveTableExport<Index>),
     /// World export
     WorldExport(WorldExport<Index>),
 }
@@ -189,6 +193,7 @@ manual_dispatch! {
     UserDefinedStructExport,
     FunctionExport,
     DataTableExport,
+    CurveTableExport,
     WorldExport
 }
 
@@ -1,7 +1,12 @@
 //! Normal export
 
+use byteorder::{ReadBytesExt, WriteBytesExt};
+
 use unreal_asset_base::{
-    reader::{ArchiveReader, ArchiveWriter},
+    enums::EOverriddenPropertyOperation,
+    flags::EClassSerializationControlExtension,
+    object_version::ObjectVersionUE5,
+    reader::{ArchiveReader, ArchiveTrait, ArchiveWriter},
     types::PackageIndexTrait,
     unversioned::{header::UnversionedHeader, Ancestry},
     Error, FNameContainer,
@@ -20,6 +25,12 @@ pub struct NormalExport<Index: PackageIndexTrait> {
     pub base_export: BaseExport<Index>,
     /// Extra data
     pub extras: Vec<u8>,
+    /// Serialization control extension, only used in >= UE5.4 with tagged properties
+    #[container_ignore]
+    pub serialization_control: EClassSerializationControlExtension,
+    /// Overridden operation, only present with overridable serialization information
+    #[container_ignore]
+    pub overridden_operation: EOverriddenPropertyOperation,
     /// Properties
     pub properties: Vec<Property>,
 }
@@ -44,6 +55,13 @@ impl<Index: PackageIndexTrait> ExportBaseTrait<Index> for NormalExport<Index> {
     }
 }
 
+/// Checks if the serialization control extension is serialized before the properties
+fn has_serialization_control<Index: PackageIndexTrait>(asset: &impl ArchiveTrait<Index>) -> bool {
+    !asset.has_unversioned_properties()
+        && asset.get_object_version_ue5()
+            >= ObjectVersionUE5::PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION
+}
+
 impl<Index: PackageIndexTrait> NormalExport<Index> {
     /// Read a `NormalExport` from an asset
     pub fn from_base<Reader: ArchiveReader<Index>>(
@@ -52,6 +70,18 @@ impl<Index: PackageIndexTrait> NormalExport<Index> {
     ) -> Result<Self, Error> {
         let mut properties = Vec::new();
 
+        let mut serialization_control = EClassSerializationControlExtension::NO_EXTENSION;
+        let mut overridden_operation = EOverriddenPropertyOperation::None;
+        if has_serialization_control(asset) {
+            serialization_control =
+                EClassSerializationControlExtension::from_bits_retain(asset.read_u8()?);
+            if serialization_control.contains(
+                EClassSerializationControlExtension::OVERRIDABLE_SERIALIZATION_INFORMATION,
+            ) {
+                overridden_operation = EOverriddenPropertyOperation::try_from(asset.read_u8()?)?;
+            }
+        }
+
         let mut unversioned_header = UnversionedHeader::new(asset)?;
         let ancestry = Ancestry::new(base.get_class_type_for_ancestry(asset));
         while let Some(e) =
@@ -63,6 +93,8 @@ impl<Index: PackageIndexTrait> NormalExport<Index> {
         Ok(NormalExport {
             base_export: base.clone(),
             extras: Vec::new(),
+            serialization_control,
+            overridden_operation,
 
             properties,
         })
@@ -71,6 +103,15 @@ impl<Index: PackageIndexTrait> NormalExport<Index> {
 
 impl<Index: PackageIndexTrait> ExportTrait<Index> for NormalExport<Index> {
     fn write<Writer: ArchiveWriter<Index>>(&self, asset: &mut Writer) -> Result<(), Error> {
+        if has_serialization_control(asset) {
+            asset.write_u8(self.serialization_control.bits())?;
+            if self.serialization_control.contains(
+                EClassSerializationControlExtension::OVERRIDABLE_SERIALIZATION_INFORMATION,
+            ) {
+                asset.write_u8(self.overridden_operation.into())?;
+            }
+        }
+
         let (unversioned_header, sorted_properties) = match generate_unversioned_header(
             asset,
             &self.properties,
@@ -35,6 +35,10 @@ macro_rules! parse_simple_property {
         }
 
         impl FPropertyTrait for $prop_name {
+            fn get_generic_property(&self) -> &FGenericProperty {
+                &self.generic_property
+            }
+
             fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
                 &self,
                 asset: &mut Writer,
@@ -79,6 +83,10 @@ macro_rules! parse_simple_property_index {
         }
 
         impl FPropertyTrait for $prop_name {
+            fn get_generic_property(&self) -> &FGenericProperty {
+                &self.generic_property
+            }
+
             fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(&self, asset: &mut Writer) -> Result<(), Error> {
                 self.generic_property.write(asset)?;
                 $(
@@ -122,6 +130,10 @@ macro_rules! parse_simple_property_prop {
         }
 
         impl FPropertyTrait for $prop_name {
+            fn get_generic_property(&self) -> &FGenericProperty {
+                &self.generic_property
+            }
+
             fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(&self, asset: &mut Writer) -> Result<(), Error> {
                 self.generic_property.write(asset)?;
                 $(
@@ -136,6 +148,8 @@ macro_rules! parse_simple_property_prop {
 /// This must be implemented for all FProperties
 #[enum_dispatch]
 pub trait FPropertyTrait: Debug + Clone + PartialEq + Eq + Hash {
+    /// Get the generic property
+    fn get_generic_property(&self) -> &FGenericProperty;
     /// Write `FProperty` to an asset
     fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
         &self,
@@ -173,6 +187,8 @@ pub enum FProperty {
     FInterfaceProperty,
     /// Map
     FMapProperty,
+    /// Optional
+    FOptionalProperty,
     /// Bool
     FBoolProperty,
     /// Byte
@@ -203,6 +219,7 @@ impl unreal_asset_base::types::fname::FNameContainer for FProperty {
             FProperty::FMulticastInlineDelegateProperty(prop) => prop.traverse_fnames(traverse),
             FProperty::FInterfaceProperty(prop) => prop.traverse_fnames(traverse),
             FProperty::FMapProperty(prop) => prop.traverse_fnames(traverse),
+            FProperty::FOptionalProperty(prop) => prop.traverse_fnames(traverse),
             FProperty::FBoolProperty(prop) => prop.traverse_fnames(traverse),
             FProperty::FByteProperty(prop) => prop.traverse_fnames(traverse),
             FProperty::FStructProperty(prop) => prop.traverse_fnames(traverse),
@@ -235,6 +252,7 @@ impl FProperty {
                 }
                 "InterfaceProperty" => FInterfaceProperty::new(asset)?.into(),
                 "MapProperty" => FMapProperty::new(asset)?.into(),
+                "OptionalProperty" => FOptionalProperty::new(asset)?.into(),
                 "BoolProperty" => FBoolProperty::new(asset)?.into(),
                 "ByteProperty" => FByteProperty::new(asset)?.into(),
                 "StructProperty" => FStructProperty::new(asset)?.into(),
@@ -277,6 +295,7 @@ impl ToSerializedName for FProperty {
             }
             FProperty::FInterfaceProperty(_) => String::from("InterfaceProperty"),
             FProperty::FMapProperty(_) => String::from("MapProperty"),
+            FProperty::FOptionalProperty(_) => String::from("OptionalProperty"),
             FProperty::FBoolProperty(_) => String::from("BoolProperty"),
             FProperty::FByteProperty(_) => String::from("ByteProperty"),
             FProperty::FStructProperty(_) => String::from("StructProperty"),
@@ -388,6 +407,10 @@ impl FGenericProperty {
 }
 
 impl FPropertyTrait for FGenericProperty {
+    fn get_generic_property(&self) -> &FGenericProperty {
+        self
+    }
+
     fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
         &self,
         asset: &mut Writer,
@@ -422,6 +445,10 @@ impl FEnumProperty {
 }
 
 impl FPropertyTrait for FEnumProperty {
+    fn get_generic_property(&self) -> &FGenericProperty {
+        &self.generic_property
+    }
+
     fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
         &self,
         asset: &mut Writer,
@@ -459,6 +486,10 @@ impl FBoolProperty {
 }
 
 impl FPropertyTrait for FBoolProperty {
+    fn get_generic_property(&self) -> &FGenericProperty {
+        &self.generic_property
+    }
+
     fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
         &self,
         asset: &mut Writer,
@@ -491,6 +522,11 @@ parse_simple_property_prop!(
     /// Value
     value_prop
 );
+parse_simple_property_prop!(
+    FOptionalProperty,
+    /// Value property
+    value_property
+);
 
 parse_simple_property_index!(
     FObjectProperty,
@@ -34,6 +34,10 @@ macro_rules! parse_simple_property {
         }
 
         impl UPropertyTrait for $prop_name {
+            fn get_generic_property(&self) -> &UGenericProperty {
+                &self.generic_property
+            }
+
             fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(&self, asset: &mut Writer) -> Result<(), Error> {
                 self.generic_property.write(asset)?;
                 Ok(())
@@ -74,6 +78,10 @@ macro_rules! parse_simple_property {
         }
 
         impl UPropertyTrait for $prop_name {
+            fn get_generic_property(&self) -> &UGenericProperty {
+                &self.generic_property
+            }
+
             fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(&self, asset: &mut Writer) -> Result<(), Error> {
                 self.generic_property.write(asset)?;
                 $(
@@ -88,6 +96,8 @@ macro_rules! parse_simple_property {
 /// This must be implemented for all UProperties
 #[enum_dispatch]
 pub trait UPropertyTrait: Debug + Clone + PartialEq + Eq + Hash {
+    /// Get the generic property
+    fn get_generic_property(&self) -> &UGenericProperty;
     /// Write `UProperty` to an asset
     fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
         &self,
@@ -313,6 +323,10 @@ impl UGenericProperty {
 }
 
 impl UPropertyTrait for UGenericProperty {
+    fn get_generic_property(&self) -> &UGenericProperty {
+        self
+    }
+
     fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
         &self,
         asset: &mut Writer,
@@ -354,6 +368,10 @@ impl UBoolProperty {
 }
 
 impl UPropertyTrait for UBoolProperty {
+    fn get_generic_property(&self) -> &UGenericProperty {
+        &self.generic_property
+    }
+
     fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
         &self,
         asset: &mut Writer,
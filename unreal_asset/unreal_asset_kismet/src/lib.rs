@@ -26,6 +26,8 @@ use unreal_asset_base::{
     Error,
 };
 
+pub use unreal_asset_base::types::FieldPath;
+
 /// Kismet expression token
 #[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, TryFromPrimitive, IntoPrimitive)]
 #[repr(u8)]
@@ -347,26 +349,6 @@ pub enum EBlueprintTextLiteralType {
     StringTableEntry,
 }
 
-/// Kismet field path
-#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
-pub struct FieldPath {
-    /// Path
-    pub path: Vec<FName>,
-    /// Path owner
-    #[container_ignore]
-    pub resolved_owner: PackageIndex,
-}
-
-impl FieldPath {
-    /// Create a new `FieldPath` instance
-    pub fn new(path: Vec<FName>, resolved_owner: PackageIndex) -> Self {
-        FieldPath {
-            path,
-            resolved_owner,
-        }
-    }
-}
-
 /// Read a UTF-8 kismet string
 fn read_kismet_string<Reader: ArchiveReader<impl PackageIndexTrait>>(
     asset: &mut Reader,
@@ -11,8 +11,12 @@ pub struct ArrayProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
+    /// Complete property type name, only used in >= UE5.4 property tags
+    pub property_type_name: Option<PropertyTypeName>,
     /// Array type
     pub array_type: Option<FName>,
     /// Array values
@@ -33,21 +37,28 @@ impl ArrayProperty {
         duplication_index: i32,
         serialize_struct_differently: bool,
     ) -> Result<Self, Error> {
-        let (array_type, property_guid) = match include_header {
-            true => (Some(asset.read_fname()?), asset.read_property_guid()?),
-            false => (None, None),
+        let (array_type, property_guid, property_tag_extensions) = match include_header {
+            true => (
+                Some(asset.read_fname()?),
+                asset.read_property_guid()?,
+                asset.read_property_tag_extensions()?,
+            ),
+            false => (None, None, PropertyTagExtensions::default()),
         };
-        ArrayProperty::new_no_header(
-            asset,
-            name,
-            ancestry,
-            include_header,
-            length,
-            duplication_index,
-            serialize_struct_differently,
-            array_type,
-            property_guid,
-        )
+        Ok(ArrayProperty {
+            property_tag_extensions,
+            ..ArrayProperty::new_no_header(
+                asset,
+                name,
+                ancestry,
+                include_header,
+                length,
+                duplication_index,
+                serialize_struct_differently,
+                array_type,
+                property_guid,
+            )?
+        })
     }
 
     /// Create an `ArrayProperty` from an array of properties
@@ -61,9 +72,11 @@ impl ArrayProperty {
             name,
             ancestry,
             property_guid: None,
+            property_tag_extensions: PropertyTagExtensions::default(),
             array_type,
             value,
             duplication_index: 0,
+            property_type_name: None,
             dummy_property: None,
         }
     }
@@ -71,6 +84,34 @@ impl ArrayProperty {
     /// Read an `ArrayProperty` from an asset without reading the property header
     #[allow(clippy::too_many_arguments)]
     pub fn new_no_header<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        length: i64,
+        duplication_index: i32,
+        serialize_struct_differently: bool,
+        array_type: Option<FName>,
+        property_guid: Option<Guid>,
+    ) -> Result<Self, Error> {
+        ArrayProperty::new_no_header_with_struct_type(
+            asset,
+            name,
+            ancestry,
+            include_header,
+            length,
+            duplication_index,
+            serialize_struct_differently,
+            array_type,
+            None,
+            property_guid,
+        )
+    }
+
+    /// Read an `ArrayProperty` from an asset without reading the property header
+    /// while specifying the inner struct type, e.g. from a complete property type name
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_no_header_with_struct_type<Reader: ArchiveReader<impl PackageIndexTrait>>(
         asset: &mut Reader,
         name: FName,
         ancestry: Ancestry,
@@ -79,6 +120,7 @@ impl ArrayProperty {
         duplication_index: i32,
         serialize_struct_differently: bool,
         mut array_type: Option<FName>,
+        mut array_struct_type: Option<FName>,
         property_guid: Option<Guid>,
     ) -> Result<Self, Error> {
         let num_entries = asset.read_i32::<LE>()?;
@@ -90,7 +132,6 @@ impl ArrayProperty {
 
         let mut dummy_struct = None;
 
-        let mut array_struct_type = None;
         if array_type.is_none() {
             if let Some(struct_data) = asset
                 .get_mappings()
@@ -123,7 +164,14 @@ impl ArrayProperty {
             && !asset.has_unversioned_properties()
         {
             let mut full_type = FName::from_slice("Generic");
-            if asset.get_object_version() >= ObjectVersion::VER_UE4_INNER_ARRAY_TAG_INFO {
+            // >= UE5.4 property tags carry the inner struct type instead of an inner tag
+            let complete_type_name =
+                asset.get_object_version_ue5() >= ObjectVersionUE5::PROPERTY_TAG_COMPLETE_TYPE_NAME;
+            if let Some(struct_type) = array_struct_type.as_ref().filter(|_| complete_type_name) {
+                full_type = struct_type.clone();
+            } else if !complete_type_name
+                && asset.get_object_version() >= ObjectVersion::VER_UE4_INNER_ARRAY_TAG_INFO
+            {
                 name = asset.read_fname()?;
                 if name == "None" {
                     return Ok(ArrayProperty::default());
@@ -151,6 +199,7 @@ impl ArrayProperty {
 
                 struct_guid = Some(asset.read_guid()?);
                 asset.read_property_guid()?;
+                asset.read_property_tag_extensions()?;
             } else if let Some(type_override) = name
                 .get_content(|name| asset.get_array_struct_type_override().get_by_key(name))
                 .cloned()
@@ -224,7 +273,9 @@ impl ArrayProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions: PropertyTagExtensions::default(),
             duplication_index,
+            property_type_name: None,
             array_type,
             dummy_property: dummy_struct,
             value: entries,
@@ -249,6 +300,7 @@ impl ArrayProperty {
         if include_header {
             asset.write_fname(array_type.as_ref().ok_or_else(PropertyError::headerless)?)?;
             asset.write_property_guid(self.property_guid.as_ref())?;
+            asset.write_property_tag_extensions(&self.property_tag_extensions)?;
         }
 
         let begin = asset.position();
@@ -273,8 +325,13 @@ impl ArrayProperty {
                 },
             }?;
 
+            let write_inner_tag = asset.get_object_version()
+                >= ObjectVersion::VER_UE4_INNER_ARRAY_TAG_INFO
+                && asset.get_object_version_ue5()
+                    < ObjectVersionUE5::PROPERTY_TAG_COMPLETE_TYPE_NAME;
+
             let mut length_loc = None;
-            if asset.get_object_version() >= ObjectVersion::VER_UE4_INNER_ARRAY_TAG_INFO {
+            if write_inner_tag {
                 asset.write_fname(&property.name)?;
                 asset.write_fname(&asset.get_name_map().get_mut().add_fname("StructProperty"))?;
                 length_loc = Some(asset.position());
@@ -293,6 +350,7 @@ impl ArrayProperty {
                 {
                     asset.write_u8(0)?;
                 }
+                asset.write_property_tag_extensions(&PropertyTagExtensions::default())?;
             }
 
             for property in &self.value {
@@ -306,7 +364,7 @@ impl ArrayProperty {
                 struct_property.write(asset, false)?;
             }
 
-            if asset.get_object_version() >= ObjectVersion::VER_UE4_INNER_ARRAY_TAG_INFO {
+            if write_inner_tag {
                 let length_loc = length_loc.expect("Corrupted memory");
                 let full_len = asset.position() - length_loc;
                 let new_loc = asset.position();
@@ -170,6 +170,19 @@ impl PropertyDataTrait for ClothLodDataProperty {
         self.struct_property.get_property_guid()
     }
 
+    fn set_property_guid(&mut self, property_guid: Option<Guid>) {
+        self.struct_property.set_property_guid(property_guid)
+    }
+
+    fn get_property_tag_extensions(&self) -> PropertyTagExtensions {
+        self.struct_property.get_property_tag_extensions()
+    }
+
+    fn set_property_tag_extensions(&mut self, property_tag_extensions: PropertyTagExtensions) {
+        self.struct_property
+            .set_property_tag_extensions(property_tag_extensions)
+    }
+
     fn get_ancestry(&self) -> &Ancestry {
         self.struct_property.get_ancestry()
     }
@@ -13,6 +13,8 @@ pub struct ColorProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Color
@@ -30,6 +32,8 @@ pub struct LinearColorProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Color
@@ -47,12 +51,13 @@ impl ColorProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         let color = Color::from_argb(asset.read_i32::<LE>()?);
         Ok(ColorProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             color,
         })
@@ -80,7 +85,7 @@ impl LinearColorProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         let color = Color::new(
             OrderedFloat(asset.read_f32::<LE>()?),
             OrderedFloat(asset.read_f32::<LE>()?),
@@ -91,6 +96,7 @@ impl LinearColorProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             color,
         })
@@ -0,0 +1,235 @@
+//! Simple, integral and string curve key properties
+
+use crate::int_property::{FloatProperty, IntProperty};
+use crate::property_prelude::*;
+use crate::struct_property::{field_ancestry, push_field, unexpected_field, write_fields};
+
+/// Simple curve key property
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SimpleCurveKeyProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Time
+    pub time: OrderedFloat<f32>,
+    /// Curve key value
+    pub value: OrderedFloat<f32>,
+}
+impl_property_data_trait!(SimpleCurveKeyProperty);
+
+impl SimpleCurveKeyProperty {
+    /// Read a `SimpleCurveKeyProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let time = OrderedFloat(asset.read_f32::<LE>()?);
+        let value = OrderedFloat(asset.read_f32::<LE>()?);
+
+        Ok(SimpleCurveKeyProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            time,
+            value,
+        })
+    }
+}
+
+impl PropertyTrait for SimpleCurveKeyProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+        asset.write_f32::<LE>(self.time.0)?;
+        asset.write_f32::<LE>(self.value.0)?;
+        Ok(size_of::<f32>() * 2)
+    }
+}
+
+/// String curve key property
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct StringCurveKeyProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Time
+    pub time: OrderedFloat<f32>,
+    /// Curve key value
+    pub value: Option<String>,
+}
+impl_property_data_trait!(StringCurveKeyProperty);
+
+impl StringCurveKeyProperty {
+    /// Read a `StringCurveKeyProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let time = OrderedFloat(asset.read_f32::<LE>()?);
+        let value = asset.read_fstring()?;
+
+        Ok(StringCurveKeyProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            time,
+            value,
+        })
+    }
+}
+
+impl PropertyTrait for StringCurveKeyProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+        let begin = asset.position();
+        asset.write_f32::<LE>(self.time.0)?;
+        asset.write_fstring(self.value.as_deref())?;
+        Ok((asset.position() - begin) as usize)
+    }
+}
+
+/// Integral curve key property
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntegralKeyProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Time
+    pub time: OrderedFloat<f32>,
+    /// Curve key value
+    pub value: i32,
+    /// Serialized fields, fields with default values are only written if listed here
+    pub serialized_fields: Vec<FName>,
+}
+impl_property_data_trait!(IntegralKeyProperty);
+
+impl IntegralKeyProperty {
+    /// Read an `IntegralKeyProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let mut key = IntegralKeyProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            ..Default::default()
+        };
+
+        let fields_ancestry = field_ancestry(&key.ancestry, "IntegralKey");
+        for field in StructProperty::read_properties(asset, fields_ancestry)? {
+            let field_name = field.get_name();
+            match field {
+                Property::EmptyProperty(_) if field_name == "Time" || field_name == "Value" => {}
+                Property::FloatProperty(e) if field_name == "Time" => key.time = e.value,
+                Property::IntProperty(e) if field_name == "Value" => key.value = e.value,
+                e => return Err(unexpected_field("IntegralKey", &e)),
+            }
+            key.serialized_fields.push(field_name);
+        }
+
+        Ok(key)
+    }
+}
+
+impl PropertyTrait for IntegralKeyProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+
+        let ancestry = field_ancestry(&self.ancestry, "IntegralKey");
+        let serialized_fields = &self.serialized_fields;
+        let mut fields = Vec::new();
+
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "Time",
+            self.time.0 == 0.0,
+            |name| {
+                FloatProperty {
+                    name,
+                    ancestry: ancestry.clone(),
+                    property_guid: None,
+                    property_tag_extensions: PropertyTagExtensions::default(),
+                    duplication_index: 0,
+                    value: self.time,
+                }
+                .into()
+            },
+        );
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "Value",
+            self.value == 0,
+            |name| {
+                IntProperty {
+                    name,
+                    ancestry: ancestry.clone(),
+                    property_guid: None,
+                    property_tag_extensions: PropertyTagExtensions::default(),
+                    duplication_index: 0,
+                    value: self.value,
+                }
+                .into()
+            },
+        );
+
+        write_fields(asset, fields, serialized_fields, "IntegralKey")
+    }
+}
@@ -0,0 +1,1136 @@
+//! Rich, simple, integral and string curve properties
+
+use crate::curve_key_property::{
+    IntegralKeyProperty, SimpleCurveKeyProperty, StringCurveKeyProperty,
+};
+use crate::int_property::{BoolProperty, FloatProperty, IntProperty};
+use crate::property_prelude::*;
+use crate::rich_curve_key_property::{
+    RichCurveExtrapolation, RichCurveInterpMode, RichCurveKeyProperty, RichCurveTangentWeightMode,
+};
+use crate::struct_property::{
+    enum_field, enum_field_value, field_ancestry, push_field, push_field_with_default,
+    struct_array_field, struct_array_values, unexpected_field, write_fields,
+};
+
+/// Default value of a real curve, means the curve has no default value
+const UNSET_DEFAULT_VALUE: f32 = f32::MAX;
+
+/// Tolerance used when checking if values are nearly zero
+const SMALL_NUMBER: f64 = 1e-8;
+
+/// Tolerance used when checking if polynomial coefficients are nearly zero
+const KINDA_SMALL_NUMBER: f64 = 1e-4;
+
+/// Fields shared by rich and simple curves
+struct RealCurveFields<'a> {
+    /// Struct type
+    struct_type: &'a str,
+    /// Default value
+    default_value: &'a mut OrderedFloat<f32>,
+    /// Pre infinity extrapolation
+    pre_infinity_extrap: &'a mut RichCurveExtrapolation,
+    /// Post infinity extrapolation
+    post_infinity_extrap: &'a mut RichCurveExtrapolation,
+}
+
+impl RealCurveFields<'_> {
+    /// Read a shared field, returns `false` if the field isn't a shared field
+    fn read(&mut self, field: &Property) -> Result<bool, Error> {
+        let field_name = field.get_name();
+        let extrap = if field_name == "PreInfinityExtrap" {
+            &mut *self.pre_infinity_extrap
+        } else if field_name == "PostInfinityExtrap" {
+            &mut *self.post_infinity_extrap
+        } else if field_name == "DefaultValue" {
+            *self.default_value = match field {
+                Property::FloatProperty(e) => e.value,
+                Property::EmptyProperty(_) => OrderedFloat(0.0),
+                e => return Err(unexpected_field(self.struct_type, e)),
+            };
+            return Ok(true);
+        } else {
+            return Ok(false);
+        };
+
+        *extrap = match field {
+            Property::EmptyProperty(_) => RichCurveExtrapolation::Cycle,
+            e => enum_field_value(
+                self.struct_type,
+                e,
+                |e| RichCurveExtrapolation::try_from(e).ok(),
+                RichCurveExtrapolation::from_value_name,
+            )?,
+        };
+        Ok(true)
+    }
+}
+
+/// Add the fields shared by rich and simple curves to be written
+fn push_real_curve_fields<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+    asset: &Writer,
+    fields: &mut Vec<Property>,
+    serialized_fields: &[FName],
+    ancestry: &Ancestry,
+    default_value: OrderedFloat<f32>,
+    pre_infinity_extrap: RichCurveExtrapolation,
+    post_infinity_extrap: RichCurveExtrapolation,
+) {
+    push_field_with_default(
+        asset,
+        fields,
+        serialized_fields,
+        "DefaultValue",
+        default_value.0 == UNSET_DEFAULT_VALUE,
+        default_value.0 == 0.0,
+        |name| {
+            FloatProperty {
+                name,
+                ancestry: ancestry.clone(),
+                property_guid: None,
+                property_tag_extensions: PropertyTagExtensions::default(),
+                duplication_index: 0,
+                value: default_value,
+            }
+            .into()
+        },
+    );
+
+    for (field_name, extrap) in [
+        ("PreInfinityExtrap", pre_infinity_extrap),
+        ("PostInfinityExtrap", post_infinity_extrap),
+    ] {
+        push_field_with_default(
+            asset,
+            fields,
+            serialized_fields,
+            field_name,
+            extrap == RichCurveExtrapolation::Constant,
+            u8::from(extrap) == 0,
+            |name| {
+                enum_field(
+                    asset,
+                    name,
+                    ancestry.clone(),
+                    "ERichCurveExtrapolation",
+                    extrap.value_name(),
+                    extrap.into(),
+                )
+            },
+        );
+    }
+}
+
+/// Time and value of a rich or simple curve key
+trait RealCurveKey {
+    /// Key time
+    fn time(&self) -> f32;
+    /// Key value
+    fn value(&self) -> f32;
+}
+
+impl RealCurveKey for RichCurveKeyProperty {
+    fn time(&self) -> f32 {
+        self.time.0
+    }
+
+    fn value(&self) -> f32 {
+        self.value.0
+    }
+}
+
+impl RealCurveKey for SimpleCurveKeyProperty {
+    fn time(&self) -> f32 {
+        self.time.0
+    }
+
+    fn value(&self) -> f32 {
+        self.value.0
+    }
+}
+
+/// Evaluate a rich or simple curve, `eval_between` interpolates between two keys
+fn eval_real_curve<Key: RealCurveKey>(
+    keys: &[Key],
+    default_value: f32,
+    pre_infinity_extrap: RichCurveExtrapolation,
+    post_infinity_extrap: RichCurveExtrapolation,
+    time: f32,
+    eval_between: impl Fn(&Key, &Key, f32) -> f32,
+) -> f32 {
+    let (time, cycle_value_offset) =
+        remap_time(keys, pre_infinity_extrap, post_infinity_extrap, time);
+
+    let (Some(first), Some(last)) = (keys.first(), keys.last()) else {
+        return default_value;
+    };
+
+    let value = if keys.len() < 2 || time <= first.time() {
+        match pre_infinity_extrap == RichCurveExtrapolation::Linear && keys.len() > 1 {
+            true => extrapolate_linear(first, &keys[1], time),
+            false => first.value(),
+        }
+    } else if time < last.time() {
+        let next = (1 + keys[1..].partition_point(|e| time >= e.time())).min(keys.len() - 1);
+        eval_between(&keys[next - 1], &keys[next], time)
+    } else {
+        match post_infinity_extrap == RichCurveExtrapolation::Linear {
+            true => extrapolate_linear(last, &keys[keys.len() - 2], time),
+            false => last.value(),
+        }
+    };
+
+    value + cycle_value_offset
+}
+
+/// Extrapolate linearly from a key along the line through another key
+fn extrapolate_linear(key: &impl RealCurveKey, other: &impl RealCurveKey, time: f32) -> f32 {
+    let delta_time = other.time() - key.time();
+    if (delta_time.abs() as f64) <= SMALL_NUMBER {
+        return key.value();
+    }
+
+    let slope = (other.value() - key.value()) / delta_time;
+    slope * (time - key.time()) + key.value()
+}
+
+/// Remap a time outside of the keys into the key range for cycling extrapolation
+///
+/// Returns the remapped time and the value offset of the cycle the time is in
+fn remap_time(
+    keys: &[impl RealCurveKey],
+    pre_infinity_extrap: RichCurveExtrapolation,
+    post_infinity_extrap: RichCurveExtrapolation,
+    time: f32,
+) -> (f32, f32) {
+    let (Some(first), Some(last)) = (keys.first(), keys.last()) else {
+        return (time, 0.0);
+    };
+    if keys.len() < 2 {
+        return (time, 0.0);
+    }
+
+    let (extrap, cycle_value_delta) = if time <= first.time() {
+        (pre_infinity_extrap, first.value() - last.value())
+    } else if time >= last.time() {
+        (post_infinity_extrap, last.value() - first.value())
+    } else {
+        return (time, 0.0);
+    };
+
+    if matches!(
+        extrap,
+        RichCurveExtrapolation::Linear | RichCurveExtrapolation::Constant
+    ) {
+        return (time, 0.0);
+    }
+
+    let (min_time, max_time) = (first.time(), last.time());
+    let (mut time, cycle_count) = cycle_time(min_time, max_time, time);
+    let mut cycle_value_offset = 0.0;
+    match extrap {
+        RichCurveExtrapolation::CycleWithOffset => {
+            cycle_value_offset = cycle_value_delta * cycle_count as f32
+        }
+        RichCurveExtrapolation::Oscillate if cycle_count % 2 == 1 => {
+            time = min_time + (max_time - time)
+        }
+        _ => {}
+    }
+
+    (time, cycle_value_offset)
+}
+
+/// Wrap a time into a time range, returns the wrapped time and the amount of cycles wrapped
+fn cycle_time(min_time: f32, max_time: f32, time: f32) -> (f32, i32) {
+    let duration = max_time - min_time;
+    if duration <= 0.0 {
+        return (time, 0);
+    }
+
+    let mut cycled_time = time;
+    let mut cycle_count = 0;
+    if time > max_time {
+        cycle_count = ((max_time - time) / duration).floor() as i32;
+        cycled_time = time + duration * cycle_count as f32;
+    } else if time < min_time {
+        cycle_count = ((time - min_time) / duration).floor() as i32;
+        cycled_time = time - duration * cycle_count as f32;
+    }
+
+    if cycled_time == max_time && time < min_time {
+        cycled_time = min_time;
+    }
+    if cycled_time == min_time && time > max_time {
+        cycled_time = max_time;
+    }
+
+    (cycled_time, cycle_count.abs())
+}
+
+/// Linearly interpolate between two values
+fn lerp(a: f32, b: f32, alpha: f32) -> f32 {
+    a + alpha * (b - a)
+}
+
+/// Interpolate along a cubic bezier curve
+fn bezier_interp(p0: f32, p1: f32, p2: f32, p3: f32, alpha: f32) -> f32 {
+    let p01 = lerp(p0, p1, alpha);
+    let p12 = lerp(p1, p2, alpha);
+    let p23 = lerp(p2, p3, alpha);
+    let p012 = lerp(p01, p12, alpha);
+    let p123 = lerp(p12, p23, alpha);
+    lerp(p012, p123, alpha)
+}
+
+/// Evaluate a rich curve between two keys
+fn eval_rich_curve_keys(
+    key1: &RichCurveKeyProperty,
+    key2: &RichCurveKeyProperty,
+    time: f32,
+) -> f32 {
+    let diff = key2.time.0 - key1.time.0;
+    if diff <= 0.0 || key1.interp_mode == RichCurveInterpMode::Constant {
+        return key1.value.0;
+    }
+
+    let alpha = (time - key1.time.0) / diff;
+    let (p0, p3) = (key1.value.0, key2.value.0);
+    if key1.interp_mode == RichCurveInterpMode::Linear {
+        return lerp(p0, p3, alpha);
+    }
+
+    let is_weighted = !matches!(
+        key1.tangent_weight_mode,
+        RichCurveTangentWeightMode::WeightedNone | RichCurveTangentWeightMode::WeightedArrive
+    ) || !matches!(
+        key2.tangent_weight_mode,
+        RichCurveTangentWeightMode::WeightedNone | RichCurveTangentWeightMode::WeightedLeave
+    );
+    if is_weighted {
+        return eval_weighted_rich_curve_keys(key1, key2, alpha);
+    }
+
+    let p1 = p0 + key1.leave_tangent.0 * diff / 3.0;
+    let p2 = p3 - key2.arrive_tangent.0 * diff / 3.0;
+    bezier_interp(p0, p1, p2, p3, alpha)
+}
+
+/// Evaluate a rich curve between two keys using weighted tangents
+fn eval_weighted_rich_curve_keys(
+    key1: &RichCurveKeyProperty,
+    key2: &RichCurveKeyProperty,
+    alpha: f32,
+) -> f32 {
+    let (time1, time2) = (key1.time.0, key2.time.0);
+    let range = time2 - time1;
+
+    // tangent weights that aren't used are derived from the tangent and the key distance
+    let weight = |tangent: f32, tangent_weight: f32, is_weighted: bool| match is_weighted {
+        true => tangent_weight,
+        false => (range * range + (tangent * range).powi(2)).sqrt() / 3.0,
+    };
+
+    let (leave_sin, leave_cos) = key1.leave_tangent.0.atan().sin_cos();
+    let leave_weight = weight(
+        key1.leave_tangent.0,
+        key1.leave_tangent_weight.0,
+        matches!(
+            key1.tangent_weight_mode,
+            RichCurveTangentWeightMode::WeightedLeave | RichCurveTangentWeightMode::WeightedBoth
+        ),
+    );
+    let key1_tangent_time = leave_cos * leave_weight + time1;
+    let key1_tangent_value = leave_sin * leave_weight + key1.value.0;
+
+    let (arrive_sin, arrive_cos) = key2.arrive_tangent.0.atan().sin_cos();
+    let arrive_weight = weight(
+        key2.arrive_tangent.0,
+        key2.arrive_tangent_weight.0,
+        matches!(
+            key2.tangent_weight_mode,
+            RichCurveTangentWeightMode::WeightedArrive | RichCurveTangentWeightMode::WeightedBoth
+        ),
+    );
+    let key2_tangent_time = -arrive_cos * arrive_weight + time2;
+    let key2_tangent_value = -arrive_sin * arrive_weight + key2.value.0;
+
+    // find the curve parameter for the time by solving the time bezier in power basis
+    let x1 = ((key1_tangent_time - time1) / range) as f64;
+    let x2 = ((key2_tangent_time - time1) / range) as f64;
+    let (a, b, c) = (x1, x2 - x1, 1.0 - x2);
+    let d = b - a;
+    let solutions = solve_cubic([-alpha as f64, 3.0 * a, 3.0 * d, c - b - d]);
+
+    let interp = match solutions.as_slice() {
+        [] => alpha,
+        [solution] => *solution as f32,
+        solutions => solutions
+            .iter()
+            .copied()
+            .filter(|e| (0.0..=1.0).contains(e))
+            .reduce(f64::max)
+            .unwrap_or_default() as f32,
+    };
+
+    bezier_interp(
+        key1.value.0,
+        key1_tangent_value,
+        key2_tangent_value,
+        key2.value.0,
+        interp,
+    )
+}
+
+/// Find the real solutions of `coeff[3] * x^3 + coeff[2] * x^2 + coeff[1] * x + coeff[0] = 0`
+///
+/// Nearly zero leading coefficients are dropped, as they make the normal form unstable
+fn solve_cubic(coeff: [f64; 4]) -> Vec<f64> {
+    if coeff[3].abs() <= KINDA_SMALL_NUMBER {
+        if coeff[2].abs() <= KINDA_SMALL_NUMBER {
+            return match coeff[1].abs() <= SMALL_NUMBER {
+                true => Vec::new(),
+                false => vec![-coeff[0] / coeff[1]],
+            };
+        }
+
+        let discriminant = coeff[1] * coeff[1] - 4.0 * coeff[2] * coeff[0];
+        if discriminant < 0.0 {
+            return Vec::new();
+        }
+        let root = discriminant.sqrt();
+        return vec![
+            (-coeff[1] + root) / (2.0 * coeff[2]),
+            (-coeff[1] - root) / (2.0 * coeff[2]),
+        ];
+    }
+
+    // normal form x^3 + ax^2 + bx + c = 0
+    let a = coeff[2] / coeff[3];
+    let b = coeff[1] / coeff[3];
+    let c = coeff[0] / coeff[3];
+
+    // substitute x = y - a/3 to get y^3 + py + q = 0
+    let a_squared = a * a;
+    let p = (-a_squared / 3.0 + b) / 3.0;
+    let q = (2.0 / 27.0 * a * a_squared - a * b / 3.0 + c) / 2.0;
+
+    let p_cubed = p * p * p;
+    let d = q * q + p_cubed;
+
+    let solutions = if d.abs() <= SMALL_NUMBER {
+        match q.abs() <= SMALL_NUMBER {
+            true => vec![0.0],
+            false => {
+                let u = (-q).cbrt();
+                vec![2.0 * u, -u]
+            }
+        }
+    } else if d < 0.0 {
+        let phi = (-q / (-p_cubed).sqrt()).acos() / 3.0;
+        let t = 2.0 * (-p).sqrt();
+        vec![
+            t * phi.cos(),
+            -t * (phi + std::f64::consts::FRAC_PI_3).cos(),
+            -t * (phi - std::f64::consts::FRAC_PI_3).cos(),
+        ]
+    } else {
+        let d_root = d.sqrt();
+        vec![(d_root - q).cbrt() - (d_root + q).cbrt()]
+    };
+
+    solutions.into_iter().map(|e| e - a / 3.0).collect()
+}
+
+/// Rich curve property
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RichCurveProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Default value, `f32::MAX` if unset
+    pub default_value: OrderedFloat<f32>,
+    /// Extrapolation before the first key
+    #[container_ignore]
+    pub pre_infinity_extrap: RichCurveExtrapolation,
+    /// Extrapolation after the last key
+    #[container_ignore]
+    pub post_infinity_extrap: RichCurveExtrapolation,
+    /// Keys
+    pub keys: Vec<RichCurveKeyProperty>,
+    /// Serialized fields, fields with default values are only written if listed here
+    pub serialized_fields: Vec<FName>,
+}
+impl_property_data_trait!(RichCurveProperty);
+
+impl Default for RichCurveProperty {
+    fn default() -> Self {
+        RichCurveProperty {
+            name: FName::default(),
+            ancestry: Ancestry::default(),
+            property_guid: None,
+            property_tag_extensions: PropertyTagExtensions::default(),
+            duplication_index: 0,
+            default_value: OrderedFloat(UNSET_DEFAULT_VALUE),
+            pre_infinity_extrap: RichCurveExtrapolation::Constant,
+            post_infinity_extrap: RichCurveExtrapolation::Constant,
+            keys: Vec::new(),
+            serialized_fields: Vec::new(),
+        }
+    }
+}
+
+impl RichCurveProperty {
+    /// Read a `RichCurveProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let mut curve = RichCurveProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            ..Default::default()
+        };
+
+        let fields_ancestry = field_ancestry(&curve.ancestry, "RichCurve");
+        for field in StructProperty::read_properties(asset, fields_ancestry)? {
+            let field_name = field.get_name();
+            let is_real_curve_field = RealCurveFields {
+                struct_type: "RichCurve",
+                default_value: &mut curve.default_value,
+                pre_infinity_extrap: &mut curve.pre_infinity_extrap,
+                post_infinity_extrap: &mut curve.post_infinity_extrap,
+            }
+            .read(&field)?;
+
+            match field {
+                _ if is_real_curve_field => {}
+                Property::EmptyProperty(_) if field_name == "Keys" => {}
+                Property::ArrayProperty(e) if field_name == "Keys" => {
+                    curve.keys = struct_array_values("RichCurve", &e, |e| {
+                        cast!(Property, RichCurveKeyProperty, e).cloned()
+                    })?
+                }
+                e => return Err(unexpected_field("RichCurve", &e)),
+            }
+            curve.serialized_fields.push(field_name);
+        }
+
+        Ok(curve)
+    }
+
+    /// Evaluate this curve at a time, curves without keys or a default value evaluate to 0
+    pub fn eval(&self, time: f32) -> f32 {
+        self.eval_with_default(time, 0.0)
+    }
+
+    /// Evaluate this curve at a time, `default_value` is used if the curve has no keys or default value
+    pub fn eval_with_default(&self, time: f32, default_value: f32) -> f32 {
+        let default_value = match self.default_value.0 == UNSET_DEFAULT_VALUE {
+            true => default_value,
+            false => self.default_value.0,
+        };
+
+        eval_real_curve(
+            &self.keys,
+            default_value,
+            self.pre_infinity_extrap,
+            self.post_infinity_extrap,
+            time,
+            eval_rich_curve_keys,
+        )
+    }
+}
+
+impl PropertyTrait for RichCurveProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+
+        let ancestry = field_ancestry(&self.ancestry, "RichCurve");
+        let serialized_fields = &self.serialized_fields;
+        let mut fields = Vec::new();
+
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "Keys",
+            self.keys.is_empty(),
+            |name| {
+                struct_array_field(
+                    asset,
+                    name,
+                    &ancestry,
+                    "RichCurveKey",
+                    &self.keys,
+                    |key, name, ancestry| {
+                        RichCurveKeyProperty {
+                            name,
+                            ancestry,
+                            ..key.clone()
+                        }
+                        .into()
+                    },
+                )
+            },
+        );
+        push_real_curve_fields(
+            asset,
+            &mut fields,
+            serialized_fields,
+            &ancestry,
+            self.default_value,
+            self.pre_infinity_extrap,
+            self.post_infinity_extrap,
+        );
+
+        write_fields(asset, fields, serialized_fields, "RichCurve")
+    }
+}
+
+/// Simple curve property
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SimpleCurveProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Default value, `f32::MAX` if unset
+    pub default_value: OrderedFloat<f32>,
+    /// Extrapolation before the first key
+    #[container_ignore]
+    pub pre_infinity_extrap: RichCurveExtrapolation,
+    /// Extrapolation after the last key
+    #[container_ignore]
+    pub post_infinity_extrap: RichCurveExtrapolation,
+    /// Interpolation mode used between all keys
+    #[container_ignore]
+    pub interp_mode: RichCurveInterpMode,
+    /// Keys
+    pub keys: Vec<SimpleCurveKeyProperty>,
+    /// Serialized fields, fields with default values are only written if listed here
+    pub serialized_fields: Vec<FName>,
+}
+impl_property_data_trait!(SimpleCurveProperty);
+
+impl Default for SimpleCurveProperty {
+    fn default() -> Self {
+        SimpleCurveProperty {
+            name: FName::default(),
+            ancestry: Ancestry::default(),
+            property_guid: None,
+            property_tag_extensions: PropertyTagExtensions::default(),
+            duplication_index: 0,
+            default_value: OrderedFloat(UNSET_DEFAULT_VALUE),
+            pre_infinity_extrap: RichCurveExtrapolation::Constant,
+            post_infinity_extrap: RichCurveExtrapolation::Constant,
+            interp_mode: RichCurveInterpMode::Linear,
+            keys: Vec::new(),
+            serialized_fields: Vec::new(),
+        }
+    }
+}
+
+impl SimpleCurveProperty {
+    /// Read a `SimpleCurveProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let mut curve = SimpleCurveProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            ..Default::default()
+        };
+
+        let fields_ancestry = field_ancestry(&curve.ancestry, "SimpleCurve");
+        for field in StructProperty::read_properties(asset, fields_ancestry)? {
+            let field_name = field.get_name();
+            let is_real_curve_field = RealCurveFields {
+                struct_type: "SimpleCurve",
+                default_value: &mut curve.default_value,
+                pre_infinity_extrap: &mut curve.pre_infinity_extrap,
+                post_infinity_extrap: &mut curve.post_infinity_extrap,
+            }
+            .read(&field)?;
+
+            match field {
+                _ if is_real_curve_field => {}
+                Property::EmptyProperty(_) if field_name == "InterpMode" => {
+                    curve.interp_mode = RichCurveInterpMode::Linear
+                }
+                Property::EmptyProperty(_) if field_name == "Keys" => {}
+                ref e if field_name == "InterpMode" => {
+                    curve.interp_mode = enum_field_value(
+                        "SimpleCurve",
+                        e,
+                        |e| RichCurveInterpMode::try_from(e as i8).ok(),
+                        RichCurveInterpMode::from_value_name,
+                    )?
+                }
+                Property::ArrayProperty(e) if field_name == "Keys" => {
+                    curve.keys = struct_array_values("SimpleCurve", &e, |e| {
+                        cast!(Property, SimpleCurveKeyProperty, e).cloned()
+                    })?
+                }
+                e => return Err(unexpected_field("SimpleCurve", &e)),
+            }
+            curve.serialized_fields.push(field_name);
+        }
+
+        Ok(curve)
+    }
+
+    /// Evaluate this curve at a time, curves without keys or a default value evaluate to 0
+    pub fn eval(&self, time: f32) -> f32 {
+        self.eval_with_default(time, 0.0)
+    }
+
+    /// Evaluate this curve at a time, `default_value` is used if the curve has no keys or default value
+    ///
+    /// Simple curves only support constant and linear interpolation, cubic interpolation is evaluated as linear
+    pub fn eval_with_default(&self, time: f32, default_value: f32) -> f32 {
+        let default_value = match self.default_value.0 == UNSET_DEFAULT_VALUE {
+            true => default_value,
+            false => self.default_value.0,
+        };
+
+        eval_real_curve(
+            &self.keys,
+            default_value,
+            self.pre_infinity_extrap,
+            self.post_infinity_extrap,
+            time,
+            |key1, key2, time| {
+                let diff = key2.time.0 - key1.time.0;
+                match diff > 0.0 && self.interp_mode != RichCurveInterpMode::Constant {
+                    true => lerp(key1.value.0, key2.value.0, (time - key1.time.0) / diff),
+                    false => key1.value.0,
+                }
+            },
+        )
+    }
+}
+
+impl PropertyTrait for SimpleCurveProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+
+        let ancestry = field_ancestry(&self.ancestry, "SimpleCurve");
+        let serialized_fields = &self.serialized_fields;
+        let mut fields = Vec::new();
+
+        push_field_with_default(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "InterpMode",
+            self.interp_mode == RichCurveInterpMode::Linear,
+            self.interp_mode == RichCurveInterpMode::Linear,
+            |name| {
+                enum_field(
+                    asset,
+                    name,
+                    ancestry.clone(),
+                    "ERichCurveInterpMode",
+                    self.interp_mode.value_name(),
+                    i8::from(self.interp_mode) as u8,
+                )
+            },
+        );
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "Keys",
+            self.keys.is_empty(),
+            |name| {
+                struct_array_field(
+                    asset,
+                    name,
+                    &ancestry,
+                    "SimpleCurveKey",
+                    &self.keys,
+                    |key, name, ancestry| {
+                        SimpleCurveKeyProperty {
+                            name,
+                            ancestry,
+                            ..key.clone()
+                        }
+                        .into()
+                    },
+                )
+            },
+        );
+        push_real_curve_fields(
+            asset,
+            &mut fields,
+            serialized_fields,
+            &ancestry,
+            self.default_value,
+            self.pre_infinity_extrap,
+            self.post_infinity_extrap,
+        );
+
+        write_fields(asset, fields, serialized_fields, "SimpleCurve")
+    }
+}
+
+/// Integral curve property
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntegralCurveProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Keys
+    pub keys: Vec<IntegralKeyProperty>,
+    /// Default value, used when the curve has no keys
+    pub default_value: i32,
+    /// Use the default value instead of the first key value before the first key
+    pub use_default_value_before_first_key: bool,
+    /// Serialized fields, fields with default values are only written if listed here
+    pub serialized_fields: Vec<FName>,
+}
+impl_property_data_trait!(IntegralCurveProperty);
+
+impl Default for IntegralCurveProperty {
+    fn default() -> Self {
+        IntegralCurveProperty {
+            name: FName::default(),
+            ancestry: Ancestry::default(),
+            property_guid: None,
+            property_tag_extensions: PropertyTagExtensions::default(),
+            duplication_index: 0,
+            keys: Vec::new(),
+            default_value: i32::MAX,
+            use_default_value_before_first_key: false,
+            serialized_fields: Vec::new(),
+        }
+    }
+}
+
+impl IntegralCurveProperty {
+    /// Read an `IntegralCurveProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let mut curve = IntegralCurveProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            ..Default::default()
+        };
+
+        let fields_ancestry = field_ancestry(&curve.ancestry, "IntegralCurve");
+        for field in StructProperty::read_properties(asset, fields_ancestry)? {
+            let field_name = field.get_name();
+            match field {
+                Property::EmptyProperty(_) if field_name == "DefaultValue" => {
+                    curve.default_value = 0
+                }
+                Property::EmptyProperty(_)
+                    if field_name == "Keys" || field_name == "bUseDefaultValueBeforeFirstKey" => {}
+                Property::ArrayProperty(e) if field_name == "Keys" => {
+                    curve.keys = struct_array_values("IntegralCurve", &e, |e| {
+                        cast!(Property, IntegralKeyProperty, e).cloned()
+                    })?
+                }
+                Property::IntProperty(e) if field_name == "DefaultValue" => {
+                    curve.default_value = e.value
+                }
+                Property::BoolProperty(e) if field_name == "bUseDefaultValueBeforeFirstKey" => {
+                    curve.use_default_value_before_first_key = e.value
+                }
+                e => return Err(unexpected_field("IntegralCurve", &e)),
+            }
+            curve.serialized_fields.push(field_name);
+        }
+
+        Ok(curve)
+    }
+
+    /// Evaluate this curve at a time, the value of the last key at or before the time is used
+    pub fn eval(&self, time: f32) -> i32 {
+        let (Some(first), Some(last)) = (self.keys.first(), self.keys.last()) else {
+            return self.default_value;
+        };
+
+        if time < first.time.0 {
+            return match self.use_default_value_before_first_key {
+                true => self.default_value,
+                false => first.value,
+            };
+        }
+        if time >= last.time.0 {
+            return last.value;
+        }
+
+        let next = self.keys.partition_point(|e| time >= e.time.0).max(1);
+        self.keys[next - 1].value
+    }
+}
+
+impl PropertyTrait for IntegralCurveProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+
+        let ancestry = field_ancestry(&self.ancestry, "IntegralCurve");
+        let serialized_fields = &self.serialized_fields;
+        let mut fields = Vec::new();
+
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "Keys",
+            self.keys.is_empty(),
+            |name| {
+                struct_array_field(
+                    asset,
+                    name,
+                    &ancestry,
+                    "IntegralKey",
+                    &self.keys,
+                    |key, name, ancestry| {
+                        IntegralKeyProperty {
+                            name,
+                            ancestry,
+                            ..key.clone()
+                        }
+                        .into()
+                    },
+                )
+            },
+        );
+        push_field_with_default(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "DefaultValue",
+            self.default_value == i32::MAX,
+            self.default_value == 0,
+            |name| {
+                IntProperty {
+                    name,
+                    ancestry: ancestry.clone(),
+                    property_guid: None,
+                    property_tag_extensions: PropertyTagExtensions::default(),
+                    duplication_index: 0,
+                    value: self.default_value,
+                }
+                .into()
+            },
+        );
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "bUseDefaultValueBeforeFirstKey",
+            !self.use_default_value_before_first_key,
+            |name| {
+                BoolProperty {
+                    name,
+                    ancestry: ancestry.clone(),
+                    property_guid: None,
+                    property_tag_extensions: PropertyTagExtensions::default(),
+                    duplication_index: 0,
+                    value: self.use_default_value_before_first_key,
+                }
+                .into()
+            },
+        );
+
+        write_fields(asset, fields, serialized_fields, "IntegralCurve")
+    }
+}
+
+/// String curve property
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct StringCurveProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Default value, used when the curve has no keys
+    pub default_value: Option<String>,
+    /// Keys
+    pub keys: Vec<StringCurveKeyProperty>,
+    /// Serialized fields, fields with default values are only written if listed here
+    pub serialized_fields: Vec<FName>,
+}
+impl_property_data_trait!(StringCurveProperty);
+
+impl StringCurveProperty {
+    /// Read a `StringCurveProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let mut curve = StringCurveProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            ..Default::default()
+        };
+
+        let fields_ancestry = field_ancestry(&curve.ancestry, "StringCurve");
+        for field in StructProperty::read_properties(asset, fields_ancestry)? {
+            let field_name = field.get_name();
+            match field {
+                Property::EmptyProperty(_)
+                    if field_name == "DefaultValue" || field_name == "Keys" => {}
+                Property::StrProperty(e) if field_name == "DefaultValue" => {
+                    curve.default_value = e.value
+                }
+                Property::ArrayProperty(e) if field_name == "Keys" => {
+                    curve.keys = struct_array_values("StringCurve", &e, |e| {
+                        cast!(Property, StringCurveKeyProperty, e).cloned()
+                    })?
+                }
+                e => return Err(unexpected_field("StringCurve", &e)),
+            }
+            curve.serialized_fields.push(field_name);
+        }
+
+        Ok(curve)
+    }
+
+    /// Evaluate this curve at a time, the value of the last key at or before the time is used
+    ///
+    /// The default value is used before the first key
+    pub fn eval(&self, time: f32) -> Option<&str> {
+        match self.keys.partition_point(|e| time >= e.time.0) {
+            0 => self.default_value.as_deref(),
+            next => self.keys[next - 1].value.as_deref(),
+        }
+    }
+}
+
+impl PropertyTrait for StringCurveProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+
+        let ancestry = field_ancestry(&self.ancestry, "StringCurve");
+        let serialized_fields = &self.serialized_fields;
+        let mut fields = Vec::new();
+
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "DefaultValue",
+            self.default_value.is_none(),
+            |name| {
+                StrProperty {
+                    name,
+                    ancestry: ancestry.clone(),
+                    property_guid: None,
+                    property_tag_extensions: PropertyTagExtensions::default(),
+                    duplication_index: 0,
+                    value: self.default_value.clone(),
+                    encoding: Default::default(),
+                }
+                .into()
+            },
+        );
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "Keys",
+            self.keys.is_empty(),
+            |name| {
+                struct_array_field(
+                    asset,
+                    name,
+                    &ancestry,
+                    "StringCurveKey",
+                    &self.keys,
+                    |key, name, ancestry| {
+                        StringCurveKeyProperty {
+                            name,
+                            ancestry,
+                            ..key.clone()
+                        }
+                        .into()
+                    },
+                )
+            },
+        );
+
+        write_fields(asset, fields, serialized_fields, "StringCurve")
+    }
+}
@@ -11,6 +11,8 @@ pub struct TimeSpanProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Ticks
@@ -27,6 +29,8 @@ pub struct DateTimeProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Ticks
@@ -43,12 +47,13 @@ impl TimeSpanProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         let ticks = asset.read_i64::<LE>()?;
         Ok(TimeSpanProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             ticks,
         })
@@ -66,12 +71,13 @@ impl DateTimeProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         let ticks = asset.read_i64::<LE>()?;
         Ok(DateTimeProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             ticks,
         })
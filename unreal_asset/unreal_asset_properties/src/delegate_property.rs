@@ -28,8 +28,12 @@ pub struct DelegateProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
+    /// Complete property type name, only used in >= UE5.4 property tags
+    pub property_type_name: Option<PropertyTypeName>,
     /// Delegate value
     pub value: Delegate,
 }
@@ -45,13 +49,15 @@ impl DelegateProperty {
         _length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         Ok(DelegateProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
+            property_type_name: None,
             value: Delegate::new(
                 PackageIndex::new(asset.read_i32::<LE>()?),
                 asset.read_fname()?,
@@ -87,8 +93,12 @@ macro_rules! impl_multicast {
             pub ancestry: Ancestry,
             /// Property guid
             pub property_guid: Option<Guid>,
+            /// Property tag extensions
+            pub property_tag_extensions: PropertyTagExtensions,
             /// Property duplication index
             pub duplication_index: i32,
+            /// Complete property type name, only used in >= UE5.4 property tags
+            pub property_type_name: Option<PropertyTypeName>,
             /// Delegates
             pub value: Vec<Delegate>,
         }
@@ -103,7 +113,8 @@ macro_rules! impl_multicast {
                 _length: i64,
                 duplication_index: i32,
             ) -> Result<Self, Error> {
-                let property_guid = optional_guid!(asset, include_header);
+                let (property_guid, property_tag_extensions) =
+                    optional_guid!(asset, include_header);
 
                 let length = asset.read_i32::<LE>()?;
                 let mut value = Vec::with_capacity(length as usize);
@@ -118,7 +129,9 @@ macro_rules! impl_multicast {
                     name,
                     ancestry,
                     property_guid,
+                    property_tag_extensions,
                     duplication_index,
+                    property_type_name: None,
                     value,
                 })
             }
@@ -1,8 +1,11 @@
 //! Empty unversioned property
 
 use crate::property_prelude::*;
+use crate::property_tag::PropertyTypeName;
 
 /// Empty unversioned property
+///
+/// Also used for >= UE5.4 property tags with skipped value serialization
 #[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct EmptyProperty {
     /// Property type name
@@ -11,6 +14,8 @@ pub struct EmptyProperty {
     pub name: FName,
     /// Property ancestry
     pub ancestry: Ancestry,
+    /// Complete property type name, only used in >= UE5.4 property tags
+    pub property_type_name: Option<PropertyTypeName>,
 }
 
 impl EmptyProperty {
@@ -20,6 +25,7 @@ impl EmptyProperty {
             type_name,
             name,
             ancestry,
+            property_type_name: None,
         }
     }
 }
@@ -41,6 +47,14 @@ impl PropertyDataTrait for EmptyProperty {
         None
     }
 
+    fn set_property_guid(&mut self, _property_guid: Option<Guid>) {}
+
+    fn get_property_tag_extensions(&self) -> PropertyTagExtensions {
+        PropertyTagExtensions::default()
+    }
+
+    fn set_property_tag_extensions(&mut self, _property_tag_extensions: PropertyTagExtensions) {}
+
     fn get_ancestry(&self) -> &Ancestry {
         &self.ancestry
     }
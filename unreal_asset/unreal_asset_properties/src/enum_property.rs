@@ -11,8 +11,12 @@ pub struct EnumProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
+    /// Complete property type name, only used in >= UE5.4 property tags
+    pub property_type_name: Option<PropertyTypeName>,
     /// Enum type
     pub enum_type: Option<FName>,
     /// Inner type, used only with unversioned properties
@@ -62,7 +66,9 @@ impl EnumProperty {
                         name,
                         ancestry,
                         property_guid: None,
+                        property_tag_extensions: PropertyTagExtensions::default(),
                         duplication_index,
+                        property_type_name: None,
                         enum_type: Some(enum_ty),
                         inner_type: Some(inner_ty),
                         value,
@@ -74,12 +80,15 @@ impl EnumProperty {
             }
         }
 
-        let property_guid = match include_header {
+        let (property_guid, property_tag_extensions) = match include_header {
             true => {
                 enum_type = Some(asset.read_fname()?);
-                asset.read_property_guid()?
+                (
+                    asset.read_property_guid()?,
+                    asset.read_property_tag_extensions()?,
+                )
             }
-            false => None,
+            false => (None, PropertyTagExtensions::default()),
         };
         let value = asset.read_fname()?;
 
@@ -87,7 +96,9 @@ impl EnumProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
+            property_type_name: None,
             enum_type,
             inner_type,
             value: Some(value),
@@ -152,6 +163,7 @@ impl PropertyTrait for EnumProperty {
                     .ok_or_else(PropertyError::headerless)?,
             )?;
             asset.write_property_guid(self.property_guid.as_ref())?;
+            asset.write_property_tag_extensions(&self.property_tag_extensions)?;
         }
         asset.write_fname(self.value.as_ref().unwrap())?;
 
@@ -0,0 +1,89 @@
+//! Field path property
+
+use unreal_asset_base::custom_version::FReleaseObjectVersion;
+use unreal_asset_base::reader::ArchiveTrait;
+use unreal_asset_base::types::FieldPath;
+
+use crate::property_prelude::*;
+
+/// Field path property
+#[derive(FNameContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
+pub struct FieldPathProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Complete property type name, only used in >= UE5.4 property tags
+    pub property_type_name: Option<PropertyTypeName>,
+    /// Field path value
+    pub value: FieldPath,
+}
+impl_property_data_trait!(FieldPathProperty);
+
+/// Check if a field path serializes its resolved owner
+fn has_resolved_owner<Index: PackageIndexTrait>(asset: &impl ArchiveTrait<Index>) -> bool {
+    asset
+        .get_custom_version::<FFortniteMainBranchObjectVersion>()
+        .version
+        >= FFortniteMainBranchObjectVersion::FFieldPathOwnerSerialization as i32
+        || asset.get_custom_version::<FReleaseObjectVersion>().version
+            >= FReleaseObjectVersion::FFieldPathOwnerSerialization as i32
+}
+
+impl FieldPathProperty {
+    /// Read a `FieldPathProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let path = ArchiveReader::read_array(asset, |asset: &mut Reader| asset.read_fname())?;
+        let resolved_owner = match has_resolved_owner(asset) {
+            true => PackageIndex::new(asset.read_i32::<LE>()?),
+            false => PackageIndex::new(0),
+        };
+
+        Ok(FieldPathProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            property_type_name: None,
+            value: FieldPath::new(path, resolved_owner),
+        })
+    }
+}
+
+impl PropertyTrait for FieldPathProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+
+        let begin = asset.position();
+
+        asset.write_i32::<LE>(self.value.path.len() as i32)?;
+        for entry in &self.value.path {
+            asset.write_fname(entry)?;
+        }
+
+        if has_resolved_owner(asset) {
+            asset.write_i32::<LE>(self.value.resolved_owner.index)?;
+        }
+
+        Ok((asset.position() - begin) as usize)
+    }
+}
@@ -11,6 +11,8 @@ pub struct FloatRangeProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Lower bound
@@ -29,7 +31,7 @@ impl FloatRangeProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let lower_bound = asset.read_f32::<LE>()?;
         let upper_bound = asset.read_f32::<LE>()?;
@@ -38,6 +40,7 @@ impl FloatRangeProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             lower_bound: OrderedFloat(lower_bound),
             upper_bound: OrderedFloat(upper_bound),
@@ -58,6 +58,8 @@ pub struct FontCharacterProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Font character
@@ -76,7 +78,7 @@ impl FontCharacterProperty {
         _length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let value = FontCharacter::new(asset)?;
 
@@ -84,6 +86,7 @@ impl FontCharacterProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
@@ -20,6 +20,8 @@ pub struct UniqueNetIdProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Value
@@ -37,7 +39,7 @@ impl UniqueNetIdProperty {
         _length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let size = asset.read_i32::<LE>()?;
         let value = match size > 0 {
@@ -52,6 +54,7 @@ impl UniqueNetIdProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
@@ -0,0 +1,153 @@
+//! Gameplay attribute property
+
+use unreal_asset_base::types::FieldPath;
+
+use crate::field_path_property::FieldPathProperty;
+use crate::object_property::ObjectProperty;
+use crate::property_prelude::*;
+use crate::struct_property::{field_ancestry, push_field, unexpected_field, write_fields};
+
+/// Gameplay attribute property
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct GameplayAttributeProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Attribute name
+    pub attribute_name: Option<String>,
+    /// Attribute property
+    pub attribute: FieldPath,
+    /// Attribute set or struct owning the attribute
+    #[container_ignore]
+    pub attribute_owner: PackageIndex,
+    /// Serialized fields, fields with default values are only written if listed here
+    pub serialized_fields: Vec<FName>,
+}
+impl_property_data_trait!(GameplayAttributeProperty);
+
+impl GameplayAttributeProperty {
+    /// Read a `GameplayAttributeProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let mut attribute = GameplayAttributeProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            ..Default::default()
+        };
+
+        let fields_ancestry = field_ancestry(&attribute.ancestry, "GameplayAttribute");
+        for field in StructProperty::read_properties(asset, fields_ancestry)? {
+            let field_name = field.get_name();
+            match field {
+                Property::EmptyProperty(_)
+                    if ["AttributeName", "Attribute", "AttributeOwner"]
+                        .iter()
+                        .any(|e| field_name == *e) => {}
+                Property::StrProperty(e) if field_name == "AttributeName" => {
+                    attribute.attribute_name = e.value
+                }
+                Property::FieldPathProperty(e) if field_name == "Attribute" => {
+                    attribute.attribute = e.value
+                }
+                Property::ObjectProperty(e) if field_name == "AttributeOwner" => {
+                    attribute.attribute_owner = e.value
+                }
+                e => return Err(unexpected_field("GameplayAttribute", &e)),
+            }
+            attribute.serialized_fields.push(field_name);
+        }
+
+        Ok(attribute)
+    }
+}
+
+impl PropertyTrait for GameplayAttributeProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+
+        let ancestry = field_ancestry(&self.ancestry, "GameplayAttribute");
+        let serialized_fields = &self.serialized_fields;
+        let mut fields = Vec::new();
+
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "AttributeName",
+            self.attribute_name.is_none(),
+            |name| {
+                StrProperty {
+                    name,
+                    ancestry: ancestry.clone(),
+                    property_guid: None,
+                    property_tag_extensions: PropertyTagExtensions::default(),
+                    duplication_index: 0,
+                    value: self.attribute_name.clone(),
+                    encoding: Default::default(),
+                }
+                .into()
+            },
+        );
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "Attribute",
+            self.attribute.path.is_empty() && self.attribute.resolved_owner.index == 0,
+            |name| {
+                FieldPathProperty {
+                    name,
+                    ancestry: ancestry.clone(),
+                    property_guid: None,
+                    property_tag_extensions: PropertyTagExtensions::default(),
+                    duplication_index: 0,
+                    property_type_name: None,
+                    value: self.attribute.clone(),
+                }
+                .into()
+            },
+        );
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "AttributeOwner",
+            self.attribute_owner.index == 0,
+            |name| {
+                ObjectProperty {
+                    name,
+                    ancestry: ancestry.clone(),
+                    property_guid: None,
+                    property_tag_extensions: PropertyTagExtensions::default(),
+                    duplication_index: 0,
+                    property_type_name: None,
+                    value: self.attribute_owner,
+                }
+                .into()
+            },
+        );
+
+        write_fields(asset, fields, serialized_fields, "GameplayAttribute")
+    }
+}
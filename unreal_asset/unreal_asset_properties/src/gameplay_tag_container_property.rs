@@ -1,5 +1,6 @@
 //! Gameplay tag container property
 
+use crate::gameplay_tag_property::{tag_matches, GameplayTagQueryProperty};
 use crate::property_prelude::*;
 
 /// Gameplay tag container property
@@ -11,6 +12,8 @@ pub struct GameplayTagContainerProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Gameplay tags
@@ -28,7 +31,7 @@ impl GameplayTagContainerProperty {
         _length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let length = asset.read_i32::<LE>()?;
         let mut value = Vec::with_capacity(length as usize);
@@ -40,10 +43,30 @@ impl GameplayTagContainerProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
     }
+
+    /// Check if this container has a tag, parent tags of the tags in this container also match
+    pub fn has_tag(&self, tag: &FName) -> bool {
+        self.value.iter().any(|e| tag_matches(e, tag))
+    }
+
+    /// Check if this container has a tag, only exact matches are considered
+    pub fn has_tag_exact(&self, tag: &FName) -> bool {
+        tag.get_content(|tag| {
+            self.value
+                .iter()
+                .any(|e| e.get_content(|e| e.eq_ignore_ascii_case(tag)))
+        })
+    }
+
+    /// Check if this container matches a gameplay tag query
+    pub fn matches_query(&self, query: &GameplayTagQueryProperty) -> bool {
+        query.matches(self)
+    }
 }
 
 impl PropertyTrait for GameplayTagContainerProperty {
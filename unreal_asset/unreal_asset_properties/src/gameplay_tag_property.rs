@@ -0,0 +1,441 @@
+//! Gameplay tag and gameplay tag query properties
+
+use crate::array_property::ArrayProperty;
+use crate::empty_property::EmptyProperty;
+use crate::gameplay_tag_container_property::GameplayTagContainerProperty;
+use crate::int_property::{ByteProperty, BytePropertyValue, IntProperty};
+use crate::property_prelude::*;
+use crate::str_property::NameProperty;
+use crate::struct_property::{
+    field_ancestry, push_field, struct_array_field, struct_array_values, unexpected_field,
+    write_fields,
+};
+
+/// Gameplay tag query expression type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum EGameplayTagQueryExprType {
+    /// Undefined
+    #[default]
+    Undefined = 0,
+    /// Any of the tags are in the container
+    AnyTagsMatch,
+    /// All of the tags are in the container
+    AllTagsMatch,
+    /// None of the tags are in the container
+    NoTagsMatch,
+    /// Any of the sub expressions match
+    AnyExprMatch,
+    /// All of the sub expressions match
+    AllExprMatch,
+    /// None of the sub expressions match
+    NoExprMatch,
+}
+
+/// Check if a gameplay tag matches another tag, parent tags of `tag` also match
+///
+/// E.g. `A.B` matches `A.B` and `A`, but not `A.C` or `A.B.C`
+pub fn tag_matches(tag: &FName, tag_to_check: &FName) -> bool {
+    tag.get_content(|tag| {
+        tag_to_check.get_content(|tag_to_check| match tag.get(..tag_to_check.len()) {
+            Some(prefix) => {
+                prefix.eq_ignore_ascii_case(tag_to_check)
+                    && (tag.len() == tag_to_check.len()
+                        || tag.as_bytes()[tag_to_check.len()] == b'.')
+            }
+            None => false,
+        })
+    })
+}
+
+/// Gameplay tag property
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct GameplayTagProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Tag name
+    pub value: FName,
+}
+impl_property_data_trait!(GameplayTagProperty);
+
+impl GameplayTagProperty {
+    /// Read a `GameplayTagProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let mut value = None;
+        let fields_ancestry = field_ancestry(&ancestry, "GameplayTag");
+        for field in StructProperty::read_properties(asset, fields_ancestry)? {
+            match field {
+                Property::NameProperty(e) if e.name == "TagName" => value = Some(e.value),
+                Property::EmptyProperty(ref e) if e.name == "TagName" => {}
+                e => return Err(unexpected_field("GameplayTag", &e)),
+            }
+        }
+
+        Ok(GameplayTagProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            value: value.unwrap_or_else(|| asset.add_fname("None")),
+        })
+    }
+
+    /// Check if this tag matches another tag, parent tags of this tag also match
+    pub fn matches_tag(&self, tag_to_check: &FName) -> bool {
+        tag_matches(&self.value, tag_to_check)
+    }
+}
+
+impl PropertyTrait for GameplayTagProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+
+        // the tag name is always serialized as it's the only field
+        let name = asset.get_name_map().get_mut().add_fname("TagName");
+        let ancestry = field_ancestry(&self.ancestry, "GameplayTag");
+        let field: Property = match self.value == "None" && asset.has_unversioned_properties() {
+            true => EmptyProperty::new(FName::from_slice("NameProperty"), name, ancestry).into(),
+            false => NameProperty {
+                name,
+                ancestry,
+                property_guid: None,
+                property_tag_extensions: PropertyTagExtensions::default(),
+                duplication_index: 0,
+                value: self.value.clone(),
+            }
+            .into(),
+        };
+
+        StructProperty::write_properties(asset, &[field], &FName::from_slice("GameplayTag"))
+    }
+}
+
+/// Gameplay tag query property
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct GameplayTagQueryProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Token stream version
+    pub token_stream_version: i32,
+    /// Tags referenced by the token stream
+    pub tag_dictionary: Vec<FName>,
+    /// Query token stream
+    pub query_token_stream: Vec<u8>,
+    /// User description
+    pub user_description: Option<String>,
+    /// Automatically generated description
+    pub auto_description: Option<String>,
+    /// Serialized fields, fields with default values are only written if listed here
+    pub serialized_fields: Vec<FName>,
+}
+impl_property_data_trait!(GameplayTagQueryProperty);
+
+impl GameplayTagQueryProperty {
+    /// Read a `GameplayTagQueryProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let mut query = GameplayTagQueryProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            ..Default::default()
+        };
+
+        let fields_ancestry = field_ancestry(&query.ancestry, "GameplayTagQuery");
+        for field in StructProperty::read_properties(asset, fields_ancestry)? {
+            let field_name = field.get_name();
+            match field {
+                Property::EmptyProperty(_)
+                    if [
+                        "TokenStreamVersion",
+                        "TagDictionary",
+                        "QueryTokenStream",
+                        "UserDescription",
+                        "AutoDescription",
+                    ]
+                    .iter()
+                    .any(|e| field_name == *e) => {}
+                Property::IntProperty(e) if field_name == "TokenStreamVersion" => {
+                    query.token_stream_version = e.value
+                }
+                Property::ArrayProperty(e) if field_name == "TagDictionary" => {
+                    query.tag_dictionary =
+                        struct_array_values("GameplayTagQuery", &e, |e| match e {
+                            Property::GameplayTagProperty(e) => Some(e.value.clone()),
+                            // structs read without a known struct type
+                            Property::NameProperty(e) if e.name == "TagName" => {
+                                Some(e.value.clone())
+                            }
+                            _ => None,
+                        })?
+                }
+                Property::ArrayProperty(e) if field_name == "QueryTokenStream" => {
+                    query.query_token_stream = e
+                        .value
+                        .iter()
+                        .map(|e| match e {
+                            Property::ByteProperty(ByteProperty {
+                                value: BytePropertyValue::Byte(value),
+                                ..
+                            }) => Ok(*value),
+                            e => Err(unexpected_field("GameplayTagQuery", e)),
+                        })
+                        .collect::<Result<_, _>>()?
+                }
+                Property::StrProperty(e) if field_name == "UserDescription" => {
+                    query.user_description = e.value
+                }
+                Property::StrProperty(e) if field_name == "AutoDescription" => {
+                    query.auto_description = e.value
+                }
+                e => return Err(unexpected_field("GameplayTagQuery", &e)),
+            }
+            query.serialized_fields.push(field_name);
+        }
+
+        Ok(query)
+    }
+
+    /// Check if this query is empty, empty queries never match
+    pub fn is_empty(&self) -> bool {
+        self.query_token_stream.is_empty()
+    }
+
+    /// Check if a gameplay tag container matches this query
+    pub fn matches(&self, container: &GameplayTagContainerProperty) -> bool {
+        self.matches_tags(&container.value)
+    }
+
+    /// Check if a list of gameplay tags matches this query, parent tags of the listed tags also match
+    ///
+    /// Malformed token streams never match
+    pub fn matches_tags(&self, tags: &[FName]) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        let mut evaluator = QueryEvaluator {
+            query: self,
+            tags,
+            position: 0,
+        };
+        evaluator.eval().unwrap_or(false)
+    }
+}
+
+/// Gameplay tag query token stream evaluator
+struct QueryEvaluator<'a> {
+    query: &'a GameplayTagQueryProperty,
+    tags: &'a [FName],
+    position: usize,
+}
+
+impl QueryEvaluator<'_> {
+    /// Read the next token
+    fn next_token(&mut self) -> Option<u8> {
+        let token = self.query.query_token_stream.get(self.position).copied();
+        self.position += 1;
+        token
+    }
+
+    /// Evaluate the root expression
+    fn eval(&mut self) -> Option<bool> {
+        let _stream_version = self.next_token()?;
+        match self.next_token()? != 0 {
+            true => self.eval_expr(),
+            false => Some(false),
+        }
+    }
+
+    /// Evaluate an expression
+    fn eval_expr(&mut self) -> Option<bool> {
+        let expr_type = EGameplayTagQueryExprType::try_from(self.next_token()?).ok()?;
+        let count = self.next_token()? as usize;
+
+        let mut matched = 0;
+        for _ in 0..count {
+            let is_match = match expr_type {
+                EGameplayTagQueryExprType::AnyTagsMatch
+                | EGameplayTagQueryExprType::AllTagsMatch
+                | EGameplayTagQueryExprType::NoTagsMatch => {
+                    let tag = self.query.tag_dictionary.get(self.next_token()? as usize)?;
+                    self.tags.iter().any(|e| tag_matches(e, tag))
+                }
+                EGameplayTagQueryExprType::AnyExprMatch
+                | EGameplayTagQueryExprType::AllExprMatch
+                | EGameplayTagQueryExprType::NoExprMatch => self.eval_expr()?,
+                EGameplayTagQueryExprType::Undefined => return None,
+            };
+            matched += is_match as usize;
+        }
+
+        match expr_type {
+            EGameplayTagQueryExprType::AnyTagsMatch | EGameplayTagQueryExprType::AnyExprMatch => {
+                Some(matched > 0)
+            }
+            EGameplayTagQueryExprType::AllTagsMatch | EGameplayTagQueryExprType::AllExprMatch => {
+                Some(matched == count)
+            }
+            EGameplayTagQueryExprType::NoTagsMatch | EGameplayTagQueryExprType::NoExprMatch => {
+                Some(matched == 0)
+            }
+            EGameplayTagQueryExprType::Undefined => None,
+        }
+    }
+}
+
+impl PropertyTrait for GameplayTagQueryProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+
+        let ancestry = field_ancestry(&self.ancestry, "GameplayTagQuery");
+        let serialized_fields = &self.serialized_fields;
+        let mut fields = Vec::new();
+
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "TokenStreamVersion",
+            self.token_stream_version == 0,
+            |name| {
+                IntProperty {
+                    name,
+                    ancestry: ancestry.clone(),
+                    property_guid: None,
+                    property_tag_extensions: PropertyTagExtensions::default(),
+                    duplication_index: 0,
+                    value: self.token_stream_version,
+                }
+                .into()
+            },
+        );
+
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "TagDictionary",
+            self.tag_dictionary.is_empty(),
+            |name| {
+                struct_array_field(
+                    asset,
+                    name,
+                    &ancestry,
+                    "GameplayTag",
+                    &self.tag_dictionary,
+                    |value, name, ancestry| {
+                        GameplayTagProperty {
+                            name,
+                            ancestry,
+                            property_guid: None,
+                            property_tag_extensions: PropertyTagExtensions::default(),
+                            duplication_index: 0,
+                            value: value.clone(),
+                        }
+                        .into()
+                    },
+                )
+            },
+        );
+
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "QueryTokenStream",
+            self.query_token_stream.is_empty(),
+            |name| {
+                let tokens = self
+                    .query_token_stream
+                    .iter()
+                    .enumerate()
+                    .map(|(i, token)| {
+                        ByteProperty {
+                            name: FName::new_dummy(i.to_string(), i32::MIN),
+                            ancestry: ancestry.clone(),
+                            property_guid: None,
+                            property_tag_extensions: PropertyTagExtensions::default(),
+                            duplication_index: 0,
+                            property_type_name: None,
+                            enum_type: None,
+                            value: BytePropertyValue::Byte(*token),
+                        }
+                        .into()
+                    })
+                    .collect();
+                let byte_property = asset.get_name_map().get_mut().add_fname("ByteProperty");
+                ArrayProperty::from_arr(name, ancestry.clone(), Some(byte_property), tokens).into()
+            },
+        );
+
+        for (field_name, value) in [
+            ("UserDescription", &self.user_description),
+            ("AutoDescription", &self.auto_description),
+        ] {
+            push_field(
+                asset,
+                &mut fields,
+                serialized_fields,
+                field_name,
+                value.is_none(),
+                |name| {
+                    StrProperty {
+                        name,
+                        ancestry: ancestry.clone(),
+                        property_guid: None,
+                        property_tag_extensions: PropertyTagExtensions::default(),
+                        duplication_index: 0,
+                        value: value.clone(),
+                        encoding: Default::default(),
+                    }
+                    .into()
+                },
+            );
+        }
+
+        write_fields(asset, fields, serialized_fields, "GameplayTagQuery")
+    }
+}
@@ -11,6 +11,8 @@ pub struct GuidProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Guid value
@@ -27,12 +29,13 @@ impl GuidProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         let value = asset.read_guid()?;
         Ok(GuidProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
@@ -0,0 +1,146 @@
+//! Instanced struct property
+
+use unreal_asset_base::custom_version::FInstancedStructCustomVersion;
+use unreal_asset_base::reader::ArchiveTrait;
+
+use crate::property_prelude::*;
+
+/// Instanced struct property, a struct of any type chosen at runtime
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct InstancedStructProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Serialization version, only serialized before `FInstancedStructCustomVersion::CustomVersionAdded`
+    pub version: u8,
+    /// Script struct
+    #[container_ignore]
+    pub struct_index: PackageIndex,
+    /// Struct value, `None` if no script struct is set
+    pub value: Option<StructProperty>,
+}
+impl_property_data_trait!(InstancedStructProperty);
+
+impl InstancedStructProperty {
+    /// Read an `InstancedStructProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let version = match Self::has_version(asset) {
+            true => asset.read_u8()?,
+            false => 0,
+        };
+        let struct_index = PackageIndex::new(asset.read_i32::<LE>()?);
+        let serial_size = asset.read_i32::<LE>()?;
+
+        let value = match struct_index.index {
+            0 if serial_size != 0 => {
+                return Err(Error::invalid_file(format!(
+                    "Instanced struct without a script struct has {serial_size} bytes of data"
+                )));
+            }
+            0 => None,
+            _ => {
+                let struct_type = Self::resolve_struct_type(asset, struct_index)?;
+                let begin = asset.position();
+                let value = StructProperty::custom_header(
+                    asset,
+                    name.clone(),
+                    ancestry.with_parent(name.clone()),
+                    serial_size as i64,
+                    0,
+                    Some(struct_type),
+                    None,
+                    None,
+                )?;
+
+                let read = asset.position() - begin;
+                if read != serial_size as u64 {
+                    return Err(Error::invalid_file(format!(
+                        "Instanced struct read {read} bytes, expected {serial_size}"
+                    )));
+                }
+                Some(value)
+            }
+        };
+
+        Ok(InstancedStructProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            version,
+            struct_index,
+            value,
+        })
+    }
+
+    /// Resolve the script struct name from an import
+    fn resolve_struct_type<Index: PackageIndexTrait>(
+        asset: &impl ArchiveTrait<Index>,
+        struct_index: PackageIndex,
+    ) -> Result<FName, Error> {
+        asset
+            .get_object_name_packageindex(struct_index)
+            .ok_or_else(|| {
+                Error::invalid_package_index(format!(
+                    "Failed to resolve instanced struct type {}",
+                    struct_index.index
+                ))
+            })
+    }
+
+    /// Check if the serialization version byte is present
+    fn has_version<Index: PackageIndexTrait>(asset: &impl ArchiveTrait<Index>) -> bool {
+        asset
+            .get_custom_version::<FInstancedStructCustomVersion>()
+            .version
+            < FInstancedStructCustomVersion::CustomVersionAdded as i32
+    }
+}
+
+impl PropertyTrait for InstancedStructProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+        let begin = asset.position();
+
+        if Self::has_version(asset) {
+            asset.write_u8(self.version)?;
+        }
+        asset.write_i32::<LE>(self.struct_index.index)?;
+
+        let size_offset = asset.position();
+        asset.write_i32::<LE>(0)?;
+
+        if let Some(ref value) = self.value {
+            let struct_type = Self::resolve_struct_type(asset, self.struct_index)?;
+            let value_begin = asset.position();
+            value.write_with_type(asset, false, Some(struct_type))?;
+            let end = asset.position();
+
+            asset.seek(SeekFrom::Start(size_offset))?;
+            asset.write_i32::<LE>((end - value_begin) as i32)?;
+            asset.seek(SeekFrom::Start(end))?;
+        }
+
+        Ok((asset.position() - begin) as usize)
+    }
+}
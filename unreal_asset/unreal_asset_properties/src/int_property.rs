@@ -15,12 +15,14 @@ macro_rules! impl_int_property {
                 _length: i64,
                 duplication_index: i32,
             ) -> Result<Self, Error> {
-                let property_guid = optional_guid!(asset, include_header);
+                let (property_guid, property_tag_extensions) =
+                    optional_guid!(asset, include_header);
 
                 Ok($property_type {
                     name,
                     ancestry,
                     property_guid,
+                    property_tag_extensions,
                     duplication_index,
                     value: asset.$read_func::<LE>()?,
                 })
@@ -40,6 +42,8 @@ pub struct Int8Property {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Int8 value
@@ -71,8 +75,12 @@ pub struct ByteProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
+    /// Complete property type name, only used in >= UE5.4 property tags
+    pub property_type_name: Option<PropertyTypeName>,
     /// Enum type
     pub enum_type: Option<FName>,
     /// Byte value
@@ -89,6 +97,8 @@ pub struct BoolProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Boolean value
@@ -105,6 +115,8 @@ pub struct IntProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Int32 value
@@ -121,6 +133,8 @@ pub struct Int16Property {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Int16 value
@@ -137,6 +151,8 @@ pub struct Int64Property {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Int64 value
@@ -153,6 +169,8 @@ pub struct UInt16Property {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// UInt16 value
@@ -169,6 +187,8 @@ pub struct UInt32Property {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// UInt32 value
@@ -185,6 +205,8 @@ pub struct UInt64Property {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// UInt64 value
@@ -201,6 +223,8 @@ pub struct FloatProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Float value
@@ -217,6 +241,8 @@ pub struct DoubleProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Double value
@@ -235,12 +261,13 @@ impl BoolProperty {
         duplication_index: i32,
     ) -> Result<Self, Error> {
         let value = asset.read_bool()?;
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         Ok(BoolProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
@@ -269,11 +296,12 @@ impl Int8Property {
         _length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         Ok(Int8Property {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value: asset.read_i8()?,
         })
@@ -342,9 +370,13 @@ impl ByteProperty {
         fallback_length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let (enum_type, property_guid) = match include_header {
-            true => (Some(asset.read_fname()?), asset.read_property_guid()?),
-            false => (None, None),
+        let (enum_type, property_guid, property_tag_extensions) = match include_header {
+            true => (
+                Some(asset.read_fname()?),
+                asset.read_property_guid()?,
+                asset.read_property_tag_extensions()?,
+            ),
+            false => (None, None, PropertyTagExtensions::default()),
         };
 
         let value = ByteProperty::read_value(asset, length)
@@ -354,7 +386,9 @@ impl ByteProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
+            property_type_name: None,
             enum_type,
             value,
         })
@@ -368,12 +402,14 @@ impl PropertyTrait for ByteProperty {
         include_header: bool,
     ) -> Result<usize, Error> {
         if include_header {
-            asset.write_fname(
-                self.enum_type
-                    .as_ref()
-                    .ok_or_else(PropertyError::headerless)?,
-            )?;
+            // byte properties read from >= UE5.4 property tags have no enum type if not an enum
+            let enum_type = match self.enum_type {
+                Some(ref enum_type) => enum_type.clone(),
+                None => asset.get_name_map().get_mut().add_fname("None"),
+            };
+            asset.write_fname(&enum_type)?;
             asset.write_property_guid(self.property_guid.as_ref())?;
+            asset.write_property_tag_extensions(&self.property_tag_extensions)?;
         }
 
         match self.value {
@@ -399,12 +435,13 @@ impl FloatProperty {
         _length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         Ok(FloatProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value: OrderedFloat(asset.read_f32::<LE>()?),
         })
@@ -433,12 +470,13 @@ impl DoubleProperty {
         _length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         Ok(DoubleProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value: OrderedFloat(asset.read_f64::<LE>()?),
         })
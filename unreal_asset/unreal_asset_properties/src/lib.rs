@@ -15,10 +15,12 @@ use enum_dispatch::enum_dispatch;
 // macro reexports
 pub use unreal_asset_base::types::FName;
 use unreal_asset_base::types::PackageIndexTrait;
+pub use unreal_asset_base::types::PropertyTagExtensions;
 pub use unreal_asset_base::unversioned::Ancestry;
 pub use unreal_asset_base::Guid;
 
 use unreal_asset_base::error::{Error, PropertyError};
+use unreal_asset_base::object_version::ObjectVersionUE5;
 use unreal_asset_base::reader::{ArchiveReader, ArchiveWriter};
 use unreal_asset_base::types::fname::ToSerializedName;
 use unreal_asset_base::unversioned::header::UnversionedHeaderFragment;
@@ -30,22 +32,30 @@ use unreal_asset_base::FNameContainer;
 pub mod array_property;
 pub mod cloth_lod_property;
 pub mod color_property;
+pub mod curve_key_property;
+pub mod curve_property;
 pub mod date_property;
 pub mod delegate_property;
 pub mod empty_property;
 pub mod enum_property;
+pub mod field_path_property;
 pub mod float_range_property;
 pub mod font_character_property;
 pub mod game_framework;
+pub mod gameplay_attribute_property;
 pub mod gameplay_tag_container_property;
+pub mod gameplay_tag_property;
 pub mod guid_property;
+pub mod instanced_struct_property;
 pub mod int_property;
 pub mod map_property;
 pub mod material_input_property;
 pub mod movies;
 pub mod niagara;
 pub mod object_property;
+pub mod optional_property;
 pub mod per_platform_property;
+pub mod property_tag;
 pub mod raw_struct_property;
 pub mod rich_curve_key_property;
 pub mod sampler_property;
@@ -55,6 +65,7 @@ pub mod smart_name_property;
 pub mod soft_path_property;
 pub mod str_property;
 pub mod struct_property;
+pub mod text_history;
 pub mod unknown_property;
 pub mod vector_property;
 pub mod view_target_blend_property;
@@ -63,6 +74,10 @@ pub mod world_tile_property;
 use array_property::ArrayProperty;
 use cloth_lod_property::ClothLodDataProperty;
 use color_property::{ColorProperty, LinearColorProperty};
+use curve_key_property::{IntegralKeyProperty, SimpleCurveKeyProperty, StringCurveKeyProperty};
+use curve_property::{
+    IntegralCurveProperty, RichCurveProperty, SimpleCurveProperty, StringCurveProperty,
+};
 use date_property::{DateTimeProperty, TimeSpanProperty};
 use delegate_property::{
     DelegateProperty, MulticastDelegateProperty, MulticastInlineDelegateProperty,
@@ -70,11 +85,15 @@ use delegate_property::{
 };
 use empty_property::EmptyProperty;
 use enum_property::EnumProperty;
+use field_path_property::FieldPathProperty;
 use float_range_property::FloatRangeProperty;
 use font_character_property::FontCharacterProperty;
 use game_framework::unique_net_id_property::UniqueNetIdProperty;
+use gameplay_attribute_property::GameplayAttributeProperty;
 use gameplay_tag_container_property::GameplayTagContainerProperty;
+use gameplay_tag_property::{GameplayTagProperty, GameplayTagQueryProperty};
 use guid_property::GuidProperty;
+use instanced_struct_property::InstancedStructProperty;
 use int_property::{
     BoolProperty, ByteProperty, DoubleProperty, FloatProperty, Int16Property, Int64Property,
     Int8Property, IntProperty, UInt16Property, UInt32Property, UInt64Property,
@@ -86,6 +105,16 @@ use material_input_property::{
     VectorMaterialInputProperty,
 };
 use movies::movie_scene_eval_template_ptr_property::MovieSceneEvalTemplatePtrProperty;
+use movies::movie_scene_double_channel_property::MovieSceneDoubleChannelProperty;
+use movies::movie_scene_double_value_property::MovieSceneDoubleValueProperty;
+use movies::movie_scene_bool_channel_property::MovieSceneBoolChannelProperty;
+use movies::movie_scene_byte_channel_property::MovieSceneByteChannelProperty;
+use movies::movie_scene_integer_channel_property::MovieSceneIntegerChannelProperty;
+use movies::movie_scene_string_channel_property::MovieSceneStringChannelProperty;
+use movies::movie_scene_object_path_channel_property::MovieSceneObjectPathChannelProperty;
+use movies::movie_scene_event_channel_property::MovieSceneEventChannelProperty;
+use movies::movie_scene_time_warp_variant_property::MovieSceneTimeWarpVariantProperty;
+use movies::frame_number_property::FrameNumberProperty;
 use movies::movie_scene_evaluation_field_entity_tree_property::MovieSceneEvaluationFieldEntityTreeProperty;
 use movies::movie_scene_evaluation_key_property::MovieSceneEvaluationKeyProperty;
 use movies::movie_scene_event_parameters_property::MovieSceneEventParametersProperty;
@@ -105,10 +134,15 @@ use movies::section_evaluation_data_tree_property::SectionEvaluationDataTreeProp
 use niagara::niagara_variable_property::{
     NiagaraVariableProperty, NiagaraVariableWithOffsetProperty,
 };
-use object_property::{AssetObjectProperty, ObjectProperty, SoftObjectProperty};
+use object_property::{
+    AssetObjectProperty, InterfaceProperty, LazyObjectProperty, ObjectProperty, SoftObjectProperty,
+    WeakObjectProperty,
+};
+use optional_property::OptionalProperty;
 use per_platform_property::{
     PerPlatformBoolProperty, PerPlatformFloatProperty, PerPlatformIntProperty,
 };
+use property_tag::{EPropertyTagFlags, PropertyTypeName};
 use raw_struct_property::RawStructProperty;
 use rich_curve_key_property::RichCurveKeyProperty;
 use sampler_property::{
@@ -122,7 +156,9 @@ use soft_path_property::{
     SoftAssetPathProperty, SoftClassPathProperty, SoftObjectPathProperty,
     StringAssetReferenceProperty,
 };
-use str_property::{NameProperty, StrProperty, TextProperty};
+use str_property::{
+    AnsiStrProperty, NameProperty, StrProperty, TextProperty, Utf8StrProperty,
+};
 use struct_property::StructProperty;
 use unknown_property::UnknownProperty;
 use vector_property::{
@@ -143,7 +179,7 @@ mod property_prelude {
 
     pub use unreal_asset_base::cast;
     pub use unreal_asset_base::custom_version::{
-        CustomVersion, FEditorObjectVersion, FFortniteMainBranchObjectVersion,
+        CustomVersion, FCoreObjectVersion, FEditorObjectVersion, FFortniteMainBranchObjectVersion,
         FSequencerObjectVersion,
     };
     pub use unreal_asset_base::error::PropertyError;
@@ -151,7 +187,7 @@ mod property_prelude {
     pub use unreal_asset_base::reader::{ArchiveReader, ArchiveWriter};
     pub use unreal_asset_base::types::{
         fname::{FName, ToSerializedName},
-        PackageIndex, PackageIndexTrait,
+        PackageIndex, PackageIndexTrait, PropertyTagExtensions,
     };
     pub use unreal_asset_base::unversioned::{
         header::UnversionedHeader,
@@ -166,6 +202,7 @@ mod property_prelude {
     pub use super::object_property::SoftObjectPath;
     pub use super::optional_guid;
     pub use super::optional_guid_write;
+    pub use super::property_tag::PropertyTypeName;
     pub use super::simple_property_write;
     pub use super::str_property::StrProperty;
     pub use super::struct_property::StructProperty;
@@ -174,23 +211,27 @@ mod property_prelude {
     pub use super::PropertyTrait;
 }
 
-/// Read a property guid if reading with header
+/// Read a property guid and tag extensions if reading with header
 #[macro_export]
 macro_rules! optional_guid {
     ($asset:ident, $include_header:ident) => {
         match $include_header {
-            true => $asset.read_property_guid()?,
-            false => None,
+            true => (
+                $asset.read_property_guid()?,
+                $asset.read_property_tag_extensions()?,
+            ),
+            false => (None, $crate::PropertyTagExtensions::default()),
         }
     };
 }
 
-/// Write a property guid if writing with header
+/// Write a property guid and tag extensions if writing with header
 #[macro_export]
 macro_rules! optional_guid_write {
     ($self:ident, $asset:ident, $include_header:ident) => {
         if $include_header {
             $asset.write_property_guid($self.property_guid.as_ref())?;
+            $asset.write_property_tag_extensions(&$self.property_tag_extensions)?;
         }
     };
 }
@@ -234,6 +275,21 @@ macro_rules! impl_property_data_trait {
                 self.property_guid.clone()
             }
 
+            fn set_property_guid(&mut self, property_guid: Option<$crate::Guid>) {
+                self.property_guid = property_guid;
+            }
+
+            fn get_property_tag_extensions(&self) -> $crate::PropertyTagExtensions {
+                self.property_tag_extensions
+            }
+
+            fn set_property_tag_extensions(
+                &mut self,
+                property_tag_extensions: $crate::PropertyTagExtensions,
+            ) {
+                self.property_tag_extensions = property_tag_extensions;
+            }
+
             fn get_ancestry(&self) -> &$crate::Ancestry {
                 &self.ancestry
             }
@@ -245,7 +301,25 @@ macro_rules! impl_property_data_trait {
     };
 }
 
-const CUSTOM_SERIALIZATION: [&str; 57] = [
+/// Structs with typed properties that are still serialized as regular structs
+const TAGGED_SERIALIZATION: [&str; 14] = [
+    "GameplayTag",
+    "GameplayTagQuery",
+    "GameplayAttribute",
+    "RichCurve",
+    "SimpleCurve",
+    "IntegralCurve",
+    "IntegralKey",
+    "StringCurve",
+    "MovieSceneBoolChannel",
+    "MovieSceneByteChannel",
+    "MovieSceneIntegerChannel",
+    "MovieSceneStringChannel",
+    "MovieSceneObjectPathChannel",
+    "MovieSceneEventChannel",
+];
+
+const CUSTOM_SERIALIZATION: [&str; 78] = [
     "SkeletalMeshSamplingLODBuiltData",
     "SkeletalMeshAreaWeightedTriangleSampler",
     "SmartName",
@@ -262,9 +336,20 @@ const CUSTOM_SERIALIZATION: [&str; 57] = [
     "VectorMaterialInput",
     "Vector2MaterialInput",
     "GameplayTagContainer",
+    "GameplayTag",
+    "GameplayTagQuery",
+    "GameplayAttribute",
+    "InstancedStruct",
     "PerPlatformBool",
     "PerPlatformInt",
     "RichCurveKey",
+    "RichCurve",
+    "SimpleCurveKey",
+    "SimpleCurve",
+    "IntegralKey",
+    "IntegralCurve",
+    "StringCurveKey",
+    "StringCurve",
     "SoftAssetPath",
     "Timespan",
     "DateTime",
@@ -298,12 +383,22 @@ const CUSTOM_SERIALIZATION: [&str; 57] = [
     "MovieSceneEventParameters",
     "MovieSceneFloatChannel",
     "MovieSceneFloatValue",
+    "MovieSceneDoubleChannel",
+    "MovieSceneDoubleValue",
+    "MovieSceneBoolChannel",
+    "MovieSceneByteChannel",
+    "MovieSceneIntegerChannel",
+    "MovieSceneStringChannel",
+    "MovieSceneObjectPathChannel",
+    "MovieSceneEventChannel",
     "MovieSceneFrameRange",
     "MovieSceneSegment",
     "MovieSceneSegmentIdentifier",
     "MovieSceneTrackIdentifier",
     "MovieSceneSequenceId",
     "MovieSceneEvaluationKey",
+    "MovieSceneTimeWarpVariant",
+    "FrameNumber",
 ];
 
 /// This must be implemented for all properties
@@ -317,6 +412,12 @@ pub trait PropertyDataTrait {
     fn get_duplication_index(&self) -> i32;
     /// Get property's guid
     fn get_property_guid(&self) -> Option<Guid>;
+    /// Set property's guid
+    fn set_property_guid(&mut self, property_guid: Option<Guid>);
+    /// Get property's tag extensions
+    fn get_property_tag_extensions(&self) -> PropertyTagExtensions;
+    /// Set property's tag extensions
+    fn set_property_tag_extensions(&mut self, property_tag_extensions: PropertyTagExtensions);
     /// Get property's ancestry
     fn get_ancestry(&self) -> &Ancestry;
     /// Get a mutable reference to property's ancestry
@@ -366,6 +467,10 @@ pub enum Property {
     NameProperty,
     /// String property
     StrProperty,
+    /// UTF-8 string property
+    Utf8StrProperty,
+    /// ANSI string property
+    AnsiStrProperty,
     /// Text property
     TextProperty,
     /// Object property
@@ -374,6 +479,14 @@ pub enum Property {
     AssetObjectProperty,
     /// Soft object property
     SoftObjectProperty,
+    /// Weak object property
+    WeakObjectProperty,
+    /// Lazy object property
+    LazyObjectProperty,
+    /// Interface property
+    InterfaceProperty,
+    /// Field path property
+    FieldPathProperty,
     /// Int point property
     IntPointProperty,
     /// Vector property
@@ -408,6 +521,8 @@ pub enum Property {
     ArrayProperty,
     /// Map property
     MapProperty,
+    /// Optional property
+    OptionalProperty,
     /// Per-platform bool property
     PerPlatformBoolProperty,
     /// Per-platform int property
@@ -452,10 +567,32 @@ pub enum Property {
     MulticastInlineDelegateProperty,
     /// Rich curve key property
     RichCurveKeyProperty,
+    /// Rich curve property
+    RichCurveProperty,
+    /// Simple curve key property
+    SimpleCurveKeyProperty,
+    /// Simple curve property
+    SimpleCurveProperty,
+    /// Integral curve key property
+    IntegralKeyProperty,
+    /// Integral curve property
+    IntegralCurveProperty,
+    /// String curve key property
+    StringCurveKeyProperty,
+    /// String curve property
+    StringCurveProperty,
     /// View target blend params property
     ViewTargetBlendParamsProperty,
     /// Gameplay tag container property
     GameplayTagContainerProperty,
+    /// Gameplay tag property
+    GameplayTagProperty,
+    /// Gameplay tag query property
+    GameplayTagQueryProperty,
+    /// Gameplay attribute property
+    GameplayAttributeProperty,
+    /// Instanced struct property
+    InstancedStructProperty,
     /// Smart name property
     SmartNameProperty,
     /// Struct property
@@ -498,6 +635,22 @@ pub enum Property {
     MovieSceneFloatChannelProperty,
     /// Movie scene float value property
     MovieSceneFloatValueProperty,
+    /// Movie scene double channel property
+    MovieSceneDoubleChannelProperty,
+    /// Movie scene double value property
+    MovieSceneDoubleValueProperty,
+    /// Movie scene bool channel property
+    MovieSceneBoolChannelProperty,
+    /// Movie scene byte channel property
+    MovieSceneByteChannelProperty,
+    /// Movie scene integer channel property
+    MovieSceneIntegerChannelProperty,
+    /// Movie scene string channel property
+    MovieSceneStringChannelProperty,
+    /// Movie scene object path channel property
+    MovieSceneObjectPathChannelProperty,
+    /// Movie scene event channel property
+    MovieSceneEventChannelProperty,
     /// Movie scene frame range property
     MovieSceneFrameRangeProperty,
     /// Movie scene segment property
@@ -510,6 +663,10 @@ pub enum Property {
     MovieSceneSequenceIdProperty,
     /// Movie scene evaluation key property
     MovieSceneEvaluationKeyProperty,
+    /// Movie scene time warp variant property
+    MovieSceneTimeWarpVariantProperty,
+    /// Frame number property
+    FrameNumberProperty,
 
     /// Empty unversioned property
     EmptyProperty,
@@ -603,6 +760,10 @@ impl Property {
                 return Ok(None);
             }
 
+            if asset.get_object_version_ue5() >= ObjectVersionUE5::PROPERTY_TAG_COMPLETE_TYPE_NAME {
+                return Property::new_complete_type_name(asset, name, ancestry).map(Some);
+            }
+
             property_type = asset.read_fname()?;
             length = asset.read_i32::<LE>()?;
             duplication_index = asset.read_i32::<LE>()?;
@@ -622,6 +783,185 @@ impl Property {
         .map(Some)
     }
 
+    /// Read the rest of a >= UE5.4 property tag and the property value
+    fn new_complete_type_name<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+    ) -> Result<Self, Error> {
+        let type_name = PropertyTypeName::read(asset)?;
+        let length = asset.read_i32::<LE>()?;
+        let flags = EPropertyTagFlags::from_bits_retain(asset.read_u8()?);
+
+        let duplication_index = match flags.contains(EPropertyTagFlags::HAS_ARRAY_INDEX) {
+            true => asset.read_i32::<LE>()?,
+            false => 0,
+        };
+        let property_guid = match flags.contains(EPropertyTagFlags::HAS_PROPERTY_GUID) {
+            true => Some(asset.read_guid()?),
+            false => None,
+        };
+
+        let property_tag_extensions =
+            match flags.contains(EPropertyTagFlags::HAS_PROPERTY_EXTENSIONS) {
+                true => PropertyTagExtensions::read(asset)?,
+                false => PropertyTagExtensions::default(),
+            };
+
+        if flags.contains(EPropertyTagFlags::SKIPPED_SERIALIZE) {
+            // value wasn't serialized, skip anything the tag still claims to have
+            asset.seek(SeekFrom::Current(length as i64))?;
+            return Ok(EmptyProperty {
+                type_name: type_name.name.clone(),
+                name,
+                ancestry,
+                property_type_name: Some(type_name),
+            }
+            .into());
+        }
+
+        let mut property = Property::from_type_name(
+            asset,
+            &type_name,
+            name,
+            ancestry,
+            length as i64,
+            duplication_index,
+            property_guid,
+            flags,
+        )?;
+        property.set_property_tag_extensions(property_tag_extensions);
+        Ok(property)
+    }
+
+    /// Tries to read a property from an ArchiveReader while specifying a complete type name
+    ///
+    /// This is used for >= UE5.4 property tags, where all header data is stored in the tag
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_type_name<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        type_name: &PropertyTypeName,
+        name: FName,
+        ancestry: Ancestry,
+        length: i64,
+        duplication_index: i32,
+        property_guid: Option<Guid>,
+        flags: EPropertyTagFlags,
+    ) -> Result<Self, Error> {
+        let parameter_name = |index: usize| type_name.get_parameter(index).map(|e| e.name.clone());
+        // struct type of a container's inner type, e.g. ArrayProperty(StructProperty(Vector))
+        let inner_struct_type = |index: usize| {
+            type_name
+                .get_parameter(index)
+                .and_then(|e| e.get_parameter(0))
+                .map(|e| e.name.clone())
+        };
+
+        let mut property: Property = type_name.name.get_content(|ty| {
+            Ok::<Property, Error>(match ty {
+                "BoolProperty" => BoolProperty {
+                    name,
+                    ancestry,
+                    property_guid,
+                    property_tag_extensions: PropertyTagExtensions::default(),
+                    duplication_index,
+                    value: flags.contains(EPropertyTagFlags::BOOL_TRUE),
+                }
+                .into(),
+                "StructProperty" => {
+                    let struct_guid = type_name
+                        .get_parameter(1)
+                        .and_then(|e| e.to_guid())
+                        .unwrap_or_default();
+
+                    StructProperty::custom_header(
+                        asset,
+                        name,
+                        ancestry,
+                        length,
+                        duplication_index,
+                        parameter_name(0),
+                        Some(struct_guid),
+                        property_guid,
+                    )?
+                    .into()
+                }
+                "ArrayProperty" => ArrayProperty::new_no_header_with_struct_type(
+                    asset,
+                    name,
+                    ancestry,
+                    true,
+                    length,
+                    duplication_index,
+                    true,
+                    parameter_name(0),
+                    inner_struct_type(0),
+                    property_guid,
+                )?
+                .into(),
+                "SetProperty" => SetProperty::new_no_header(
+                    asset,
+                    name,
+                    ancestry,
+                    length,
+                    duplication_index,
+                    parameter_name(0),
+                    inner_struct_type(0),
+                    property_guid,
+                )?
+                .into(),
+                "OptionalProperty" => OptionalProperty::new_no_header(
+                    asset,
+                    name,
+                    ancestry,
+                    length,
+                    duplication_index,
+                    parameter_name(0),
+                    inner_struct_type(0),
+                    property_guid,
+                )?
+                .into(),
+                "MapProperty" => MapProperty::new_no_header(
+                    asset,
+                    name,
+                    ancestry,
+                    duplication_index,
+                    parameter_name(0),
+                    parameter_name(1),
+                    inner_struct_type(0),
+                    inner_struct_type(1),
+                    property_guid,
+                )?
+                .into(),
+                _ => Property::from_type(
+                    asset,
+                    &type_name.name,
+                    name,
+                    ancestry,
+                    false,
+                    length,
+                    0,
+                    duplication_index,
+                    false,
+                )?,
+            })
+        })?;
+
+        property.set_property_guid(property_guid);
+        match property {
+            Property::ByteProperty(ref mut byte) => byte.enum_type = parameter_name(0),
+            Property::EnumProperty(ref mut enum_property) => {
+                enum_property.enum_type = parameter_name(0)
+            }
+            _ => {}
+        }
+        if let Some(property_type_name) = property.get_property_type_name_mut() {
+            *property_type_name = Some(type_name.clone());
+        }
+
+        Ok(property)
+    }
+
     /// Tries to read a property from an ArchiveReader while specified a type and length
     #[allow(clippy::too_many_arguments)]
     pub fn from_type<Reader: ArchiveReader<impl PackageIndexTrait>>(
@@ -750,6 +1090,22 @@ impl Property {
                     StrProperty::new(asset, name, ancestry, include_header, duplication_index)?
                         .into()
                 }
+                "Utf8StrProperty" => Utf8StrProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "AnsiStrProperty" => AnsiStrProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
                 "TextProperty" => {
                     TextProperty::new(asset, name, ancestry, include_header, duplication_index)?
                         .into()
@@ -775,6 +1131,38 @@ impl Property {
                     duplication_index,
                 )?
                 .into(),
+                "WeakObjectProperty" => WeakObjectProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "LazyObjectProperty" => LazyObjectProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "InterfaceProperty" => InterfaceProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "FieldPathProperty" => FieldPathProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
 
                 "IntPoint" => {
                     IntPointProperty::new(asset, name, ancestry, include_header, duplication_index)?
@@ -856,6 +1244,15 @@ impl Property {
                     true,
                 )?
                 .into(),
+                "OptionalProperty" => OptionalProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    length,
+                    duplication_index,
+                )?
+                .into(),
                 "MapProperty" => {
                     MapProperty::new(asset, name, ancestry, include_header, duplication_index)?
                         .into()
@@ -1059,6 +1456,62 @@ impl Property {
                     duplication_index,
                 )?
                 .into(),
+                "RichCurve" => RichCurveProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "SimpleCurveKey" => SimpleCurveKeyProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "SimpleCurve" => SimpleCurveProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "IntegralKey" => IntegralKeyProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "IntegralCurve" => IntegralCurveProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "StringCurveKey" => StringCurveKeyProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "StringCurve" => StringCurveProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
                 "ViewTargetBlendParams" => ViewTargetBlendParamsProperty::new(
                     asset,
                     name,
@@ -1077,6 +1530,38 @@ impl Property {
                     duplication_index,
                 )?
                 .into(),
+                "GameplayTag" => GameplayTagProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "GameplayTagQuery" => GameplayTagQueryProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "GameplayAttribute" => GameplayAttributeProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "InstancedStruct" => InstancedStructProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
                 "SmartName" => SmartNameProperty::new(
                     asset,
                     name,
@@ -1264,6 +1749,70 @@ impl Property {
                     duplication_index,
                 )?
                 .into(),
+                "MovieSceneDoubleChannel" => MovieSceneDoubleChannelProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "MovieSceneDoubleValue" => MovieSceneDoubleValueProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "MovieSceneBoolChannel" => MovieSceneBoolChannelProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "MovieSceneByteChannel" => MovieSceneByteChannelProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "MovieSceneIntegerChannel" => MovieSceneIntegerChannelProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "MovieSceneStringChannel" => MovieSceneStringChannelProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "MovieSceneObjectPathChannel" => MovieSceneObjectPathChannelProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "MovieSceneEventChannel" => MovieSceneEventChannelProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
                 "MovieSceneFrameRange" => MovieSceneFrameRangeProperty::new(
                     asset,
                     name,
@@ -1312,6 +1861,22 @@ impl Property {
                     duplication_index,
                 )?
                 .into(),
+                "MovieSceneTimeWarpVariant" => MovieSceneTimeWarpVariantProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
+                "FrameNumber" => FrameNumberProperty::new(
+                    asset,
+                    name,
+                    ancestry,
+                    include_header,
+                    duplication_index,
+                )?
+                .into(),
 
                 _ => UnknownProperty::new(
                     asset,
@@ -1335,6 +1900,10 @@ impl Property {
     ) -> Result<usize, Error> {
         asset.write_fname(&property.get_name())?;
 
+        if asset.get_object_version_ue5() >= ObjectVersionUE5::PROPERTY_TAG_COMPLETE_TYPE_NAME {
+            return Property::write_complete_type_name(property, asset);
+        }
+
         let property_serialized_name = property.to_serialized_name();
         asset.write_fname(
             &asset
@@ -1355,10 +1924,215 @@ impl Property {
         Ok(begin as usize)
     }
 
+    /// Write the rest of a >= UE5.4 property tag and the property value
+    fn write_complete_type_name<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        property: &Property,
+        asset: &mut Writer,
+    ) -> Result<usize, Error> {
+        property.build_property_type_name(asset).write(asset)?;
+
+        let duplication_index = property.get_duplication_index();
+        let property_guid = property.get_property_guid();
+        let property_tag_extensions = property.get_property_tag_extensions();
+
+        let mut flags = EPropertyTagFlags::NONE;
+        flags.set(EPropertyTagFlags::HAS_ARRAY_INDEX, duplication_index != 0);
+        flags.set(
+            EPropertyTagFlags::HAS_PROPERTY_GUID,
+            property_guid.is_some(),
+        );
+        flags.set(
+            EPropertyTagFlags::HAS_PROPERTY_EXTENSIONS,
+            !property_tag_extensions.flags.is_empty(),
+        );
+        match property {
+            Property::BoolProperty(bool_property) => {
+                flags.set(EPropertyTagFlags::BOOL_TRUE, bool_property.value)
+            }
+            Property::EmptyProperty(_) => flags.set(EPropertyTagFlags::SKIPPED_SERIALIZE, true),
+            Property::StructProperty(struct_property) => flags.set(
+                EPropertyTagFlags::HAS_BINARY_OR_NATIVE_SERIALIZE,
+                struct_property.is_custom_serialized(asset, struct_property.struct_type.as_ref())
+                    && !struct_property
+                        .struct_type
+                        .as_ref()
+                        .is_some_and(|e| e.get_content(Property::has_tagged_serialization)),
+            ),
+            _ => {}
+        }
+
+        let begin = asset.position();
+        asset.write_i32::<LE>(0)?; // initial length
+        asset.write_u8(flags.bits())?;
+        if flags.contains(EPropertyTagFlags::HAS_ARRAY_INDEX) {
+            asset.write_i32::<LE>(duplication_index)?;
+        }
+        if let Some(property_guid) = property_guid {
+            asset.write_guid(&property_guid)?;
+        }
+        if flags.contains(EPropertyTagFlags::HAS_PROPERTY_EXTENSIONS) {
+            property_tag_extensions.write(asset)?;
+        }
+
+        // bool values are stored in the tag flags, skipped values aren't stored at all
+        let len = match property {
+            Property::BoolProperty(_) | Property::EmptyProperty(_) => 0,
+            _ => property.write(asset, false)?,
+        };
+        let end = asset.position();
+
+        asset.seek(SeekFrom::Start(begin))?;
+        asset.write_i32::<LE>(len as i32)?;
+        asset.seek(SeekFrom::Start(end))?;
+        Ok(begin as usize)
+    }
+
+    /// Get the complete type name used when writing this property with a >= UE5.4 property tag
+    ///
+    /// The type name read from the tag is used if present,
+    /// otherwise it's built from the property without any package paths
+    pub fn build_property_type_name<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &Writer,
+    ) -> PropertyTypeName {
+        if let Some(property_type_name) = self.get_property_type_name() {
+            return property_type_name.clone();
+        }
+
+        let node = |name: &str| {
+            PropertyTypeName::new(asset.get_name_map().get_mut().add_fname(name), Vec::new())
+        };
+        let leaf = |name: &FName| match name {
+            // e.g. struct types that were never read from the asset
+            FName::Dummy { value, number } => PropertyTypeName::new(
+                asset
+                    .get_name_map()
+                    .get_mut()
+                    .add_fname_with_number(value, *number),
+                Vec::new(),
+            ),
+            _ => PropertyTypeName::new(name.clone(), Vec::new()),
+        };
+        let array_parameters = |array: &ArrayProperty| match (
+            array.value.first(),
+            array.dummy_property.as_ref(),
+            array.array_type.as_ref(),
+        ) {
+            (Some(first), _, _) => vec![first.build_property_type_name(asset)],
+            (None, Some(dummy), _) => {
+                vec![Property::StructProperty(dummy.clone()).build_property_type_name(asset)]
+            }
+            (None, None, Some(array_type)) => vec![leaf(array_type)],
+            (None, None, None) => Vec::new(),
+        };
+
+        let parameters = match self {
+            Property::StructProperty(struct_property) => {
+                let mut parameters: Vec<_> = struct_property.struct_type.iter().map(leaf).collect();
+                if let Some(struct_guid) = struct_property.struct_guid.filter(|e| !e.is_zero()) {
+                    parameters.push(node(&PropertyTypeName::guid_to_string(&struct_guid)));
+                }
+                parameters
+            }
+            Property::ByteProperty(byte) => byte
+                .enum_type
+                .iter()
+                .filter(|e| *e != "None")
+                .map(leaf)
+                .collect(),
+            Property::EnumProperty(enum_property) => match enum_property.enum_type {
+                Some(ref enum_type) => vec![
+                    leaf(enum_type),
+                    match enum_property.inner_type {
+                        Some(ref inner_type) => leaf(inner_type),
+                        None => node("ByteProperty"),
+                    },
+                ],
+                None => Vec::new(),
+            },
+            Property::ArrayProperty(array) => array_parameters(array),
+            Property::SetProperty(set) => match set.value.value.is_empty() {
+                true => set.array_type.iter().map(leaf).collect(),
+                false => array_parameters(&set.value),
+            },
+            Property::OptionalProperty(optional) => match optional.value {
+                Some(ref value) => vec![value.build_property_type_name(asset)],
+                None => optional.inner_type.iter().map(leaf).collect(),
+            },
+            Property::MapProperty(map) => match map.value.iter().next() {
+                Some((_, key, value)) => vec![
+                    key.build_property_type_name(asset),
+                    value.build_property_type_name(asset),
+                ],
+                None => vec![leaf(&map.key_type), leaf(&map.value_type)],
+            },
+            _ => Vec::new(),
+        };
+
+        let mut property_type_name = node(&self.to_serialized_name());
+        property_type_name.parameters = parameters;
+        property_type_name
+    }
+
     /// Check if a property type has custom serialization
     pub fn has_custom_serialization(name: &str) -> bool {
         CUSTOM_SERIALIZATION.contains(&name)
     }
+
+    /// Check if a property type with custom serialization is serialized as a regular struct
+    pub fn has_tagged_serialization(name: &str) -> bool {
+        TAGGED_SERIALIZATION.contains(&name)
+    }
+}
+
+/// Implements complete type name accessors for properties that store one
+macro_rules! property_type_name_accessors {
+    ($($inner:ident),*) => {
+        impl Property {
+            /// Get property's complete type name, only present if read from a >= UE5.4 property tag
+            pub fn get_property_type_name(&self) -> Option<&PropertyTypeName> {
+                match self {
+                    $(
+                        Self::$inner(e) => e.property_type_name.as_ref(),
+                    )*
+                    _ => None,
+                }
+            }
+
+            /// Get a mutable reference to property's complete type name,
+            /// `None` if this property type doesn't store one
+            pub fn get_property_type_name_mut(&mut self) -> Option<&mut Option<PropertyTypeName>> {
+                match self {
+                    $(
+                        Self::$inner(e) => Some(&mut e.property_type_name),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+property_type_name_accessors! {
+    ByteProperty,
+    EnumProperty,
+    StructProperty,
+    ArrayProperty,
+    SetProperty,
+    MapProperty,
+    OptionalProperty,
+    ObjectProperty,
+    SoftObjectProperty,
+    WeakObjectProperty,
+    LazyObjectProperty,
+    InterfaceProperty,
+    FieldPathProperty,
+    DelegateProperty,
+    MulticastDelegateProperty,
+    MulticastSparseDelegateProperty,
+    MulticastInlineDelegateProperty,
+    UnknownProperty,
+    EmptyProperty
 }
 
 /// Implements `ToSerializedName` trait for properties
@@ -1396,9 +2170,20 @@ property_inner_serialized_name! {
     VectorMaterialInputProperty: "VectorMaterialInput",
     Vector2MaterialInputProperty: "Vector2MaterialInput",
     GameplayTagContainerProperty: "GameplayTagContainer",
+    GameplayTagProperty: "GameplayTag",
+    GameplayTagQueryProperty: "GameplayTagQuery",
+    GameplayAttributeProperty: "GameplayAttribute",
+    InstancedStructProperty: "InstancedStruct",
     PerPlatformBoolProperty: "PerPlatformBool",
     PerPlatformIntProperty: "PerPlatformInt",
     RichCurveKeyProperty: "RichCurveKey",
+    RichCurveProperty: "RichCurve",
+    SimpleCurveKeyProperty: "SimpleCurveKey",
+    SimpleCurveProperty: "SimpleCurve",
+    IntegralKeyProperty: "IntegralKey",
+    IntegralCurveProperty: "IntegralCurve",
+    StringCurveKeyProperty: "StringCurveKey",
+    StringCurveProperty: "StringCurve",
     SoftAssetPathProperty: "SoftAssetPath",
     TimeSpanProperty: "Timespan",
     DateTimeProperty: "DateTime",
@@ -1429,6 +2214,7 @@ property_inner_serialized_name! {
     Int8Property: "Int8Property",
     IntProperty: "IntProperty",
     MapProperty: "MapProperty",
+    OptionalProperty: "OptionalProperty",
     MulticastDelegateProperty: "MulticastDelegateProperty",
     MulticastSparseDelegateProperty: "MulticastSparseDelegateProperty",
     MulticastInlineDelegateProperty: "MulticastInlineDelegateProperty",
@@ -1437,7 +2223,13 @@ property_inner_serialized_name! {
     ObjectProperty: "ObjectProperty",
     AssetObjectProperty: "AssetObjectProperty",
     SoftObjectProperty: "SoftObjectProperty",
+    WeakObjectProperty: "WeakObjectProperty",
+    LazyObjectProperty: "LazyObjectProperty",
+    InterfaceProperty: "InterfaceProperty",
+    FieldPathProperty: "FieldPathProperty",
     StrProperty: "StrProperty",
+    Utf8StrProperty: "Utf8StrProperty",
+    AnsiStrProperty: "AnsiStrProperty",
     TextProperty: "TextProperty",
     UInt16Property: "UInt16Property",
     UInt32Property: "UInt32Property",
@@ -1461,12 +2253,22 @@ property_inner_serialized_name! {
     MovieSceneEventParametersProperty: "MovieSceneEventParameters",
     MovieSceneFloatChannelProperty: "MovieSceneFloatChannel",
     MovieSceneFloatValueProperty: "MovieSceneFloatValue",
+    MovieSceneDoubleChannelProperty: "MovieSceneDoubleChannel",
+    MovieSceneDoubleValueProperty: "MovieSceneDoubleValue",
+    MovieSceneBoolChannelProperty: "MovieSceneBoolChannel",
+    MovieSceneByteChannelProperty: "MovieSceneByteChannel",
+    MovieSceneIntegerChannelProperty: "MovieSceneIntegerChannel",
+    MovieSceneStringChannelProperty: "MovieSceneStringChannel",
+    MovieSceneObjectPathChannelProperty: "MovieSceneObjectPathChannel",
+    MovieSceneEventChannelProperty: "MovieSceneEventChannel",
     MovieSceneFrameRangeProperty: "MovieSceneFrameRange",
     MovieSceneSegmentProperty: "MovieSceneSegment",
     MovieSceneSegmentIdentifierProperty: "MovieSceneSegmentIdentifier",
     MovieSceneTrackIdentifierProperty: "MovieSceneTrackIdentifier",
     MovieSceneSequenceIdProperty: "MovieSceneSequenceId",
-    MovieSceneEvaluationKeyProperty: "MovieSceneEvaluationKey"
+    MovieSceneEvaluationKeyProperty: "MovieSceneEvaluationKey",
+    MovieSceneTimeWarpVariantProperty: "MovieSceneTimeWarpVariant",
+    FrameNumberProperty: "FrameNumber"
 }
 
 /// Generate property unversioned header
@@ -15,8 +15,12 @@ pub struct MapProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
+    /// Complete property type name, only used in >= UE5.4 property tags
+    pub property_type_name: Option<PropertyTypeName>,
     /// Key type
     pub key_type: FName,
     /// Value type
@@ -41,6 +45,7 @@ impl Hash for MapProperty {
 
 impl MapProperty {
     /// Map type_name to a `Property` and read it from an asset
+    #[allow(clippy::too_many_arguments)]
     fn map_type_to_class<Reader: ArchiveReader<impl PackageIndexTrait>>(
         asset: &mut Reader,
         type_name: FName,
@@ -49,14 +54,16 @@ impl MapProperty {
         length: i64,
         include_header: bool,
         is_key: bool,
+        known_struct_type: Option<FName>,
     ) -> Result<Property, Error> {
         let new_ancestry = ancestry.with_parent(name.clone());
         type_name.get_content(|ty| match ty {
             "StructProperty" => {
-                let mut struct_type = None;
+                let mut struct_type = known_struct_type;
 
                 if let Some(map_data) = asset
                     .get_mappings()
+                    .filter(|_| struct_type.is_none())
                     .and_then(|e| e.get_property(&name, ancestry))
                     .and_then(|e| cast!(UsmapPropertyData, UsmapMapPropertyData, &e.property_data))
                 {
@@ -127,13 +134,44 @@ impl MapProperty {
         let mut type_1 = None;
         let mut type_2 = None;
         let mut property_guid = None;
+        let mut property_tag_extensions = PropertyTagExtensions::default();
 
         if include_header && !asset.has_unversioned_properties() {
             type_1 = Some(asset.read_fname()?);
             type_2 = Some(asset.read_fname()?);
             property_guid = asset.read_property_guid()?;
+            property_tag_extensions = asset.read_property_tag_extensions()?;
         }
 
+        Ok(MapProperty {
+            property_tag_extensions,
+            ..MapProperty::new_no_header(
+                asset,
+                name,
+                ancestry,
+                duplication_index,
+                type_1,
+                type_2,
+                None,
+                None,
+                property_guid,
+            )?
+        })
+    }
+
+    /// Read a `MapProperty` from an asset without reading the property header
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_no_header<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        duplication_index: i32,
+        mut type_1: Option<FName>,
+        mut type_2: Option<FName>,
+        key_struct_type: Option<FName>,
+        value_struct_type: Option<FName>,
+        property_guid: Option<Guid>,
+    ) -> Result<Self, Error> {
         if type_1.is_none() && type_2.is_none() {
             if let Some(property) = asset
                 .get_mappings()
@@ -165,6 +203,7 @@ impl MapProperty {
                 0,
                 false,
                 true,
+                key_struct_type.clone(),
             )?);
             keys_to_remove = Some(vec);
         }
@@ -181,6 +220,7 @@ impl MapProperty {
                 0,
                 false,
                 true,
+                key_struct_type.clone(),
             )?;
             let value = MapProperty::map_type_to_class(
                 asset,
@@ -190,6 +230,7 @@ impl MapProperty {
                 0,
                 false,
                 false,
+                value_struct_type.clone(),
             )?;
             values.insert(key, value);
         }
@@ -198,7 +239,9 @@ impl MapProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions: PropertyTagExtensions::default(),
             duplication_index,
+            property_type_name: None,
             key_type: type_1,
             value_type: type_2,
             value: values,
@@ -232,6 +275,7 @@ impl PropertyTrait for MapProperty {
                 asset.write_fname(&self.value_type)?;
             }
             asset.write_property_guid(self.property_guid.as_ref())?;
+            asset.write_property_tag_extensions(&self.property_tag_extensions)?;
         }
 
         let begin = asset.position();
@@ -28,6 +28,8 @@ pub struct ColorMaterialInputProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Material expression
@@ -46,6 +48,8 @@ pub struct ScalarMaterialInputProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Material expression
@@ -64,6 +68,8 @@ pub struct ShadingModelMaterialInputProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Material expression
@@ -82,6 +88,8 @@ pub struct VectorMaterialInputProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property index
     pub duplication_index: i32,
     /// Material expression
@@ -100,6 +108,8 @@ pub struct Vector2MaterialInputProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Material expression
@@ -118,6 +128,8 @@ pub struct ExpressionInputProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Material expression
@@ -134,6 +146,8 @@ pub struct MaterialAttributesInputProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Material expression
@@ -186,7 +200,7 @@ impl ColorMaterialInputProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         let material_expression = MaterialExpression::new(asset, name.clone(), false)?;
         asset.read_i32::<LE>()?;
 
@@ -202,6 +216,7 @@ impl ColorMaterialInputProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             material_expression,
             value,
@@ -232,7 +247,7 @@ impl ScalarMaterialInputProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         let material_expression = MaterialExpression::new(asset, name.clone(), false)?;
         asset.read_i32::<LE>()?;
 
@@ -242,6 +257,7 @@ impl ScalarMaterialInputProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             material_expression,
             value: OrderedFloat(value),
@@ -272,7 +288,7 @@ impl ShadingModelMaterialInputProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         let material_expression = MaterialExpression::new(asset, name.clone(), false)?;
 
         asset.read_i32::<LE>()?;
@@ -281,6 +297,7 @@ impl ShadingModelMaterialInputProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             material_expression,
             value,
@@ -311,7 +328,7 @@ impl VectorMaterialInputProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         let material_expression = MaterialExpression::new(asset, name.clone(), false)?;
 
         asset.read_i32::<LE>()?;
@@ -326,6 +343,7 @@ impl VectorMaterialInputProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             material_expression,
             value,
@@ -356,7 +374,7 @@ impl Vector2MaterialInputProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         let material_expression = MaterialExpression::new(asset, name.clone(), false)?;
 
         asset.read_i32::<LE>()?;
@@ -371,6 +389,7 @@ impl Vector2MaterialInputProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             material_expression,
             value,
@@ -401,13 +420,14 @@ impl ExpressionInputProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         let material_expression = MaterialExpression::new(asset, name.clone(), false)?;
 
         Ok(ExpressionInputProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             material_expression,
         })
@@ -434,13 +454,14 @@ impl MaterialAttributesInputProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         let material_expression = MaterialExpression::new(asset, name.clone(), false)?;
 
         Ok(MaterialAttributesInputProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             material_expression,
         })
@@ -234,3 +234,25 @@ pub enum ESpawnOwnership {
     /// Max
     ESpawnOwnership_MAX = 3,
 }
+
+/// Enum MovieScene.EMovieSceneTimeWarpType
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, TryFromPrimitive, IntoPrimitive)]
+pub enum EMovieSceneTimeWarpType {
+    /// Fixed play rate
+    FixedPlayRate = 0,
+    /// Custom time warp getter
+    Custom = 1,
+    /// Fixed time
+    FixedTime = 2,
+    /// Frame rate
+    FrameRate = 3,
+    /// Loop
+    Loop = 4,
+    /// Clamp
+    Clamp = 5,
+    /// Loop with a fractional duration
+    LoopFloat = 6,
+    /// Clamp with a fractional maximum
+    ClampFloat = 7,
+}
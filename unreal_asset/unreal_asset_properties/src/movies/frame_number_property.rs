@@ -0,0 +1,99 @@
+//! Frame number property
+
+use unreal_asset_base::types::movie::FrameNumber;
+
+use crate::array_property::ArrayProperty;
+use crate::property_prelude::*;
+use crate::struct_property::{struct_array_field, struct_array_values};
+
+/// Frame number property
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct FrameNumberProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Value
+    #[container_ignore]
+    pub value: FrameNumber,
+}
+impl_property_data_trait!(FrameNumberProperty);
+
+impl FrameNumberProperty {
+    /// Read a `FrameNumberProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let value = FrameNumber::new(asset.read_i32::<LE>()?);
+
+        Ok(FrameNumberProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            value,
+        })
+    }
+}
+
+impl PropertyTrait for FrameNumberProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+        asset.write_i32::<LE>(self.value.value)?;
+        Ok(size_of::<i32>())
+    }
+}
+
+/// Create an array field of frame numbers
+pub(crate) fn frame_numbers_field<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+    asset: &Writer,
+    name: FName,
+    ancestry: &Ancestry,
+    times: &[FrameNumber],
+) -> Property {
+    struct_array_field(
+        asset,
+        name,
+        ancestry,
+        "FrameNumber",
+        times,
+        |time, name, ancestry| {
+            FrameNumberProperty {
+                name,
+                ancestry,
+                property_guid: None,
+                property_tag_extensions: PropertyTagExtensions::default(),
+                duplication_index: 0,
+                value: *time,
+            }
+            .into()
+        },
+    )
+}
+
+/// Get the frame numbers of an array field
+pub(crate) fn frame_number_values(
+    struct_type: &str,
+    array: &ArrayProperty,
+) -> Result<Vec<FrameNumber>, Error> {
+    struct_array_values(struct_type, array, |e| {
+        cast!(Property, FrameNumberProperty, e).map(|e| e.value)
+    })
+}
@@ -4,18 +4,28 @@ use crate::property_prelude::*;
 use crate::rich_curve_key_property::RichCurveTangentWeightMode;
 
 pub mod enums;
+pub mod frame_number_property;
+pub mod movie_scene_bool_channel_property;
+pub mod movie_scene_byte_channel_property;
+pub mod movie_scene_double_channel_property;
+pub mod movie_scene_double_value_property;
 pub mod movie_scene_eval_template_ptr_property;
 pub mod movie_scene_evaluation;
 pub mod movie_scene_evaluation_field_entity_tree_property;
 pub mod movie_scene_evaluation_key_property;
+pub mod movie_scene_event_channel_property;
 pub mod movie_scene_event_parameters_property;
 pub mod movie_scene_float_channel_property;
 pub mod movie_scene_float_value_property;
 pub mod movie_scene_frame_range_property;
+pub mod movie_scene_integer_channel_property;
+pub mod movie_scene_object_path_channel_property;
 pub mod movie_scene_segment_property;
 pub mod movie_scene_sequence_id_property;
 pub mod movie_scene_sequence_instance_data_ptr_property;
+pub mod movie_scene_string_channel_property;
 pub mod movie_scene_sub_sequence_tree_property;
+pub mod movie_scene_time_warp_variant_property;
 pub mod movie_scene_track_field_data_property;
 pub mod movie_scene_track_identifier_property;
 pub mod movie_scene_track_implementation_ptr_property;
@@ -68,6 +78,15 @@ impl MovieSceneTangentData {
     pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
         &self,
         asset: &mut Writer,
+    ) -> Result<(), Error> {
+        self.write_padded(asset, self.clang_win64)
+    }
+
+    /// Write `MovieSceneTangentData` to an asset, optionally padded to its in-memory size
+    pub(crate) fn write_padded<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        padded: bool,
     ) -> Result<(), Error> {
         asset.write_f32::<LE>(self.arrive_tangent.0)?;
         asset.write_f32::<LE>(self.leave_tangent.0)?;
@@ -75,9 +94,88 @@ impl MovieSceneTangentData {
         asset.write_f32::<LE>(self.leave_tangent_weight.0)?;
         asset.write_i8(self.tangent_weight_mode as i8)?;
 
-        if self.clang_win64 {
+        if padded {
             asset.write_all(&[0u8; 3])?;
         }
         Ok(())
     }
 }
+
+/// Size of serialized tangent data without padding
+const TANGENT_DATA_SIZE: i32 = 17;
+
+/// Size of the padding after serialized tangent data
+const TANGENT_DATA_PADDING: i32 = 3;
+
+/// Read a bulk serialized array of curve channel values
+///
+/// Bulk serialized values keep their in-memory layout, the serialized element size
+/// is used to tell if the values are padded.
+///
+/// # Arguments
+///
+/// * `value_size` - size of a value without tangent data and padding
+/// * `read` - reads a value, gets if tangent data is padded
+pub(crate) fn read_channel_values<Reader: ArchiveReader<impl PackageIndexTrait>, T>(
+    asset: &mut Reader,
+    value_size: i32,
+    read: impl Fn(&mut Reader, bool) -> Result<T, Error>,
+) -> Result<(i32, Vec<T>), Error> {
+    let struct_length = asset.read_i32::<LE>()?;
+    let values_length = asset.read_i32::<LE>()?;
+
+    let packed_size = value_size + TANGENT_DATA_SIZE;
+    if values_length > 0 && struct_length < packed_size {
+        return Err(Error::invalid_file(format!(
+            "Invalid channel value size {struct_length}, expected at least {packed_size}"
+        )));
+    }
+    let padded = struct_length >= packed_size + TANGENT_DATA_PADDING;
+
+    let mut values = Vec::with_capacity(values_length.max(0) as usize);
+    for _ in 0..values_length {
+        let begin = asset.position();
+        values.push(read(asset, padded)?);
+
+        let padding = begin + struct_length as u64 - asset.position();
+        asset.seek(SeekFrom::Current(padding as i64))?;
+    }
+
+    Ok((struct_length, values))
+}
+
+/// Write a bulk serialized array of curve channel values
+///
+/// # Arguments
+///
+/// * `struct_length` - serialized element size
+/// * `value_size` - size of a value without tangent data and padding
+/// * `write` - writes a value, gets if tangent data is padded
+pub(crate) fn write_channel_values<Writer: ArchiveWriter<impl PackageIndexTrait>, T>(
+    asset: &mut Writer,
+    struct_length: i32,
+    value_size: i32,
+    values: &[T],
+    write: impl Fn(&T, &mut Writer, bool) -> Result<(), Error>,
+) -> Result<(), Error> {
+    asset.write_i32::<LE>(struct_length)?;
+    asset.write_i32::<LE>(values.len() as i32)?;
+
+    let packed_size = value_size + TANGENT_DATA_SIZE;
+    if !values.is_empty() && struct_length < packed_size {
+        return Err(Error::invalid_file(format!(
+            "Invalid channel value size {struct_length}, expected at least {packed_size}"
+        )));
+    }
+    let padded = struct_length >= packed_size + TANGENT_DATA_PADDING;
+
+    for value in values {
+        let begin = asset.position();
+        write(value, asset, padded)?;
+
+        let padding = begin + struct_length as u64 - asset.position();
+        asset.write_all(&vec![0u8; padding as usize])?;
+    }
+
+    Ok(())
+}
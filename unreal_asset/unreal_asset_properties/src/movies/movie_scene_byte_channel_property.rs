@@ -0,0 +1,205 @@
+//! Movie scene byte channel property
+
+use unreal_asset_base::types::movie::FrameNumber;
+
+use crate::int_property::{BoolProperty, ByteProperty, BytePropertyValue};
+use crate::object_property::ObjectProperty;
+use crate::property_prelude::*;
+use crate::struct_property::{
+    array_field, array_values, field_ancestry, push_field, unexpected_field, write_fields,
+};
+
+use super::frame_number_property::{frame_number_values, frame_numbers_field};
+
+/// Get the value of a byte property that isn't an enum value name
+fn byte_value(property: &Property) -> Option<u8> {
+    match cast!(Property, ByteProperty, property)?.value {
+        BytePropertyValue::Byte(value) => Some(value),
+        BytePropertyValue::FName(_) => None,
+    }
+}
+
+/// Create a byte property
+fn byte_property(name: FName, ancestry: Ancestry, value: u8) -> Property {
+    ByteProperty {
+        name,
+        ancestry,
+        property_guid: None,
+        property_tag_extensions: PropertyTagExtensions::default(),
+        duplication_index: 0,
+        property_type_name: None,
+        enum_type: None,
+        value: BytePropertyValue::Byte(value),
+    }
+    .into()
+}
+
+/// Movie scene byte channel property
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MovieSceneByteChannelProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Key times
+    #[container_ignore]
+    pub times: Vec<FrameNumber>,
+    /// Default value
+    pub default_value: u8,
+    /// Has default value
+    pub has_default_value: bool,
+    /// Key values
+    pub values: Vec<u8>,
+    /// Enum the values belong to
+    #[container_ignore]
+    pub enum_type: PackageIndex,
+    /// Serialized fields, fields with default values are only written if listed here
+    pub serialized_fields: Vec<FName>,
+}
+impl_property_data_trait!(MovieSceneByteChannelProperty);
+
+impl MovieSceneByteChannelProperty {
+    /// Read a `MovieSceneByteChannelProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let mut channel = MovieSceneByteChannelProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            ..Default::default()
+        };
+
+        let fields_ancestry = field_ancestry(&channel.ancestry, "MovieSceneByteChannel");
+        for field in StructProperty::read_properties(asset, fields_ancestry)? {
+            let field_name = field.get_name();
+            match field {
+                Property::EmptyProperty(_)
+                    if field_name == "Times"
+                        || field_name == "DefaultValue"
+                        || field_name == "bHasDefaultValue"
+                        || field_name == "Values"
+                        || field_name == "Enum" => {}
+                Property::ArrayProperty(e) if field_name == "Times" => {
+                    channel.times = frame_number_values("MovieSceneByteChannel", &e)?
+                }
+                ref e if field_name == "DefaultValue" => {
+                    channel.default_value =
+                        byte_value(e).ok_or_else(|| unexpected_field("MovieSceneByteChannel", e))?
+                }
+                Property::BoolProperty(e) if field_name == "bHasDefaultValue" => {
+                    channel.has_default_value = e.value
+                }
+                Property::ArrayProperty(e) if field_name == "Values" => {
+                    channel.values = array_values("MovieSceneByteChannel", &e, byte_value)?
+                }
+                Property::ObjectProperty(e) if field_name == "Enum" => channel.enum_type = e.value,
+                e => return Err(unexpected_field("MovieSceneByteChannel", &e)),
+            }
+            channel.serialized_fields.push(field_name);
+        }
+
+        Ok(channel)
+    }
+}
+
+impl PropertyTrait for MovieSceneByteChannelProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+
+        let ancestry = field_ancestry(&self.ancestry, "MovieSceneByteChannel");
+        let serialized_fields = &self.serialized_fields;
+        let mut fields = Vec::new();
+
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "Times",
+            self.times.is_empty(),
+            |name| frame_numbers_field(asset, name, &ancestry, &self.times),
+        );
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "DefaultValue",
+            self.default_value == 0,
+            |name| byte_property(name, ancestry.clone(), self.default_value),
+        );
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "bHasDefaultValue",
+            !self.has_default_value,
+            |name| {
+                BoolProperty {
+                    name,
+                    ancestry: ancestry.clone(),
+                    property_guid: None,
+                    property_tag_extensions: PropertyTagExtensions::default(),
+                    duplication_index: 0,
+                    value: self.has_default_value,
+                }
+                .into()
+            },
+        );
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "Values",
+            self.values.is_empty(),
+            |name| {
+                array_field(
+                    asset,
+                    name,
+                    &ancestry,
+                    "ByteProperty",
+                    &self.values,
+                    |value, name, ancestry| byte_property(name, ancestry, *value),
+                )
+            },
+        );
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "Enum",
+            self.enum_type.index == 0,
+            |name| {
+                ObjectProperty {
+                    name,
+                    ancestry: ancestry.clone(),
+                    property_guid: None,
+                    property_tag_extensions: PropertyTagExtensions::default(),
+                    duplication_index: 0,
+                    property_type_name: None,
+                    value: self.enum_type,
+                }
+                .into()
+            },
+        );
+
+        write_fields(asset, fields, serialized_fields, "MovieSceneByteChannel")
+    }
+}
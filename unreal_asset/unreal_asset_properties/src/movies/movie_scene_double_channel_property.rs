@@ -0,0 +1,197 @@
+//! Movie scene double channel property
+
+use unreal_asset_base::types::movie::{FrameNumber, FrameRate};
+
+use crate::property_prelude::*;
+use crate::rich_curve_key_property::RichCurveExtrapolation;
+
+use super::movie_scene_double_value_property::MovieSceneDoubleValue;
+use super::{read_channel_values, write_channel_values};
+
+/// Size of a serialized double value without tangent data and padding
+const DOUBLE_VALUE_SIZE: i32 = (size_of::<f64>() + 2) as i32;
+
+/// Movie scene double channel
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MovieSceneDoubleChannel {
+    /// Pre infinity extrapolation
+    pub pre_infinity_extrap: RichCurveExtrapolation,
+    /// Post infinity extrapolation
+    pub post_infinity_extrap: RichCurveExtrapolation,
+
+    /// Frame times structure length
+    pub times_struct_length: i32,
+    /// Frame times
+    pub times: Vec<FrameNumber>,
+
+    /// Values structure length
+    pub values_struct_length: i32,
+    /// Values
+    pub values: Vec<MovieSceneDoubleValue>,
+
+    /// Default value
+    pub default_value: OrderedFloat<f64>,
+    /// Has default value
+    pub has_default_value: bool,
+    /// Tick resolution
+    pub tick_resolution: FrameRate,
+    /// Show curve, only serialized since `FFortniteMainBranchObjectVersion::SerializeFloatChannelShowCurve`
+    pub show_curve: bool,
+}
+
+impl MovieSceneDoubleChannel {
+    /// Read a `MovieSceneDoubleChannel` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+    ) -> Result<Self, Error> {
+        let pre_infinity_extrap: RichCurveExtrapolation =
+            RichCurveExtrapolation::try_from(asset.read_u8()?)?;
+        let post_infinity_extrap: RichCurveExtrapolation =
+            RichCurveExtrapolation::try_from(asset.read_u8()?)?;
+
+        let times_struct_length = asset.read_i32::<LE>()?;
+        let times_length = asset.read_i32::<LE>()?;
+
+        let mut times = Vec::with_capacity(times_length as usize);
+        for _ in 0..times_length {
+            times.push(FrameNumber::new(asset.read_i32::<LE>()?));
+        }
+
+        let (values_struct_length, values) =
+            read_channel_values(asset, DOUBLE_VALUE_SIZE, |asset, padded| {
+                MovieSceneDoubleValue::new(asset, padded)
+            })?;
+
+        let default_value = asset.read_f64::<LE>()?;
+        let has_default_value = asset.read_i32::<LE>()? == 1;
+
+        let tick_resolution = FrameRate::new(asset.read_i32::<LE>()?, asset.read_i32::<LE>()?);
+
+        let show_curve = match asset
+            .get_custom_version::<FFortniteMainBranchObjectVersion>()
+            .version
+            >= FFortniteMainBranchObjectVersion::SerializeFloatChannelShowCurve as i32
+        {
+            true => asset.read_i32::<LE>()? == 1,
+            false => false,
+        };
+
+        Ok(MovieSceneDoubleChannel {
+            pre_infinity_extrap,
+            post_infinity_extrap,
+            times_struct_length,
+            times,
+            values_struct_length,
+            values,
+            default_value: OrderedFloat(default_value),
+            has_default_value,
+            tick_resolution,
+            show_curve,
+        })
+    }
+
+    /// Write a `MovieSceneDoubleChannel` to an asset
+    pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+    ) -> Result<(), Error> {
+        asset.write_u8(self.pre_infinity_extrap as u8)?;
+        asset.write_u8(self.post_infinity_extrap as u8)?;
+
+        asset.write_i32::<LE>(self.times_struct_length)?;
+        asset.write_i32::<LE>(self.times.len() as i32)?;
+
+        for time in &self.times {
+            asset.write_i32::<LE>(time.value)?;
+        }
+
+        write_channel_values(
+            asset,
+            self.values_struct_length,
+            DOUBLE_VALUE_SIZE,
+            &self.values,
+            |value, asset, padded| value.write_padded(asset, padded),
+        )?;
+
+        asset.write_f64::<LE>(self.default_value.0)?;
+        asset.write_i32::<LE>(match self.has_default_value {
+            true => 1,
+            false => 0,
+        })?;
+
+        asset.write_i32::<LE>(self.tick_resolution.numerator)?;
+        asset.write_i32::<LE>(self.tick_resolution.denominator)?;
+
+        if asset
+            .get_custom_version::<FFortniteMainBranchObjectVersion>()
+            .version
+            >= FFortniteMainBranchObjectVersion::SerializeFloatChannelShowCurve as i32
+        {
+            asset.write_i32::<LE>(match self.show_curve {
+                true => 1,
+                false => 0,
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Movie scene double channel property
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MovieSceneDoubleChannelProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Value
+    #[container_ignore]
+    pub value: MovieSceneDoubleChannel,
+}
+impl_property_data_trait!(MovieSceneDoubleChannelProperty);
+
+impl MovieSceneDoubleChannelProperty {
+    /// Read a `MovieSceneDoubleChannelProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let value = MovieSceneDoubleChannel::new(asset)?;
+
+        Ok(MovieSceneDoubleChannelProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            value,
+        })
+    }
+}
+
+impl PropertyTrait for MovieSceneDoubleChannelProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+
+        let begin = asset.position();
+
+        self.value.write(asset)?;
+
+        Ok((asset.position() - begin) as usize)
+    }
+}
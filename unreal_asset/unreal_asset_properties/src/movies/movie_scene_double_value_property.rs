@@ -0,0 +1,119 @@
+//! Movie scene double value property
+
+use crate::property_prelude::*;
+use crate::rich_curve_key_property::{RichCurveInterpMode, RichCurveTangentMode};
+
+use super::MovieSceneTangentData;
+
+/// Movie scene double value
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MovieSceneDoubleValue {
+    /// Value
+    pub value: OrderedFloat<f64>,
+    /// Tangent
+    pub tangent: MovieSceneTangentData,
+    /// Interpolation mode
+    pub interp_mode: RichCurveInterpMode,
+    /// Tangent mode
+    pub tangent_mode: RichCurveTangentMode,
+}
+
+impl MovieSceneDoubleValue {
+    /// Read a `MovieSceneDoubleValue` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        clang_win64: bool,
+    ) -> Result<Self, Error> {
+        let value = asset.read_f64::<LE>()?;
+        let tangent = MovieSceneTangentData::new(asset, clang_win64)?;
+        let interp_mode: RichCurveInterpMode = RichCurveInterpMode::try_from(asset.read_i8()?)?;
+        let tangent_mode: RichCurveTangentMode = RichCurveTangentMode::try_from(asset.read_i8()?)?;
+
+        Ok(MovieSceneDoubleValue {
+            value: OrderedFloat(value),
+            tangent,
+            interp_mode,
+            tangent_mode,
+        })
+    }
+
+    /// Write a `MovieSceneDoubleValue` to an asset
+    pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+    ) -> Result<(), Error> {
+        self.write_padded(asset, self.tangent.clang_win64)
+    }
+
+    /// Write a `MovieSceneDoubleValue` to an asset, optionally padding tangent data to its in-memory size
+    pub(crate) fn write_padded<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        padded: bool,
+    ) -> Result<(), Error> {
+        asset.write_f64::<LE>(self.value.0)?;
+        self.tangent.write_padded(asset, padded)?;
+        asset.write_i8(self.interp_mode as i8)?;
+        asset.write_i8(self.tangent_mode as i8)?;
+        Ok(())
+    }
+}
+
+/// Movie scene double value property
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MovieSceneDoubleValueProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Value
+    #[container_ignore]
+    pub value: MovieSceneDoubleValue,
+}
+impl_property_data_trait!(MovieSceneDoubleValueProperty);
+
+impl MovieSceneDoubleValueProperty {
+    /// Read a `MovieSceneDoubleValueProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let value = MovieSceneDoubleValue::new(asset, false)?;
+
+        Ok(MovieSceneDoubleValueProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            value,
+        })
+    }
+}
+
+impl PropertyTrait for MovieSceneDoubleValueProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+
+        let begin = asset.position();
+
+        self.value.write(asset)?;
+
+        Ok((asset.position() - begin) as usize)
+    }
+}
@@ -13,6 +13,8 @@ pub struct MovieSceneEvaluationFieldEntityTreeProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Value
@@ -30,7 +32,7 @@ impl MovieSceneEvaluationFieldEntityTreeProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let value = MovieSceneEvaluationFieldEntityTree::new(asset)?;
 
@@ -38,6 +40,7 @@ impl MovieSceneEvaluationFieldEntityTreeProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
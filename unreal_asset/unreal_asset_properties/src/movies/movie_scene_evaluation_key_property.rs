@@ -54,6 +54,8 @@ pub struct MovieSceneEvaluationKeyProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Value
@@ -71,7 +73,7 @@ impl MovieSceneEvaluationKeyProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let value = MovieSceneEvaluationKey::new(asset)?;
 
@@ -79,6 +81,7 @@ impl MovieSceneEvaluationKeyProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
@@ -0,0 +1,118 @@
+//! Movie scene event channel property
+
+use unreal_asset_base::types::movie::FrameNumber;
+
+use crate::property_prelude::*;
+use crate::struct_property::{
+    field_ancestry, push_field, tagged_struct_array_field, tagged_struct_array_values,
+    unexpected_field, write_fields,
+};
+
+use super::frame_number_property::{frame_number_values, frame_numbers_field};
+
+/// Movie scene event channel property
+///
+/// Key values are `MovieSceneEvent` structs
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MovieSceneEventChannelProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Key times
+    #[container_ignore]
+    pub key_times: Vec<FrameNumber>,
+    /// Key values
+    pub key_values: Vec<StructProperty>,
+    /// Serialized fields, fields with default values are only written if listed here
+    pub serialized_fields: Vec<FName>,
+}
+impl_property_data_trait!(MovieSceneEventChannelProperty);
+
+impl MovieSceneEventChannelProperty {
+    /// Read a `MovieSceneEventChannelProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let mut channel = MovieSceneEventChannelProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            ..Default::default()
+        };
+
+        let fields_ancestry = field_ancestry(&channel.ancestry, "MovieSceneEventChannel");
+        for field in StructProperty::read_properties(asset, fields_ancestry)? {
+            let field_name = field.get_name();
+            match field {
+                Property::EmptyProperty(_)
+                    if field_name == "KeyTimes" || field_name == "KeyValues" => {}
+                Property::ArrayProperty(e) if field_name == "KeyTimes" => {
+                    channel.key_times = frame_number_values("MovieSceneEventChannel", &e)?
+                }
+                Property::ArrayProperty(e) if field_name == "KeyValues" => {
+                    channel.key_values = tagged_struct_array_values("MovieSceneEventChannel", &e)?
+                }
+                e => return Err(unexpected_field("MovieSceneEventChannel", &e)),
+            }
+            channel.serialized_fields.push(field_name);
+        }
+
+        Ok(channel)
+    }
+}
+
+impl PropertyTrait for MovieSceneEventChannelProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+
+        let ancestry = field_ancestry(&self.ancestry, "MovieSceneEventChannel");
+        let serialized_fields = &self.serialized_fields;
+        let mut fields = Vec::new();
+
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "KeyTimes",
+            self.key_times.is_empty(),
+            |name| frame_numbers_field(asset, name, &ancestry, &self.key_times),
+        );
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "KeyValues",
+            self.key_values.is_empty(),
+            |name| {
+                tagged_struct_array_field(
+                    asset,
+                    name,
+                    &ancestry,
+                    "MovieSceneEvent",
+                    &self.key_values,
+                )
+            },
+        );
+
+        write_fields(asset, fields, serialized_fields, "MovieSceneEventChannel")
+    }
+}
@@ -6,6 +6,10 @@ use crate::property_prelude::*;
 use crate::rich_curve_key_property::RichCurveExtrapolation;
 
 use super::movie_scene_float_value_property::MovieSceneFloatValue;
+use super::{read_channel_values, write_channel_values};
+
+/// Size of a serialized float value without tangent data and padding
+const FLOAT_VALUE_SIZE: i32 = (size_of::<f32>() + 2) as i32;
 
 /// Movie scene float channel
 #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
@@ -31,6 +35,8 @@ pub struct MovieSceneFloatChannel {
     pub has_default_value: bool,
     /// Tick resolution
     pub tick_resolution: FrameRate,
+    /// Show curve, only serialized since `FFortniteMainBranchObjectVersion::SerializeFloatChannelShowCurve`
+    pub show_curve: bool,
 }
 
 impl MovieSceneFloatChannel {
@@ -51,20 +57,25 @@ impl MovieSceneFloatChannel {
             times.push(FrameNumber::new(asset.read_i32::<LE>()?));
         }
 
-        let values_struct_length = asset.read_i32::<LE>()?;
-        let values_length = asset.read_i32::<LE>()?;
-
-        let mut values = Vec::with_capacity(values_length as usize);
-        for _ in 0..values_length {
-            //todo: clangwin64 is always false?
-            values.push(MovieSceneFloatValue::new(asset, false)?);
-        }
+        let (values_struct_length, values) =
+            read_channel_values(asset, FLOAT_VALUE_SIZE, |asset, padded| {
+                MovieSceneFloatValue::new(asset, padded)
+            })?;
 
         let default_value = asset.read_f32::<LE>()?;
         let has_default_value = asset.read_i32::<LE>()? == 1;
 
         let tick_resolution = FrameRate::new(asset.read_i32::<LE>()?, asset.read_i32::<LE>()?);
 
+        let show_curve = match asset
+            .get_custom_version::<FFortniteMainBranchObjectVersion>()
+            .version
+            >= FFortniteMainBranchObjectVersion::SerializeFloatChannelShowCurve as i32
+        {
+            true => asset.read_i32::<LE>()? == 1,
+            false => false,
+        };
+
         Ok(MovieSceneFloatChannel {
             pre_infinity_extrap,
             post_infinity_extrap,
@@ -75,6 +86,7 @@ impl MovieSceneFloatChannel {
             default_value: OrderedFloat(default_value),
             has_default_value,
             tick_resolution,
+            show_curve,
         })
     }
 
@@ -93,12 +105,13 @@ impl MovieSceneFloatChannel {
             asset.write_i32::<LE>(time.value)?;
         }
 
-        asset.write_i32::<LE>(self.values_struct_length)?;
-        asset.write_i32::<LE>(self.values.len() as i32)?;
-
-        for value in &self.values {
-            value.write(asset)?;
-        }
+        write_channel_values(
+            asset,
+            self.values_struct_length,
+            FLOAT_VALUE_SIZE,
+            &self.values,
+            |value, asset, padded| value.write_padded(asset, padded),
+        )?;
 
         asset.write_f32::<LE>(self.default_value.0)?;
         asset.write_i32::<LE>(match self.has_default_value {
@@ -109,6 +122,17 @@ impl MovieSceneFloatChannel {
         asset.write_i32::<LE>(self.tick_resolution.numerator)?;
         asset.write_i32::<LE>(self.tick_resolution.denominator)?;
 
+        if asset
+            .get_custom_version::<FFortniteMainBranchObjectVersion>()
+            .version
+            >= FFortniteMainBranchObjectVersion::SerializeFloatChannelShowCurve as i32
+        {
+            asset.write_i32::<LE>(match self.show_curve {
+                true => 1,
+                false => 0,
+            })?;
+        }
+
         Ok(())
     }
 }
@@ -122,6 +146,8 @@ pub struct MovieSceneFloatChannelProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Value
@@ -139,7 +165,7 @@ impl MovieSceneFloatChannelProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let value = MovieSceneFloatChannel::new(asset)?;
 
@@ -147,6 +173,7 @@ impl MovieSceneFloatChannelProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
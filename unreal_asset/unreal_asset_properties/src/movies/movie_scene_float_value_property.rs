@@ -41,9 +41,18 @@ impl MovieSceneFloatValue {
     pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
         &self,
         asset: &mut Writer,
+    ) -> Result<(), Error> {
+        self.write_padded(asset, self.tangent.clang_win64)
+    }
+
+    /// Write a `MovieSceneFloatValue` to an asset, optionally padding tangent data to its in-memory size
+    pub(crate) fn write_padded<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        padded: bool,
     ) -> Result<(), Error> {
         asset.write_f32::<LE>(self.value.0)?;
-        self.tangent.write(asset)?;
+        self.tangent.write_padded(asset, padded)?;
         asset.write_i8(self.interp_mode as i8)?;
         asset.write_i8(self.tangent_mode as i8)?;
         Ok(())
@@ -59,6 +68,8 @@ pub struct MovieSceneFloatValueProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Value
@@ -76,7 +87,7 @@ impl MovieSceneFloatValueProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         // todo: clangwin64 is always false?
         let value = MovieSceneFloatValue::new(asset, false)?;
@@ -85,6 +96,7 @@ impl MovieSceneFloatValueProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
@@ -45,6 +45,8 @@ pub struct MovieSceneFrameRangeProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Lower bound
@@ -65,7 +67,7 @@ impl MovieSceneFrameRangeProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let lower_bound = Int32RangeBound::new(asset)?;
         let upper_bound = Int32RangeBound::new(asset)?;
@@ -74,6 +76,7 @@ impl MovieSceneFrameRangeProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             lower_bound,
             upper_bound,
@@ -0,0 +1,187 @@
+//! Movie scene object path channel property
+
+use unreal_asset_base::types::movie::FrameNumber;
+
+use crate::object_property::ObjectProperty;
+use crate::property_prelude::*;
+use crate::struct_property::{
+    field_ancestry, push_field, tagged_struct_array_field, tagged_struct_array_values,
+    unexpected_field, write_fields,
+};
+
+use super::frame_number_property::{frame_number_values, frame_numbers_field};
+
+/// Movie scene object path channel property
+///
+/// Key values are `MovieSceneObjectPathChannelKeyValue` structs with `SoftPtr` and `HardPtr` fields
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MovieSceneObjectPathChannelProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Key times
+    #[container_ignore]
+    pub times: Vec<FrameNumber>,
+    /// Key values
+    pub values: Vec<StructProperty>,
+    /// Default value
+    pub default_value: Option<StructProperty>,
+    /// Class the objects must be of
+    #[container_ignore]
+    pub property_class: PackageIndex,
+    /// Serialized fields, fields with default values are only written if listed here
+    pub serialized_fields: Vec<FName>,
+}
+impl_property_data_trait!(MovieSceneObjectPathChannelProperty);
+
+impl MovieSceneObjectPathChannelProperty {
+    /// Read a `MovieSceneObjectPathChannelProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let mut channel = MovieSceneObjectPathChannelProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            ..Default::default()
+        };
+
+        let fields_ancestry = field_ancestry(&channel.ancestry, "MovieSceneObjectPathChannel");
+        for field in StructProperty::read_properties(asset, fields_ancestry)? {
+            let field_name = field.get_name();
+            match field {
+                Property::EmptyProperty(_)
+                    if field_name == "Times"
+                        || field_name == "Values"
+                        || field_name == "DefaultValue"
+                        || field_name == "PropertyClass" => {}
+                Property::ArrayProperty(e) if field_name == "Times" => {
+                    channel.times = frame_number_values("MovieSceneObjectPathChannel", &e)?
+                }
+                Property::ArrayProperty(e) if field_name == "Values" => {
+                    channel.values = tagged_struct_array_values("MovieSceneObjectPathChannel", &e)?
+                }
+                Property::StructProperty(e) if field_name == "DefaultValue" => {
+                    channel.default_value = Some(e)
+                }
+                Property::ObjectProperty(e) if field_name == "PropertyClass" => {
+                    channel.property_class = e.value
+                }
+                e => return Err(unexpected_field("MovieSceneObjectPathChannel", &e)),
+            }
+            channel.serialized_fields.push(field_name);
+        }
+
+        Ok(channel)
+    }
+}
+
+impl PropertyTrait for MovieSceneObjectPathChannelProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+
+        let ancestry = field_ancestry(&self.ancestry, "MovieSceneObjectPathChannel");
+        let serialized_fields = &self.serialized_fields;
+        let mut fields = Vec::new();
+
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "Times",
+            self.times.is_empty(),
+            |name| frame_numbers_field(asset, name, &ancestry, &self.times),
+        );
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "Values",
+            self.values.is_empty(),
+            |name| {
+                tagged_struct_array_field(
+                    asset,
+                    name,
+                    &ancestry,
+                    "MovieSceneObjectPathChannelKeyValue",
+                    &self.values,
+                )
+            },
+        );
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "DefaultValue",
+            self.default_value.is_none(),
+            |name| {
+                let default_value = match self.default_value {
+                    Some(ref e) => e.clone(),
+                    None => {
+                        let struct_type = asset
+                            .get_name_map()
+                            .get_mut()
+                            .add_fname("MovieSceneObjectPathChannelKeyValue");
+                        StructProperty::dummy(
+                            FName::default(),
+                            Ancestry::default(),
+                            struct_type,
+                            Some(Guid::default()),
+                        )
+                    }
+                };
+                StructProperty {
+                    name,
+                    ancestry: ancestry.clone(),
+                    ..default_value
+                }
+                .into()
+            },
+        );
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "PropertyClass",
+            self.property_class.index == 0,
+            |name| {
+                ObjectProperty {
+                    name,
+                    ancestry: ancestry.clone(),
+                    property_guid: None,
+                    property_tag_extensions: PropertyTagExtensions::default(),
+                    duplication_index: 0,
+                    property_type_name: None,
+                    value: self.property_class,
+                }
+                .into()
+            },
+        );
+
+        write_fields(
+            asset,
+            fields,
+            serialized_fields,
+            "MovieSceneObjectPathChannel",
+        )
+    }
+}
@@ -132,6 +132,8 @@ pub struct MovieSceneSegmentProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Value
@@ -148,7 +150,7 @@ impl MovieSceneSegmentProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let value =
             MovieSceneSegment::new(asset, name.clone(), ancestry.with_parent(name.clone()))?;
@@ -157,6 +159,7 @@ impl MovieSceneSegmentProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
@@ -188,6 +191,8 @@ pub struct MovieSceneSegmentIdentifierProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Value
@@ -205,7 +210,7 @@ impl MovieSceneSegmentIdentifierProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let value = MovieSceneSegmentIdentifier::new(asset)?;
 
@@ -213,6 +218,7 @@ impl MovieSceneSegmentIdentifierProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
@@ -11,6 +11,8 @@ pub struct MovieSceneSequenceInstanceDataPtrProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Value
@@ -28,7 +30,7 @@ impl MovieSceneSequenceInstanceDataPtrProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let value = PackageIndex::new(asset.read_i32::<LE>()?);
 
@@ -36,6 +38,7 @@ impl MovieSceneSequenceInstanceDataPtrProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
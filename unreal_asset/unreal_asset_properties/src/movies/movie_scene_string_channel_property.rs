@@ -0,0 +1,171 @@
+//! Movie scene string channel property
+
+use unreal_asset_base::types::movie::FrameNumber;
+
+use crate::int_property::BoolProperty;
+use crate::property_prelude::*;
+use crate::struct_property::{
+    array_field, array_values, field_ancestry, push_field, unexpected_field, write_fields,
+};
+
+use super::frame_number_property::{frame_number_values, frame_numbers_field};
+
+/// Movie scene string channel property
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MovieSceneStringChannelProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Key times
+    #[container_ignore]
+    pub times: Vec<FrameNumber>,
+    /// Key values
+    pub values: Vec<Option<String>>,
+    /// Default value
+    pub default_value: Option<String>,
+    /// Has default value
+    pub has_default_value: bool,
+    /// Serialized fields, fields with default values are only written if listed here
+    pub serialized_fields: Vec<FName>,
+}
+impl_property_data_trait!(MovieSceneStringChannelProperty);
+
+impl MovieSceneStringChannelProperty {
+    /// Read a `MovieSceneStringChannelProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let mut channel = MovieSceneStringChannelProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            ..Default::default()
+        };
+
+        let fields_ancestry = field_ancestry(&channel.ancestry, "MovieSceneStringChannel");
+        for field in StructProperty::read_properties(asset, fields_ancestry)? {
+            let field_name = field.get_name();
+            match field {
+                Property::EmptyProperty(_)
+                    if field_name == "Times"
+                        || field_name == "Values"
+                        || field_name == "DefaultValue"
+                        || field_name == "bHasDefaultValue" => {}
+                Property::ArrayProperty(e) if field_name == "Times" => {
+                    channel.times = frame_number_values("MovieSceneStringChannel", &e)?
+                }
+                Property::ArrayProperty(e) if field_name == "Values" => {
+                    channel.values = array_values("MovieSceneStringChannel", &e, |e| {
+                        cast!(Property, StrProperty, e).map(|e| e.value.clone())
+                    })?
+                }
+                Property::StrProperty(e) if field_name == "DefaultValue" => {
+                    channel.default_value = e.value
+                }
+                Property::BoolProperty(e) if field_name == "bHasDefaultValue" => {
+                    channel.has_default_value = e.value
+                }
+                e => return Err(unexpected_field("MovieSceneStringChannel", &e)),
+            }
+            channel.serialized_fields.push(field_name);
+        }
+
+        Ok(channel)
+    }
+}
+
+impl PropertyTrait for MovieSceneStringChannelProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+
+        let ancestry = field_ancestry(&self.ancestry, "MovieSceneStringChannel");
+        let serialized_fields = &self.serialized_fields;
+        let mut fields = Vec::new();
+
+        let str_field = |name, ancestry, value| {
+            StrProperty {
+                name,
+                ancestry,
+                property_guid: None,
+                property_tag_extensions: PropertyTagExtensions::default(),
+                duplication_index: 0,
+                value,
+                encoding: Default::default(),
+            }
+            .into()
+        };
+
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "Times",
+            self.times.is_empty(),
+            |name| frame_numbers_field(asset, name, &ancestry, &self.times),
+        );
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "Values",
+            self.values.is_empty(),
+            |name| {
+                array_field(
+                    asset,
+                    name,
+                    &ancestry,
+                    "StrProperty",
+                    &self.values,
+                    |value, name, ancestry| str_field(name, ancestry, value.clone()),
+                )
+            },
+        );
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "DefaultValue",
+            self.default_value.is_none(),
+            |name| str_field(name, ancestry.clone(), self.default_value.clone()),
+        );
+        push_field(
+            asset,
+            &mut fields,
+            serialized_fields,
+            "bHasDefaultValue",
+            !self.has_default_value,
+            |name| {
+                BoolProperty {
+                    name,
+                    ancestry: ancestry.clone(),
+                    property_guid: None,
+                    property_tag_extensions: PropertyTagExtensions::default(),
+                    duplication_index: 0,
+                    value: self.has_default_value,
+                }
+                .into()
+            },
+        );
+
+        write_fields(asset, fields, serialized_fields, "MovieSceneStringChannel")
+    }
+}
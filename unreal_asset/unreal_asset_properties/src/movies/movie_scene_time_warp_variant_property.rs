@@ -0,0 +1,171 @@
+//! Movie scene time warp variant property
+
+use unreal_asset_base::types::movie::{FrameNumber, FrameRate};
+
+use crate::property_prelude::*;
+
+use super::enums::EMovieSceneTimeWarpType;
+
+/// Movie scene time warp variant
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MovieSceneTimeWarpVariant {
+    /// Fixed play rate
+    FixedPlayRate(OrderedFloat<f64>),
+    /// Custom time warp getter object
+    Custom(PackageIndex),
+    /// Fixed time
+    FixedTime(FrameNumber),
+    /// Frame rate
+    FrameRate(FrameRate),
+    /// Loop with a duration
+    Loop(FrameNumber),
+    /// Clamp to a maximum time
+    Clamp(FrameNumber),
+    /// Loop with a fractional duration in frames
+    LoopFloat(OrderedFloat<f32>),
+    /// Clamp to a fractional maximum time in frames
+    ClampFloat(OrderedFloat<f32>),
+}
+
+impl Default for MovieSceneTimeWarpVariant {
+    fn default() -> Self {
+        MovieSceneTimeWarpVariant::FixedPlayRate(OrderedFloat(1.0))
+    }
+}
+
+impl MovieSceneTimeWarpVariant {
+    /// Read a `MovieSceneTimeWarpVariant` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+    ) -> Result<Self, Error> {
+        let ty = EMovieSceneTimeWarpType::try_from(asset.read_u8()?)?;
+
+        Ok(match ty {
+            EMovieSceneTimeWarpType::FixedPlayRate => {
+                MovieSceneTimeWarpVariant::FixedPlayRate(OrderedFloat(asset.read_f64::<LE>()?))
+            }
+            EMovieSceneTimeWarpType::Custom => {
+                MovieSceneTimeWarpVariant::Custom(PackageIndex::new(asset.read_i32::<LE>()?))
+            }
+            EMovieSceneTimeWarpType::FixedTime => {
+                MovieSceneTimeWarpVariant::FixedTime(FrameNumber::new(asset.read_i32::<LE>()?))
+            }
+            EMovieSceneTimeWarpType::FrameRate => MovieSceneTimeWarpVariant::FrameRate(
+                FrameRate::new(asset.read_i32::<LE>()?, asset.read_i32::<LE>()?),
+            ),
+            EMovieSceneTimeWarpType::Loop => {
+                MovieSceneTimeWarpVariant::Loop(FrameNumber::new(asset.read_i32::<LE>()?))
+            }
+            EMovieSceneTimeWarpType::Clamp => {
+                MovieSceneTimeWarpVariant::Clamp(FrameNumber::new(asset.read_i32::<LE>()?))
+            }
+            EMovieSceneTimeWarpType::LoopFloat => {
+                MovieSceneTimeWarpVariant::LoopFloat(OrderedFloat(asset.read_f32::<LE>()?))
+            }
+            EMovieSceneTimeWarpType::ClampFloat => {
+                MovieSceneTimeWarpVariant::ClampFloat(OrderedFloat(asset.read_f32::<LE>()?))
+            }
+        })
+    }
+
+    /// Get the time warp type
+    pub fn get_type(&self) -> EMovieSceneTimeWarpType {
+        match self {
+            MovieSceneTimeWarpVariant::FixedPlayRate(_) => EMovieSceneTimeWarpType::FixedPlayRate,
+            MovieSceneTimeWarpVariant::Custom(_) => EMovieSceneTimeWarpType::Custom,
+            MovieSceneTimeWarpVariant::FixedTime(_) => EMovieSceneTimeWarpType::FixedTime,
+            MovieSceneTimeWarpVariant::FrameRate(_) => EMovieSceneTimeWarpType::FrameRate,
+            MovieSceneTimeWarpVariant::Loop(_) => EMovieSceneTimeWarpType::Loop,
+            MovieSceneTimeWarpVariant::Clamp(_) => EMovieSceneTimeWarpType::Clamp,
+            MovieSceneTimeWarpVariant::LoopFloat(_) => EMovieSceneTimeWarpType::LoopFloat,
+            MovieSceneTimeWarpVariant::ClampFloat(_) => EMovieSceneTimeWarpType::ClampFloat,
+        }
+    }
+
+    /// Write a `MovieSceneTimeWarpVariant` to an asset
+    pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+    ) -> Result<(), Error> {
+        asset.write_u8(self.get_type().into())?;
+
+        match self {
+            MovieSceneTimeWarpVariant::FixedPlayRate(play_rate) => {
+                asset.write_f64::<LE>(play_rate.0)?
+            }
+            MovieSceneTimeWarpVariant::Custom(custom) => asset.write_i32::<LE>(custom.index)?,
+            MovieSceneTimeWarpVariant::FixedTime(frame_number)
+            | MovieSceneTimeWarpVariant::Loop(frame_number)
+            | MovieSceneTimeWarpVariant::Clamp(frame_number) => {
+                asset.write_i32::<LE>(frame_number.value)?
+            }
+            MovieSceneTimeWarpVariant::FrameRate(frame_rate) => {
+                asset.write_i32::<LE>(frame_rate.numerator)?;
+                asset.write_i32::<LE>(frame_rate.denominator)?;
+            }
+            MovieSceneTimeWarpVariant::LoopFloat(frames)
+            | MovieSceneTimeWarpVariant::ClampFloat(frames) => asset.write_f32::<LE>(frames.0)?,
+        };
+
+        Ok(())
+    }
+}
+
+/// Movie scene time warp variant property
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct MovieSceneTimeWarpVariantProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Value
+    #[container_ignore]
+    pub value: MovieSceneTimeWarpVariant,
+}
+impl_property_data_trait!(MovieSceneTimeWarpVariantProperty);
+
+impl MovieSceneTimeWarpVariantProperty {
+    /// Read a `MovieSceneTimeWarpVariantProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+
+        let value = MovieSceneTimeWarpVariant::new(asset)?;
+
+        Ok(MovieSceneTimeWarpVariantProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            value,
+        })
+    }
+}
+
+impl PropertyTrait for MovieSceneTimeWarpVariantProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+
+        let begin = asset.position();
+
+        self.value.write(asset)?;
+
+        Ok((asset.position() - begin) as usize)
+    }
+}
@@ -38,6 +38,8 @@ pub struct MovieSceneTrackIdentifierProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Identifier
@@ -55,7 +57,7 @@ impl MovieSceneTrackIdentifierProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let value = MovieSceneTrackIdentifier::new(asset)?;
 
@@ -63,6 +65,7 @@ impl MovieSceneTrackIdentifierProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
@@ -11,6 +11,8 @@ pub struct MovieSceneTrackImplementationPtrProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Properties
@@ -27,7 +29,7 @@ impl MovieSceneTrackImplementationPtrProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let mut value: Vec<Property> = Vec::new();
 
@@ -58,6 +60,7 @@ impl MovieSceneTrackImplementationPtrProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
@@ -79,6 +79,8 @@ pub struct SectionEvaluationDataTreeProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Evaluation tree
@@ -95,7 +97,7 @@ impl SectionEvaluationDataTreeProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let value = SectionEvaluationTree::new(asset)?;
 
@@ -103,6 +105,7 @@ impl SectionEvaluationDataTreeProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
@@ -46,7 +46,9 @@ impl NiagaraVariableProperty {
                 struct_type: None,
                 struct_guid: None,
                 property_guid: None,
+                property_tag_extensions: PropertyTagExtensions::default(),
                 duplication_index,
+                property_type_name: None,
                 serialize_none: false,
                 value: properties,
             },
@@ -73,6 +75,19 @@ impl PropertyDataTrait for NiagaraVariableProperty {
         self.struct_property.get_property_guid()
     }
 
+    fn set_property_guid(&mut self, property_guid: Option<Guid>) {
+        self.struct_property.set_property_guid(property_guid)
+    }
+
+    fn get_property_tag_extensions(&self) -> PropertyTagExtensions {
+        self.struct_property.get_property_tag_extensions()
+    }
+
+    fn set_property_tag_extensions(&mut self, property_tag_extensions: PropertyTagExtensions) {
+        self.struct_property
+            .set_property_tag_extensions(property_tag_extensions)
+    }
+
     fn get_ancestry(&self) -> &Ancestry {
         self.struct_property.get_ancestry()
     }
@@ -168,6 +183,19 @@ impl PropertyDataTrait for NiagaraVariableWithOffsetProperty {
         self.niagara_variable.get_property_guid()
     }
 
+    fn set_property_guid(&mut self, property_guid: Option<Guid>) {
+        self.niagara_variable.set_property_guid(property_guid)
+    }
+
+    fn get_property_tag_extensions(&self) -> PropertyTagExtensions {
+        self.niagara_variable.get_property_tag_extensions()
+    }
+
+    fn set_property_tag_extensions(&mut self, property_tag_extensions: PropertyTagExtensions) {
+        self.niagara_variable
+            .set_property_tag_extensions(property_tag_extensions)
+    }
+
     fn get_ancestry(&self) -> &Ancestry {
         self.niagara_variable.get_ancestry()
     }
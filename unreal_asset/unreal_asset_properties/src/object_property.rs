@@ -11,14 +11,80 @@ pub struct ObjectProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
+    /// Complete property type name, only used in >= UE5.4 property tags
+    pub property_type_name: Option<PropertyTypeName>,
     /// Value
     #[container_ignore]
     pub value: PackageIndex,
 }
 impl_property_data_trait!(ObjectProperty);
 
+/// Weak object property
+#[derive(FNameContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
+pub struct WeakObjectProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Complete property type name, only used in >= UE5.4 property tags
+    pub property_type_name: Option<PropertyTypeName>,
+    /// Value
+    #[container_ignore]
+    pub value: PackageIndex,
+}
+impl_property_data_trait!(WeakObjectProperty);
+
+/// Lazy object property
+#[derive(FNameContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
+pub struct LazyObjectProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Complete property type name, only used in >= UE5.4 property tags
+    pub property_type_name: Option<PropertyTypeName>,
+    /// Unique object guid
+    pub value: Guid,
+}
+impl_property_data_trait!(LazyObjectProperty);
+
+/// Interface property
+#[derive(FNameContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
+pub struct InterfaceProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Complete property type name, only used in >= UE5.4 property tags
+    pub property_type_name: Option<PropertyTypeName>,
+    /// Value
+    #[container_ignore]
+    pub value: PackageIndex,
+}
+impl_property_data_trait!(InterfaceProperty);
+
 /// Asset object property
 #[derive(FNameContainer, Debug, Clone, Default, Hash, PartialEq, Eq)]
 pub struct AssetObjectProperty {
@@ -28,6 +94,8 @@ pub struct AssetObjectProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Value
@@ -138,8 +206,12 @@ pub struct SoftObjectProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
+    /// Complete property type name, only used in >= UE5.4 property tags
+    pub property_type_name: Option<PropertyTypeName>,
     /// Soft object path value
     pub value: SoftObjectPath,
 }
@@ -154,13 +226,15 @@ impl ObjectProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         let value = asset.read_i32::<LE>()?;
         Ok(ObjectProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
+            property_type_name: None,
             value: PackageIndex::new(value),
         })
     }
@@ -178,6 +252,111 @@ impl PropertyTrait for ObjectProperty {
     }
 }
 
+impl WeakObjectProperty {
+    /// Read a `WeakObjectProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+        let value = asset.read_i32::<LE>()?;
+        Ok(WeakObjectProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            property_type_name: None,
+            value: PackageIndex::new(value),
+        })
+    }
+}
+
+impl PropertyTrait for WeakObjectProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+        asset.write_i32::<LE>(self.value.index)?;
+        Ok(size_of::<i32>())
+    }
+}
+
+impl LazyObjectProperty {
+    /// Read a `LazyObjectProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+        let value = asset.read_guid()?;
+        Ok(LazyObjectProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            property_type_name: None,
+            value,
+        })
+    }
+}
+
+impl PropertyTrait for LazyObjectProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+        asset.write_guid(&self.value)?;
+        Ok(16)
+    }
+}
+
+impl InterfaceProperty {
+    /// Read an `InterfaceProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+        let value = asset.read_i32::<LE>()?;
+        Ok(InterfaceProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions,
+            duplication_index,
+            property_type_name: None,
+            value: PackageIndex::new(value),
+        })
+    }
+}
+
+impl PropertyTrait for InterfaceProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        optional_guid_write!(self, asset, include_header);
+        asset.write_i32::<LE>(self.value.index)?;
+        Ok(size_of::<i32>())
+    }
+}
+
 impl AssetObjectProperty {
     /// Read an `AssetObjectProperty` from an asset
     pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
@@ -187,12 +366,13 @@ impl AssetObjectProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         let value = asset.read_fstring()?;
         Ok(AssetObjectProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
@@ -219,14 +399,16 @@ impl SoftObjectProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         let value = SoftObjectPath::new(asset)?;
 
         Ok(SoftObjectProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
+            property_type_name: None,
             value,
         })
     }
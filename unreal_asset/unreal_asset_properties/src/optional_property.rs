@@ -0,0 +1,182 @@
+//! Optional property
+
+use crate::property_prelude::*;
+
+/// Optional property, a `TOptional` that may hold an inner property
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct OptionalProperty {
+    /// Name
+    pub name: FName,
+    /// Property ancestry
+    pub ancestry: Ancestry,
+    /// Property guid
+    pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
+    /// Property duplication index
+    pub duplication_index: i32,
+    /// Complete property type name, only used in >= UE5.4 property tags
+    pub property_type_name: Option<PropertyTypeName>,
+    /// Inner type
+    pub inner_type: Option<FName>,
+    /// Inner value, `None` if the optional is unset
+    pub value: Option<Box<Property>>,
+}
+impl_property_data_trait!(OptionalProperty);
+
+impl OptionalProperty {
+    /// Read an `OptionalProperty` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        include_header: bool,
+        length: i64,
+        duplication_index: i32,
+    ) -> Result<Self, Error> {
+        let (inner_type, property_guid, property_tag_extensions) = match include_header {
+            true => (
+                Some(asset.read_fname()?),
+                asset.read_property_guid()?,
+                asset.read_property_tag_extensions()?,
+            ),
+            false => (None, None, PropertyTagExtensions::default()),
+        };
+
+        Ok(OptionalProperty {
+            property_tag_extensions,
+            ..OptionalProperty::new_no_header(
+                asset,
+                name,
+                ancestry,
+                length,
+                duplication_index,
+                inner_type,
+                None,
+                property_guid,
+            )?
+        })
+    }
+
+    /// Read an `OptionalProperty` from an asset without reading the property header
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_no_header<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        length: i64,
+        duplication_index: i32,
+        mut inner_type: Option<FName>,
+        mut inner_struct_type: Option<FName>,
+        property_guid: Option<Guid>,
+    ) -> Result<Self, Error> {
+        if inner_type.is_none() {
+            if let Some(optional_data) = asset
+                .get_mappings()
+                .and_then(|e| e.get_property(&name, &ancestry))
+                .and_then(|e| {
+                    cast!(
+                        UsmapPropertyData,
+                        UsmapOptionalPropertyData,
+                        &e.property_data
+                    )
+                })
+            {
+                inner_type = Some(FName::new_dummy(
+                    optional_data.inner_type.get_property_type().to_string(),
+                    0,
+                ));
+                if let Some(inner_struct_data) = cast!(
+                    UsmapPropertyData,
+                    UsmapStructPropertyData,
+                    optional_data.inner_type.as_ref()
+                ) {
+                    inner_struct_type =
+                        Some(FName::new_dummy(inner_struct_data.struct_type.clone(), 0));
+                }
+            }
+        }
+
+        if asset.has_unversioned_properties() && inner_type.is_none() {
+            return name.get_content(|name| Err(PropertyError::no_type(name, &ancestry).into()));
+        }
+
+        // the set flag is serialized as a 4 byte boolean
+        let is_set = asset.read_i32::<LE>()? != 0;
+        let value = match is_set {
+            true => {
+                let inner_type = inner_type
+                    .as_ref()
+                    .ok_or_else(|| Error::invalid_file("Unknown optional type".to_string()))?;
+                let length = length - size_of::<i32>() as i64;
+
+                let value: Property = match inner_type == "StructProperty" {
+                    true => StructProperty::custom_header(
+                        asset,
+                        name.clone(),
+                        ancestry.with_parent(name.clone()),
+                        length,
+                        0,
+                        Some(inner_struct_type.unwrap_or_else(|| FName::from_slice("Generic"))),
+                        None,
+                        None,
+                    )?
+                    .into(),
+                    false => Property::from_type(
+                        asset,
+                        inner_type,
+                        name.clone(),
+                        ancestry.clone(),
+                        false,
+                        length,
+                        0,
+                        0,
+                        false,
+                    )?,
+                };
+                Some(Box::new(value))
+            }
+            false => None,
+        };
+
+        Ok(OptionalProperty {
+            name,
+            ancestry,
+            property_guid,
+            property_tag_extensions: PropertyTagExtensions::default(),
+            duplication_index,
+            property_type_name: None,
+            inner_type,
+            value,
+        })
+    }
+}
+
+impl PropertyTrait for OptionalProperty {
+    fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+    ) -> Result<usize, Error> {
+        let inner_type = match self.value {
+            Some(ref value) => {
+                let value = value.to_serialized_name();
+                Some(asset.get_name_map().get_mut().add_fname(&value))
+            }
+            None => self.inner_type.clone(),
+        };
+
+        if include_header {
+            asset.write_fname(inner_type.as_ref().ok_or_else(PropertyError::headerless)?)?;
+            asset.write_property_guid(self.property_guid.as_ref())?;
+            asset.write_property_tag_extensions(&self.property_tag_extensions)?;
+        }
+
+        let begin = asset.position();
+        asset.write_i32::<LE>(self.value.is_some() as i32)?;
+        if let Some(ref value) = self.value {
+            value.write(asset, false)?;
+        }
+        Ok((asset.position() - begin) as usize)
+    }
+}
@@ -11,6 +11,8 @@ pub struct PerPlatformBoolProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Values for each platform
@@ -27,6 +29,8 @@ pub struct PerPlatformIntProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Values for each platform
@@ -43,6 +47,8 @@ pub struct PerPlatformFloatProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Values for each platform
@@ -60,7 +66,7 @@ impl PerPlatformBoolProperty {
         _length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let num_entries = asset.read_i32::<LE>()?;
         let mut value = Vec::with_capacity(num_entries as usize);
@@ -73,6 +79,7 @@ impl PerPlatformBoolProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
@@ -104,7 +111,7 @@ impl PerPlatformIntProperty {
         _length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let num_entries = asset.read_i32::<LE>()?;
         let mut value = Vec::with_capacity(num_entries as usize);
@@ -117,6 +124,7 @@ impl PerPlatformIntProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
@@ -148,7 +156,7 @@ impl PerPlatformFloatProperty {
         _length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let num_entries = asset.read_i32::<LE>()?;
         let mut value = Vec::with_capacity(num_entries as usize);
@@ -161,6 +169,7 @@ impl PerPlatformFloatProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
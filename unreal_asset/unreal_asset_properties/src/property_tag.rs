@@ -0,0 +1,121 @@
+//! UE5.4+ property tag types
+
+use bitflags::bitflags;
+
+use crate::property_prelude::*;
+
+bitflags! {
+    /// Property tag flags, serialized in >= ObjectVersionUE5::PROPERTY_TAG_COMPLETE_TYPE_NAME
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct EPropertyTagFlags : u8
+    {
+        /// No flags
+        const NONE = 0x00;
+        /// Tag has a non-zero array index
+        const HAS_ARRAY_INDEX = 0x01;
+        /// Tag has a property guid
+        const HAS_PROPERTY_GUID = 0x02;
+        /// Tag has property extensions
+        const HAS_PROPERTY_EXTENSIONS = 0x04;
+        /// Value was serialized with binary or native serialization
+        const HAS_BINARY_OR_NATIVE_SERIALIZE = 0x08;
+        /// Bool property value is true
+        const BOOL_TRUE = 0x10;
+        /// Value serialization was skipped
+        const SKIPPED_SERIALIZE = 0x20;
+    }
+}
+
+/// Complete property type name
+///
+/// A type name together with its parameters, e.g.
+/// `StructProperty(Vector(/Script/CoreUObject))` or `MapProperty(NameProperty, IntProperty)`
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[container_nobounds]
+pub struct PropertyTypeName {
+    /// Type name
+    pub name: FName,
+    /// Type parameters
+    pub parameters: Vec<PropertyTypeName>,
+}
+
+impl PropertyTypeName {
+    /// Create a new `PropertyTypeName` instance
+    pub fn new(name: FName, parameters: Vec<PropertyTypeName>) -> Self {
+        PropertyTypeName { name, parameters }
+    }
+
+    /// Read a `PropertyTypeName` from an asset
+    pub fn read<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+    ) -> Result<Self, Error> {
+        // nodes are serialized flattened in preorder, each followed by its parameter count
+        let mut nodes = Vec::new();
+        let mut remaining = 1;
+        while remaining > 0 {
+            let name = asset.read_fname()?;
+            let inner_count = asset.read_i32::<LE>()?;
+            if inner_count < 0 {
+                return Err(Error::invalid_file(format!(
+                    "Invalid property type name parameter count {inner_count}"
+                )));
+            }
+
+            remaining += inner_count - 1;
+            nodes.push((name, inner_count));
+        }
+
+        Ok(PropertyTypeName::from_nodes(&mut nodes.into_iter()))
+    }
+
+    /// Build a `PropertyTypeName` from flattened nodes
+    fn from_nodes(nodes: &mut impl Iterator<Item = (FName, i32)>) -> Self {
+        let (name, inner_count) = nodes.next().unwrap_or_default();
+        let parameters = (0..inner_count)
+            .map(|_| PropertyTypeName::from_nodes(nodes))
+            .collect();
+
+        PropertyTypeName { name, parameters }
+    }
+
+    /// Write a `PropertyTypeName` to an asset
+    pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+    ) -> Result<(), Error> {
+        asset.write_fname(&self.name)?;
+        asset.write_i32::<LE>(self.parameters.len() as i32)?;
+        for parameter in &self.parameters {
+            parameter.write(asset)?;
+        }
+        Ok(())
+    }
+
+    /// Get a type parameter by index
+    pub fn get_parameter(&self, index: usize) -> Option<&PropertyTypeName> {
+        self.parameters.get(index)
+    }
+
+    /// Interpret this type name as a guid
+    ///
+    /// Guids are stored as 32 hex digits, e.g. a struct guid parameter
+    pub fn to_guid(&self) -> Option<Guid> {
+        self.name.get_content(|name| {
+            if name.len() != 32 {
+                return None;
+            }
+
+            let mut ints = [0u32; 4];
+            for (i, int) in ints.iter_mut().enumerate() {
+                *int = u32::from_str_radix(name.get(i * 8..(i + 1) * 8)?, 16).ok()?;
+            }
+            Some(Guid::from_ints(ints[0], ints[1], ints[2], ints[3]))
+        })
+    }
+
+    /// Format a guid the way it is stored in a type name
+    pub fn guid_to_string(guid: &Guid) -> String {
+        let (a, b, c, d): (u32, u32, u32, u32) = (*guid).into();
+        format!("{a:08X}{b:08X}{c:08X}{d:08X}")
+    }
+}
@@ -11,6 +11,8 @@ pub struct RawStructProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Raw data
@@ -28,7 +30,7 @@ impl RawStructProperty {
         duplication_index: i32,
         length: i64,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let mut value = vec![0u8; length as usize];
         asset.read_exact(&mut value)?;
@@ -37,6 +39,7 @@ impl RawStructProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
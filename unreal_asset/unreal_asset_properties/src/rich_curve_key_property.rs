@@ -23,6 +23,28 @@ pub enum RichCurveExtrapolation {
     MAX = 6,
 }
 
+impl RichCurveExtrapolation {
+    /// Get the `ERichCurveExtrapolation` value name of this extrapolation
+    pub fn value_name(&self) -> &'static str {
+        match self {
+            RichCurveExtrapolation::Cycle => "RCCE_Cycle",
+            RichCurveExtrapolation::CycleWithOffset => "RCCE_CycleWithOffset",
+            RichCurveExtrapolation::Oscillate => "RCCE_Oscillate",
+            RichCurveExtrapolation::Linear => "RCCE_Linear",
+            RichCurveExtrapolation::Constant => "RCCE_Constant",
+            RichCurveExtrapolation::None => "RCCE_None",
+            RichCurveExtrapolation::MAX => "RCCE_MAX",
+        }
+    }
+
+    /// Get an extrapolation from its `ERichCurveExtrapolation` value name
+    pub fn from_value_name(name: &str) -> Option<Self> {
+        (0..=RichCurveExtrapolation::MAX as u8)
+            .filter_map(|e| RichCurveExtrapolation::try_from(e).ok())
+            .find(|e| e.value_name() == name)
+    }
+}
+
 /// Rich curve interpolation mode
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, IntoPrimitive, TryFromPrimitive, Hash)]
 #[repr(i8)]
@@ -38,6 +60,25 @@ pub enum RichCurveInterpMode {
     None,
 }
 
+impl RichCurveInterpMode {
+    /// Get the `ERichCurveInterpMode` value name of this interpolation mode
+    pub fn value_name(&self) -> &'static str {
+        match self {
+            RichCurveInterpMode::Linear => "RCIM_Linear",
+            RichCurveInterpMode::Constant => "RCIM_Constant",
+            RichCurveInterpMode::Cubic => "RCIM_Cubic",
+            RichCurveInterpMode::None => "RCIM_None",
+        }
+    }
+
+    /// Get an interpolation mode from its `ERichCurveInterpMode` value name
+    pub fn from_value_name(name: &str) -> Option<Self> {
+        (0..=RichCurveInterpMode::None as i8)
+            .filter_map(|e| RichCurveInterpMode::try_from(e).ok())
+            .find(|e| e.value_name() == name)
+    }
+}
+
 /// Rich curve tangent mode
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, IntoPrimitive, TryFromPrimitive, Hash)]
 #[repr(i8)]
@@ -77,6 +118,8 @@ pub struct RichCurveKeyProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Interpolation mode
@@ -113,7 +156,7 @@ impl RichCurveKeyProperty {
         _length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let interp_mode = RichCurveInterpMode::try_from(asset.read_i8()?)?; // todo: implement normal errors
         let tangent_mode = RichCurveTangentMode::try_from(asset.read_i8()?)?;
@@ -130,6 +173,7 @@ impl RichCurveKeyProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             interp_mode,
             tangent_mode,
@@ -11,6 +11,8 @@ pub struct WeightedRandomSamplerProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Probabilities
@@ -31,6 +33,8 @@ pub struct SkeletalMeshAreaWeightedTriangleSampler {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Probabilities
@@ -51,6 +55,8 @@ pub struct SkeletalMeshSamplingLODBuiltDataProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Sampler
@@ -68,7 +74,7 @@ impl WeightedRandomSamplerProperty {
         _length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let size = asset.read_i32::<LE>()?;
         let mut prob = Vec::with_capacity(size as usize);
@@ -88,6 +94,7 @@ impl WeightedRandomSamplerProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             prob,
             alias,
@@ -132,7 +139,7 @@ impl SkeletalMeshAreaWeightedTriangleSampler {
         _length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let size = asset.read_i32::<LE>()?;
         let mut prob = Vec::with_capacity(size as usize);
@@ -152,6 +159,7 @@ impl SkeletalMeshAreaWeightedTriangleSampler {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             prob,
             alias,
@@ -196,7 +204,7 @@ impl SkeletalMeshSamplingLODBuiltDataProperty {
         _length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         let sampler_property = WeightedRandomSamplerProperty::new(
             asset,
             name.clone(),
@@ -210,6 +218,7 @@ impl SkeletalMeshSamplingLODBuiltDataProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             sampler_property,
         })
@@ -12,8 +12,12 @@ pub struct SetProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
+    /// Complete property type name, only used in >= UE5.4 property tags
+    pub property_type_name: Option<PropertyTypeName>,
     /// Array type
     pub array_type: Option<FName>,
     /// Set values
@@ -33,12 +37,43 @@ impl SetProperty {
         length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let (array_type, property_guid) = match include_header {
-            true => (Some(asset.read_fname()?), asset.read_property_guid()?),
-            false => (None, None),
+        let (array_type, property_guid, property_tag_extensions) = match include_header {
+            true => (
+                Some(asset.read_fname()?),
+                asset.read_property_guid()?,
+                asset.read_property_tag_extensions()?,
+            ),
+            false => (None, None, PropertyTagExtensions::default()),
         };
 
-        let removed_items = ArrayProperty::new_no_header(
+        Ok(SetProperty {
+            property_tag_extensions,
+            ..SetProperty::new_no_header(
+                asset,
+                name,
+                ancestry,
+                length,
+                duplication_index,
+                array_type,
+                None,
+                property_guid,
+            )?
+        })
+    }
+
+    /// Read a `SetProperty` from an asset without reading the property header
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_no_header<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        name: FName,
+        ancestry: Ancestry,
+        length: i64,
+        duplication_index: i32,
+        array_type: Option<FName>,
+        array_struct_type: Option<FName>,
+        property_guid: Option<Guid>,
+    ) -> Result<Self, Error> {
+        let removed_items = ArrayProperty::new_no_header_with_struct_type(
             asset,
             name.clone(),
             ancestry.with_parent(name.clone()),
@@ -47,10 +82,11 @@ impl SetProperty {
             0,
             false,
             array_type.clone(),
+            array_struct_type.clone(),
             property_guid,
         )?;
 
-        let items = ArrayProperty::new_no_header(
+        let items = ArrayProperty::new_no_header_with_struct_type(
             asset,
             name.clone(),
             ancestry.clone(),
@@ -59,6 +95,7 @@ impl SetProperty {
             0,
             false,
             array_type.clone(),
+            array_struct_type,
             property_guid,
         )?;
 
@@ -66,7 +103,9 @@ impl SetProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions: PropertyTagExtensions::default(),
             duplication_index,
+            property_type_name: None,
             array_type,
             value: items,
             removed_items,
@@ -91,6 +130,7 @@ impl PropertyTrait for SetProperty {
         if include_header {
             asset.write_fname(array_type.as_ref().ok_or_else(PropertyError::headerless)?)?;
             asset.write_property_guid(self.property_guid.as_ref())?;
+            asset.write_property_tag_extensions(&self.property_tag_extensions)?;
         }
 
         let removed_items_len = self.removed_items.write_full(asset, false, false)?;
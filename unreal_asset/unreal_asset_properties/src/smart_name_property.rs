@@ -13,6 +13,8 @@ pub struct SmartNameProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Display name
@@ -34,7 +36,7 @@ impl SmartNameProperty {
         _length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let display_name = asset.read_fname()?;
 
@@ -55,6 +57,7 @@ impl SmartNameProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             display_name,
             smart_name_id,
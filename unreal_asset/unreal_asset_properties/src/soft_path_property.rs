@@ -55,6 +55,8 @@ pub struct SoftAssetPathProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Value
@@ -71,6 +73,8 @@ pub struct SoftObjectPathProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Value
@@ -87,6 +91,8 @@ pub struct SoftClassPathProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Value
@@ -103,6 +109,8 @@ pub struct StringAssetReferenceProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Value
@@ -122,13 +130,15 @@ macro_rules! impl_soft_path_property {
                 _length: i64,
                 duplication_index: i32,
             ) -> Result<Self, Error> {
-                let property_guid = optional_guid!(asset, include_header);
+                let (property_guid, property_tag_extensions) =
+                    optional_guid!(asset, include_header);
                 let value = SoftObjectPathPropertyValue::new(asset)?;
 
                 Ok($property_name {
                     name,
                     ancestry,
                     property_guid,
+                    property_tag_extensions,
                     duplication_index,
                     value,
                 })
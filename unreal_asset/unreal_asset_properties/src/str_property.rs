@@ -1,6 +1,9 @@
 //! String properties
 
+use unreal_asset_base::reader::FStringEncoding;
+
 use crate::property_prelude::*;
+use crate::text_history::{FText, TextHistory};
 
 /// Text history type
 #[derive(
@@ -59,10 +62,15 @@ pub struct StrProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// FString value
     pub value: Option<String>,
+    /// Encoding used when writing the value, wide values are read as `Utf16` and narrow values as `Auto`
+    #[container_ignore]
+    pub encoding: FStringEncoding,
 }
 impl_property_data_trait!(StrProperty);
 
@@ -75,6 +83,8 @@ pub struct TextProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Culture invariant string
@@ -89,6 +99,8 @@ pub struct TextProperty {
     pub history_type: TextHistoryType,
     /// FString value
     pub value: Option<String>,
+    /// History data of formatted and generated texts
+    pub history: Option<TextHistory>,
 }
 impl_property_data_trait!(TextProperty);
 
@@ -101,6 +113,8 @@ pub struct NameProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// FName value
@@ -117,14 +131,17 @@ impl StrProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+        let (value, encoding) = asset.read_fstring_with_encoding()?;
 
         Ok(StrProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
-            value: asset.read_fstring()?,
+            value,
+            encoding,
         })
     }
 }
@@ -137,11 +154,84 @@ impl PropertyTrait for StrProperty {
     ) -> Result<usize, Error> {
         optional_guid_write!(self, asset, include_header);
         let begin = asset.position();
-        asset.write_fstring(self.value.as_deref())?;
+        asset.write_fstring_with_encoding(self.value.as_deref(), self.encoding)?;
         Ok((asset.position() - begin) as usize)
     }
 }
 
+// narrow string properties are always stored with single byte characters
+macro_rules! impl_narrow_str {
+    ($property_name:ident, $encoding:ident) => {
+        /// $property_name
+        #[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+        pub struct $property_name {
+            /// Name
+            pub name: FName,
+            /// Property ancestry
+            pub ancestry: Ancestry,
+            /// Property guid
+            pub property_guid: Option<Guid>,
+            /// Property tag extensions
+            pub property_tag_extensions: PropertyTagExtensions,
+            /// Property duplication index
+            pub duplication_index: i32,
+            /// String value
+            pub value: Option<String>,
+        }
+        impl_property_data_trait!($property_name);
+
+        impl $property_name {
+            /// Read a `$property_name` from an asset
+            pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+                asset: &mut Reader,
+                name: FName,
+                ancestry: Ancestry,
+                include_header: bool,
+                duplication_index: i32,
+            ) -> Result<Self, Error> {
+                let (property_guid, property_tag_extensions) =
+                    optional_guid!(asset, include_header);
+
+                let (value, encoding) = asset.read_fstring_with_encoding()?;
+                if encoding == FStringEncoding::Utf16 {
+                    return Err(Error::invalid_file(format!(
+                        "{} stored as UTF-16",
+                        stringify!($property_name)
+                    )));
+                }
+
+                Ok($property_name {
+                    name,
+                    ancestry,
+                    property_guid,
+                    property_tag_extensions,
+                    duplication_index,
+                    value,
+                })
+            }
+        }
+
+        impl PropertyTrait for $property_name {
+            fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+                &self,
+                asset: &mut Writer,
+                include_header: bool,
+            ) -> Result<usize, Error> {
+                optional_guid_write!(self, asset, include_header);
+                let begin = asset.position();
+                asset.write_fstring_with_encoding(
+                    self.value.as_deref(),
+                    FStringEncoding::$encoding,
+                )?;
+                Ok((asset.position() - begin) as usize)
+            }
+        }
+    };
+}
+
+impl_narrow_str!(Utf8StrProperty, Utf8);
+impl_narrow_str!(AnsiStrProperty, Ascii);
+
 impl TextProperty {
     /// Read a `TextProperty` from an asset
     pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
@@ -151,75 +241,37 @@ impl TextProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
-
-        let mut culture_invariant_string = None;
-        let mut namespace = None;
-        let mut value = None;
-
-        if asset.get_object_version() < ObjectVersion::VER_UE4_FTEXT_HISTORY {
-            culture_invariant_string = asset.read_fstring()?;
-            if asset.get_object_version()
-                >= ObjectVersion::VER_UE4_ADDED_NAMESPACE_AND_KEY_DATA_TO_FTEXT
-            {
-                namespace = asset.read_fstring()?;
-                value = asset.read_fstring()?;
-            } else {
-                namespace = None;
-                value = asset.read_fstring()?;
-            }
-        }
-
-        let flags = asset.read_u32::<LE>()?;
-        let mut history_type = TextHistoryType::Base;
-        let mut table_id = None;
-        if asset.get_object_version() >= ObjectVersion::VER_UE4_FTEXT_HISTORY {
-            history_type = TextHistoryType::try_from(asset.read_i8()?)?;
-
-            match history_type {
-                TextHistoryType::None => {
-                    value = None;
-                    let version: CustomVersion = asset.get_custom_version::<FEditorObjectVersion>();
-                    if version.version
-                        >= FEditorObjectVersion::CultureInvariantTextSerializationKeyStability
-                            as i32
-                    {
-                        let has_culture_invariant_string = asset.read_i32::<LE>()? == 1;
-                        if has_culture_invariant_string {
-                            culture_invariant_string = asset.read_fstring()?;
-                        }
-                    }
-                }
-                TextHistoryType::Base => {
-                    namespace = asset.read_fstring()?;
-                    value = asset.read_fstring()?;
-                    culture_invariant_string = asset.read_fstring()?;
-                }
-                TextHistoryType::StringTableEntry => {
-                    table_id = Some(asset.read_fname()?);
-                    value = asset.read_fstring()?;
-                }
-                _ => {
-                    return Err(Error::unimplemented(format!(
-                        "Unimplemented reader for {history_type:?}"
-                    )));
-                }
-            }
-        }
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
+        let text = FText::new(asset)?;
 
         Ok(TextProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
-            culture_invariant_string,
-            namespace,
-            table_id,
-            flags,
-            history_type,
-            value,
+            culture_invariant_string: text.culture_invariant_string,
+            namespace: text.namespace,
+            table_id: text.table_id,
+            flags: text.flags,
+            history_type: text.history_type,
+            value: text.value,
+            history: text.history,
         })
     }
+
+    /// Get the `FText` value of this property
+    pub fn to_text(&self) -> FText {
+        FText {
+            culture_invariant_string: self.culture_invariant_string.clone(),
+            namespace: self.namespace.clone(),
+            table_id: self.table_id.clone(),
+            flags: self.flags,
+            history_type: self.history_type,
+            value: self.value.clone(),
+            history: self.history.clone(),
+        }
+    }
 }
 
 impl PropertyTrait for TextProperty {
@@ -230,62 +282,7 @@ impl PropertyTrait for TextProperty {
     ) -> Result<usize, Error> {
         optional_guid_write!(self, asset, include_header);
         let begin = asset.position();
-
-        if asset.get_object_version() < ObjectVersion::VER_UE4_FTEXT_HISTORY {
-            asset.write_fstring(self.culture_invariant_string.as_deref())?;
-            if asset.get_object_version()
-                >= ObjectVersion::VER_UE4_ADDED_NAMESPACE_AND_KEY_DATA_TO_FTEXT
-            {
-                asset.write_fstring(self.namespace.as_deref())?;
-                asset.write_fstring(self.value.as_deref())?;
-            } else {
-                asset.write_fstring(self.value.as_deref())?;
-            }
-        }
-        asset.write_u32::<LE>(self.flags)?;
-
-        if asset.get_object_version() >= ObjectVersion::VER_UE4_FTEXT_HISTORY {
-            let history_type = self.history_type;
-            asset.write_i8(history_type.into())?;
-            match history_type {
-                TextHistoryType::None => {
-                    if asset.get_custom_version::<FEditorObjectVersion>().version
-                        >= FEditorObjectVersion::CultureInvariantTextSerializationKeyStability
-                            as i32
-                    {
-                        let is_empty = match &self.culture_invariant_string {
-                            Some(e) => e.is_empty(),
-                            None => true,
-                        };
-                        match is_empty {
-                            true => asset.write_i32::<LE>(0)?,
-                            false => {
-                                asset.write_i32::<LE>(1)?;
-                                asset.write_fstring(self.culture_invariant_string.as_deref())?;
-                            }
-                        }
-                    }
-                    Ok(())
-                }
-                TextHistoryType::Base => {
-                    asset.write_fstring(self.namespace.as_deref())?;
-                    asset.write_fstring(self.value.as_deref())?;
-                    asset.write_fstring(self.culture_invariant_string.as_deref())?;
-                    Ok(())
-                }
-                TextHistoryType::StringTableEntry => {
-                    asset.write_fname(self.table_id.as_ref().ok_or_else(|| {
-                        PropertyError::property_field_none("table_id", "FName")
-                    })?)?;
-                    asset.write_fstring(self.value.as_deref())?;
-                    Ok(())
-                }
-                _ => Err(Error::unimplemented(format!(
-                    "Unimplemented writer for {}",
-                    history_type as i8
-                ))),
-            }?;
-        }
+        self.to_text().write(asset)?;
         Ok((asset.position() - begin) as usize)
     }
 }
@@ -299,12 +296,13 @@ impl NameProperty {
         include_header: bool,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         let value = asset.read_fname()?;
         Ok(NameProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
             value,
         })
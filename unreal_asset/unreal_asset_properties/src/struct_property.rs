@@ -1,5 +1,8 @@
 //! Struct property
 
+use crate::array_property::ArrayProperty;
+use crate::empty_property::EmptyProperty;
+use crate::int_property::{ByteProperty, BytePropertyValue};
 use crate::property_prelude::*;
 
 /// Struct property
@@ -15,8 +18,12 @@ pub struct StructProperty {
     pub struct_guid: Option<Guid>,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
+    /// Complete property type name, only used in >= UE5.4 property tags
+    pub property_type_name: Option<PropertyTypeName>,
     /// Should serialize None
     pub serialize_none: bool,
     /// Struct variables
@@ -38,7 +45,9 @@ impl StructProperty {
             struct_type: Some(struct_type),
             struct_guid,
             property_guid: None,
+            property_tag_extensions: PropertyTagExtensions::default(),
             duplication_index: 0,
+            property_type_name: None,
             serialize_none: true,
             value: Vec::new(),
         }
@@ -56,6 +65,7 @@ impl StructProperty {
         let mut struct_type = None;
         let mut struct_guid = None;
         let mut property_guid = None;
+        let mut property_tag_extensions = PropertyTagExtensions::default();
 
         if include_header && !asset.has_unversioned_properties() {
             struct_type = Some(asset.read_fname()?);
@@ -63,18 +73,22 @@ impl StructProperty {
                 struct_guid = Some(asset.read_guid()?);
             }
             property_guid = asset.read_property_guid()?;
+            property_tag_extensions = asset.read_property_tag_extensions()?;
         }
 
-        StructProperty::custom_header(
-            asset,
-            name,
-            ancestry,
-            length,
-            duplication_index,
-            struct_type,
-            struct_guid,
-            property_guid,
-        )
+        Ok(StructProperty {
+            property_tag_extensions,
+            ..StructProperty::custom_header(
+                asset,
+                name,
+                ancestry,
+                length,
+                duplication_index,
+                struct_type,
+                struct_guid,
+                property_guid,
+            )?
+        })
     }
 
     /// Read a `StructProperty` with custom header values set
@@ -135,7 +149,7 @@ impl StructProperty {
                         custom_serialization =
                             !(asset.has_unversioned_properties() || is_lower_bound);
                     }
-                    "RichCurveKey"
+                    "RichCurveKey" | "RichCurve"
                         if asset.get_object_version()
                             < ObjectVersion::VER_UE4_SERIALIZE_RICH_CURVE_KEY =>
                     {
@@ -147,6 +161,12 @@ impl StructProperty {
                     {
                         custom_serialization = false;
                     }
+                    "GameplayAttribute"
+                        if asset.get_custom_version::<FCoreObjectVersion>().version
+                            < FCoreObjectVersion::FProperties as i32 =>
+                    {
+                        custom_serialization = false;
+                    }
                     "MovieSceneFloatChannel" => {
                         if asset
                             .get_custom_version::<FSequencerObjectVersion>()
@@ -173,7 +193,9 @@ impl StructProperty {
                 struct_type,
                 struct_guid,
                 property_guid,
+                property_tag_extensions: PropertyTagExtensions::default(),
                 duplication_index,
+                property_type_name: None,
                 serialize_none: false,
                 value: Vec::new(),
             });
@@ -200,22 +222,15 @@ impl StructProperty {
                 struct_type,
                 struct_guid,
                 property_guid,
+                property_tag_extensions: PropertyTagExtensions::default(),
                 duplication_index,
+                property_type_name: None,
                 serialize_none: true,
                 value,
             })
         } else {
-            let mut values = Vec::new();
-            let mut unversioned_header = UnversionedHeader::new(asset)?;
             let new_ancestry = ancestry.with_parent(struct_type.clone().unwrap());
-            while let Some(property) = Property::new(
-                asset,
-                new_ancestry.clone(),
-                unversioned_header.as_mut(),
-                true,
-            )? {
-                values.push(property);
-            }
+            let values = StructProperty::read_properties(asset, new_ancestry)?;
 
             Ok(StructProperty {
                 name,
@@ -223,40 +238,33 @@ impl StructProperty {
                 struct_type,
                 struct_guid,
                 property_guid,
+                property_tag_extensions: PropertyTagExtensions::default(),
                 duplication_index,
+                property_type_name: None,
                 serialize_none: true,
                 value: values,
             })
         }
     }
 
-    /// Write a `StructProperty` overriding struct type
-    pub fn write_with_type<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+    /// Check if this `StructProperty` is written with custom serialization for a given struct type
+    pub fn is_custom_serialized<Writer: ArchiveWriter<impl PackageIndexTrait>>(
         &self,
-        asset: &mut Writer,
-        include_header: bool,
-        struct_type: Option<FName>,
-    ) -> Result<usize, Error> {
-        if include_header {
-            asset.write_fname(struct_type.as_ref().ok_or_else(PropertyError::headerless)?)?;
-            if asset.get_object_version() >= ObjectVersion::VER_UE4_STRUCT_GUID_IN_PROPERTY_TAG {
-                asset.write_guid(&self.struct_guid.ok_or_else(PropertyError::headerless)?)?;
-            }
-            asset.write_property_guid(self.property_guid.as_ref())?;
-        }
-
+        asset: &Writer,
+        struct_type: Option<&FName>,
+    ) -> bool {
         let mut has_custom_serialization = match struct_type {
-            Some(ref e) => e.get_content(Property::has_custom_serialization),
+            Some(e) => e.get_content(Property::has_custom_serialization),
             None => false,
         };
 
-        if let Some(ref struct_type) = struct_type {
+        if let Some(struct_type) = struct_type {
             if struct_type == "FloatRange" {
                 has_custom_serialization = self.value.len() == 1
                     && cast!(Property, FloatRangeProperty, &self.value[0]).is_some();
             }
 
-            if struct_type == "RichCurveKey"
+            if (struct_type == "RichCurveKey" || struct_type == "RichCurve")
                 && asset.get_object_version() < ObjectVersion::VER_UE4_SERIALIZE_RICH_CURVE_KEY
             {
                 has_custom_serialization = false;
@@ -269,6 +277,13 @@ impl StructProperty {
                 has_custom_serialization = false;
             }
 
+            if struct_type == "GameplayAttribute"
+                && asset.get_custom_version::<FCoreObjectVersion>().version
+                    < FCoreObjectVersion::FProperties as i32
+            {
+                has_custom_serialization = false;
+            }
+
             if struct_type == "MovieSceneFloatChannel"
                 && asset
                     .get_custom_version::<FSequencerObjectVersion>()
@@ -283,6 +298,27 @@ impl StructProperty {
             }
         }
 
+        has_custom_serialization
+    }
+
+    /// Write a `StructProperty` overriding struct type
+    pub fn write_with_type<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        include_header: bool,
+        struct_type: Option<FName>,
+    ) -> Result<usize, Error> {
+        if include_header {
+            asset.write_fname(struct_type.as_ref().ok_or_else(PropertyError::headerless)?)?;
+            if asset.get_object_version() >= ObjectVersion::VER_UE4_STRUCT_GUID_IN_PROPERTY_TAG {
+                asset.write_guid(&self.struct_guid.ok_or_else(PropertyError::headerless)?)?;
+            }
+            asset.write_property_guid(self.property_guid.as_ref())?;
+            asset.write_property_tag_extensions(&self.property_tag_extensions)?;
+        }
+
+        let has_custom_serialization = self.is_custom_serialized(asset, struct_type.as_ref());
+
         if has_custom_serialization {
             if self.value.len() != 1 {
                 return Err(PropertyError::invalid_struct(
@@ -298,31 +334,56 @@ impl StructProperty {
         } else if self.value.is_empty() && !self.serialize_none {
             Ok(0)
         } else {
-            let begin = asset.position();
-
-            let (unversioned_header, sorted_properties) = match generate_unversioned_header(
+            StructProperty::write_properties(
                 asset,
                 &self.value,
                 self.struct_type.as_ref().unwrap_or(&FName::default()),
-            )? {
+            )
+        }
+    }
+
+    /// Read the tagged or unversioned properties of a struct until the end of the struct
+    pub fn read_properties<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        ancestry: Ancestry,
+    ) -> Result<Vec<Property>, Error> {
+        let mut values = Vec::new();
+        let mut unversioned_header = UnversionedHeader::new(asset)?;
+        while let Some(property) =
+            Property::new(asset, ancestry.clone(), unversioned_header.as_mut(), true)?
+        {
+            values.push(property);
+        }
+        Ok(values)
+    }
+
+    /// Write properties of a struct as tagged or unversioned properties
+    pub fn write_properties<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        asset: &mut Writer,
+        properties: &[Property],
+        parent_name: &FName,
+    ) -> Result<usize, Error> {
+        let begin = asset.position();
+
+        let (unversioned_header, sorted_properties) =
+            match generate_unversioned_header(asset, properties, parent_name)? {
                 Some((a, b)) => (Some(a), Some(b)),
                 None => (None, None),
             };
 
-            if let Some(unversioned_header) = unversioned_header {
-                unversioned_header.write(asset)?;
-            }
+        if let Some(unversioned_header) = unversioned_header {
+            unversioned_header.write(asset)?;
+        }
 
-            let properties = sorted_properties.as_ref().unwrap_or(&self.value);
-            for entry in properties.iter() {
-                Property::write(entry, asset, true)?;
-            }
+        let properties = sorted_properties.as_deref().unwrap_or(properties);
+        for entry in properties.iter() {
+            Property::write(entry, asset, true)?;
+        }
 
-            if !asset.has_unversioned_properties() {
-                asset.write_fname(&asset.get_name_map().get_mut().add_fname("None"))?;
-            }
-            Ok((asset.position() - begin) as usize)
+        if !asset.has_unversioned_properties() {
+            asset.write_fname(&asset.get_name_map().get_mut().add_fname("None"))?;
         }
+        Ok((asset.position() - begin) as usize)
     }
 }
 
@@ -335,3 +396,298 @@ impl PropertyTrait for StructProperty {
         self.write_with_type(asset, include_header, self.struct_type.clone())
     }
 }
+
+/// Get the ancestry used by the fields of a struct with custom typed serialization
+pub(crate) fn field_ancestry(ancestry: &Ancestry, struct_type: &str) -> Ancestry {
+    ancestry
+        .without_parent()
+        .with_parent(FName::new_dummy(struct_type.to_string(), 0))
+}
+
+/// Create an error for a struct field that isn't known
+pub(crate) fn unexpected_field(struct_type: &str, field: &Property) -> Error {
+    field.get_name().get_content(|name| {
+        PropertyError::invalid_struct(format!(
+            "Unexpected {} field {} of type {}",
+            struct_type,
+            name,
+            field.to_serialized_name()
+        ))
+        .into()
+    })
+}
+
+/// Add a struct field with a zero default value to be written
+///
+/// Fields with default values are only written if they were serialized,
+/// serialized default fields are written as zero when using unversioned properties
+pub(crate) fn push_field<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+    asset: &Writer,
+    fields: &mut Vec<Property>,
+    serialized_fields: &[FName],
+    name: &str,
+    is_default: bool,
+    field: impl FnOnce(FName) -> Property,
+) {
+    push_field_with_default(
+        asset,
+        fields,
+        serialized_fields,
+        name,
+        is_default,
+        is_default,
+        field,
+    );
+}
+
+/// Add a struct field with a non-zero default value to be written
+///
+/// Fields with default values are only written if they were serialized,
+/// zero fields are written without a value when using unversioned properties
+pub(crate) fn push_field_with_default<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+    asset: &Writer,
+    fields: &mut Vec<Property>,
+    serialized_fields: &[FName],
+    name: &str,
+    is_default: bool,
+    is_zero: bool,
+    field: impl FnOnce(FName) -> Property,
+) {
+    if is_default && !serialized_fields.iter().any(|e| e == name) {
+        return;
+    }
+
+    let name = asset.get_name_map().get_mut().add_fname(name);
+    let field = field(name);
+    fields.push(match is_zero && asset.has_unversioned_properties() {
+        true => EmptyProperty::new(
+            FName::new_dummy(field.to_serialized_name(), 0),
+            field.get_name(),
+            field.get_ancestry().clone(),
+        )
+        .into(),
+        false => field,
+    });
+}
+
+/// Write the fields of a struct with custom typed serialization
+///
+/// Serialized fields are written in the order they were read in, new fields are written after them
+pub(crate) fn write_fields<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+    asset: &mut Writer,
+    mut fields: Vec<Property>,
+    serialized_fields: &[FName],
+    struct_type: &str,
+) -> Result<usize, Error> {
+    fields.sort_by_key(|field| {
+        let name = field.get_name();
+        serialized_fields
+            .iter()
+            .position(|e| *e == name)
+            .unwrap_or(usize::MAX)
+    });
+    StructProperty::write_properties(asset, &fields, &FName::from_slice(struct_type))
+}
+
+/// Create an array field of structs with custom typed serialization
+pub(crate) fn struct_array_field<Writer: ArchiveWriter<impl PackageIndexTrait>, T>(
+    asset: &Writer,
+    name: FName,
+    ancestry: &Ancestry,
+    struct_type: &str,
+    values: &[T],
+    value: impl Fn(&T, FName, Ancestry) -> Property,
+) -> Property {
+    let struct_property = asset.get_name_map().get_mut().add_fname("StructProperty");
+    let struct_type = asset.get_name_map().get_mut().add_fname(struct_type);
+    let entry_ancestry = ancestry.with_parent(name.clone());
+    let entry = |entry_value: Option<&T>| {
+        let mut entry = StructProperty::dummy(
+            name.clone(),
+            entry_ancestry.clone(),
+            struct_type.clone(),
+            Some(Guid::default()),
+        );
+        if let Some(entry_value) = entry_value {
+            entry.value.push(value(
+                entry_value,
+                name.clone(),
+                entry_ancestry.with_parent(name.clone()),
+            ));
+        }
+        entry
+    };
+
+    let mut array = ArrayProperty::from_arr(
+        name.clone(),
+        ancestry.clone(),
+        Some(struct_property),
+        values.iter().map(|e| entry(Some(e)).into()).collect(),
+    );
+    array.dummy_property = Some(entry(None));
+    array.into()
+}
+
+/// Get the values of an array field of structs with custom typed serialization
+pub(crate) fn struct_array_values<'a, T>(
+    struct_type: &str,
+    array: &'a ArrayProperty,
+    value: impl Fn(&'a Property) -> Option<T>,
+) -> Result<Vec<T>, Error> {
+    array
+        .value
+        .iter()
+        .map(|entry| {
+            let entry_value = match entry {
+                Property::StructProperty(e) if e.value.len() == 1 => value(&e.value[0]),
+                _ => None,
+            };
+            entry_value.ok_or_else(|| unexpected_field(struct_type, entry))
+        })
+        .collect()
+}
+
+/// Create an array field of structs serialized as regular structs
+pub(crate) fn tagged_struct_array_field<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+    asset: &Writer,
+    name: FName,
+    ancestry: &Ancestry,
+    struct_type: &str,
+    values: &[StructProperty],
+) -> Property {
+    let struct_property = asset.get_name_map().get_mut().add_fname("StructProperty");
+    let struct_type = asset.get_name_map().get_mut().add_fname(struct_type);
+    let entry_ancestry = ancestry.with_parent(name.clone());
+
+    let mut array = ArrayProperty::from_arr(
+        name.clone(),
+        ancestry.clone(),
+        Some(struct_property),
+        values
+            .iter()
+            .map(|e| {
+                StructProperty {
+                    name: name.clone(),
+                    ancestry: entry_ancestry.clone(),
+                    ..e.clone()
+                }
+                .into()
+            })
+            .collect(),
+    );
+    array.dummy_property = Some(StructProperty::dummy(
+        name,
+        entry_ancestry,
+        struct_type,
+        Some(Guid::default()),
+    ));
+    array.into()
+}
+
+/// Get the values of an array field of structs serialized as regular structs
+pub(crate) fn tagged_struct_array_values(
+    struct_type: &str,
+    array: &ArrayProperty,
+) -> Result<Vec<StructProperty>, Error> {
+    array_values(struct_type, array, |e| {
+        cast!(Property, StructProperty, e).cloned()
+    })
+}
+
+/// Create an array field of non-struct values
+pub(crate) fn array_field<Writer: ArchiveWriter<impl PackageIndexTrait>, T>(
+    asset: &Writer,
+    name: FName,
+    ancestry: &Ancestry,
+    array_type: &str,
+    values: &[T],
+    value: impl Fn(&T, FName, Ancestry) -> Property,
+) -> Property {
+    let array_type = asset.get_name_map().get_mut().add_fname(array_type);
+
+    ArrayProperty::from_arr(
+        name,
+        ancestry.clone(),
+        Some(array_type),
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, e)| {
+                value(
+                    e,
+                    FName::new_dummy(i.to_string(), i32::MIN),
+                    ancestry.clone(),
+                )
+            })
+            .collect(),
+    )
+    .into()
+}
+
+/// Get the values of an array field
+pub(crate) fn array_values<'a, T>(
+    struct_type: &str,
+    array: &'a ArrayProperty,
+    value: impl Fn(&'a Property) -> Option<T>,
+) -> Result<Vec<T>, Error> {
+    array
+        .value
+        .iter()
+        .map(|entry| value(entry).ok_or_else(|| unexpected_field(struct_type, entry)))
+        .collect()
+}
+
+/// Create an enum byte field
+///
+/// Enum values are written by name in tagged properties and by value in unversioned properties
+pub(crate) fn enum_field<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+    asset: &Writer,
+    name: FName,
+    ancestry: Ancestry,
+    enum_type: &str,
+    value_name: &str,
+    value: u8,
+) -> Property {
+    let (enum_type, value) = match asset.has_unversioned_properties() {
+        true => (None, BytePropertyValue::Byte(value)),
+        false => {
+            let mut name_map = asset.get_name_map();
+            let mut name_map = name_map.get_mut();
+            (
+                Some(name_map.add_fname(enum_type)),
+                BytePropertyValue::FName(name_map.add_fname(value_name)),
+            )
+        }
+    };
+
+    ByteProperty {
+        name,
+        ancestry,
+        property_guid: None,
+        property_tag_extensions: PropertyTagExtensions::default(),
+        duplication_index: 0,
+        property_type_name: None,
+        enum_type,
+        value,
+    }
+    .into()
+}
+
+/// Get the value of an enum byte field by converting its value name or value
+pub(crate) fn enum_field_value<T>(
+    struct_type: &str,
+    field: &Property,
+    from_value: impl Fn(u8) -> Option<T>,
+    from_name: impl Fn(&str) -> Option<T>,
+) -> Result<T, Error> {
+    let value = match field {
+        Property::ByteProperty(e) => match &e.value {
+            BytePropertyValue::Byte(value) => from_value(*value),
+            BytePropertyValue::FName(value) => value.get_content(|value| {
+                from_name(value.rsplit_once("::").map_or(value, |(_, value)| value))
+            }),
+        },
+        _ => None,
+    };
+    value.ok_or_else(|| unexpected_field(struct_type, field))
+}
@@ -0,0 +1,827 @@
+//! FText and text histories
+
+use crate::property_prelude::*;
+use crate::str_property::TextHistoryType;
+
+/// Format argument type
+#[derive(
+    FNameContainer,
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    Hash,
+)]
+#[repr(u8)]
+pub enum FormatArgumentType {
+    /// Int
+    #[default]
+    Int,
+    /// UInt
+    UInt,
+    /// Float
+    Float,
+    /// Double
+    Double,
+    /// Text
+    Text,
+    /// Gender
+    Gender,
+}
+
+/// Text gender
+#[derive(
+    FNameContainer,
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    Hash,
+)]
+#[repr(u8)]
+pub enum TextGender {
+    /// Masculine
+    #[default]
+    Masculine,
+    /// Feminine
+    Feminine,
+    /// Neuter
+    Neuter,
+}
+
+/// Number rounding mode
+#[derive(
+    FNameContainer,
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    Hash,
+)]
+#[repr(i8)]
+pub enum RoundingMode {
+    /// Rounds to the nearest place, equidistant ties go to the value which is closest to an even value
+    #[default]
+    HalfToEven,
+    /// Rounds to nearest place, equidistant ties go to the value which is further from zero
+    HalfFromZero,
+    /// Rounds to nearest place, equidistant ties go to the value which is closer to zero
+    HalfToZero,
+    /// Rounds to the value which is further from zero
+    FromZero,
+    /// Rounds to the value which is closer to zero
+    ToZero,
+    /// Rounds to the value which is more negative
+    ToNegativeInfinity,
+    /// Rounds to the value which is more positive
+    ToPositiveInfinity,
+}
+
+/// Date and time formatting style
+#[derive(
+    FNameContainer,
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    Hash,
+)]
+#[repr(i8)]
+pub enum DateTimeStyle {
+    /// Default
+    #[default]
+    Default,
+    /// Short
+    Short,
+    /// Medium
+    Medium,
+    /// Long
+    Long,
+    /// Full
+    Full,
+    /// Custom
+    Custom,
+}
+
+/// Text transform type
+#[derive(
+    FNameContainer,
+    Debug,
+    Copy,
+    Clone,
+    Default,
+    PartialEq,
+    Eq,
+    IntoPrimitive,
+    TryFromPrimitive,
+    Hash,
+)]
+#[repr(u8)]
+pub enum TransformType {
+    /// To lowercase
+    #[default]
+    ToLower,
+    /// To uppercase
+    ToUpper,
+}
+
+/// Format argument value
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[container_nobounds]
+pub enum FormatArgumentValue {
+    /// Int
+    Int(i64),
+    /// UInt
+    UInt(u64),
+    /// Float
+    Float(OrderedFloat<f32>),
+    /// Double
+    Double(OrderedFloat<f64>),
+    /// Text
+    Text(Box<FText>),
+    /// Gender
+    Gender(TextGender),
+}
+
+impl FormatArgumentValue {
+    /// Read a `FormatArgumentValue` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+    ) -> Result<Self, Error> {
+        let argument_type = FormatArgumentType::try_from(asset.read_i8()? as u8)?;
+        Self::read_value(asset, argument_type, false)
+    }
+
+    /// Read a value of a given type, argument data stores integers as 32-bit values
+    fn read_value<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        argument_type: FormatArgumentType,
+        argument_data: bool,
+    ) -> Result<Self, Error> {
+        Ok(match argument_type {
+            FormatArgumentType::Int => match argument_data {
+                true => FormatArgumentValue::Int(asset.read_i32::<LE>()? as i64),
+                false => FormatArgumentValue::Int(asset.read_i64::<LE>()?),
+            },
+            FormatArgumentType::UInt => match argument_data {
+                true => FormatArgumentValue::UInt(asset.read_u32::<LE>()? as u64),
+                false => FormatArgumentValue::UInt(asset.read_u64::<LE>()?),
+            },
+            FormatArgumentType::Float => {
+                FormatArgumentValue::Float(OrderedFloat(asset.read_f32::<LE>()?))
+            }
+            FormatArgumentType::Double => {
+                FormatArgumentValue::Double(OrderedFloat(asset.read_f64::<LE>()?))
+            }
+            FormatArgumentType::Text => FormatArgumentValue::Text(Box::new(FText::new(asset)?)),
+            FormatArgumentType::Gender => {
+                FormatArgumentValue::Gender(TextGender::try_from(asset.read_u8()?)?)
+            }
+        })
+    }
+
+    /// Get the argument type of this value
+    pub fn get_argument_type(&self) -> FormatArgumentType {
+        match self {
+            FormatArgumentValue::Int(_) => FormatArgumentType::Int,
+            FormatArgumentValue::UInt(_) => FormatArgumentType::UInt,
+            FormatArgumentValue::Float(_) => FormatArgumentType::Float,
+            FormatArgumentValue::Double(_) => FormatArgumentType::Double,
+            FormatArgumentValue::Text(_) => FormatArgumentType::Text,
+            FormatArgumentValue::Gender(_) => FormatArgumentType::Gender,
+        }
+    }
+
+    /// Write a `FormatArgumentValue` to an asset
+    pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+    ) -> Result<(), Error> {
+        asset.write_i8(u8::from(self.get_argument_type()) as i8)?;
+        self.write_value(asset, false)
+    }
+
+    /// Write the value, argument data stores integers as 32-bit values
+    fn write_value<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        argument_data: bool,
+    ) -> Result<(), Error> {
+        match *self {
+            FormatArgumentValue::Int(value) => match argument_data {
+                true => asset.write_i32::<LE>(value as i32)?,
+                false => asset.write_i64::<LE>(value)?,
+            },
+            FormatArgumentValue::UInt(value) => match argument_data {
+                true => asset.write_u32::<LE>(value as u32)?,
+                false => asset.write_u64::<LE>(value)?,
+            },
+            FormatArgumentValue::Float(value) => asset.write_f32::<LE>(value.0)?,
+            FormatArgumentValue::Double(value) => asset.write_f64::<LE>(value.0)?,
+            FormatArgumentValue::Text(ref value) => value.write(asset)?,
+            FormatArgumentValue::Gender(value) => asset.write_u8(value.into())?,
+        }
+        Ok(())
+    }
+}
+
+/// Named format argument, used by blueprint format text nodes
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FormatArgumentData {
+    /// Argument name
+    pub name: Option<String>,
+    /// Argument value
+    pub value: FormatArgumentValue,
+}
+
+impl FormatArgumentData {
+    /// Read a `FormatArgumentData` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+    ) -> Result<Self, Error> {
+        let name = asset.read_fstring()?;
+
+        // older versions could only store text arguments
+        let value = match asset.get_custom_version::<FEditorObjectVersion>().version
+            < FEditorObjectVersion::TextFormatArgumentDataIsVariant as i32
+        {
+            true => FormatArgumentValue::Text(Box::new(FText::new(asset)?)),
+            false => {
+                let argument_type = FormatArgumentType::try_from(asset.read_u8()?)?;
+                FormatArgumentValue::read_value(asset, argument_type, true)?
+            }
+        };
+
+        Ok(FormatArgumentData { name, value })
+    }
+
+    /// Write a `FormatArgumentData` to an asset
+    pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+    ) -> Result<(), Error> {
+        asset.write_fstring(self.name.as_deref())?;
+
+        if asset.get_custom_version::<FEditorObjectVersion>().version
+            < FEditorObjectVersion::TextFormatArgumentDataIsVariant as i32
+        {
+            return match self.value {
+                FormatArgumentValue::Text(ref value) => value.write(asset),
+                _ => Err(PropertyError::other(format!(
+                    "Cannot write {:?} format argument data in this engine version",
+                    self.value.get_argument_type()
+                ))
+                .into()),
+            };
+        }
+
+        asset.write_u8(self.value.get_argument_type().into())?;
+        self.value.write_value(asset, true)
+    }
+}
+
+/// Number formatting options
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NumberFormattingOptions {
+    /// Always show the sign
+    pub always_sign: bool,
+    /// Use digit grouping
+    pub use_grouping: bool,
+    /// Rounding mode
+    pub rounding_mode: RoundingMode,
+    /// Minimum integral digits
+    pub minimum_integral_digits: i32,
+    /// Maximum integral digits
+    pub maximum_integral_digits: i32,
+    /// Minimum fractional digits
+    pub minimum_fractional_digits: i32,
+    /// Maximum fractional digits
+    pub maximum_fractional_digits: i32,
+}
+
+impl NumberFormattingOptions {
+    /// Read `NumberFormattingOptions` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+    ) -> Result<Self, Error> {
+        let always_sign = match asset.get_custom_version::<FEditorObjectVersion>().version
+            >= FEditorObjectVersion::AddedAlwaysSignNumberFormattingOption as i32
+        {
+            true => asset.read_i32::<LE>()? == 1,
+            false => false,
+        };
+        let use_grouping = asset.read_i32::<LE>()? == 1;
+        let rounding_mode = RoundingMode::try_from(asset.read_i8()?)?;
+
+        Ok(NumberFormattingOptions {
+            always_sign,
+            use_grouping,
+            rounding_mode,
+            minimum_integral_digits: asset.read_i32::<LE>()?,
+            maximum_integral_digits: asset.read_i32::<LE>()?,
+            minimum_fractional_digits: asset.read_i32::<LE>()?,
+            maximum_fractional_digits: asset.read_i32::<LE>()?,
+        })
+    }
+
+    /// Write `NumberFormattingOptions` to an asset
+    pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+    ) -> Result<(), Error> {
+        if asset.get_custom_version::<FEditorObjectVersion>().version
+            >= FEditorObjectVersion::AddedAlwaysSignNumberFormattingOption as i32
+        {
+            asset.write_i32::<LE>(self.always_sign as i32)?;
+        }
+        asset.write_i32::<LE>(self.use_grouping as i32)?;
+        asset.write_i8(self.rounding_mode.into())?;
+        asset.write_i32::<LE>(self.minimum_integral_digits)?;
+        asset.write_i32::<LE>(self.maximum_integral_digits)?;
+        asset.write_i32::<LE>(self.minimum_fractional_digits)?;
+        asset.write_i32::<LE>(self.maximum_fractional_digits)?;
+        Ok(())
+    }
+}
+
+/// Named format history, e.g. `"{Count} items"`
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NamedFormatHistory {
+    /// Source format
+    pub source_format: Box<FText>,
+    /// Named arguments
+    pub arguments: Vec<(Option<String>, FormatArgumentValue)>,
+}
+
+/// Ordered format history, e.g. `"{0} items"`
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OrderedFormatHistory {
+    /// Source format
+    pub source_format: Box<FText>,
+    /// Ordered arguments
+    pub arguments: Vec<FormatArgumentValue>,
+}
+
+/// Argument format history
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArgumentFormatHistory {
+    /// Source format
+    pub source_format: Box<FText>,
+    /// Arguments
+    pub arguments: Vec<FormatArgumentData>,
+}
+
+/// Number formatting history, used by `AsNumber`, `AsPercent` and `AsCurrency`
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FormatNumberHistory {
+    /// Currency code, only used by `AsCurrency`
+    pub currency_code: Option<String>,
+    /// Source value
+    pub source_value: FormatArgumentValue,
+    /// Formatting options
+    pub format_options: Option<NumberFormattingOptions>,
+    /// Target culture name
+    pub target_culture: Option<String>,
+}
+
+/// Date and time formatting history, used by `AsDate`, `AsTime` and `AsDateTime`
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DateTimeHistory {
+    /// Source date time ticks
+    pub source_date_time: i64,
+    /// Date style, not used by `AsTime`
+    pub date_style: DateTimeStyle,
+    /// Time style, not used by `AsDate`
+    pub time_style: DateTimeStyle,
+    /// Time zone
+    pub time_zone: Option<String>,
+    /// Target culture name
+    pub target_culture: Option<String>,
+}
+
+/// Transform history
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TransformHistory {
+    /// Source text
+    pub source_text: Box<FText>,
+    /// Transform type
+    pub transform_type: TransformType,
+}
+
+/// Text generator history
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TextGeneratorHistory {
+    /// Generator type id
+    pub generator_type_id: FName,
+    /// Serialized generator contents, not present when the type id is `None`
+    pub generator_contents: Option<Vec<u8>>,
+}
+
+/// Text history data for formatted and generated texts
+#[derive(FNameContainer, Debug, Clone, PartialEq, Eq, Hash)]
+#[container_nobounds]
+pub enum TextHistory {
+    /// Named format
+    NamedFormat(NamedFormatHistory),
+    /// Ordered format
+    OrderedFormat(OrderedFormatHistory),
+    /// Argument format
+    ArgumentFormat(ArgumentFormatHistory),
+    /// As number, as percent and as currency
+    FormatNumber(FormatNumberHistory),
+    /// As date, as time and as date time
+    DateTime(DateTimeHistory),
+    /// Transform
+    Transform(TransformHistory),
+    /// Text generator
+    TextGenerator(TextGeneratorHistory),
+}
+
+impl TextHistory {
+    /// Read a `TextHistory` of a given type from an asset
+    ///
+    /// Returns `None` for history types that don't store history data
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+        history_type: TextHistoryType,
+    ) -> Result<Option<Self>, Error> {
+        Ok(Some(match history_type {
+            TextHistoryType::NamedFormat => TextHistory::NamedFormat(NamedFormatHistory {
+                source_format: Box::new(FText::new(asset)?),
+                arguments: ArchiveReader::read_array(asset, |asset: &mut Reader| {
+                    Ok((asset.read_fstring()?, FormatArgumentValue::new(asset)?))
+                })?,
+            }),
+            TextHistoryType::OrderedFormat => TextHistory::OrderedFormat(OrderedFormatHistory {
+                source_format: Box::new(FText::new(asset)?),
+                arguments: ArchiveReader::read_array(asset, |asset: &mut Reader| {
+                    FormatArgumentValue::new(asset)
+                })?,
+            }),
+            TextHistoryType::ArgumentFormat => TextHistory::ArgumentFormat(ArgumentFormatHistory {
+                source_format: Box::new(FText::new(asset)?),
+                arguments: ArchiveReader::read_array(asset, |asset: &mut Reader| {
+                    FormatArgumentData::new(asset)
+                })?,
+            }),
+            TextHistoryType::AsNumber
+            | TextHistoryType::AsPercent
+            | TextHistoryType::AsCurrency => {
+                let currency_code = match history_type == TextHistoryType::AsCurrency
+                    && asset.get_object_version()
+                        >= ObjectVersion::VER_UE4_ADDED_CURRENCY_CODE_TO_FTEXT
+                {
+                    true => asset.read_fstring()?,
+                    false => None,
+                };
+                let source_value = FormatArgumentValue::new(asset)?;
+                let format_options = match asset.read_i32::<LE>()? == 1 {
+                    true => Some(NumberFormattingOptions::new(asset)?),
+                    false => None,
+                };
+
+                TextHistory::FormatNumber(FormatNumberHistory {
+                    currency_code,
+                    source_value,
+                    format_options,
+                    target_culture: asset.read_fstring()?,
+                })
+            }
+            TextHistoryType::AsDate | TextHistoryType::AsTime | TextHistoryType::AsDateTime => {
+                let source_date_time = asset.read_i64::<LE>()?;
+                let date_style = match history_type != TextHistoryType::AsTime {
+                    true => DateTimeStyle::try_from(asset.read_i8()?)?,
+                    false => DateTimeStyle::Default,
+                };
+                let time_style = match history_type != TextHistoryType::AsDate {
+                    true => DateTimeStyle::try_from(asset.read_i8()?)?,
+                    false => DateTimeStyle::Default,
+                };
+                let time_zone = match history_type != TextHistoryType::AsDate
+                    || asset.get_object_version()
+                        >= ObjectVersion::VER_UE4_FTEXT_HISTORY_DATE_TIMEZONE
+                {
+                    true => asset.read_fstring()?,
+                    false => None,
+                };
+
+                TextHistory::DateTime(DateTimeHistory {
+                    source_date_time,
+                    date_style,
+                    time_style,
+                    time_zone,
+                    target_culture: asset.read_fstring()?,
+                })
+            }
+            TextHistoryType::Transform => TextHistory::Transform(TransformHistory {
+                source_text: Box::new(FText::new(asset)?),
+                transform_type: TransformType::try_from(asset.read_u8()?)?,
+            }),
+            TextHistoryType::TextGenerator => {
+                let generator_type_id = asset.read_fname()?;
+                let generator_contents = match generator_type_id.get_content(|e| e == "None") {
+                    true => None,
+                    false => {
+                        let length = asset.read_i32::<LE>()?;
+                        let mut contents = vec![0u8; length as usize];
+                        asset.read_exact(&mut contents)?;
+                        Some(contents)
+                    }
+                };
+
+                TextHistory::TextGenerator(TextGeneratorHistory {
+                    generator_type_id,
+                    generator_contents,
+                })
+            }
+            _ => return Ok(None),
+        }))
+    }
+
+    /// Write a `TextHistory` of a given type to an asset
+    pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+        history_type: TextHistoryType,
+    ) -> Result<(), Error> {
+        match self {
+            TextHistory::NamedFormat(history) => {
+                history.source_format.write(asset)?;
+                asset.write_i32::<LE>(history.arguments.len() as i32)?;
+                for (name, value) in &history.arguments {
+                    asset.write_fstring(name.as_deref())?;
+                    value.write(asset)?;
+                }
+            }
+            TextHistory::OrderedFormat(history) => {
+                history.source_format.write(asset)?;
+                asset.write_i32::<LE>(history.arguments.len() as i32)?;
+                for value in &history.arguments {
+                    value.write(asset)?;
+                }
+            }
+            TextHistory::ArgumentFormat(history) => {
+                history.source_format.write(asset)?;
+                asset.write_i32::<LE>(history.arguments.len() as i32)?;
+                for argument in &history.arguments {
+                    argument.write(asset)?;
+                }
+            }
+            TextHistory::FormatNumber(history) => {
+                if history_type == TextHistoryType::AsCurrency
+                    && asset.get_object_version()
+                        >= ObjectVersion::VER_UE4_ADDED_CURRENCY_CODE_TO_FTEXT
+                {
+                    asset.write_fstring(history.currency_code.as_deref())?;
+                }
+                history.source_value.write(asset)?;
+                asset.write_i32::<LE>(history.format_options.is_some() as i32)?;
+                if let Some(format_options) = &history.format_options {
+                    format_options.write(asset)?;
+                }
+                asset.write_fstring(history.target_culture.as_deref())?;
+            }
+            TextHistory::DateTime(history) => {
+                asset.write_i64::<LE>(history.source_date_time)?;
+                if history_type != TextHistoryType::AsTime {
+                    asset.write_i8(history.date_style.into())?;
+                }
+                if history_type != TextHistoryType::AsDate {
+                    asset.write_i8(history.time_style.into())?;
+                }
+                if history_type != TextHistoryType::AsDate
+                    || asset.get_object_version()
+                        >= ObjectVersion::VER_UE4_FTEXT_HISTORY_DATE_TIMEZONE
+                {
+                    asset.write_fstring(history.time_zone.as_deref())?;
+                }
+                asset.write_fstring(history.target_culture.as_deref())?;
+            }
+            TextHistory::Transform(history) => {
+                history.source_text.write(asset)?;
+                asset.write_u8(history.transform_type.into())?;
+            }
+            TextHistory::TextGenerator(history) => {
+                asset.write_fname(&history.generator_type_id)?;
+                if let Some(contents) = &history.generator_contents {
+                    asset.write_i32::<LE>(contents.len() as i32)?;
+                    asset.write_all(contents)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check if this history can be written as a given history type
+    pub fn matches(&self, history_type: TextHistoryType) -> bool {
+        matches!(
+            (self, history_type),
+            (TextHistory::NamedFormat(_), TextHistoryType::NamedFormat)
+                | (
+                    TextHistory::OrderedFormat(_),
+                    TextHistoryType::OrderedFormat
+                )
+                | (
+                    TextHistory::ArgumentFormat(_),
+                    TextHistoryType::ArgumentFormat
+                )
+                | (
+                    TextHistory::FormatNumber(_),
+                    TextHistoryType::AsNumber
+                        | TextHistoryType::AsPercent
+                        | TextHistoryType::AsCurrency
+                )
+                | (
+                    TextHistory::DateTime(_),
+                    TextHistoryType::AsDate | TextHistoryType::AsTime | TextHistoryType::AsDateTime
+                )
+                | (TextHistory::Transform(_), TextHistoryType::Transform)
+                | (
+                    TextHistory::TextGenerator(_),
+                    TextHistoryType::TextGenerator
+                )
+        )
+    }
+}
+
+/// Serialized FText
+#[derive(FNameContainer, Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[container_nobounds]
+pub struct FText {
+    /// Culture invariant string
+    pub culture_invariant_string: Option<String>,
+    /// Namespace
+    pub namespace: Option<String>,
+    /// String table id
+    pub table_id: Option<FName>,
+    /// Flags
+    pub flags: u32,
+    /// History type
+    pub history_type: TextHistoryType,
+    /// FString value
+    pub value: Option<String>,
+    /// History data of formatted and generated texts
+    pub history: Option<TextHistory>,
+}
+
+impl FText {
+    /// Read an `FText` from an asset
+    pub fn new<Reader: ArchiveReader<impl PackageIndexTrait>>(
+        asset: &mut Reader,
+    ) -> Result<Self, Error> {
+        let mut culture_invariant_string = None;
+        let mut namespace = None;
+        let mut value = None;
+
+        if asset.get_object_version() < ObjectVersion::VER_UE4_FTEXT_HISTORY {
+            culture_invariant_string = asset.read_fstring()?;
+            if asset.get_object_version()
+                >= ObjectVersion::VER_UE4_ADDED_NAMESPACE_AND_KEY_DATA_TO_FTEXT
+            {
+                namespace = asset.read_fstring()?;
+                value = asset.read_fstring()?;
+            } else {
+                namespace = None;
+                value = asset.read_fstring()?;
+            }
+        }
+
+        let flags = asset.read_u32::<LE>()?;
+        let mut history_type = TextHistoryType::Base;
+        let mut table_id = None;
+        let mut history = None;
+        if asset.get_object_version() >= ObjectVersion::VER_UE4_FTEXT_HISTORY {
+            history_type = TextHistoryType::try_from(asset.read_i8()?)?;
+
+            match history_type {
+                TextHistoryType::None => {
+                    value = None;
+                    let version: CustomVersion = asset.get_custom_version::<FEditorObjectVersion>();
+                    if version.version
+                        >= FEditorObjectVersion::CultureInvariantTextSerializationKeyStability
+                            as i32
+                    {
+                        let has_culture_invariant_string = asset.read_i32::<LE>()? == 1;
+                        if has_culture_invariant_string {
+                            culture_invariant_string = asset.read_fstring()?;
+                        }
+                    }
+                }
+                TextHistoryType::Base => {
+                    namespace = asset.read_fstring()?;
+                    value = asset.read_fstring()?;
+                    culture_invariant_string = asset.read_fstring()?;
+                }
+                TextHistoryType::StringTableEntry => {
+                    table_id = Some(asset.read_fname()?);
+                    value = asset.read_fstring()?;
+                }
+                TextHistoryType::RawText => {
+                    return Err(Error::unimplemented(format!(
+                        "Unimplemented reader for {history_type:?}"
+                    )));
+                }
+                _ => history = TextHistory::new(asset, history_type)?,
+            }
+        }
+
+        Ok(FText {
+            culture_invariant_string,
+            namespace,
+            table_id,
+            flags,
+            history_type,
+            value,
+            history,
+        })
+    }
+
+    /// Write an `FText` to an asset
+    pub fn write<Writer: ArchiveWriter<impl PackageIndexTrait>>(
+        &self,
+        asset: &mut Writer,
+    ) -> Result<(), Error> {
+        if asset.get_object_version() < ObjectVersion::VER_UE4_FTEXT_HISTORY {
+            asset.write_fstring(self.culture_invariant_string.as_deref())?;
+            if asset.get_object_version()
+                >= ObjectVersion::VER_UE4_ADDED_NAMESPACE_AND_KEY_DATA_TO_FTEXT
+            {
+                asset.write_fstring(self.namespace.as_deref())?;
+                asset.write_fstring(self.value.as_deref())?;
+            } else {
+                asset.write_fstring(self.value.as_deref())?;
+            }
+        }
+        asset.write_u32::<LE>(self.flags)?;
+
+        if asset.get_object_version() >= ObjectVersion::VER_UE4_FTEXT_HISTORY {
+            let history_type = self.history_type;
+            asset.write_i8(history_type.into())?;
+            match history_type {
+                TextHistoryType::None => {
+                    if asset.get_custom_version::<FEditorObjectVersion>().version
+                        >= FEditorObjectVersion::CultureInvariantTextSerializationKeyStability
+                            as i32
+                    {
+                        let is_empty = match &self.culture_invariant_string {
+                            Some(e) => e.is_empty(),
+                            None => true,
+                        };
+                        match is_empty {
+                            true => asset.write_i32::<LE>(0)?,
+                            false => {
+                                asset.write_i32::<LE>(1)?;
+                                asset.write_fstring(self.culture_invariant_string.as_deref())?;
+                            }
+                        }
+                    }
+                    Ok(())
+                }
+                TextHistoryType::Base => {
+                    asset.write_fstring(self.namespace.as_deref())?;
+                    asset.write_fstring(self.value.as_deref())?;
+                    asset.write_fstring(self.culture_invariant_string.as_deref())?;
+                    Ok(())
+                }
+                TextHistoryType::StringTableEntry => {
+                    asset.write_fname(self.table_id.as_ref().ok_or_else(|| {
+                        PropertyError::property_field_none("table_id", "FName")
+                    })?)?;
+                    asset.write_fstring(self.value.as_deref())?;
+                    Ok(())
+                }
+                TextHistoryType::RawText => Err(Error::unimplemented(format!(
+                    "Unimplemented writer for {}",
+                    history_type as i8
+                ))),
+                _ => match &self.history {
+                    Some(history) if history.matches(history_type) => {
+                        history.write(asset, history_type)
+                    }
+                    _ => Err(PropertyError::property_field_none(
+                        "history",
+                        &format!("{history_type:?} history"),
+                    )
+                    .into()),
+                },
+            }?;
+        }
+        Ok(())
+    }
+}
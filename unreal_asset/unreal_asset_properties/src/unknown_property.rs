@@ -13,8 +13,12 @@ pub struct UnknownProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
+    /// Complete property type name, only used in >= UE5.4 property tags
+    pub property_type_name: Option<PropertyTypeName>,
     /// Raw data
     pub value: Vec<u8>,
     /// Serialized type
@@ -33,7 +37,7 @@ impl UnknownProperty {
         duplication_index: i32,
         serialized_type: FName,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
         let mut value = vec![0u8; length as usize];
         asset.read_exact(&mut value)?;
 
@@ -41,7 +45,9 @@ impl UnknownProperty {
             name,
             ancestry,
             property_guid,
+            property_tag_extensions,
             duplication_index,
+            property_type_name: None,
             value,
             serialized_type,
         })
@@ -30,6 +30,8 @@ pub struct ViewTargetBlendParamsProperty {
     pub ancestry: Ancestry,
     /// Property guid
     pub property_guid: Option<Guid>,
+    /// Property tag extensions
+    pub property_tag_extensions: PropertyTagExtensions,
     /// Property duplication index
     pub duplication_index: i32,
     /// Blend time
@@ -54,7 +56,7 @@ impl ViewTargetBlendParamsProperty {
         _length: i64,
         duplication_index: i32,
     ) -> Result<Self, Error> {
-        let property_guid = optional_guid!(asset, include_header);
+        let (property_guid, property_tag_extensions) = optional_guid!(asset, include_header);
 
         let blend_time = OrderedFloat(asset.read_f32::<LE>()?);
         let blend_function = ViewTargetBlendFunction::try_from(asset.read_u8()?)?;
@@ -64,6 +66,7 @@ impl ViewTargetBlendParamsProperty {
         Ok(ViewTargetBlendParamsProperty {
             name,
             property_guid,
+            property_tag_extensions,
             ancestry,
             duplication_index,
             blend_time,
@@ -19,6 +19,9 @@ pub enum FStringError {
     /// String has invalid terminator
     #[error("Invalid string terminator {0} at position {1}")]
     InvalidStringTerminator(u16, u64),
+    /// String can't be written with the requested encoding
+    #[error("String {0:?} is not ASCII and can't be written with single byte characters")]
+    NonAsciiString(String),
     /// String is not in the expected UTF-8 format
     #[error("Utf8 Error {0}")]
     Utf8(#[from] FromUtf8Error),
@@ -38,4 +38,4 @@ pub use read_ext::UnrealReadExt;
 #[cfg(feature = "read_write")]
 pub mod write_ext;
 #[cfg(feature = "read_write")]
-pub use write_ext::UnrealWriteExt;
+pub use write_ext::{FStringEncoding, UnrealWriteExt};
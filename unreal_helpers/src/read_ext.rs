@@ -6,6 +6,7 @@ use std::mem::size_of;
 use byteorder::{ReadBytesExt, LE};
 
 use crate::error::FStringError;
+use crate::write_ext::FStringEncoding;
 
 /// Extension for anything that implements `Read` to more easily read Unreal data formats.
 pub trait UnrealReadExt {
@@ -26,6 +27,13 @@ pub trait UnrealReadExt {
 
     /// Read string of format \<length i32\>\<string\>\<null\>.
     fn read_fstring(&mut self) -> Result<Option<String>, FStringError>;
+
+    /// Read string of format \<length i32\>\<string\>\<null\> along with the encoding to write it back with.
+    ///
+    /// Wide strings are returned with `Utf16`, narrow strings with `Auto` so they stay narrow as long as they are ASCII.
+    fn read_fstring_with_encoding(
+        &mut self,
+    ) -> Result<(Option<String>, FStringEncoding), FStringError>;
 }
 
 impl<R: Read + Seek> UnrealReadExt for R {
@@ -51,13 +59,23 @@ impl<R: Read + Seek> UnrealReadExt for R {
     }
 
     fn read_fstring(&mut self) -> Result<Option<String>, FStringError> {
+        Ok(self.read_fstring_with_encoding()?.0)
+    }
+
+    fn read_fstring_with_encoding(
+        &mut self,
+    ) -> Result<(Option<String>, FStringEncoding), FStringError> {
         let len = self.read_i32::<LE>()?;
 
         let (len, is_wide) = match len < 0 {
             true => (-len, true),
             false => (len, false),
         };
-        read_fstring_len(self, len, is_wide)
+        let encoding = match is_wide {
+            true => FStringEncoding::Utf16,
+            false => FStringEncoding::Auto,
+        };
+        Ok((read_fstring_len(self, len, is_wide)?, encoding))
     }
 
     #[cfg(feature = "guid")]
@@ -7,6 +7,20 @@ use byteorder::{WriteBytesExt, LE};
 
 use crate::error::FStringError;
 
+/// Encoding used when writing an FString.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum FStringEncoding {
+    /// Single byte characters if the string is ASCII, UTF-16 otherwise.
+    #[default]
+    Auto,
+    /// Single byte characters, writing fails if the string isn't ASCII.
+    Ascii,
+    /// Single byte UTF-8 code units, the string's bytes are written as-is.
+    Utf8,
+    /// UTF-16 characters.
+    Utf16,
+}
+
 /// Extension for anything that implements `Write` to more easily write Unreal data formats.
 pub trait UnrealWriteExt {
     /// Write bool as u8.
@@ -27,6 +41,13 @@ pub trait UnrealWriteExt {
 
     /// Write string of format \<length i32\>\<string\>\<null\>.
     fn write_fstring(&mut self, string: Option<&str>) -> Result<usize, FStringError>;
+
+    /// Write string of format \<length i32\>\<string\>\<null\> with the given encoding.
+    fn write_fstring_with_encoding(
+        &mut self,
+        string: Option<&str>,
+        encoding: FStringEncoding,
+    ) -> Result<usize, FStringError>;
 }
 
 impl<W: Write> UnrealWriteExt for W {
@@ -55,8 +76,23 @@ impl<W: Write> UnrealWriteExt for W {
     }
 
     fn write_fstring(&mut self, string: Option<&str>) -> Result<usize, FStringError> {
+        self.write_fstring_with_encoding(string, FStringEncoding::Auto)
+    }
+
+    fn write_fstring_with_encoding(
+        &mut self,
+        string: Option<&str>,
+        encoding: FStringEncoding,
+    ) -> Result<usize, FStringError> {
         if let Some(string) = string {
-            let is_unicode = string.len() != string.chars().count();
+            let is_unicode = match encoding {
+                FStringEncoding::Auto => !string.is_ascii(),
+                FStringEncoding::Ascii if !string.is_ascii() => {
+                    return Err(FStringError::NonAsciiString(string.to_string()));
+                }
+                FStringEncoding::Ascii | FStringEncoding::Utf8 => false,
+                FStringEncoding::Utf16 => true,
+            };
 
             if is_unicode {
                 let utf16 = string.encode_utf16().collect::<Vec<_>>();
@@ -2,7 +2,7 @@
 
 use std::io::Cursor;
 
-use unreal_helpers::{error::FStringError, UnrealReadExt, UnrealWriteExt};
+use unreal_helpers::{error::FStringError, FStringEncoding, UnrealReadExt, UnrealWriteExt};
 
 #[test]
 fn test_read_fstring() -> Result<(), FStringError> {
@@ -16,6 +16,15 @@ fn test_read_fstring() -> Result<(), FStringError> {
     let maybe_string = cursor.read_fstring()?;
     assert_eq!(maybe_string, Some("\u{A7}".to_string()));
 
+    // Encoding, narrow strings are written back narrow only while they are ASCII
+    let mut cursor = Cursor::new(vec![5u8, 0u8, 0u8, 0u8, b't', b'e', b's', b't', 0u8]);
+    let (_, encoding) = cursor.read_fstring_with_encoding()?;
+    assert_eq!(encoding, FStringEncoding::Auto);
+
+    let mut cursor = Cursor::new(vec![0xfeu8, 0xffu8, 0xffu8, 0xffu8, b'a', 0u8, 0u8, 0u8]);
+    let (_, encoding) = cursor.read_fstring_with_encoding()?;
+    assert_eq!(encoding, FStringEncoding::Utf16);
+
     // Null
     let mut cursor = Cursor::new(vec![0u8; 4]);
     let maybe_string = cursor.read_fstring()?;
@@ -55,6 +64,19 @@ fn test_write_fstring() -> Result<(), FStringError> {
         &[0xfeu8, 0xffu8, 0xffu8, 0xffu8, 0xa7u8, 0u8, 0u8, 0u8],
     );
 
+    // Non-ASCII, single byte characters
+    let mut cursor = Cursor::new(Vec::new());
+    let err = cursor
+        .write_fstring_with_encoding(Some("\u{A7}"), FStringEncoding::Ascii)
+        .expect_err("Expected err");
+    assert!(matches!(err, FStringError::NonAsciiString(_)));
+    assert!(cursor.get_ref().is_empty());
+
+    // Non-ASCII, UTF-8
+    let mut cursor = Cursor::new(Vec::new());
+    cursor.write_fstring_with_encoding(Some("\u{A7}"), FStringEncoding::Utf8)?;
+    assert_eq!(cursor.get_ref(), &[3u8, 0u8, 0u8, 0u8, 0xc2u8, 0xa7u8, 0u8]);
+
     // Null
     let mut cursor = Cursor::new(Vec::new());
     cursor.write_fstring(None)?;